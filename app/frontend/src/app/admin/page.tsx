@@ -128,11 +128,19 @@ export default function AdminPage() {
     setActionLoading(true);
     try {
       const feedKey = new PublicKey(oracleFeed);
-      await client.configureOracle(bondType as BondType, feedKey, enabled);
-      addToast("success", `Oracle ${enabled ? "enabled" : "disabled"} for bond type ${bondType}`);
+      const changeId = await client.queueOracleChange(
+        bondType as BondType,
+        feedKey,
+        enabled
+      );
+      addToast(
+        "success",
+        `Oracle ${enabled ? "enable" : "disable"} queued for bond type ${bondType} ` +
+          `(change #${changeId}, executable after the vault timelock)`
+      );
       await fetchData();
     } catch (e: any) {
-      addToast("error", e.message ?? "Failed to configure oracle");
+      addToast("error", e.message ?? "Failed to queue oracle change");
     } finally {
       setActionLoading(false);
     }
@@ -146,15 +154,19 @@ export default function AdminPage() {
     setActionLoading(true);
     try {
       const attestorKey = new PublicKey(attestor);
-      await client.configureReserveAttestor(
+      const changeId = await client.queueReserveAttestorChange(
         bondType as BondType,
         attestorKey,
         new BN(maxStaleness)
       );
-      addToast("success", `Attestor configured for bond type ${bondType}`);
+      addToast(
+        "success",
+        `Attestor change queued for bond type ${bondType} ` +
+          `(change #${changeId}, executable after the vault timelock)`
+      );
       await fetchData();
     } catch (e: any) {
-      addToast("error", e.message ?? "Failed to configure attestor");
+      addToast("error", e.message ?? "Failed to queue attestor change");
     } finally {
       setActionLoading(false);
     }
@@ -0,0 +1,945 @@
+//! stablebond — admin CLI for the Stablebond Protocol. Every admin
+//! instruction on stablebond-core, stablebond-yield and
+//! stablebond-transfer-hook has a subcommand; `show` pretty-prints on-chain
+//! state.
+
+mod parse;
+mod show;
+mod tx;
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::builder::BoolishValueParser;
+use clap::{Args, Parser, Subcommand};
+use solana_sdk::signature::read_keypair_file;
+use stablebond_client::accounts::{
+    fetch, fetch_bond_registry, fetch_bond_vault, fetch_protocol_config, fetch_token_program,
+    fetch_yield_source,
+};
+use stablebond_client::instructions::protocol::Settlement;
+use stablebond_client::instructions::vault::VaultTokens;
+use stablebond_client::instructions::{protocol, transfer_hook, vault};
+use stablebond_client::pda;
+use stablebond_client::stablebond_core::instructions::{
+    InitializeProtocolParams, RegisterYieldSourceParams, UpdateProtocolConfigParams,
+    UpdateYieldSourceParams,
+};
+use stablebond_client::stablebond_core::state::{ConfigChange, PendingChange};
+use stablebond_client::stablebond_transfer_hook::InitializeHookParams;
+use stablebond_client::stablebond_types::{
+    BondConfig, BondType, FreezeReason, LimitWindow, YieldSourceType,
+};
+use stablebond_client::stablebond_yield::state::{PendingVaultChange, VaultChange};
+use stablebond_client::{
+    Pubkey, RpcClient, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, TRANSFER_HOOK_PROGRAM_ID,
+};
+
+use tx::Submitter;
+
+#[derive(Parser)]
+#[command(version, about = "Stablebond Protocol admin CLI")]
+struct Cli {
+    /// JSON-RPC endpoint
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+    /// Commitment used for reads and confirmation
+    #[arg(long, global = true, default_value = "confirmed")]
+    commitment: String,
+    /// Solana CLI JSON keypair of the signer [default: ~/.config/solana/id.json]
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<PathBuf>,
+    /// Pass the signer's role assignment (for delegated admins)
+    #[arg(long, global = true)]
+    with_role: bool,
+    /// Simulate transactions and print logs instead of submitting
+    #[arg(long, global = true)]
+    dry_run: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the protocol config, USDC vault and bond registry
+    InitializeProtocol {
+        /// Settlement mint (SPL Token or Token-2022)
+        #[arg(long)]
+        usdc_mint: Pubkey,
+        #[arg(long)]
+        treasury: Pubkey,
+        /// Accredit KYC registry program
+        #[arg(long)]
+        kyc_registry: Pubkey,
+        /// Sovereign identity program
+        #[arg(long)]
+        sovereign_program: Pubkey,
+        #[arg(long, default_value_t = 0)]
+        conversion_fee_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        management_fee_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        performance_fee_bps: u16,
+    },
+    /// Add a bond to the registry
+    RegisterBond {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        /// Settlement currency mint
+        #[arg(long)]
+        currency_mint: Pubkey,
+        /// FX price feed (currency vs USD)
+        #[arg(long, default_value_t = Pubkey::default())]
+        oracle_feed: Pubkey,
+        /// ISO currency code [default: the bond type's currency]
+        #[arg(long, value_parser = parse::currency)]
+        denomination: Option<[u8; 3]>,
+        #[arg(long)]
+        coupon_rate_bps: u16,
+        /// Unix timestamp (0 = rolling)
+        #[arg(long, default_value_t = 0)]
+        maturity_date: i64,
+        #[arg(long)]
+        face_value: u64,
+        #[arg(long, default_value_t = 0)]
+        haircut_bps: u16,
+        /// [default: the bond type's baseline APY]
+        #[arg(long)]
+        default_apy_bps: Option<u16>,
+        /// Minimum Sovereign tier (0-4)
+        #[arg(long, default_value_t = 1)]
+        min_tier: u8,
+        /// Register the bond as inactive
+        #[arg(long)]
+        inactive: bool,
+    },
+    /// Register a yield source for a token mint
+    RegisterYieldSource {
+        /// Share token mint; seeds the YieldSource PDA
+        #[arg(long)]
+        token_mint: Pubkey,
+        /// Display name (max 32 bytes)
+        #[arg(long, value_parser = parse::name)]
+        name: [u8; 32],
+        #[arg(long, value_parser = parse::source_type, default_value = "sovereign-bond")]
+        source_type: YieldSourceType,
+        #[arg(long, value_parser = parse::bond_type)]
+        bond_type: BondType,
+        #[arg(long)]
+        deposit_vault: Pubkey,
+        #[arg(long)]
+        yield_token_vault: Pubkey,
+        #[arg(long)]
+        currency_mint: Pubkey,
+        /// Conversion price feed
+        #[arg(long)]
+        oracle_feed: Pubkey,
+        #[arg(long)]
+        coupon_rate_bps: u16,
+        /// Unix timestamp (0 = rolling)
+        #[arg(long, default_value_t = 0)]
+        maturity_date: i64,
+        #[arg(long, default_value_t = 0)]
+        haircut_bps: u16,
+        #[arg(long, default_value_t = 10_000)]
+        allocation_weight_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        min_deposit: u64,
+        #[arg(long, default_value_t = u64::MAX)]
+        max_allocation: u64,
+    },
+    /// Pause every protocol operation
+    Pause,
+    /// Resume every protocol operation
+    Resume,
+    /// Set which operations are paused, protocol-wide or for one yield source
+    SetPauseFlags {
+        /// Comma-separated operations, `all` or `none`
+        #[arg(value_parser = parse::pause_flags)]
+        paused_ops: u8,
+        /// Set the flags of this yield source instead of the protocol's
+        #[arg(long)]
+        token_mint: Option<Pubkey>,
+    },
+    /// Clear a tripped circuit breaker, optionally accepting the rejected NAV
+    ResetCircuitBreaker {
+        token_mint: Pubkey,
+        #[arg(long)]
+        accept_nav: bool,
+    },
+    /// Replace a member's delegated roles
+    SetRoles {
+        member: Pubkey,
+        /// Comma-separated roles, `all` or `none`
+        #[arg(value_parser = parse::roles)]
+        roles: u8,
+    },
+    /// Propose a new protocol authority; it must run `accept-authority`
+    ProposeAuthority { new_authority: Pubkey },
+    /// Accept a proposed protocol authority transfer (signed by the new authority)
+    AcceptAuthority,
+    /// Freeze a user's position (compliance officer)
+    Freeze {
+        owner: Pubkey,
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        #[arg(long, value_parser = parse::freeze_reason)]
+        reason: FreezeReason,
+    },
+    /// Unfreeze a user's position (compliance officer)
+    Unfreeze {
+        owner: Pubkey,
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+    },
+    /// Queue a timelocked config change; prints its change id
+    #[command(subcommand)]
+    QueueChange(ChangeCommand),
+    /// Apply a queued config change once its delay has passed
+    ExecuteChange { change_id: u64 },
+    /// Drop a queued config change
+    CancelChange { change_id: u64 },
+    /// Lengthen the config timelock (takes effect at once)
+    IncreaseTimelockDelay { seconds: i64 },
+    /// Grow accounts created by an earlier program version to the current layout
+    #[command(subcommand)]
+    Migrate(MigrateCommand),
+    /// Bond vault administration (stablebond-yield)
+    #[command(subcommand)]
+    Vault(VaultCommand),
+    /// Pretty-print on-chain accounts
+    #[command(subcommand)]
+    Show(ShowCommand),
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    #[arg(long)]
+    treasury: Option<Pubkey>,
+    #[arg(long)]
+    conversion_fee_bps: Option<u16>,
+    #[arg(long)]
+    management_fee_bps: Option<u16>,
+    #[arg(long)]
+    performance_fee_bps: Option<u16>,
+    /// Limit window per tier 0-4, e.g. `calendar,calendar,rolling,rolling,rolling`
+    #[arg(long, value_parser = parse::limit_window, value_delimiter = ',')]
+    tier_limit_windows: Option<Vec<LimitWindow>>,
+    #[arg(long)]
+    compliance_officer: Option<Pubkey>,
+}
+
+impl ConfigArgs {
+    fn params(self) -> anyhow::Result<UpdateProtocolConfigParams> {
+        Ok(UpdateProtocolConfigParams {
+            treasury: self.treasury,
+            conversion_fee_bps: self.conversion_fee_bps,
+            management_fee_bps: self.management_fee_bps,
+            performance_fee_bps: self.performance_fee_bps,
+            tier_limit_windows: self
+                .tier_limit_windows
+                .map(<[LimitWindow; 5]>::try_from)
+                .transpose()
+                .map_err(|_| anyhow!("--tier-limit-windows takes exactly 5 values"))?,
+            compliance_officer: self.compliance_officer,
+        })
+    }
+}
+
+#[derive(Args)]
+struct YieldSourceArgs {
+    #[arg(long)]
+    allocation_weight_bps: Option<u16>,
+    #[arg(long)]
+    min_deposit: Option<u64>,
+    #[arg(long)]
+    max_allocation: Option<u64>,
+    /// on/off
+    #[arg(long, value_parser = BoolishValueParser::new())]
+    active: Option<bool>,
+    /// Circuit breaker: max NAV move per update (0 = unchecked)
+    #[arg(long)]
+    max_nav_change_bps: Option<u16>,
+    /// Circuit breaker: max NAV move per UTC day (0 = unchecked)
+    #[arg(long)]
+    max_daily_nav_change_bps: Option<u16>,
+}
+
+impl From<YieldSourceArgs> for UpdateYieldSourceParams {
+    fn from(args: YieldSourceArgs) -> Self {
+        Self {
+            allocation_weight_bps: args.allocation_weight_bps,
+            min_deposit: args.min_deposit,
+            max_allocation: args.max_allocation,
+            is_active: args.active,
+            max_nav_change_bps: args.max_nav_change_bps,
+            max_daily_nav_change_bps: args.max_daily_nav_change_bps,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ChangeCommand {
+    /// Protocol fees, treasury, limit windows or compliance officer
+    Config(ConfigArgs),
+    /// Yield source allocation, limits or circuit breaker bounds
+    YieldSource {
+        token_mint: Pubkey,
+        #[command(flatten)]
+        params: YieldSourceArgs,
+    },
+    /// Shorten the timelock delay
+    Delay { seconds: i64 },
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+    /// Migrate the protocol config; run before the other migrations
+    Config,
+    /// Migrate the yield source for a token mint
+    YieldSource { token_mint: Pubkey },
+    /// Migrate a user's position for a bond type (any signer may pay)
+    Position {
+        owner: Pubkey,
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+    },
+}
+
+#[derive(Subcommand)]
+enum VaultCommand {
+    /// Create the vault, share mint and currency vault for a bond type, seeded
+    /// with the locked deposit from the signer's currency account. The signer
+    /// must also be the stablebond-yield upgrade authority.
+    Init {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        #[arg(long)]
+        currency_mint: Pubkey,
+        /// Currency account funding the seed deposit [default: the signer's ATA]
+        #[arg(long)]
+        authority_currency: Option<Pubkey>,
+        /// Create the share mint under Token-2022 instead of SPL Token
+        #[arg(long)]
+        token_2022_shares: bool,
+        /// Route share transfers through the KYC transfer hook (Token-2022
+        /// shares); run `vault transfer-hook` afterwards
+        #[arg(long, requires = "token_2022_shares")]
+        kyc_transfer_hook: bool,
+        /// Fallback APY [default: the bond type's baseline APY]
+        #[arg(long)]
+        target_apy_bps: Option<u16>,
+        #[arg(long)]
+        coupon_rate_bps: u16,
+        /// Unix timestamp (0 = rolling)
+        #[arg(long, default_value_t = 0)]
+        maturity_date: i64,
+    },
+    /// Set the minimum reserve coverage below which deposits and accrual stop
+    SetMinCoverage {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        min_coverage_bps: u16,
+    },
+    /// Allow or forbid legacy immediate withdrawals
+    ImmediateWithdraw {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        /// on/off
+        #[arg(value_parser = BoolishValueParser::new())]
+        allow: bool,
+    },
+    /// Write the KYC transfer hook's extra-account list for a vault created
+    /// with --kyc-transfer-hook
+    TransferHook {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        /// Seed prefix of Accredit whitelist entries ([seed, wallet])
+        #[arg(long)]
+        whitelist_seed: String,
+        /// Seed prefix of Sovereign identities ([seed, wallet])
+        #[arg(long)]
+        identity_seed: String,
+        /// Rewrite an existing list from the current protocol config
+        #[arg(long)]
+        update: bool,
+    },
+    /// Move the signer's legacy authority-seeded vault into the bond type's
+    /// vault; holders then swap shares with `migrate_shares`
+    Migrate {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+    },
+    /// Propose a new vault authority; it must run `vault accept-authority`
+    ProposeAuthority {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        new_authority: Pubkey,
+    },
+    /// Accept a proposed vault authority transfer (signed by the new authority)
+    AcceptAuthority {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+    },
+    /// Set the vault's compliance officer
+    SetComplianceOfficer {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        officer: Pubkey,
+    },
+    /// Freeze a holder's share account (compliance officer)
+    Freeze {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        owner: Pubkey,
+        #[arg(long, value_parser = parse::freeze_reason)]
+        reason: FreezeReason,
+        /// Share account to freeze [default: the owner's ATA]
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+    /// Thaw a holder's share account (compliance officer)
+    Thaw {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        owner: Pubkey,
+        /// Share account to thaw [default: the owner's ATA]
+        #[arg(long)]
+        token_account: Option<Pubkey>,
+    },
+    /// Set which vault operations are paused
+    SetPauseFlags {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        /// Comma-separated operations, `all` or `none`
+        #[arg(value_parser = parse::pause_flags)]
+        paused_ops: u8,
+    },
+    /// Replace a member's delegated roles on the vault
+    SetRoles {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        member: Pubkey,
+        /// Comma-separated roles, `all` or `none`
+        #[arg(value_parser = parse::roles)]
+        roles: u8,
+    },
+    /// Queue a timelocked vault change; prints its change id
+    QueueChange {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        #[command(subcommand)]
+        change: VaultChangeCommand,
+    },
+    /// Apply a queued vault change once its delay has passed
+    ExecuteChange {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        change_id: u64,
+    },
+    /// Drop a queued vault change
+    CancelChange {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        change_id: u64,
+    },
+    /// Lengthen the vault timelock (takes effect at once)
+    IncreaseTimelockDelay {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        seconds: i64,
+    },
+    /// Set the share token's name, symbol and URI (Token-2022 shares)
+    SetMetadata {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        symbol: String,
+        /// Off-chain metadata JSON
+        #[arg(long, default_value = "")]
+        uri: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum VaultChangeCommand {
+    /// Fallback target APY
+    Apy { apy_bps: u16 },
+    /// Bond price oracle; enables oracle pricing unless `--disable`
+    Oracle {
+        #[arg(long)]
+        feed: Pubkey,
+        /// Keep the feed but price at the target APY
+        #[arg(long)]
+        disable: bool,
+    },
+    /// Shorten the timelock delay
+    Delay { seconds: i64 },
+    /// Single proof-of-reserve attestor and maximum attestation age
+    Attestor {
+        attestor: Pubkey,
+        /// Seconds before an attestation is stale
+        #[arg(long)]
+        max_staleness: i64,
+    },
+    /// Quorum attestor set
+    AttestorSet {
+        /// Comma-separated attestor keys
+        #[arg(long, value_delimiter = ',', required = true)]
+        attestors: Vec<Pubkey>,
+        /// Matching attestations needed (at least 2)
+        #[arg(long)]
+        threshold: u8,
+        /// Seconds within which attestations count toward one round
+        #[arg(long)]
+        window: i64,
+        /// Maximum spread between attested reserves
+        #[arg(long, default_value_t = 0)]
+        tolerance_bps: u16,
+    },
+}
+
+#[derive(Subcommand)]
+enum ShowCommand {
+    /// ProtocolConfig
+    Config,
+    /// BondRegistry
+    Registry,
+    /// YieldSource for a token mint
+    YieldSource { token_mint: Pubkey },
+    /// BondVault for a bond type
+    Vault {
+        #[arg(value_parser = parse::bond_type)]
+        bond_type: BondType,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let rpc = RpcClient::new_with_commitment(&cli.url, &cli.commitment);
+
+    if let Command::Show(command) = &cli.command {
+        return run_show(&rpc, command);
+    }
+
+    let keypair_path = match &cli.keypair {
+        Some(path) => path.clone(),
+        None => default_keypair_path()?,
+    };
+    let signer = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow!("reading keypair {}: {e}", keypair_path.display()))?;
+    let submitter = Submitter::new(&rpc, signer, cli.dry_run);
+    let authority = submitter.pubkey();
+    let with_role = cli.with_role;
+
+    let ix = match cli.command {
+        Command::InitializeProtocol {
+            usdc_mint,
+            treasury,
+            kyc_registry,
+            sovereign_program,
+            conversion_fee_bps,
+            management_fee_bps,
+            performance_fee_bps,
+        } => protocol::initialize_protocol(
+            authority,
+            &Settlement {
+                mint: usdc_mint,
+                token_program: fetch_token_program(&rpc, &usdc_mint)?,
+            },
+            InitializeProtocolParams {
+                treasury,
+                kyc_registry,
+                sovereign_program,
+                conversion_fee_bps,
+                management_fee_bps,
+                performance_fee_bps,
+            },
+        ),
+        Command::RegisterBond {
+            bond_type,
+            currency_mint,
+            oracle_feed,
+            denomination,
+            coupon_rate_bps,
+            maturity_date,
+            face_value,
+            haircut_bps,
+            default_apy_bps,
+            min_tier,
+            inactive,
+        } => protocol::register_bond(
+            authority,
+            BondConfig {
+                bond_type,
+                currency_mint,
+                denomination_currency: denomination
+                    .unwrap_or_else(|| bond_type.denomination_currency()),
+                oracle_feed,
+                coupon_rate_bps,
+                maturity_date,
+                face_value,
+                haircut_bps,
+                default_apy_bps: default_apy_bps.unwrap_or_else(|| bond_type.default_apy_bps()),
+                min_tier,
+                is_active: !inactive,
+            },
+        ),
+        Command::RegisterYieldSource {
+            token_mint,
+            name,
+            source_type,
+            bond_type,
+            deposit_vault,
+            yield_token_vault,
+            currency_mint,
+            oracle_feed,
+            coupon_rate_bps,
+            maturity_date,
+            haircut_bps,
+            allocation_weight_bps,
+            min_deposit,
+            max_allocation,
+        } => protocol::register_yield_source(
+            authority,
+            token_mint,
+            RegisterYieldSourceParams {
+                name,
+                source_type,
+                bond_type,
+                deposit_vault,
+                yield_token_vault,
+                currency_mint,
+                oracle_feed,
+                coupon_rate_bps,
+                maturity_date,
+                haircut_bps,
+                allocation_weight_bps,
+                min_deposit,
+                max_allocation,
+            },
+        ),
+        Command::Pause => protocol::pause_protocol(authority, with_role),
+        Command::Resume => protocol::resume_protocol(authority, with_role),
+        Command::SetPauseFlags {
+            paused_ops,
+            token_mint: None,
+        } => protocol::set_pause_flags(authority, paused_ops, with_role),
+        Command::SetPauseFlags {
+            paused_ops,
+            token_mint: Some(token_mint),
+        } => protocol::set_yield_source_pause_flags(authority, token_mint, paused_ops, with_role),
+        Command::ResetCircuitBreaker {
+            token_mint,
+            accept_nav,
+        } => protocol::reset_circuit_breaker(authority, token_mint, accept_nav),
+        Command::SetRoles { member, roles } => protocol::set_member_roles(authority, member, roles),
+        Command::ProposeAuthority { new_authority } => {
+            protocol::propose_authority(authority, new_authority)
+        }
+        Command::AcceptAuthority => protocol::accept_authority(authority),
+        Command::Freeze {
+            owner,
+            bond_type,
+            reason,
+        } => protocol::freeze_position(authority, owner, bond_type, reason),
+        Command::Unfreeze { owner, bond_type } => {
+            protocol::unfreeze_position(authority, owner, bond_type)
+        }
+        Command::QueueChange(command) => {
+            let change = match command {
+                ChangeCommand::Config(args) => ConfigChange::ProtocolConfig(args.params()?),
+                ChangeCommand::YieldSource { token_mint, params } => ConfigChange::YieldSource {
+                    yield_source: pda::find_yield_source_pda(
+                        &pda::find_protocol_config_pda().0,
+                        &token_mint,
+                    )
+                    .0,
+                    params: params.into(),
+                },
+                ChangeCommand::Delay { seconds } => ConfigChange::TimelockDelay(seconds),
+            };
+            let change_id = fetch_protocol_config(&rpc)?.next_change_id;
+            println!("Change id: {change_id}");
+            protocol::queue_config_change(authority, change_id, change, with_role)
+        }
+        Command::ExecuteChange { change_id } => {
+            let pending = fetch_pending_change(&rpc, change_id)?;
+            let yield_source = match pending.change {
+                ConfigChange::YieldSource { yield_source, .. } => Some(yield_source),
+                _ => None,
+            };
+            protocol::execute_config_change(authority, change_id, pending.proposer, yield_source)
+        }
+        Command::CancelChange { change_id } => {
+            let pending = fetch_pending_change(&rpc, change_id)?;
+            protocol::cancel_config_change(authority, change_id, pending.proposer)
+        }
+        Command::IncreaseTimelockDelay { seconds } => {
+            protocol::increase_timelock_delay(authority, seconds)
+        }
+        Command::Migrate(MigrateCommand::Config) => protocol::migrate_protocol_config(authority),
+        Command::Migrate(MigrateCommand::YieldSource { token_mint }) => {
+            protocol::migrate_yield_source(authority, token_mint)
+        }
+        Command::Migrate(MigrateCommand::Position { owner, bond_type }) => {
+            protocol::migrate_user_position(authority, owner, bond_type)
+        }
+        Command::Vault(command) => vault_instruction(&rpc, authority, with_role, command)?,
+        Command::Show(_) => unreachable!("handled above"),
+    };
+    submitter.submit(ix)
+}
+
+fn vault_instruction(
+    rpc: &RpcClient,
+    authority: Pubkey,
+    with_role: bool,
+    command: VaultCommand,
+) -> anyhow::Result<stablebond_client::Instruction> {
+    Ok(match command {
+        VaultCommand::Init {
+            bond_type,
+            currency_mint,
+            authority_currency,
+            token_2022_shares,
+            kyc_transfer_hook,
+            target_apy_bps,
+            coupon_rate_bps,
+            maturity_date,
+        } => {
+            let tokens = VaultTokens {
+                currency_mint,
+                token_program: fetch_token_program(rpc, &currency_mint)?,
+                share_token_program: if token_2022_shares {
+                    TOKEN_2022_PROGRAM_ID
+                } else {
+                    TOKEN_PROGRAM_ID
+                },
+            };
+            vault::initialize_vault(
+                authority,
+                authority,
+                &tokens,
+                authority_currency.unwrap_or_else(|| {
+                    pda::find_associated_token_address_with_program(
+                        &authority,
+                        &currency_mint,
+                        &tokens.token_program,
+                    )
+                }),
+                bond_type,
+                target_apy_bps.unwrap_or_else(|| bond_type.default_apy_bps()),
+                coupon_rate_bps,
+                maturity_date,
+                kyc_transfer_hook.then_some(TRANSFER_HOOK_PROGRAM_ID),
+            )
+        }
+        VaultCommand::SetMinCoverage {
+            bond_type,
+            min_coverage_bps,
+        } => vault::set_min_coverage(authority, bond_type, min_coverage_bps, with_role),
+        VaultCommand::ImmediateWithdraw { bond_type, allow } => {
+            vault::set_immediate_withdraw(authority, bond_type, allow, with_role)
+        }
+        VaultCommand::TransferHook {
+            bond_type,
+            whitelist_seed,
+            identity_seed,
+            update,
+        } => {
+            let params = InitializeHookParams {
+                whitelist_seed: whitelist_seed.into_bytes(),
+                identity_seed: identity_seed.into_bytes(),
+            };
+            if update {
+                transfer_hook::update_extra_account_meta_list(authority, bond_type, params)
+            } else {
+                transfer_hook::initialize_extra_account_meta_list(authority, bond_type, params)
+            }
+        }
+        VaultCommand::Migrate { bond_type } => {
+            let vault = fetch_bond_vault(rpc, bond_type)?;
+            vault::migrate_vault(authority, bond_type, &VaultTokens::fetch(rpc, &vault)?)
+        }
+        VaultCommand::ProposeAuthority {
+            bond_type,
+            new_authority,
+        } => vault::propose_authority(authority, bond_type, new_authority),
+        VaultCommand::AcceptAuthority { bond_type } => {
+            vault::accept_authority(authority, bond_type)
+        }
+        VaultCommand::SetComplianceOfficer { bond_type, officer } => {
+            vault::set_compliance_officer(authority, bond_type, officer)
+        }
+        VaultCommand::Freeze {
+            bond_type,
+            owner,
+            reason,
+            token_account,
+        } => {
+            let (shares_account, share_token_program) =
+                share_account(rpc, bond_type, &owner, token_account)?;
+            vault::freeze_shares(
+                authority,
+                bond_type,
+                shares_account,
+                share_token_program,
+                reason,
+            )
+        }
+        VaultCommand::Thaw {
+            bond_type,
+            owner,
+            token_account,
+        } => {
+            let (shares_account, share_token_program) =
+                share_account(rpc, bond_type, &owner, token_account)?;
+            vault::thaw_shares(authority, bond_type, shares_account, share_token_program)
+        }
+        VaultCommand::SetPauseFlags {
+            bond_type,
+            paused_ops,
+        } => vault::set_vault_pause_flags(authority, bond_type, paused_ops, with_role),
+        VaultCommand::SetRoles {
+            bond_type,
+            member,
+            roles,
+        } => vault::set_member_roles(authority, bond_type, member, roles),
+        VaultCommand::QueueChange { bond_type, change } => {
+            let change = match change {
+                VaultChangeCommand::Apy { apy_bps } => VaultChange::Apy(apy_bps),
+                VaultChangeCommand::Oracle { feed, disable } => VaultChange::Oracle {
+                    oracle_feed: feed,
+                    enabled: !disable,
+                },
+                VaultChangeCommand::Delay { seconds } => VaultChange::TimelockDelay(seconds),
+                VaultChangeCommand::Attestor {
+                    attestor,
+                    max_staleness,
+                } => VaultChange::ReserveAttestor {
+                    attestor,
+                    max_staleness,
+                },
+                VaultChangeCommand::AttestorSet {
+                    attestors,
+                    threshold,
+                    window,
+                    tolerance_bps,
+                } => VaultChange::AttestorSet {
+                    attestors,
+                    threshold,
+                    window,
+                    tolerance_bps,
+                },
+            };
+            let change_id = fetch_bond_vault(rpc, bond_type)?.next_change_id;
+            println!("Change id: {change_id}");
+            vault::queue_vault_change(authority, bond_type, change_id, change, with_role)
+        }
+        VaultCommand::ExecuteChange {
+            bond_type,
+            change_id,
+        } => {
+            let pending = fetch_pending_vault_change(rpc, bond_type, change_id)?;
+            vault::execute_vault_change(
+                authority,
+                bond_type,
+                change_id,
+                pending.proposer,
+                matches!(pending.change, VaultChange::AttestorSet { .. }),
+            )
+        }
+        VaultCommand::CancelChange {
+            bond_type,
+            change_id,
+        } => {
+            let pending = fetch_pending_vault_change(rpc, bond_type, change_id)?;
+            vault::cancel_vault_change(authority, bond_type, change_id, pending.proposer)
+        }
+        VaultCommand::IncreaseTimelockDelay { bond_type, seconds } => {
+            vault::increase_vault_timelock_delay(authority, bond_type, seconds)
+        }
+        VaultCommand::SetMetadata {
+            bond_type,
+            name,
+            symbol,
+            uri,
+        } => vault::set_share_metadata(
+            authority,
+            bond_type,
+            fetch_token_program(rpc, &pda::find_bond_share_mint_pda(bond_type).0)?,
+            name,
+            symbol,
+            uri,
+        ),
+    })
+}
+
+fn run_show(rpc: &RpcClient, command: &ShowCommand) -> anyhow::Result<()> {
+    let config = pda::find_protocol_config_pda().0;
+    match command {
+        ShowCommand::Config => show::protocol_config(&config, &fetch_protocol_config(rpc)?),
+        ShowCommand::Registry => show::bond_registry(
+            &pda::find_bond_registry_pda(&config).0,
+            &fetch_bond_registry(rpc)?,
+        ),
+        ShowCommand::YieldSource { token_mint } => show::yield_source(
+            &pda::find_yield_source_pda(&config, token_mint).0,
+            &fetch_yield_source(rpc, token_mint)?,
+        ),
+        ShowCommand::Vault { bond_type } => show::bond_vault(
+            &pda::find_bond_vault_pda(*bond_type).0,
+            &fetch_bond_vault(rpc, *bond_type)?,
+        ),
+    }
+    Ok(())
+}
+
+/// A holder's share account (`token_account` or their ATA) and the share
+/// mint's token program.
+fn share_account(
+    rpc: &RpcClient,
+    bond_type: BondType,
+    owner: &Pubkey,
+    token_account: Option<Pubkey>,
+) -> anyhow::Result<(Pubkey, Pubkey)> {
+    let share_mint = pda::find_bond_share_mint_pda(bond_type).0;
+    let share_token_program = fetch_token_program(rpc, &share_mint)?;
+    let shares_account = token_account.unwrap_or_else(|| {
+        pda::find_associated_token_address_with_program(owner, &share_mint, &share_token_program)
+    });
+    Ok((shares_account, share_token_program))
+}
+
+fn fetch_pending_change(rpc: &RpcClient, change_id: u64) -> anyhow::Result<PendingChange> {
+    let config = pda::find_protocol_config_pda().0;
+    Ok(fetch(
+        rpc,
+        &pda::find_pending_change_pda(&config, change_id).0,
+    )?)
+}
+
+fn fetch_pending_vault_change(
+    rpc: &RpcClient,
+    bond_type: BondType,
+    change_id: u64,
+) -> anyhow::Result<PendingVaultChange> {
+    let vault = pda::find_bond_vault_pda(bond_type).0;
+    Ok(fetch(
+        rpc,
+        &pda::find_pending_vault_change_pda(&vault, change_id).0,
+    )?)
+}
+
+fn default_keypair_path() -> anyhow::Result<PathBuf> {
+    let home = std::env::var_os("HOME").ok_or_else(|| anyhow!("HOME not set; pass --keypair"))?;
+    Ok(PathBuf::from(home).join(".config/solana/id.json"))
+}
@@ -0,0 +1,184 @@
+//! Argument parsers for protocol enums and fixed-size fields.
+
+use stablebond_client::stablebond_types::{
+    BondType, FreezeReason, LimitWindow, PauseFlag, Role, YieldSourceType,
+};
+
+const BOND_TYPES: [BondType; 5] = [
+    BondType::UsTBill,
+    BondType::MxCetes,
+    BondType::BrTesouro,
+    BondType::JpJgb,
+    BondType::Custom,
+];
+
+pub const PAUSE_FLAGS: [PauseFlag; 6] = [
+    PauseFlag::Deposits,
+    PauseFlag::Conversions,
+    PauseFlag::WithdrawalRequests,
+    PauseFlag::WithdrawalClaims,
+    PauseFlag::YieldClaims,
+    PauseFlag::NavUpdates,
+];
+
+/// Accepts `us-tbill`, `mx-cetes`, `br-tesouro`, `jp-jgb`, `custom`, the
+/// display name (`"US T-Bill"`) or the numeric discriminant (`0`-`4`).
+pub fn bond_type(raw: &str) -> Result<BondType, String> {
+    let normalized = normalize(raw);
+    BOND_TYPES
+        .into_iter()
+        .find(|b| {
+            normalized == normalize(b.as_str())
+                || normalized == normalize(slug(*b))
+                || normalized == b.as_u8().to_string()
+        })
+        .ok_or_else(|| {
+            let slugs: Vec<_> = BOND_TYPES.into_iter().map(slug).collect();
+            format!(
+                "unknown bond type `{raw}` (expected one of {})",
+                slugs.join(", ")
+            )
+        })
+}
+
+pub fn slug(bond_type: BondType) -> &'static str {
+    match bond_type {
+        BondType::UsTBill => "us-tbill",
+        BondType::MxCetes => "mx-cetes",
+        BondType::BrTesouro => "br-tesouro",
+        BondType::JpJgb => "jp-jgb",
+        BondType::Custom => "custom",
+    }
+}
+
+pub fn source_type(raw: &str) -> Result<YieldSourceType, String> {
+    match normalize(raw).as_str() {
+        "tbill" => Ok(YieldSourceType::TBill),
+        "lending" => Ok(YieldSourceType::Lending),
+        "staking" => Ok(YieldSourceType::Staking),
+        "synthetic" => Ok(YieldSourceType::Synthetic),
+        "sovereignbond" => Ok(YieldSourceType::SovereignBond),
+        _ => Err(format!(
+            "unknown source type `{raw}` (expected t-bill, lending, staking, synthetic \
+             or sovereign-bond)"
+        )),
+    }
+}
+
+pub fn limit_window(raw: &str) -> Result<LimitWindow, String> {
+    match normalize(raw).as_str() {
+        "calendar" | "calendarmonth" => Ok(LimitWindow::CalendarMonth),
+        "rolling" | "rolling30day" => Ok(LimitWindow::Rolling30Day),
+        _ => Err(format!(
+            "unknown limit window `{raw}` (expected calendar or rolling)"
+        )),
+    }
+}
+
+pub fn freeze_reason(raw: &str) -> Result<FreezeReason, String> {
+    match normalize(raw).as_str() {
+        "sanctions" => Ok(FreezeReason::Sanctions),
+        "legalhold" => Ok(FreezeReason::LegalHold),
+        "fraud" | "fraudinvestigation" => Ok(FreezeReason::FraudInvestigation),
+        "kycrevoked" => Ok(FreezeReason::KycRevoked),
+        "other" => Ok(FreezeReason::Other),
+        _ => Err(format!(
+            "unknown freeze reason `{raw}` (expected sanctions, legal-hold, fraud, kyc-revoked \
+             or other)"
+        )),
+    }
+}
+
+/// Comma-separated operations (`deposits,yield-claims`), `all` or `none`.
+pub fn pause_flags(raw: &str) -> Result<u8, String> {
+    mask(raw, PauseFlag::ALL_MASK, |part| {
+        PAUSE_FLAGS
+            .into_iter()
+            .find(|flag| part == normalize(flag.as_str()))
+            .map(|flag| flag.bit())
+            .ok_or_else(|| {
+                format!(
+                    "unknown operation `{part}` (expected deposits, conversions, \
+                     withdrawal-requests, withdrawal-claims, yield-claims or nav-updates)"
+                )
+            })
+    })
+}
+
+/// Comma-separated roles (`pauser,fee-manager`), `all` or `none`.
+pub fn roles(raw: &str) -> Result<u8, String> {
+    mask(raw, Role::ALL_MASK, |part| {
+        Role::ALL
+            .into_iter()
+            .find(|role| part == normalize(role.as_str()))
+            .map(|role| role.bit())
+            .ok_or_else(|| {
+                format!(
+                    "unknown role `{part}` (expected pauser, fee-manager, oracle-admin, \
+                     attestor-admin or keeper)"
+                )
+            })
+    })
+}
+
+fn mask(raw: &str, all: u8, bit: impl Fn(&str) -> Result<u8, String>) -> Result<u8, String> {
+    match normalize(raw).as_str() {
+        "none" => return Ok(0),
+        "all" => return Ok(all),
+        _ => {}
+    }
+    raw.split(',')
+        .map(|part| bit(&normalize(part)))
+        .try_fold(0, |mask, bit| Ok(mask | bit?))
+}
+
+/// Yield source name, zero-padded to 32 bytes.
+pub fn name(raw: &str) -> Result<[u8; 32], String> {
+    let bytes = raw.as_bytes();
+    if bytes.len() > 32 {
+        return Err(format!("name is {} bytes, max 32", bytes.len()));
+    }
+    let mut name = [0u8; 32];
+    name[..bytes.len()].copy_from_slice(bytes);
+    Ok(name)
+}
+
+/// Three-letter ISO currency code.
+pub fn currency(raw: &str) -> Result<[u8; 3], String> {
+    let upper = raw.to_ascii_uppercase();
+    <[u8; 3]>::try_from(upper.as_bytes())
+        .ok()
+        .filter(|code| code.iter().all(u8::is_ascii_alphabetic))
+        .ok_or_else(|| format!("`{raw}` is not a 3-letter currency code"))
+}
+
+fn normalize(raw: &str) -> String {
+    raw.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_parse_names_all_and_none() {
+        assert_eq!(
+            pause_flags("deposits, Yield-Claims").unwrap(),
+            PauseFlag::Deposits.bit() | PauseFlag::YieldClaims.bit()
+        );
+        assert_eq!(pause_flags("all").unwrap(), PauseFlag::ALL_MASK);
+        assert_eq!(pause_flags("none").unwrap(), 0);
+        assert!(pause_flags("deposits,bogus").is_err());
+
+        assert_eq!(
+            roles("pauser,fee-manager").unwrap(),
+            Role::Pauser.bit() | Role::FeeManager.bit()
+        );
+        assert_eq!(roles("ALL").unwrap(), Role::ALL_MASK);
+        assert_eq!(roles("none").unwrap(), 0);
+        assert!(roles("admin").is_err());
+    }
+}
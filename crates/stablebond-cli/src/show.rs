@@ -0,0 +1,230 @@
+//! Human-readable rendering of protocol accounts.
+
+use stablebond_client::stablebond_core::state::{BondRegistry, ProtocolConfig, YieldSource};
+use stablebond_client::stablebond_types::{BondConfig, LimitWindow, PauseFlag};
+use stablebond_client::stablebond_yield::state::BondVault;
+use stablebond_client::Pubkey;
+
+use crate::parse::{slug, PAUSE_FLAGS};
+
+/// Label/value rows printed with aligned columns.
+struct Table(Vec<(&'static str, String)>);
+
+impl Table {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn row(&mut self, label: &'static str, value: impl ToString) -> &mut Self {
+        self.0.push((label, value.to_string()));
+        self
+    }
+
+    fn print(&self, indent: usize) {
+        let width = self
+            .0
+            .iter()
+            .map(|(label, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        for (label, value) in &self.0 {
+            println!("{:indent$}{label:<width$}  {value}", "");
+        }
+    }
+}
+
+pub fn protocol_config(address: &Pubkey, config: &ProtocolConfig) {
+    println!("ProtocolConfig {address}");
+    let windows: Vec<_> = config
+        .tier_limit_windows
+        .iter()
+        .map(|w| match w {
+            LimitWindow::CalendarMonth => "calendar",
+            LimitWindow::Rolling30Day => "rolling",
+        })
+        .collect();
+    Table::new()
+        .row("authority", config.authority)
+        .row("pending authority", optional_key(&config.pending_authority))
+        .row(
+            "compliance officer",
+            optional_key(&config.compliance_officer),
+        )
+        .row("treasury", config.treasury)
+        .row("usdc mint", config.usdc_mint)
+        .row("usdc vault", config.usdc_vault)
+        .row("kyc registry", config.kyc_registry)
+        .row("sovereign program", config.sovereign_program)
+        .row("bond registry", config.bond_registry)
+        .row("conversion fee", bps(config.conversion_fee_bps))
+        .row("management fee", bps(config.management_fee_bps))
+        .row("performance fee", bps(config.performance_fee_bps))
+        .row("total deposits", config.total_deposits)
+        .row("total yield earned", config.total_yield_earned)
+        .row("pending conversion", config.pending_conversion)
+        .row("deposit nonce", config.deposit_nonce)
+        .row("supported bonds", config.num_supported_bonds)
+        .row("active", config.is_active)
+        .row("paused", pause_flags(config.paused_ops))
+        .row("tier limit windows", windows.join(", "))
+        .row("timelock delay", seconds(config.timelock_delay))
+        .row("next change id", config.next_change_id)
+        .row("created at", config.created_at)
+        .row("updated at", config.updated_at)
+        .print(2);
+}
+
+pub fn bond_registry(address: &Pubkey, registry: &BondRegistry) {
+    println!("BondRegistry {address} ({} bonds)", registry.bonds.len());
+    for bond in &registry.bonds {
+        bond_config(bond);
+    }
+}
+
+fn bond_config(bond: &BondConfig) {
+    println!();
+    println!("  {} [{}]", bond.bond_type.as_str(), slug(bond.bond_type));
+    Table::new()
+        .row("currency mint", bond.currency_mint)
+        .row(
+            "denomination",
+            String::from_utf8_lossy(&bond.denomination_currency),
+        )
+        .row("fx oracle", optional_key(&bond.oracle_feed))
+        .row("coupon rate", bps(bond.coupon_rate_bps))
+        .row("default apy", bps(bond.default_apy_bps))
+        .row("maturity date", maturity(bond.maturity_date))
+        .row("face value", bond.face_value)
+        .row("haircut", bps(bond.haircut_bps))
+        .row("min tier", bond.min_tier)
+        .row("active", bond.is_active)
+        .print(4);
+}
+
+pub fn yield_source(address: &Pubkey, source: &YieldSource) {
+    let name = String::from_utf8_lossy(&source.name);
+    println!("YieldSource {address} ({})", name.trim_end_matches('\0'));
+    Table::new()
+        .row("source type", source.source_type.as_str())
+        .row("bond type", source.bond_type.as_str())
+        .row("token mint", source.token_mint)
+        .row("currency mint", source.currency_mint)
+        .row("deposit vault", source.deposit_vault)
+        .row("yield token vault", source.yield_token_vault)
+        .row("bond vault", optional_key(&source.bond_vault))
+        .row("oracle feed", optional_key(&source.oracle_feed))
+        .row("current apy", bps(source.current_apy_bps))
+        .row("coupon rate", bps(source.coupon_rate_bps))
+        .row("maturity date", maturity(source.maturity_date))
+        .row("haircut", bps(source.haircut_bps))
+        .row("allocation weight", bps(source.allocation_weight_bps))
+        .row("min deposit", source.min_deposit)
+        .row("max allocation", source.max_allocation)
+        .row("total deposited", source.total_deposited)
+        .row("total shares", source.total_shares)
+        .row("nav per share", nav(source.nav_per_share))
+        .row("last nav update", source.last_nav_update)
+        .row("max nav change", bps(source.max_nav_change_bps))
+        .row("max daily nav change", bps(source.max_daily_nav_change_bps))
+        .row("circuit breaker", breaker(source))
+        .row("active", source.is_active)
+        .row("paused", pause_flags(source.paused_ops))
+        .print(2);
+}
+
+pub fn bond_vault(address: &Pubkey, vault: &BondVault) {
+    println!("BondVault {address} ({})", vault.bond_type.as_str());
+    Table::new()
+        .row("authority", vault.authority)
+        .row("pending authority", optional_key(&vault.pending_authority))
+        .row(
+            "compliance officer",
+            optional_key(&vault.compliance_officer),
+        )
+        .row("currency mint", vault.currency_mint)
+        .row("share mint", vault.share_mint)
+        .row("currency vault", vault.currency_vault)
+        .row("target apy", bps(vault.target_apy_bps))
+        .row("coupon rate", bps(vault.coupon_rate_bps))
+        .row("maturity date", maturity(vault.maturity_date))
+        .row("total deposits", vault.total_deposits)
+        .row("total shares", vault.total_shares)
+        .row("nav per share", nav(vault.nav_per_share))
+        .row("last accrual", vault.last_accrual)
+        .row("oracle feed", optional_key(&vault.oracle_feed))
+        .row("oracle enabled", vault.oracle_enabled)
+        .row("last oracle price", nav(vault.last_oracle_price))
+        .row("reserve attestor", optional_key(&vault.reserve_attestor))
+        .row("attested reserve", vault.attested_reserve)
+        .row("last attestation", vault.last_attestation_at)
+        .row("max staleness", seconds(vault.attestation_max_staleness))
+        .row("min coverage", bps(vault.min_coverage_bps))
+        .row(
+            "attested coverage",
+            format!("{} bps", vault.attested_coverage_bps),
+        )
+        .row("attested liabilities", vault.attested_liabilities)
+        .row("net flow since", vault.net_flow_since_attestation)
+        .row(
+            "current coverage",
+            format!("{} bps", vault.current_coverage_bps()),
+        )
+        .row("immediate withdraw", vault.allow_immediate_withdraw)
+        .row("timelock delay", seconds(vault.timelock_delay))
+        .row("next change id", vault.next_change_id)
+        .row("active", vault.is_active)
+        .row("paused", pause_flags(vault.paused_ops))
+        .print(2);
+}
+
+fn bps(value: u16) -> String {
+    format!("{value} bps ({}.{:02}%)", value / 100, value % 100)
+}
+
+/// 1e6-scaled fixed point value.
+fn nav(value: u64) -> String {
+    format!("{}.{:06}", value / 1_000_000, value % 1_000_000)
+}
+
+fn seconds(value: i64) -> String {
+    match value {
+        0 => "disabled".to_string(),
+        _ => format!("{value}s"),
+    }
+}
+
+fn maturity(value: i64) -> String {
+    match value {
+        0 => "rolling".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+fn optional_key(key: &Pubkey) -> String {
+    if *key == Pubkey::default() {
+        "(none)".to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+fn pause_flags(mask: u8) -> String {
+    let paused: Vec<_> = PAUSE_FLAGS
+        .iter()
+        .filter(|flag| flag.is_in(mask))
+        .map(PauseFlag::as_str)
+        .collect();
+    if paused.is_empty() {
+        "none".to_string()
+    } else {
+        paused.join(", ")
+    }
+}
+
+fn breaker(source: &YieldSource) -> String {
+    if source.circuit_breaker_tripped {
+        format!("TRIPPED at nav {}", nav(source.tripped_nav))
+    } else {
+        "ok".to_string()
+    }
+}
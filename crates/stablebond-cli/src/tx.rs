@@ -0,0 +1,87 @@
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use stablebond_client::{RpcClient, SignatureStatus};
+
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Signs admin transactions and either submits them or, with `--dry-run`,
+/// simulates them and prints the program logs.
+pub struct Submitter<'a> {
+    rpc: &'a RpcClient,
+    signer: Keypair,
+    dry_run: bool,
+}
+
+impl<'a> Submitter<'a> {
+    pub fn new(rpc: &'a RpcClient, signer: Keypair, dry_run: bool) -> Self {
+        Self {
+            rpc,
+            signer,
+            dry_run,
+        }
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.signer.pubkey()
+    }
+
+    pub fn submit(&self, instruction: Instruction) -> anyhow::Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.signer.pubkey()),
+            &[&self.signer],
+            blockhash,
+        );
+        let wire = bincode::serialize(&tx)?;
+
+        if self.dry_run {
+            return self.simulate(&wire);
+        }
+        let signature = self.rpc.send_transaction(&wire)?;
+        println!("Signature: {signature}");
+        self.confirm(&signature)
+    }
+
+    fn simulate(&self, wire: &[u8]) -> anyhow::Result<()> {
+        let result = self.rpc.simulate_transaction(wire)?;
+        for line in &result.logs {
+            println!("  {line}");
+        }
+        if let Some(units) = result.units_consumed {
+            println!("Compute units: {units}");
+        }
+        match result.err {
+            Some(err) => bail!("simulation failed: {err}"),
+            None => {
+                println!("Simulation succeeded (not submitted)");
+                Ok(())
+            }
+        }
+    }
+
+    fn confirm(&self, signature: &str) -> anyhow::Result<()> {
+        let deadline = Instant::now() + CONFIRM_TIMEOUT;
+        while Instant::now() < deadline {
+            match self.rpc.get_signature_status(signature)? {
+                Some(SignatureStatus { err: Some(err), .. }) => {
+                    bail!("transaction failed: {err}")
+                }
+                Some(status) if status.confirmed => {
+                    println!("Confirmed ({})", self.rpc.commitment());
+                    return Ok(());
+                }
+                _ => sleep(Duration::from_millis(500)),
+            }
+        }
+        Err(anyhow!(
+            "{signature} not confirmed within {CONFIRM_TIMEOUT:?}"
+        ))
+    }
+}
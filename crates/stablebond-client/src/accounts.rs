@@ -0,0 +1,192 @@
+//! Typed account fetching and decoding.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator, Owner};
+use stablebond_core::state::{
+    BondRegistry, PendingDeposit, ProtocolConfig, UserPosition, WithdrawalRequest, YieldSource,
+};
+use stablebond_types::BondType;
+use stablebond_yield::state::{AttestationHistory, AttestorSet, BondVault, UserShares};
+
+use crate::error::{ClientError, Result};
+use crate::pda;
+
+/// Raw account access. Implemented by [`crate::RpcClient`]; implement it for
+/// any other RPC client (or an in-memory bank) to reuse the typed helpers.
+pub trait AccountFetcher {
+    /// Account data, or `None` if the account does not exist.
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>>;
+
+    /// Program that owns the account, or `None` if it does not exist.
+    fn get_account_owner(&self, address: &Pubkey) -> Result<Option<Pubkey>>;
+
+    /// All accounts owned by `program_id` whose data starts with `prefix`.
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        prefix: &[u8],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>>;
+}
+
+/// Decode an Anchor account, checking its discriminator.
+pub fn decode<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|e| ClientError::Decode {
+        address: *address,
+        reason: e.to_string(),
+    })
+}
+
+/// Fetch and decode one account; `None` if it does not exist.
+pub fn fetch_optional<T: AccountDeserialize>(
+    fetcher: &impl AccountFetcher,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    fetcher
+        .get_account_data(address)?
+        .map(|data| decode(address, &data))
+        .transpose()
+}
+
+/// Fetch and decode one account; errors if it does not exist.
+pub fn fetch<T: AccountDeserialize>(fetcher: &impl AccountFetcher, address: &Pubkey) -> Result<T> {
+    fetch_optional(fetcher, address)?.ok_or(ClientError::AccountNotFound(*address))
+}
+
+/// Fetch and decode every account of type `T` owned by its program.
+pub fn fetch_all<T: AccountDeserialize + Discriminator + Owner>(
+    fetcher: &impl AccountFetcher,
+) -> Result<Vec<(Pubkey, T)>> {
+    fetcher
+        .get_program_accounts(&T::owner(), T::DISCRIMINATOR)?
+        .into_iter()
+        .map(|(address, data)| Ok((address, decode(&address, &data)?)))
+        .collect()
+}
+
+/// Result of [`fetch_all_partial`].
+#[derive(Debug)]
+pub struct PartialFetch<T> {
+    pub accounts: Vec<(Pubkey, T)>,
+    /// Accounts with the right discriminator that failed to decode
+    pub undecodable: Vec<Pubkey>,
+}
+
+/// Like [`fetch_all`], but accounts that fail to decode (such as legacy
+/// layouts still awaiting migration) are returned by address instead of
+/// failing the whole fetch.
+pub fn fetch_all_partial<T: AccountDeserialize + Discriminator + Owner>(
+    fetcher: &impl AccountFetcher,
+) -> Result<PartialFetch<T>> {
+    let mut fetched = PartialFetch {
+        accounts: Vec::new(),
+        undecodable: Vec::new(),
+    };
+    for (address, data) in fetcher.get_program_accounts(&T::owner(), T::DISCRIMINATOR)? {
+        match decode(&address, &data) {
+            Ok(account) => fetched.accounts.push((address, account)),
+            Err(_) => fetched.undecodable.push(address),
+        }
+    }
+    Ok(fetched)
+}
+
+/// Token program that owns `mint`: SPL Token or Token-2022.
+pub fn fetch_token_program(fetcher: &impl AccountFetcher, mint: &Pubkey) -> Result<Pubkey> {
+    let owner = fetcher
+        .get_account_owner(mint)?
+        .ok_or(ClientError::AccountNotFound(*mint))?;
+    if owner == crate::TOKEN_PROGRAM_ID || owner == crate::TOKEN_2022_PROGRAM_ID {
+        Ok(owner)
+    } else {
+        Err(ClientError::Decode {
+            address: *mint,
+            reason: format!("not a token mint (owned by {owner})"),
+        })
+    }
+}
+
+// ─── stablebond-core ────────────────────────────────────────────────────────────
+
+pub fn fetch_protocol_config(fetcher: &impl AccountFetcher) -> Result<ProtocolConfig> {
+    fetch(fetcher, &pda::find_protocol_config_pda().0)
+}
+
+pub fn fetch_bond_registry(fetcher: &impl AccountFetcher) -> Result<BondRegistry> {
+    let config = pda::find_protocol_config_pda().0;
+    fetch(fetcher, &pda::find_bond_registry_pda(&config).0)
+}
+
+pub fn fetch_yield_source(
+    fetcher: &impl AccountFetcher,
+    token_mint: &Pubkey,
+) -> Result<YieldSource> {
+    let config = pda::find_protocol_config_pda().0;
+    fetch(fetcher, &pda::find_yield_source_pda(&config, token_mint).0)
+}
+
+pub fn fetch_user_position(
+    fetcher: &impl AccountFetcher,
+    owner: &Pubkey,
+    bond_type: BondType,
+) -> Result<Option<UserPosition>> {
+    let config = pda::find_protocol_config_pda().0;
+    fetch_optional(
+        fetcher,
+        &pda::find_user_position_pda(&config, owner, bond_type).0,
+    )
+}
+
+pub fn fetch_pending_deposit(
+    fetcher: &impl AccountFetcher,
+    user: &Pubkey,
+    nonce: u64,
+) -> Result<Option<PendingDeposit>> {
+    let config = pda::find_protocol_config_pda().0;
+    fetch_optional(
+        fetcher,
+        &pda::find_pending_deposit_pda(&config, user, nonce).0,
+    )
+}
+
+pub fn fetch_withdrawal_request(
+    fetcher: &impl AccountFetcher,
+    user: &Pubkey,
+    nonce: u64,
+) -> Result<Option<WithdrawalRequest>> {
+    let config = pda::find_protocol_config_pda().0;
+    fetch_optional(
+        fetcher,
+        &pda::find_withdrawal_request_pda(&config, user, nonce).0,
+    )
+}
+
+// ─── stablebond-yield ───────────────────────────────────────────────────────────
+
+pub fn fetch_bond_vault(fetcher: &impl AccountFetcher, bond_type: BondType) -> Result<BondVault> {
+    fetch(fetcher, &pda::find_bond_vault_pda(bond_type).0)
+}
+
+pub fn fetch_user_shares(
+    fetcher: &impl AccountFetcher,
+    bond_type: BondType,
+    user: &Pubkey,
+) -> Result<Option<UserShares>> {
+    let vault = pda::find_bond_vault_pda(bond_type).0;
+    fetch_optional(fetcher, &pda::find_bond_shares_pda(&vault, user).0)
+}
+
+pub fn fetch_attestor_set(
+    fetcher: &impl AccountFetcher,
+    bond_type: BondType,
+) -> Result<Option<AttestorSet>> {
+    let vault = pda::find_bond_vault_pda(bond_type).0;
+    fetch_optional(fetcher, &pda::find_attestor_set_pda(&vault).0)
+}
+
+pub fn fetch_attestation_history(
+    fetcher: &impl AccountFetcher,
+    bond_type: BondType,
+) -> Result<Option<AttestationHistory>> {
+    let vault = pda::find_bond_vault_pda(bond_type).0;
+    fetch_optional(fetcher, &pda::find_attestation_history_pda(&vault).0)
+}
@@ -0,0 +1,621 @@
+//! Instruction builders for the Stablebond programs. Each builder derives every PDA the
+//! instruction needs; callers only pass signers, token accounts and external
+//! accounts (oracles, KYC records) that cannot be derived.
+//!
+//! Role-gated builders take `with_role`: set it when the signer acts through a
+//! role assignment rather than as the program authority.
+
+pub mod protocol;
+pub mod transfer_hook;
+pub mod vault;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+use crate::{Instruction, Pubkey};
+
+fn build(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    use anchor_lang::idl::types::IdlInstructionAccountItem;
+    use anchor_lang::solana_program::sysvar;
+    use anchor_lang::{system_program, AccountDeserialize};
+    use stablebond_core::instructions::{
+        InitializeProtocolParams, RegisterYieldSourceParams, UpdateProtocolConfigParams,
+    };
+    use stablebond_core::state::{ConfigChange, PendingDeposit, YieldSource};
+    use stablebond_transfer_hook::InitializeHookParams;
+    use stablebond_types::{BondConfig, BondType, FreezeReason, YieldSourceType};
+    use stablebond_yield::state::{ReserveHolding, VaultChange};
+
+    use super::{protocol, transfer_hook, vault};
+    use crate::pda;
+    use crate::{Instruction, Pubkey};
+
+    const USER: Pubkey = Pubkey::new_from_array([2; 32]);
+    const MINT: Pubkey = Pubkey::new_from_array([3; 32]);
+    /// Admin, keeper and compliance signer; holds the role assignments
+    const MEMBER: Pubkey = Pubkey::new_from_array([5; 32]);
+    /// Creator of the legacy vault in the migration builders
+    const AUTHORITY: Pubkey = Pubkey::new_from_array([6; 32]);
+    /// Any account the builders pass through unchanged
+    const OTHER: Pubkey = Pubkey::new_from_array([8; 32]);
+    const BOND: BondType = BondType::UsTBill;
+    const NONCE: u64 = 7;
+    const CHANGE_ID: u64 = 7;
+
+    /// The program's own account list for an `Accounts` struct, in order.
+    macro_rules! program_accounts {
+        ($ty:ty) => {
+            <$ty>::__anchor_private_gen_idl_accounts(&mut BTreeMap::new(), &mut BTreeMap::new())
+        };
+    }
+
+    fn flatten(items: Vec<IdlInstructionAccountItem>, out: &mut Vec<(String, bool, bool, bool)>) {
+        for item in items {
+            match item {
+                IdlInstructionAccountItem::Single(a) => {
+                    out.push((a.name, a.signer, a.writable, a.optional))
+                }
+                IdlInstructionAccountItem::Composite(c) => flatten(c.accounts, out),
+            }
+        }
+    }
+
+    /// Read `ix`'s account metas back as the program's named accounts,
+    /// checking count, order-dependent signer/writable flags and the
+    /// program-id placeholder Anchor uses for an omitted optional account.
+    fn decode(ix: &Instruction, items: Vec<IdlInstructionAccountItem>) -> BTreeMap<String, Pubkey> {
+        let mut expected = Vec::new();
+        flatten(items, &mut expected);
+        assert_eq!(ix.accounts.len(), expected.len(), "account count");
+
+        let mut named = BTreeMap::new();
+        for (meta, (name, signer, writable, optional)) in ix.accounts.iter().zip(expected) {
+            if optional && meta.pubkey == ix.program_id {
+                assert!(
+                    !meta.is_signer && !meta.is_writable,
+                    "{name}: omitted optional"
+                );
+                continue;
+            }
+            assert_eq!(meta.is_signer, signer, "{name}: signer");
+            assert_eq!(meta.is_writable, writable, "{name}: writable");
+            named.insert(name, meta.pubkey);
+        }
+        named
+    }
+
+    /// Address every derivable account must take for the fixed inputs above.
+    fn expected_address(program: &Pubkey, name: &str) -> Option<Pubkey> {
+        let config = pda::find_protocol_config_pda().0;
+        let bond_vault = pda::find_bond_vault_pda(BOND).0;
+        let legacy_vault = pda::find_legacy_bond_vault_pda(&AUTHORITY, BOND).0;
+        let common = match name {
+            "system_program" => Some(system_program::ID),
+            "rent" => Some(sysvar::rent::ID),
+            "protocol_config" => Some(config),
+            "bond_vault" | "bond_vault_config" | "vault_config" => Some(bond_vault),
+            "share_mint" => Some(pda::find_bond_share_mint_pda(BOND).0),
+            _ => None,
+        };
+        if common.is_some() {
+            return common;
+        }
+
+        let address = if *program == stablebond_core::ID {
+            match name {
+                "bond_registry" => pda::find_bond_registry_pda(&config).0,
+                "usdc_vault" => pda::find_usdc_vault_pda().0,
+                "yield_source" => pda::find_yield_source_pda(&config, &MINT).0,
+                "user_position" => pda::find_user_position_pda(&config, &USER, BOND).0,
+                "user_limit" => pda::find_user_limit_pda(&config, &USER).0,
+                "pending_deposit" => pda::find_pending_deposit_pda(&config, &USER, NONCE).0,
+                "conversion_record" => pda::find_conversion_record_pda(&config, &USER, NONCE).0,
+                "withdrawal_request" => pda::find_withdrawal_request_pda(&config, &USER, NONCE).0,
+                "role_assignment" => pda::find_role_assignment_pda(&config, &MEMBER).0,
+                "pending_change" => pda::find_pending_change_pda(&config, CHANGE_ID).0,
+                _ => return None,
+            }
+        } else if *program == stablebond_yield::ID {
+            match name {
+                "program" => stablebond_yield::ID,
+                "program_data" => {
+                    Pubkey::from_str("BZSHybzWu6Q3rvDZEXP1SuZxNq39pzKZkdfbazf4nNas").unwrap()
+                }
+                "currency_vault" => pda::find_bond_currency_vault_pda(BOND).0,
+                "locked_shares" => pda::find_bond_locked_shares_pda(BOND).0,
+                "user_shares" => pda::find_bond_shares_pda(&bond_vault, &USER).0,
+                "role_assignment" => pda::find_vault_role_pda(&bond_vault, &MEMBER).0,
+                "pending_change" => pda::find_pending_vault_change_pda(&bond_vault, CHANGE_ID).0,
+                "attestor_set" => pda::find_attestor_set_pda(&bond_vault).0,
+                "attestation_history" => pda::find_attestation_history_pda(&bond_vault).0,
+                "legacy_vault" => legacy_vault,
+                "legacy_currency_vault" => {
+                    pda::find_legacy_bond_currency_vault_pda(&AUTHORITY, BOND).0
+                }
+                "legacy_share_mint" => pda::find_legacy_bond_share_mint_pda(&AUTHORITY, BOND).0,
+                "migration" => pda::find_vault_migration_pda(&legacy_vault).0,
+                "legacy_token_program" => anchor_spl::token::ID,
+                "extra_account_meta_list" => {
+                    pda::find_extra_account_metas_pda(&pda::find_bond_share_mint_pda(BOND).0).0
+                }
+                _ => return None,
+            }
+        } else {
+            match name {
+                "extra_account_meta_list" => {
+                    pda::find_extra_account_metas_pda(&pda::find_bond_share_mint_pda(BOND).0).0
+                }
+                _ => return None,
+            }
+        };
+        Some(address)
+    }
+
+    /// Decode `ix` against the program's accounts and check every derivable
+    /// account; returns the decoded accounts for builder-specific checks.
+    fn check(ix: Instruction, items: Vec<IdlInstructionAccountItem>) -> BTreeMap<String, Pubkey> {
+        let named = decode(&ix, items);
+        for (name, address) in &named {
+            if let Some(expected) = expected_address(&ix.program_id, name) {
+                assert_eq!(*address, expected, "{name}");
+            }
+        }
+        named
+    }
+
+    fn yield_source() -> YieldSource {
+        let mut ys =
+            YieldSource::try_deserialize_unchecked(&mut &vec![0u8; YieldSource::LEN][..]).unwrap();
+        ys.token_mint = MINT;
+        ys.bond_type = BOND;
+        ys.deposit_vault = OTHER;
+        ys.bond_vault = pda::find_bond_vault_pda(BOND).0;
+        ys
+    }
+
+    fn pending_deposit() -> PendingDeposit {
+        let mut pending =
+            PendingDeposit::try_deserialize_unchecked(&mut &vec![0u8; PendingDeposit::LEN][..])
+                .unwrap();
+        pending.user = USER;
+        pending.bond_type = BOND;
+        pending.nonce = NONCE;
+        pending
+    }
+
+    fn settlement() -> protocol::Settlement {
+        protocol::Settlement {
+            mint: OTHER,
+            token_program: anchor_spl::token::ID,
+        }
+    }
+
+    fn gate() -> protocol::DepositGate {
+        protocol::DepositGate {
+            fx_oracle: OTHER,
+            whitelist_entry: OTHER,
+            sovereign_identity: OTHER,
+        }
+    }
+
+    fn tokens() -> vault::VaultTokens {
+        vault::VaultTokens {
+            currency_mint: OTHER,
+            token_program: anchor_spl::token::ID,
+            share_token_program: anchor_spl::token_2022::ID,
+        }
+    }
+
+    fn protocol_params() -> UpdateProtocolConfigParams {
+        UpdateProtocolConfigParams {
+            treasury: None,
+            conversion_fee_bps: Some(10),
+            management_fee_bps: None,
+            performance_fee_bps: None,
+            tier_limit_windows: None,
+            compliance_officer: None,
+        }
+    }
+
+    #[test]
+    fn core_builders_match_program_accounts() {
+        use stablebond_core::instructions as ix;
+
+        let ys = yield_source();
+        let settlement = settlement();
+        let gate = gate();
+
+        check(
+            protocol::initialize_protocol(
+                MEMBER,
+                &settlement,
+                InitializeProtocolParams {
+                    treasury: OTHER,
+                    kyc_registry: OTHER,
+                    sovereign_program: OTHER,
+                    conversion_fee_bps: 30,
+                    management_fee_bps: 100,
+                    performance_fee_bps: 1000,
+                },
+            ),
+            program_accounts!(ix::InitializeProtocol),
+        );
+        check(
+            protocol::register_bond(
+                MEMBER,
+                BondConfig {
+                    bond_type: BOND,
+                    currency_mint: OTHER,
+                    denomination_currency: *b"USD",
+                    oracle_feed: OTHER,
+                    coupon_rate_bps: 450,
+                    maturity_date: 0,
+                    face_value: 1_000_000,
+                    haircut_bps: 0,
+                    default_apy_bps: 450,
+                    min_tier: 1,
+                    is_active: true,
+                },
+            ),
+            program_accounts!(ix::RegisterBond),
+        );
+        check(
+            protocol::register_yield_source(
+                MEMBER,
+                MINT,
+                RegisterYieldSourceParams {
+                    name: [0; 32],
+                    source_type: YieldSourceType::SovereignBond,
+                    bond_type: BOND,
+                    deposit_vault: OTHER,
+                    yield_token_vault: OTHER,
+                    currency_mint: OTHER,
+                    oracle_feed: OTHER,
+                    coupon_rate_bps: 450,
+                    maturity_date: 0,
+                    haircut_bps: 0,
+                    allocation_weight_bps: 10_000,
+                    min_deposit: 1,
+                    max_allocation: 1,
+                },
+            ),
+            program_accounts!(ix::RegisterYieldSource),
+        );
+        check(
+            protocol::deposit_cross_currency(
+                USER,
+                MINT,
+                &protocol::CrossCurrencySource {
+                    mint: OTHER,
+                    user_token: OTHER,
+                    vault: OTHER,
+                    token_program: anchor_spl::token::ID,
+                },
+                &gate,
+                NONCE,
+                1,
+                BOND,
+                1,
+            ),
+            program_accounts!(ix::DepositCrossCurrency),
+        );
+        check(
+            protocol::deposit_direct(USER, &ys, &settlement, OTHER, &gate, 1, BOND),
+            program_accounts!(ix::DepositDirect),
+        );
+        let capacity = check(
+            protocol::get_deposit_capacity(USER, BOND, true),
+            program_accounts!(ix::GetDepositCapacity),
+        );
+        assert!(capacity.contains_key("user_position"));
+        check(
+            protocol::execute_conversion(
+                MEMBER,
+                pda::find_pending_deposit_pda(&pda::find_protocol_config_pda().0, &USER, NONCE).0,
+                &pending_deposit(),
+                OTHER,
+                &ys,
+                &settlement,
+                true,
+            ),
+            program_accounts!(ix::ExecuteConversion),
+        );
+        check(
+            protocol::withdraw(USER, &ys, &settlement, OTHER, 1, BOND),
+            program_accounts!(ix::Withdraw),
+        );
+        check(
+            protocol::request_withdrawal(USER, MINT, NONCE, 1, BOND),
+            program_accounts!(ix::RequestWithdrawal),
+        );
+        check(
+            protocol::claim_withdrawal(USER, &ys, &settlement, OTHER, BOND, NONCE),
+            program_accounts!(ix::ClaimWithdrawal),
+        );
+        check(
+            protocol::cancel_withdrawal(USER, MINT, BOND, NONCE),
+            program_accounts!(ix::CancelWithdrawal),
+        );
+        check(
+            protocol::claim_yield(USER, &ys, &settlement, OTHER, BOND),
+            program_accounts!(ix::ClaimYield),
+        );
+        check(
+            protocol::update_nav(MEMBER, &ys, true),
+            program_accounts!(ix::UpdateNav),
+        );
+        for ix in [
+            protocol::pause_protocol(MEMBER, true),
+            protocol::resume_protocol(MEMBER, true),
+            protocol::set_pause_flags(MEMBER, 1, true),
+        ] {
+            check(ix, program_accounts!(ix::PauseProtocol));
+        }
+        check(
+            protocol::set_yield_source_pause_flags(MEMBER, MINT, 1, true),
+            program_accounts!(ix::SetYieldSourcePauseFlags),
+        );
+        check(
+            protocol::reset_circuit_breaker(MEMBER, MINT, true),
+            program_accounts!(ix::ResetCircuitBreaker),
+        );
+        check(
+            protocol::set_member_roles(AUTHORITY, MEMBER, 1),
+            program_accounts!(ix::SetMemberRoles),
+        );
+        check(
+            protocol::propose_authority(MEMBER, OTHER),
+            program_accounts!(ix::ProposeAuthority),
+        );
+        check(
+            protocol::accept_authority(MEMBER),
+            program_accounts!(ix::AcceptAuthority),
+        );
+        for ix in [
+            protocol::freeze_position(MEMBER, USER, BOND, FreezeReason::Sanctions),
+            protocol::unfreeze_position(MEMBER, USER, BOND),
+        ] {
+            check(ix, program_accounts!(ix::SetPositionFreeze));
+        }
+        check(
+            protocol::queue_config_change(
+                MEMBER,
+                CHANGE_ID,
+                ConfigChange::ProtocolConfig(protocol_params()),
+                true,
+            ),
+            program_accounts!(ix::QueueConfigChange),
+        );
+        check(
+            protocol::execute_config_change(
+                MEMBER,
+                CHANGE_ID,
+                OTHER,
+                Some(pda::find_yield_source_pda(&pda::find_protocol_config_pda().0, &MINT).0),
+            ),
+            program_accounts!(ix::ExecuteConfigChange),
+        );
+        check(
+            protocol::cancel_config_change(MEMBER, CHANGE_ID, OTHER),
+            program_accounts!(ix::CancelConfigChange),
+        );
+        check(
+            protocol::increase_timelock_delay(MEMBER, 1),
+            program_accounts!(ix::IncreaseTimelockDelay),
+        );
+        check(
+            protocol::migrate_protocol_config(MEMBER),
+            program_accounts!(ix::MigrateProtocolConfig),
+        );
+        check(
+            protocol::migrate_yield_source(MEMBER, MINT),
+            program_accounts!(ix::MigrateYieldSource),
+        );
+        check(
+            protocol::migrate_user_position(MEMBER, USER, BOND),
+            program_accounts!(ix::MigrateUserPosition),
+        );
+    }
+
+    #[test]
+    fn yield_builders_match_program_accounts() {
+        use stablebond_yield as ix;
+
+        let tokens = tokens();
+
+        check(
+            vault::initialize_vault(MEMBER, MEMBER, &tokens, OTHER, BOND, 450, 450, 0, None),
+            program_accounts!(ix::InitializeVault),
+        );
+        check(
+            vault::migrate_vault(AUTHORITY, BOND, &tokens),
+            program_accounts!(ix::MigrateVault),
+        );
+        check(
+            vault::migrate_shares(
+                USER,
+                BOND,
+                &AUTHORITY,
+                tokens.share_token_program,
+                OTHER,
+                OTHER,
+            ),
+            program_accounts!(ix::MigrateShares),
+        );
+        check(
+            vault::deposit(USER, BOND, &tokens, OTHER, OTHER, 1, None),
+            program_accounts!(ix::Deposit),
+        );
+        let kyc = vault::ShareKycAccounts {
+            whitelist_entry: OTHER,
+            sovereign_identity: MEMBER,
+        };
+        let deposit = check(
+            vault::deposit(USER, BOND, &tokens, OTHER, OTHER, 1, Some(&kyc)),
+            program_accounts!(ix::Deposit),
+        );
+        assert_eq!(deposit["whitelist_entry"], OTHER);
+        assert_eq!(deposit["sovereign_identity"], MEMBER);
+        let withdraw = check(
+            vault::withdraw(USER, BOND, &tokens, OTHER, OTHER, 1, true),
+            program_accounts!(ix::Withdraw),
+        );
+        assert!(withdraw.contains_key("user_shares"));
+        let withdraw = check(
+            vault::withdraw(USER, BOND, &tokens, OTHER, OTHER, 1, false),
+            program_accounts!(ix::Withdraw),
+        );
+        assert!(!withdraw.contains_key("user_shares"));
+        check(
+            vault::sync_user_shares(USER, BOND, OTHER),
+            program_accounts!(ix::SyncUserShares),
+        );
+        check(
+            vault::accrue_yield(BOND, OTHER),
+            program_accounts!(ix::AccrueYield),
+        );
+        check(
+            vault::accrue_yield_incentivized(MEMBER, BOND, &tokens, OTHER),
+            program_accounts!(ix::AccrueYieldIncentivized),
+        );
+        check(
+            vault::set_immediate_withdraw(MEMBER, BOND, true, true),
+            program_accounts!(ix::SetImmediateWithdraw),
+        );
+        check(
+            vault::set_member_roles(AUTHORITY, BOND, MEMBER, 1),
+            program_accounts!(ix::SetVaultMemberRoles),
+        );
+        check(
+            vault::set_vault_pause_flags(MEMBER, BOND, 1, true),
+            program_accounts!(ix::SetVaultPauseFlags),
+        );
+        check(
+            vault::propose_authority(MEMBER, BOND, OTHER),
+            program_accounts!(ix::ProposeVaultAuthority),
+        );
+        check(
+            vault::accept_authority(MEMBER, BOND),
+            program_accounts!(ix::AcceptVaultAuthority),
+        );
+        check(
+            vault::queue_vault_change(MEMBER, BOND, CHANGE_ID, VaultChange::Apy(1), true),
+            program_accounts!(ix::QueueVaultChange),
+        );
+        check(
+            vault::execute_vault_change(MEMBER, BOND, CHANGE_ID, OTHER, true),
+            program_accounts!(ix::ExecuteVaultChange),
+        );
+        check(
+            vault::cancel_vault_change(MEMBER, BOND, CHANGE_ID, OTHER),
+            program_accounts!(ix::CancelVaultChange),
+        );
+        check(
+            vault::increase_vault_timelock_delay(MEMBER, BOND, 1),
+            program_accounts!(ix::IncreaseVaultTimelockDelay),
+        );
+        check(
+            vault::set_min_coverage(MEMBER, BOND, 1, true),
+            program_accounts!(ix::SetMinCoverage),
+        );
+        check(
+            vault::submit_reserve_attestation(MEMBER, BOND, 1, None),
+            program_accounts!(ix::SubmitReserveAttestation),
+        );
+        check(
+            vault::submit_quorum_attestation(MEMBER, BOND, 1, None),
+            program_accounts!(ix::SubmitQuorumAttestation),
+        );
+        check(
+            vault::verify_reserve_holding(
+                BOND,
+                ReserveHolding {
+                    security_id: [0; 12],
+                    quantity: 1,
+                    price: 1_000_000,
+                },
+                vec![],
+            ),
+            program_accounts!(ix::VerifyReserveHolding),
+        );
+        check(
+            vault::set_compliance_officer(MEMBER, BOND, OTHER),
+            program_accounts!(ix::SetComplianceOfficer),
+        );
+        for ix in [
+            vault::freeze_shares(
+                MEMBER,
+                BOND,
+                OTHER,
+                tokens.share_token_program,
+                FreezeReason::Sanctions,
+            ),
+            vault::thaw_shares(MEMBER, BOND, OTHER, tokens.share_token_program),
+        ] {
+            check(ix, program_accounts!(ix::SetSharesFreeze));
+        }
+        check(
+            vault::set_share_metadata(
+                MEMBER,
+                BOND,
+                tokens.share_token_program,
+                "Share".into(),
+                "SHR".into(),
+                "https://example.com".into(),
+            ),
+            program_accounts!(ix::SetShareMetadata),
+        );
+    }
+
+    #[test]
+    fn transfer_hook_builders_match_program_accounts() {
+        check(
+            transfer_hook::initialize_extra_account_meta_list(
+                MEMBER,
+                BOND,
+                InitializeHookParams {
+                    whitelist_seed: b"whitelist".to_vec(),
+                    identity_seed: b"identity".to_vec(),
+                },
+            ),
+            program_accounts!(stablebond_transfer_hook::InitializeExtraAccountMetaList),
+        );
+        check(
+            transfer_hook::update_extra_account_meta_list(
+                MEMBER,
+                BOND,
+                InitializeHookParams {
+                    whitelist_seed: b"whitelist".to_vec(),
+                    identity_seed: b"identity".to_vec(),
+                },
+            ),
+            program_accounts!(stablebond_transfer_hook::UpdateExtraAccountMetaList),
+        );
+    }
+
+    #[test]
+    fn omitted_optional_accounts_use_the_program_id() {
+        let ix = protocol::pause_protocol(MEMBER, false);
+        assert_eq!(ix.accounts.last().unwrap().pubkey, stablebond_core::ID);
+        let named = check(
+            ix,
+            program_accounts!(stablebond_core::instructions::PauseProtocol),
+        );
+        assert!(!named.contains_key("role_assignment"));
+    }
+}
@@ -0,0 +1,632 @@
+//! stablebond-core instruction builders.
+
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use stablebond_core::instructions::{InitializeProtocolParams, RegisterYieldSourceParams};
+use stablebond_core::state::{ConfigChange, PendingDeposit, YieldSource};
+use stablebond_core::{accounts, instruction};
+use stablebond_types::{BondConfig, BondType, FreezeReason};
+
+use super::build;
+use crate::pda;
+use crate::{Instruction, Pubkey};
+
+/// Identity and oracle accounts checked on every deposit.
+#[derive(Clone, Copy, Debug)]
+pub struct DepositGate {
+    /// FX oracle for the bond's currency (any account for USD bonds); for
+    /// `deposit_cross_currency`, the yield source's `oracle_feed`, which
+    /// prices the source currency
+    pub fx_oracle: Pubkey,
+    /// Accredit `WhitelistEntry` for the depositor
+    pub whitelist_entry: Pubkey,
+    /// Sovereign identity record for the depositor
+    pub sovereign_identity: Pubkey,
+}
+
+/// Source-currency accounts for `deposit_cross_currency`.
+#[derive(Clone, Copy, Debug)]
+pub struct CrossCurrencySource {
+    pub mint: Pubkey,
+    pub user_token: Pubkey,
+    /// Protocol vault receiving the source currency
+    pub vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Settlement currency held by the protocol USDC vault and the yield source
+/// deposit vaults, with the token program that owns it (SPL Token or
+/// Token-2022; see [`crate::accounts::fetch_token_program`]).
+#[derive(Clone, Copy, Debug)]
+pub struct Settlement {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+fn config() -> Pubkey {
+    pda::find_protocol_config_pda().0
+}
+
+fn role(signer: &Pubkey, with_role: bool) -> Option<Pubkey> {
+    with_role.then(|| pda::find_role_assignment_pda(&config(), signer).0)
+}
+
+fn yield_source_address(token_mint: &Pubkey) -> Pubkey {
+    pda::find_yield_source_pda(&config(), token_mint).0
+}
+
+// ─── Setup ──────────────────────────────────────────────────────────────────────
+
+pub fn initialize_protocol(
+    authority: Pubkey,
+    settlement: &Settlement,
+    params: InitializeProtocolParams,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::InitializeProtocol {
+            authority,
+            protocol_config,
+            bond_registry: pda::find_bond_registry_pda(&protocol_config).0,
+            usdc_mint: settlement.mint,
+            usdc_vault: pda::find_usdc_vault_pda().0,
+            token_program: settlement.token_program,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeProtocol { params },
+    )
+}
+
+pub fn register_bond(authority: Pubkey, bond: BondConfig) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::RegisterBond {
+            authority,
+            protocol_config,
+            bond_registry: pda::find_bond_registry_pda(&protocol_config).0,
+        },
+        instruction::RegisterBond { config: bond },
+    )
+}
+
+pub fn register_yield_source(
+    authority: Pubkey,
+    token_mint: Pubkey,
+    params: RegisterYieldSourceParams,
+) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::RegisterYieldSource {
+            authority,
+            protocol_config: config(),
+            token_mint,
+            yield_source: yield_source_address(&token_mint),
+            system_program: system_program::ID,
+        },
+        instruction::RegisterYieldSource { params },
+    )
+}
+
+// ─── Deposits ───────────────────────────────────────────────────────────────────
+
+/// `deposit_nonce` is the nonce the new `PendingDeposit` will take:
+/// `ProtocolConfig.deposit_nonce + 1`.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_cross_currency(
+    user: Pubkey,
+    yield_source_mint: Pubkey,
+    source: &CrossCurrencySource,
+    gate: &DepositGate,
+    deposit_nonce: u64,
+    amount: u64,
+    bond_type: BondType,
+    min_output: u64,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::DepositCrossCurrency {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source_mint),
+            bond_vault: pda::find_bond_vault_pda(bond_type).0,
+            source_mint: source.mint,
+            user_source_ata: source.user_token,
+            source_vault: source.vault,
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            pending_deposit: pda::find_pending_deposit_pda(&protocol_config, &user, deposit_nonce)
+                .0,
+            bond_registry: pda::find_bond_registry_pda(&protocol_config).0,
+            user_limit: pda::find_user_limit_pda(&protocol_config, &user).0,
+            fx_oracle: gate.fx_oracle,
+            whitelist_entry: gate.whitelist_entry,
+            sovereign_identity: gate.sovereign_identity,
+            token_program: source.token_program,
+            system_program: system_program::ID,
+        },
+        instruction::DepositCrossCurrency {
+            amount,
+            bond_type,
+            min_output,
+        },
+    )
+}
+
+pub fn deposit_direct(
+    user: Pubkey,
+    yield_source: &YieldSource,
+    settlement: &Settlement,
+    user_token: Pubkey,
+    gate: &DepositGate,
+    amount: u64,
+    bond_type: BondType,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::DepositDirect {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source.token_mint),
+            bond_vault: yield_source.bond_vault,
+            settlement_mint: settlement.mint,
+            user_token,
+            deposit_vault: yield_source.deposit_vault,
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            bond_registry: pda::find_bond_registry_pda(&protocol_config).0,
+            user_limit: pda::find_user_limit_pda(&protocol_config, &user).0,
+            fx_oracle: gate.fx_oracle,
+            whitelist_entry: gate.whitelist_entry,
+            sovereign_identity: gate.sovereign_identity,
+            token_program: settlement.token_program,
+            system_program: system_program::ID,
+        },
+        instruction::DepositDirect { amount, bond_type },
+    )
+}
+
+/// View instruction; simulate it and read the return data. Pass
+/// `has_position` when the owner already holds a position in `bond_type`.
+pub fn get_deposit_capacity(owner: Pubkey, bond_type: BondType, has_position: bool) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::GetDepositCapacity {
+            owner,
+            protocol_config,
+            user_limit: pda::find_user_limit_pda(&protocol_config, &owner).0,
+            user_position: has_position
+                .then(|| pda::find_user_position_pda(&protocol_config, &owner, bond_type).0),
+        },
+        instruction::GetDepositCapacity { bond_type },
+    )
+}
+
+pub fn execute_conversion(
+    keeper: Pubkey,
+    pending_address: Pubkey,
+    pending: &PendingDeposit,
+    oracle: Pubkey,
+    yield_source: &YieldSource,
+    settlement: &Settlement,
+    with_role: bool,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::ExecuteConversion {
+            keeper,
+            protocol_config,
+            pending_deposit: pending_address,
+            user_position: pda::find_user_position_pda(
+                &protocol_config,
+                &pending.user,
+                pending.bond_type,
+            )
+            .0,
+            usdc_vault: pda::find_usdc_vault_pda().0,
+            usdc_mint: settlement.mint,
+            oracle,
+            yield_source: yield_source_address(&yield_source.token_mint),
+            yield_deposit_vault: yield_source.deposit_vault,
+            conversion_record: pda::find_conversion_record_pda(
+                &protocol_config,
+                &pending.user,
+                pending.nonce,
+            )
+            .0,
+            token_program: settlement.token_program,
+            system_program: system_program::ID,
+            role_assignment: role(&keeper, with_role),
+        },
+        instruction::ExecuteConversion {},
+    )
+}
+
+// ─── Withdrawals & Yield ────────────────────────────────────────────────────────
+
+pub fn withdraw(
+    user: Pubkey,
+    yield_source: &YieldSource,
+    settlement: &Settlement,
+    user_token: Pubkey,
+    shares: u64,
+    bond_type: BondType,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::Withdraw {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source.token_mint),
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            deposit_vault: yield_source.deposit_vault,
+            settlement_mint: settlement.mint,
+            user_token,
+            token_program: settlement.token_program,
+        },
+        instruction::Withdraw { shares, bond_type },
+    )
+}
+
+/// `nonce` is the nonce the new request will take:
+/// `UserPosition.withdrawal_nonce + 1`.
+pub fn request_withdrawal(
+    user: Pubkey,
+    yield_source_mint: Pubkey,
+    nonce: u64,
+    shares: u64,
+    bond_type: BondType,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::RequestWithdrawal {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source_mint),
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            withdrawal_request: pda::find_withdrawal_request_pda(&protocol_config, &user, nonce).0,
+            system_program: system_program::ID,
+        },
+        instruction::RequestWithdrawal { shares, bond_type },
+    )
+}
+
+pub fn claim_withdrawal(
+    user: Pubkey,
+    yield_source: &YieldSource,
+    settlement: &Settlement,
+    user_token: Pubkey,
+    bond_type: BondType,
+    nonce: u64,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::ClaimWithdrawal {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source.token_mint),
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            withdrawal_request: pda::find_withdrawal_request_pda(&protocol_config, &user, nonce).0,
+            deposit_vault: yield_source.deposit_vault,
+            settlement_mint: settlement.mint,
+            user_token,
+            token_program: settlement.token_program,
+        },
+        instruction::ClaimWithdrawal { bond_type, nonce },
+    )
+}
+
+pub fn cancel_withdrawal(
+    user: Pubkey,
+    yield_source_mint: Pubkey,
+    bond_type: BondType,
+    nonce: u64,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::CancelWithdrawal {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source_mint),
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            withdrawal_request: pda::find_withdrawal_request_pda(&protocol_config, &user, nonce).0,
+        },
+        instruction::CancelWithdrawal { bond_type, nonce },
+    )
+}
+
+pub fn claim_yield(
+    user: Pubkey,
+    yield_source: &YieldSource,
+    settlement: &Settlement,
+    user_token: Pubkey,
+    bond_type: BondType,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::ClaimYield {
+            user,
+            protocol_config,
+            yield_source: yield_source_address(&yield_source.token_mint),
+            user_position: pda::find_user_position_pda(&protocol_config, &user, bond_type).0,
+            deposit_vault: yield_source.deposit_vault,
+            settlement_mint: settlement.mint,
+            user_token,
+            token_program: settlement.token_program,
+        },
+        instruction::ClaimYield { bond_type },
+    )
+}
+
+pub fn update_nav(keeper: Pubkey, yield_source: &YieldSource, with_role: bool) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::UpdateNav {
+            keeper,
+            protocol_config: config(),
+            yield_source: yield_source_address(&yield_source.token_mint),
+            bond_vault_config: yield_source.bond_vault,
+            role_assignment: role(&keeper, with_role),
+        },
+        instruction::UpdateNav {},
+    )
+}
+
+// ─── Admin ──────────────────────────────────────────────────────────────────────
+
+fn pause_accounts(authority: Pubkey, with_role: bool) -> accounts::PauseProtocol {
+    accounts::PauseProtocol {
+        authority,
+        protocol_config: config(),
+        role_assignment: role(&authority, with_role),
+    }
+}
+
+pub fn pause_protocol(authority: Pubkey, with_role: bool) -> Instruction {
+    build(
+        stablebond_core::ID,
+        pause_accounts(authority, with_role),
+        instruction::PauseProtocol {},
+    )
+}
+
+pub fn resume_protocol(authority: Pubkey, with_role: bool) -> Instruction {
+    build(
+        stablebond_core::ID,
+        pause_accounts(authority, with_role),
+        instruction::ResumeProtocol {},
+    )
+}
+
+pub fn set_pause_flags(authority: Pubkey, paused_ops: u8, with_role: bool) -> Instruction {
+    build(
+        stablebond_core::ID,
+        pause_accounts(authority, with_role),
+        instruction::SetPauseFlags { paused_ops },
+    )
+}
+
+pub fn set_yield_source_pause_flags(
+    authority: Pubkey,
+    token_mint: Pubkey,
+    paused_ops: u8,
+    with_role: bool,
+) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::SetYieldSourcePauseFlags {
+            authority,
+            protocol_config: config(),
+            yield_source: yield_source_address(&token_mint),
+            role_assignment: role(&authority, with_role),
+        },
+        instruction::SetYieldSourcePauseFlags { paused_ops },
+    )
+}
+
+pub fn reset_circuit_breaker(
+    authority: Pubkey,
+    token_mint: Pubkey,
+    accept_nav: bool,
+) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::ResetCircuitBreaker {
+            authority,
+            protocol_config: config(),
+            yield_source: yield_source_address(&token_mint),
+        },
+        instruction::ResetCircuitBreaker { accept_nav },
+    )
+}
+
+pub fn set_member_roles(authority: Pubkey, member: Pubkey, roles: u8) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::SetMemberRoles {
+            authority,
+            protocol_config,
+            role_assignment: pda::find_role_assignment_pda(&protocol_config, &member).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetMemberRoles { member, roles },
+    )
+}
+
+pub fn propose_authority(authority: Pubkey, new_authority: Pubkey) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::ProposeAuthority {
+            authority,
+            protocol_config: config(),
+        },
+        instruction::ProposeAuthority { new_authority },
+    )
+}
+
+pub fn accept_authority(new_authority: Pubkey) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::AcceptAuthority {
+            new_authority,
+            protocol_config: config(),
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+// ─── Compliance ─────────────────────────────────────────────────────────────────
+
+pub fn freeze_position(
+    compliance_officer: Pubkey,
+    owner: Pubkey,
+    bond_type: BondType,
+    reason: FreezeReason,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::SetPositionFreeze {
+            compliance_officer,
+            protocol_config,
+            user_position: pda::find_user_position_pda(&protocol_config, &owner, bond_type).0,
+        },
+        instruction::FreezePosition { reason },
+    )
+}
+
+pub fn unfreeze_position(
+    compliance_officer: Pubkey,
+    owner: Pubkey,
+    bond_type: BondType,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::SetPositionFreeze {
+            compliance_officer,
+            protocol_config,
+            user_position: pda::find_user_position_pda(&protocol_config, &owner, bond_type).0,
+        },
+        instruction::UnfreezePosition {},
+    )
+}
+
+// ─── Timelock ───────────────────────────────────────────────────────────────────
+
+/// `change_id` is `ProtocolConfig.next_change_id`.
+pub fn queue_config_change(
+    proposer: Pubkey,
+    change_id: u64,
+    change: ConfigChange,
+    with_role: bool,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::QueueConfigChange {
+            proposer,
+            protocol_config,
+            pending_change: pda::find_pending_change_pda(&protocol_config, change_id).0,
+            role_assignment: role(&proposer, with_role),
+            system_program: system_program::ID,
+        },
+        instruction::QueueConfigChange { change },
+    )
+}
+
+/// `yield_source` is required for `ConfigChange::YieldSource` changes.
+pub fn execute_config_change(
+    executor: Pubkey,
+    change_id: u64,
+    proposer: Pubkey,
+    yield_source: Option<Pubkey>,
+) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::ExecuteConfigChange {
+            executor,
+            protocol_config,
+            pending_change: pda::find_pending_change_pda(&protocol_config, change_id).0,
+            proposer,
+            yield_source,
+        },
+        instruction::ExecuteConfigChange {},
+    )
+}
+
+pub fn cancel_config_change(authority: Pubkey, change_id: u64, proposer: Pubkey) -> Instruction {
+    let protocol_config = config();
+    build(
+        stablebond_core::ID,
+        accounts::CancelConfigChange {
+            authority,
+            protocol_config,
+            pending_change: pda::find_pending_change_pda(&protocol_config, change_id).0,
+            proposer,
+        },
+        instruction::CancelConfigChange {},
+    )
+}
+
+pub fn increase_timelock_delay(authority: Pubkey, new_delay: i64) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::IncreaseTimelockDelay {
+            authority,
+            protocol_config: config(),
+        },
+        instruction::IncreaseTimelockDelay { new_delay },
+    )
+}
+
+// ─── Layout migrations ──────────────────────────────────────────────────────────
+
+/// Run first: the other migrations load the config at its current layout.
+pub fn migrate_protocol_config(authority: Pubkey) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::MigrateProtocolConfig {
+            authority,
+            protocol_config: config(),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateProtocolConfig {},
+    )
+}
+
+pub fn migrate_yield_source(authority: Pubkey, token_mint: Pubkey) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::MigrateYieldSource {
+            authority,
+            protocol_config: config(),
+            yield_source: yield_source_address(&token_mint),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateYieldSource {},
+    )
+}
+
+pub fn migrate_user_position(payer: Pubkey, owner: Pubkey, bond_type: BondType) -> Instruction {
+    build(
+        stablebond_core::ID,
+        accounts::MigrateUserPosition {
+            payer,
+            user_position: pda::find_user_position_pda(&config(), &owner, bond_type).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateUserPosition {},
+    )
+}
@@ -0,0 +1,52 @@
+//! stablebond-transfer-hook instruction builders.
+
+use anchor_lang::system_program;
+use stablebond_transfer_hook::{accounts, instruction, InitializeHookParams};
+use stablebond_types::BondType;
+
+use super::build;
+use crate::pda;
+use crate::{Instruction, Pubkey};
+
+/// Write the `ExtraAccountMetaList` for the vault's share mint, which must
+/// have been created with this program as its transfer hook.
+pub fn initialize_extra_account_meta_list(
+    authority: Pubkey,
+    bond_type: BondType,
+    params: InitializeHookParams,
+) -> Instruction {
+    let share_mint = pda::find_bond_share_mint_pda(bond_type).0;
+    build(
+        stablebond_transfer_hook::ID,
+        accounts::InitializeExtraAccountMetaList {
+            authority,
+            bond_vault: pda::find_bond_vault_pda(bond_type).0,
+            protocol_config: pda::find_protocol_config_pda().0,
+            share_mint,
+            extra_account_meta_list: pda::find_extra_account_metas_pda(&share_mint).0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeExtraAccountMetaList { params },
+    )
+}
+
+/// Rewrite the share mint's `ExtraAccountMetaList` from the current core
+/// `ProtocolConfig` KYC registry and Sovereign program.
+pub fn update_extra_account_meta_list(
+    authority: Pubkey,
+    bond_type: BondType,
+    params: InitializeHookParams,
+) -> Instruction {
+    let share_mint = pda::find_bond_share_mint_pda(bond_type).0;
+    build(
+        stablebond_transfer_hook::ID,
+        accounts::UpdateExtraAccountMetaList {
+            authority,
+            bond_vault: pda::find_bond_vault_pda(bond_type).0,
+            protocol_config: pda::find_protocol_config_pda().0,
+            share_mint,
+            extra_account_meta_list: pda::find_extra_account_metas_pda(&share_mint).0,
+        },
+        instruction::UpdateExtraAccountMetaList { params },
+    )
+}
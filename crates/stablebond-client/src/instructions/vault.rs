@@ -0,0 +1,596 @@
+//! stablebond-yield instruction builders.
+
+use anchor_lang::solana_program::bpf_loader_upgradeable::get_program_data_address;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use stablebond_types::{BondType, FreezeReason};
+use stablebond_yield::state::{BondVault, ReserveCommitment, ReserveHolding, VaultChange};
+use stablebond_yield::{accounts, instruction};
+
+use super::build;
+use crate::accounts::{fetch_token_program, AccountFetcher};
+use crate::pda;
+use crate::{Instruction, Pubkey, Result};
+
+/// A vault's currency mint and the token programs (SPL Token or Token-2022)
+/// owning its currency and share mints.
+#[derive(Clone, Copy, Debug)]
+pub struct VaultTokens {
+    pub currency_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub share_token_program: Pubkey,
+}
+
+impl VaultTokens {
+    /// Look up the token programs of an existing vault's mints.
+    pub fn fetch(fetcher: &impl AccountFetcher, vault: &BondVault) -> Result<Self> {
+        Ok(Self {
+            currency_mint: vault.currency_mint,
+            token_program: fetch_token_program(fetcher, &vault.currency_mint)?,
+            share_token_program: fetch_token_program(fetcher, &vault.share_mint)?,
+        })
+    }
+}
+
+/// Depositor KYC accounts, required by `deposit` when the share mint routes
+/// transfers through the KYC transfer hook.
+#[derive(Clone, Copy, Debug)]
+pub struct ShareKycAccounts {
+    /// Accredit `WhitelistEntry` for the depositor
+    pub whitelist_entry: Pubkey,
+    /// Sovereign identity record for the depositor
+    pub sovereign_identity: Pubkey,
+}
+
+fn vault(bond_type: BondType) -> Pubkey {
+    pda::find_bond_vault_pda(bond_type).0
+}
+
+fn role(bond_type: BondType, signer: &Pubkey, with_role: bool) -> Option<Pubkey> {
+    with_role.then(|| pda::find_vault_role_pda(&vault(bond_type), signer).0)
+}
+
+// ─── Vault Lifecycle ────────────────────────────────────────────────────────────
+
+/// `deployer` is the stablebond-yield upgrade authority, which must co-sign.
+/// `authority_currency` funds the locked seed deposit of
+/// `BondVault::LOCKED_SHARES` currency units. The share mint is created under
+/// `tokens.share_token_program`; `share_transfer_hook` (Token-2022 shares
+/// only) routes share transfers through a hook program such as
+/// `TRANSFER_HOOK_PROGRAM_ID`.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_vault(
+    deployer: Pubkey,
+    authority: Pubkey,
+    tokens: &VaultTokens,
+    authority_currency: Pubkey,
+    bond_type: BondType,
+    target_apy_bps: u16,
+    coupon_rate_bps: u16,
+    maturity_date: i64,
+    share_transfer_hook: Option<Pubkey>,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::InitializeVault {
+            authority,
+            deployer,
+            program: stablebond_yield::ID,
+            program_data: get_program_data_address(&stablebond_yield::ID),
+            vault_config: vault(bond_type),
+            currency_mint: tokens.currency_mint,
+            share_mint: pda::find_bond_share_mint_pda(bond_type).0,
+            currency_vault: pda::find_bond_currency_vault_pda(bond_type).0,
+            authority_currency,
+            locked_shares: pda::find_bond_locked_shares_pda(bond_type).0,
+            token_program: tokens.token_program,
+            share_token_program: tokens.share_token_program,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeVault {
+            bond_type,
+            target_apy_bps,
+            coupon_rate_bps,
+            maturity_date,
+            share_transfer_hook,
+        },
+    )
+}
+
+/// Move `authority`'s legacy vault for `bond_type` into the current vault.
+/// Pass the currency mint's transfer-hook accounts, if any, as remaining
+/// accounts.
+pub fn migrate_vault(authority: Pubkey, bond_type: BondType, tokens: &VaultTokens) -> Instruction {
+    let legacy_vault = pda::find_legacy_bond_vault_pda(&authority, bond_type).0;
+    build(
+        stablebond_yield::ID,
+        accounts::MigrateVault {
+            authority,
+            vault_config: vault(bond_type),
+            legacy_vault,
+            legacy_currency_vault: pda::find_legacy_bond_currency_vault_pda(&authority, bond_type)
+                .0,
+            legacy_share_mint: pda::find_legacy_bond_share_mint_pda(&authority, bond_type).0,
+            migration: pda::find_vault_migration_pda(&legacy_vault).0,
+            currency_vault: pda::find_bond_currency_vault_pda(bond_type).0,
+            currency_mint: tokens.currency_mint,
+            token_program: tokens.token_program,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateVault {},
+    )
+}
+
+/// Swap `user`'s legacy shares (from the vault `legacy_authority` created)
+/// for shares of the current vault. Legacy share mints are SPL Token.
+pub fn migrate_shares(
+    user: Pubkey,
+    bond_type: BondType,
+    legacy_authority: &Pubkey,
+    share_token_program: Pubkey,
+    user_legacy_shares: Pubkey,
+    user_shares_ata: Pubkey,
+) -> Instruction {
+    let legacy_vault = pda::find_legacy_bond_vault_pda(legacy_authority, bond_type).0;
+    build(
+        stablebond_yield::ID,
+        accounts::MigrateShares {
+            user,
+            vault_config: vault(bond_type),
+            migration: pda::find_vault_migration_pda(&legacy_vault).0,
+            legacy_share_mint: pda::find_legacy_bond_share_mint_pda(legacy_authority, bond_type).0,
+            user_legacy_shares,
+            share_mint: pda::find_bond_share_mint_pda(bond_type).0,
+            user_shares_ata,
+            legacy_token_program: anchor_spl::token::ID,
+            share_token_program,
+        },
+        instruction::MigrateShares {},
+    )
+}
+
+pub fn deposit(
+    user: Pubkey,
+    bond_type: BondType,
+    tokens: &VaultTokens,
+    user_currency: Pubkey,
+    user_shares_ata: Pubkey,
+    amount: u64,
+    kyc: Option<&ShareKycAccounts>,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    let share_mint = pda::find_bond_share_mint_pda(bond_type).0;
+    build(
+        stablebond_yield::ID,
+        accounts::Deposit {
+            user,
+            vault_config,
+            currency_vault: pda::find_bond_currency_vault_pda(bond_type).0,
+            currency_mint: tokens.currency_mint,
+            share_mint,
+            user_currency,
+            user_shares_ata,
+            user_shares: pda::find_bond_shares_pda(&vault_config, &user).0,
+            token_program: tokens.token_program,
+            share_token_program: tokens.share_token_program,
+            system_program: system_program::ID,
+            extra_account_meta_list: kyc.map(|_| pda::find_extra_account_metas_pda(&share_mint).0),
+            whitelist_entry: kyc.map(|k| k.whitelist_entry),
+            sovereign_identity: kyc.map(|k| k.sovereign_identity),
+        },
+        instruction::Deposit { amount },
+    )
+}
+
+/// `with_user_shares` passes the user's `UserShares` metadata account so it
+/// is updated; leave it off for holders who only received shares by transfer.
+pub fn withdraw(
+    user: Pubkey,
+    bond_type: BondType,
+    tokens: &VaultTokens,
+    user_currency: Pubkey,
+    user_shares_ata: Pubkey,
+    shares: u64,
+    with_user_shares: bool,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::Withdraw {
+            user,
+            vault_config,
+            currency_vault: pda::find_bond_currency_vault_pda(bond_type).0,
+            currency_mint: tokens.currency_mint,
+            share_mint: pda::find_bond_share_mint_pda(bond_type).0,
+            user_currency,
+            user_shares_ata,
+            user_shares: with_user_shares
+                .then(|| pda::find_bond_shares_pda(&vault_config, &user).0),
+            token_program: tokens.token_program,
+            share_token_program: tokens.share_token_program,
+        },
+        instruction::Withdraw { shares },
+    )
+}
+
+pub fn sync_user_shares(user: Pubkey, bond_type: BondType, user_shares_ata: Pubkey) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::SyncUserShares {
+            user,
+            vault_config,
+            user_shares_ata,
+            user_shares: pda::find_bond_shares_pda(&vault_config, &user).0,
+            system_program: system_program::ID,
+        },
+        instruction::SyncUserShares {},
+    )
+}
+
+// ─── Yield Accrual ──────────────────────────────────────────────────────────────
+
+/// `bond_price_oracle` is the vault's `oracle_feed`; any account when the
+/// oracle is disabled.
+pub fn accrue_yield(bond_type: BondType, bond_price_oracle: Pubkey) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::AccrueYield {
+            vault_config: vault(bond_type),
+            bond_price_oracle,
+        },
+        instruction::AccrueYield {},
+    )
+}
+
+pub fn accrue_yield_incentivized(
+    keeper: Pubkey,
+    bond_type: BondType,
+    tokens: &VaultTokens,
+    keeper_token: Pubkey,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::AccrueYieldIncentivized {
+            keeper,
+            vault_config: vault(bond_type),
+            currency_vault: pda::find_bond_currency_vault_pda(bond_type).0,
+            currency_mint: tokens.currency_mint,
+            keeper_token,
+            token_program: tokens.token_program,
+        },
+        instruction::AccrueYieldIncentivized {},
+    )
+}
+
+// ─── Vault Admin ────────────────────────────────────────────────────────────────
+
+pub fn set_immediate_withdraw(
+    authority: Pubkey,
+    bond_type: BondType,
+    allow: bool,
+    with_role: bool,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::SetImmediateWithdraw {
+            authority,
+            vault_config: vault(bond_type),
+            role_assignment: role(bond_type, &authority, with_role),
+        },
+        instruction::SetImmediateWithdraw { allow },
+    )
+}
+
+pub fn set_member_roles(
+    authority: Pubkey,
+    bond_type: BondType,
+    member: Pubkey,
+    roles: u8,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::SetVaultMemberRoles {
+            authority,
+            vault_config,
+            role_assignment: pda::find_vault_role_pda(&vault_config, &member).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetMemberRoles { member, roles },
+    )
+}
+
+pub fn set_vault_pause_flags(
+    authority: Pubkey,
+    bond_type: BondType,
+    paused_ops: u8,
+    with_role: bool,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::SetVaultPauseFlags {
+            authority,
+            vault_config: vault(bond_type),
+            role_assignment: role(bond_type, &authority, with_role),
+        },
+        instruction::SetVaultPauseFlags { paused_ops },
+    )
+}
+
+pub fn propose_authority(
+    authority: Pubkey,
+    bond_type: BondType,
+    new_authority: Pubkey,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::ProposeVaultAuthority {
+            authority,
+            vault_config: vault(bond_type),
+        },
+        instruction::ProposeAuthority { new_authority },
+    )
+}
+
+pub fn accept_authority(new_authority: Pubkey, bond_type: BondType) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::AcceptVaultAuthority {
+            new_authority,
+            vault_config: vault(bond_type),
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+// ─── Timelock ───────────────────────────────────────────────────────────────────
+
+/// `change_id` is `BondVault.next_change_id`.
+pub fn queue_vault_change(
+    proposer: Pubkey,
+    bond_type: BondType,
+    change_id: u64,
+    change: VaultChange,
+    with_role: bool,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::QueueVaultChange {
+            proposer,
+            vault_config,
+            pending_change: pda::find_pending_vault_change_pda(&vault_config, change_id).0,
+            role_assignment: role(bond_type, &proposer, with_role),
+            system_program: system_program::ID,
+        },
+        instruction::QueueVaultChange { change },
+    )
+}
+
+/// Pass `with_attestor_set` only for a `VaultChange::AttestorSet`; the
+/// executor pays for the set account on its first configuration.
+pub fn execute_vault_change(
+    executor: Pubkey,
+    bond_type: BondType,
+    change_id: u64,
+    proposer: Pubkey,
+    with_attestor_set: bool,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::ExecuteVaultChange {
+            executor,
+            vault_config,
+            pending_change: pda::find_pending_vault_change_pda(&vault_config, change_id).0,
+            proposer,
+            attestor_set: with_attestor_set.then(|| pda::find_attestor_set_pda(&vault_config).0),
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteVaultChange {},
+    )
+}
+
+pub fn cancel_vault_change(
+    authority: Pubkey,
+    bond_type: BondType,
+    change_id: u64,
+    proposer: Pubkey,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::CancelVaultChange {
+            authority,
+            vault_config,
+            pending_change: pda::find_pending_vault_change_pda(&vault_config, change_id).0,
+            proposer,
+        },
+        instruction::CancelVaultChange {},
+    )
+}
+
+pub fn increase_vault_timelock_delay(
+    authority: Pubkey,
+    bond_type: BondType,
+    new_delay: i64,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::IncreaseVaultTimelockDelay {
+            authority,
+            vault_config: vault(bond_type),
+        },
+        instruction::IncreaseVaultTimelockDelay { new_delay },
+    )
+}
+
+// ─── Proof of Reserve ───────────────────────────────────────────────────────────
+
+pub fn set_min_coverage(
+    authority: Pubkey,
+    bond_type: BondType,
+    min_coverage_bps: u16,
+    with_role: bool,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::SetMinCoverage {
+            authority,
+            vault_config: vault(bond_type),
+            role_assignment: role(bond_type, &authority, with_role),
+        },
+        instruction::SetMinCoverage { min_coverage_bps },
+    )
+}
+
+pub fn submit_reserve_attestation(
+    attestor: Pubkey,
+    bond_type: BondType,
+    attested_reserve: u64,
+    commitment: Option<ReserveCommitment>,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::SubmitReserveAttestation {
+            attestor,
+            vault_config,
+            attestation_history: pda::find_attestation_history_pda(&vault_config).0,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitReserveAttestation {
+            attested_reserve,
+            commitment,
+        },
+    )
+}
+
+pub fn submit_quorum_attestation(
+    attestor: Pubkey,
+    bond_type: BondType,
+    attested_reserve: u64,
+    commitment: Option<ReserveCommitment>,
+) -> Instruction {
+    let vault_config = vault(bond_type);
+    build(
+        stablebond_yield::ID,
+        accounts::SubmitQuorumAttestation {
+            attestor,
+            vault_config,
+            attestor_set: pda::find_attestor_set_pda(&vault_config).0,
+            attestation_history: pda::find_attestation_history_pda(&vault_config).0,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitQuorumAttestation {
+            attested_reserve,
+            commitment,
+        },
+    )
+}
+
+pub fn verify_reserve_holding(
+    bond_type: BondType,
+    holding: ReserveHolding,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::VerifyReserveHolding {
+            vault_config: vault(bond_type),
+        },
+        instruction::VerifyReserveHolding { holding, proof },
+    )
+}
+
+// ─── Compliance ─────────────────────────────────────────────────────────────────
+
+pub fn set_compliance_officer(
+    authority: Pubkey,
+    bond_type: BondType,
+    officer: Pubkey,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::SetComplianceOfficer {
+            authority,
+            vault_config: vault(bond_type),
+        },
+        instruction::SetComplianceOfficer { officer },
+    )
+}
+
+fn shares_freeze_accounts(
+    compliance_officer: Pubkey,
+    bond_type: BondType,
+    shares_account: Pubkey,
+    share_token_program: Pubkey,
+) -> accounts::SetSharesFreeze {
+    accounts::SetSharesFreeze {
+        compliance_officer,
+        vault_config: vault(bond_type),
+        share_mint: pda::find_bond_share_mint_pda(bond_type).0,
+        shares_account,
+        share_token_program,
+    }
+}
+
+pub fn freeze_shares(
+    compliance_officer: Pubkey,
+    bond_type: BondType,
+    shares_account: Pubkey,
+    share_token_program: Pubkey,
+    reason: FreezeReason,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        shares_freeze_accounts(
+            compliance_officer,
+            bond_type,
+            shares_account,
+            share_token_program,
+        ),
+        instruction::FreezeShares { reason },
+    )
+}
+
+pub fn thaw_shares(
+    compliance_officer: Pubkey,
+    bond_type: BondType,
+    shares_account: Pubkey,
+    share_token_program: Pubkey,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        shares_freeze_accounts(
+            compliance_officer,
+            bond_type,
+            shares_account,
+            share_token_program,
+        ),
+        instruction::ThawShares {},
+    )
+}
+
+pub fn set_share_metadata(
+    authority: Pubkey,
+    bond_type: BondType,
+    share_token_program: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    build(
+        stablebond_yield::ID,
+        accounts::SetShareMetadata {
+            authority,
+            vault_config: vault(bond_type),
+            share_mint: pda::find_bond_share_mint_pda(bond_type).0,
+            share_token_program,
+            system_program: system_program::ID,
+        },
+        instruction::SetShareMetadata { name, symbol, uri },
+    )
+}
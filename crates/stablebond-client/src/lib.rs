@@ -0,0 +1,37 @@
+//! Off-chain Rust client for the Stablebond Protocol.
+//!
+//! Provides PDA derivation for every seed in `stablebond-core`,
+//! `stablebond-yield` and `stablebond-transfer-hook`, typed account fetching
+//! and decoding, and an instruction builder for every instruction in those
+//! programs. Instructions are plain
+//! `solana_instruction::Instruction`s, so they drop straight into a
+//! `solana-sdk` transaction.
+
+pub mod accounts;
+pub mod error;
+pub mod instructions;
+pub mod pda;
+pub mod rpc;
+
+pub use accounts::AccountFetcher;
+pub use error::{ClientError, Result};
+pub use rpc::{RpcClient, SignatureStatus, SimulationResult};
+
+pub use stablebond_core;
+pub use stablebond_transfer_hook;
+pub use stablebond_types;
+pub use stablebond_yield;
+
+pub use anchor_lang::prelude::Pubkey;
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+/// stablebond-core program id
+pub const CORE_PROGRAM_ID: Pubkey = stablebond_core::ID;
+/// stablebond-yield program id
+pub const YIELD_PROGRAM_ID: Pubkey = stablebond_yield::ID;
+/// stablebond-transfer-hook program id
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = stablebond_transfer_hook::ID;
+/// SPL Token program id
+pub const TOKEN_PROGRAM_ID: Pubkey = anchor_spl::token::ID;
+/// Token-2022 program id
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = anchor_spl::token_2022::ID;
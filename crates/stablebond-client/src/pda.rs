@@ -0,0 +1,419 @@
+//! PDA derivation for every account seed in the Stablebond programs. Mirrors
+//! `packages/sdk/src/pda.ts`.
+
+use anchor_lang::prelude::Pubkey;
+use stablebond_core::state::{
+    BondRegistry, ConversionRecord, PendingChange, PendingDeposit, ProtocolConfig, RoleAssignment,
+    UserLimit, UserPosition, WithdrawalRequest, YieldSource,
+};
+use stablebond_types::BondType;
+use stablebond_yield::state::{
+    AttestationHistory, AttestorSet, BondVault, PendingVaultChange, UserShares, VaultMigration,
+    VaultRole,
+};
+
+// ─── stablebond-core PDAs ───────────────────────────────────────────────────────
+
+pub fn find_protocol_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ProtocolConfig::SEED], &stablebond_core::ID)
+}
+
+pub fn find_usdc_vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ProtocolConfig::USDC_VAULT_SEED], &stablebond_core::ID)
+}
+
+pub fn find_bond_registry_pda(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BondRegistry::SEED, config.as_ref()], &stablebond_core::ID)
+}
+
+pub fn find_yield_source_pda(config: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[YieldSource::SEED, config.as_ref(), token_mint.as_ref()],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_user_position_pda(
+    config: &Pubkey,
+    owner: &Pubkey,
+    bond_type: BondType,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            UserPosition::SEED,
+            config.as_ref(),
+            owner.as_ref(),
+            &[bond_type.as_u8()],
+        ],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_user_limit_pda(config: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[UserLimit::SEED, config.as_ref(), owner.as_ref()],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_pending_deposit_pda(config: &Pubkey, user: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PendingDeposit::SEED,
+            config.as_ref(),
+            user.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_conversion_record_pda(config: &Pubkey, user: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ConversionRecord::SEED,
+            config.as_ref(),
+            user.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_withdrawal_request_pda(config: &Pubkey, user: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WithdrawalRequest::SEED,
+            config.as_ref(),
+            user.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_role_assignment_pda(config: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RoleAssignment::SEED, config.as_ref(), member.as_ref()],
+        &stablebond_core::ID,
+    )
+}
+
+pub fn find_pending_change_pda(config: &Pubkey, change_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PendingChange::SEED,
+            config.as_ref(),
+            &change_id.to_le_bytes(),
+        ],
+        &stablebond_core::ID,
+    )
+}
+
+// ─── stablebond-yield PDAs ──────────────────────────────────────────────────────
+
+pub fn find_bond_vault_pda(bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BondVault::SEED, &[bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_bond_share_mint_pda(bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BondVault::SHARE_MINT_SEED, &[bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_bond_currency_vault_pda(bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BondVault::CURRENCY_VAULT_SEED, &[bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_bond_locked_shares_pda(bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BondVault::LOCKED_SHARES_SEED, &[bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_bond_shares_pda(vault: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[UserShares::SEED, vault.as_ref(), user.as_ref()],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_vault_role_pda(vault: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VaultRole::SEED, vault.as_ref(), member.as_ref()],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_pending_vault_change_pda(vault: &Pubkey, change_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PendingVaultChange::SEED,
+            vault.as_ref(),
+            &change_id.to_le_bytes(),
+        ],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_attestor_set_pda(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AttestorSet::SEED, vault.as_ref()], &stablebond_yield::ID)
+}
+
+pub fn find_attestation_history_pda(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AttestationHistory::SEED, vault.as_ref()],
+        &stablebond_yield::ID,
+    )
+}
+
+/// Vault of `authority` from before vault PDAs were seeded by bond type
+/// alone; source of `migrate_vault`.
+pub fn find_legacy_bond_vault_pda(authority: &Pubkey, bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BondVault::SEED, authority.as_ref(), &[bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_legacy_bond_share_mint_pda(authority: &Pubkey, bond_type: BondType) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BondVault::SHARE_MINT_SEED,
+            authority.as_ref(),
+            &[bond_type.as_u8()],
+        ],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_legacy_bond_currency_vault_pda(
+    authority: &Pubkey,
+    bond_type: BondType,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BondVault::CURRENCY_VAULT_SEED,
+            authority.as_ref(),
+            &[bond_type.as_u8()],
+        ],
+        &stablebond_yield::ID,
+    )
+}
+
+pub fn find_vault_migration_pda(legacy_vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[VaultMigration::SEED, legacy_vault.as_ref()],
+        &stablebond_yield::ID,
+    )
+}
+
+// ─── stablebond-transfer-hook PDAs ──────────────────────────────────────────────
+
+/// `ExtraAccountMetaList` of a share mint whose transfers run through the
+/// KYC transfer hook.
+pub fn find_extra_account_metas_pda(share_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            stablebond_transfer_hook::EXTRA_ACCOUNT_METAS_SEED,
+            share_mint.as_ref(),
+        ],
+        &stablebond_transfer_hook::ID,
+    )
+}
+
+// ─── Token Accounts ─────────────────────────────────────────────────────────────
+
+/// Associated token account of `owner` for `mint` (SPL Token program).
+pub fn find_associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address(owner, mint)
+}
+
+/// Associated token account of `owner` for a `mint` owned by `token_program`
+/// (SPL Token or Token-2022).
+pub fn find_associated_token_address_with_program(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        owner,
+        mint,
+        token_program,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const CONFIG: Pubkey = Pubkey::new_from_array([1; 32]);
+    const USER: Pubkey = Pubkey::new_from_array([2; 32]);
+    const MINT: Pubkey = Pubkey::new_from_array([3; 32]);
+    const VAULT: Pubkey = Pubkey::new_from_array([4; 32]);
+    const MEMBER: Pubkey = Pubkey::new_from_array([5; 32]);
+    const AUTHORITY: Pubkey = Pubkey::new_from_array([6; 32]);
+
+    /// Addresses derived independently of this module from the seed strings
+    /// and program ids; a changed seed or argument order moves the address.
+    #[test]
+    fn pdas_match_pinned_addresses() {
+        let pinned: [((Pubkey, u8), &str, u8); 25] = [
+            (
+                find_protocol_config_pda(),
+                "3YrGPU54G2Urm1kbfdGWYp5MRhSDE5jGU4yLqmrLACSv",
+                255,
+            ),
+            (
+                find_usdc_vault_pda(),
+                "BLRemxYH4eG1coAhAY4pVn8cAwREJ4pfU8pvoRFpwxTL",
+                255,
+            ),
+            (
+                find_bond_registry_pda(&CONFIG),
+                "3Y2hEkhMHEVw7DPHLc1X4XheNrN2mVWoBzbBbKtiRoBs",
+                255,
+            ),
+            (
+                find_yield_source_pda(&CONFIG, &MINT),
+                "A9qAfiuvjAApcYDKDwb3Muy3pcMsgW5u2RCXepZmy1mG",
+                255,
+            ),
+            (
+                find_user_position_pda(&CONFIG, &USER, BondType::MxCetes),
+                "Aknd2uChBMVzsedSMMfQyToahSGgwk3JjVUdDtzy3fX3",
+                255,
+            ),
+            (
+                find_user_limit_pda(&CONFIG, &USER),
+                "Fx6DYVwg5VJxB9EHr6h1VqNtwCkXurRCCXAaDXXzjjKQ",
+                253,
+            ),
+            (
+                find_pending_deposit_pda(&CONFIG, &USER, 7),
+                "ETZ3S9ATKo2P72sBEE6mzc9U3ZoD93rq6RRi2pSCvi1k",
+                255,
+            ),
+            (
+                find_conversion_record_pda(&CONFIG, &USER, 7),
+                "A7rYEJ3zuXrNnVAxQNgXrTCTnpuND7MuFms2mALzzT59",
+                254,
+            ),
+            (
+                find_withdrawal_request_pda(&CONFIG, &USER, 7),
+                "CEmAE8B6gPMpyLWjema9XfroRhuy1BZT3T4oJ2GSgPiU",
+                254,
+            ),
+            (
+                find_role_assignment_pda(&CONFIG, &MEMBER),
+                "E4pVJwYJF6ecYeNrb6MsT78CjS9HtPaND6uyhoQ5GVxY",
+                255,
+            ),
+            (
+                find_pending_change_pda(&CONFIG, 7),
+                "C9JfvoHkeEz3oW31ryB9mXMsZkVrS7xfCH4BvE82WQdu",
+                253,
+            ),
+            (
+                find_bond_vault_pda(BondType::UsTBill),
+                "CCT3Ty8uFz6bVDXHsceuHHeKcb2PVtRnqbvEmUWLL1rE",
+                255,
+            ),
+            (
+                find_bond_share_mint_pda(BondType::UsTBill),
+                "E2XUZBLhVYEykn6916rLYpaRtntdBadbDpJ9oJVJWtQ9",
+                255,
+            ),
+            (
+                find_bond_currency_vault_pda(BondType::UsTBill),
+                "GSznuviF7MQqPqHSzEjxUsn4vmBkoZkCmWwUpnK3E1BQ",
+                254,
+            ),
+            (
+                find_bond_locked_shares_pda(BondType::UsTBill),
+                "2WhwR4nCp6za5NvF7Dk9Dm8MwTDQMeCLYSVViyaWxTCf",
+                255,
+            ),
+            (
+                find_bond_shares_pda(&VAULT, &USER),
+                "ATSpbZj9VR3yRd8CWhkEpqoxDsHetwGGxYdtEo53FR45",
+                255,
+            ),
+            (
+                find_vault_role_pda(&VAULT, &MEMBER),
+                "3AGGQMYyr1DYbYoXJPJshdCdRLiPJoDSCikDNr6ecrn2",
+                255,
+            ),
+            (
+                find_pending_vault_change_pda(&VAULT, 7),
+                "B16HEZEykVZeJe29dVuwiMGBH6RJ8zeMWpvmTcMsTTRV",
+                253,
+            ),
+            (
+                find_attestor_set_pda(&VAULT),
+                "DqTBmty8B3hfA127pt3xdazA4iLNi3jp7y19t7n1yJaA",
+                255,
+            ),
+            (
+                find_attestation_history_pda(&VAULT),
+                "EzTUPUiyUqiZ5LdmQ9NxYVKchbQrUjG4wrRJKp2w6MCb",
+                253,
+            ),
+            (
+                find_legacy_bond_vault_pda(&AUTHORITY, BondType::JpJgb),
+                "BU7qK4nYvCoDJRQZAur2GsPmFN9DBPL5FQiPgrLbCjXx",
+                254,
+            ),
+            (
+                find_legacy_bond_share_mint_pda(&AUTHORITY, BondType::JpJgb),
+                "DroriGi6Lgds4ohcrSiNpnKHTZZ77iYtEgRMLyigQXEP",
+                253,
+            ),
+            (
+                find_legacy_bond_currency_vault_pda(&AUTHORITY, BondType::JpJgb),
+                "7CqkdXFWKgnhh8dA2Uq6U5m2jFHqRNghwzjjmaN3NBoM",
+                254,
+            ),
+            (
+                find_vault_migration_pda(&VAULT),
+                "8yLTjVACHyktHdeiLv7z3VYAcAgbErnFQ1cueoQGHpDk",
+                255,
+            ),
+            (
+                find_extra_account_metas_pda(&MINT),
+                "3jrsnch5ne5kDujzdj7jBvCHpyUUFU3jfLFWs3MBuwpa",
+                253,
+            ),
+        ];
+        for (i, ((address, bump), expected, expected_bump)) in pinned.into_iter().enumerate() {
+            assert_eq!(address, Pubkey::from_str(expected).unwrap(), "pda #{i}");
+            assert_eq!(bump, expected_bump, "bump of pda #{i}");
+        }
+    }
+
+    #[test]
+    fn associated_token_addresses_match_pinned_addresses() {
+        assert_eq!(
+            find_associated_token_address(&USER, &MINT),
+            Pubkey::from_str("BKbxqhBJfLZNgac5dEUesF1V5xRZSzxDkcpQBAy4c8sw").unwrap()
+        );
+        assert_eq!(
+            find_associated_token_address_with_program(&USER, &MINT, &anchor_spl::token::ID),
+            find_associated_token_address(&USER, &MINT)
+        );
+        assert_eq!(
+            find_associated_token_address_with_program(&USER, &MINT, &anchor_spl::token_2022::ID),
+            Pubkey::from_str("DB2rSUqWCtYs8BwzuLqJZK1DfqQ22eeudg4LBS1X6Urh").unwrap()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Minimal blocking JSON-RPC client covering account reads, the cluster clock
+//! and transaction submission. Any full-featured RPC client can be used for
+//! account reads instead by implementing [`AccountFetcher`].
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::sysvar;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+
+use crate::accounts::AccountFetcher;
+use crate::error::{ClientError, Result};
+
+pub struct RpcClient {
+    url: String,
+    commitment: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    /// Client at `url` reading with `confirmed` commitment.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::new_with_commitment(url, "confirmed")
+    }
+
+    pub fn new_with_commitment(url: impl Into<String>, commitment: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            commitment: commitment.into(),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn commitment(&self) -> &str {
+        &self.commitment
+    }
+
+    /// Send a raw JSON-RPC request and deserialize its `result`.
+    pub fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|e| ClientError::Transport(e.to_string()))?
+            .into_json()
+            .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(ClientError::Rpc {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        serde_json::from_value(response["result"].clone())
+            .map_err(|e| ClientError::InvalidResponse(e.to_string()))
+    }
+
+    /// Accounts for `addresses` in order (`None` for missing accounts).
+    pub fn get_multiple_accounts_data(&self, addresses: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        let keys: Vec<String> = addresses.iter().map(Pubkey::to_string).collect();
+        let result: Value = self.call(
+            "getMultipleAccounts",
+            json!([keys, { "encoding": "base64", "commitment": self.commitment }]),
+        )?;
+        result["value"]
+            .as_array()
+            .ok_or_else(|| ClientError::InvalidResponse("missing value".into()))?
+            .iter()
+            .map(|account| match account {
+                Value::Null => Ok(None),
+                account => decode_account_data(account).map(Some),
+            })
+            .collect()
+    }
+
+    /// Cluster unix timestamp from the Clock sysvar, the same clock the
+    /// programs compare expiries against.
+    pub fn get_cluster_time(&self) -> Result<i64> {
+        // Clock: slot(8) + epoch_start_timestamp(8) + epoch(8) + leader_schedule_epoch(8)
+        //   + unix_timestamp(8)
+        const UNIX_TIMESTAMP_OFFSET: usize = 32;
+        let data = self
+            .get_account_data(&sysvar::clock::ID)?
+            .ok_or(ClientError::AccountNotFound(sysvar::clock::ID))?;
+        data.get(UNIX_TIMESTAMP_OFFSET..UNIX_TIMESTAMP_OFFSET + 8)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()))
+            .ok_or_else(|| ClientError::InvalidResponse("short Clock sysvar".into()))
+    }
+
+    // ─── Transactions ───────────────────────────────────────────────────────────
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result: Value = self.call(
+            "getLatestBlockhash",
+            json!([{ "commitment": self.commitment }]),
+        )?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| ClientError::InvalidResponse("missing blockhash".into()))?;
+        Hash::from_str(blockhash).map_err(|e| ClientError::InvalidResponse(e.to_string()))
+    }
+
+    /// Submit a wire-format transaction (with preflight); returns its signature.
+    pub fn send_transaction(&self, wire: &[u8]) -> Result<String> {
+        self.call(
+            "sendTransaction",
+            json!([BASE64_STANDARD.encode(wire), {
+                "encoding": "base64",
+                "preflightCommitment": self.commitment,
+            }]),
+        )
+    }
+
+    /// Simulate a wire-format transaction without signature verification.
+    pub fn simulate_transaction(&self, wire: &[u8]) -> Result<SimulationResult> {
+        let result: Value = self.call(
+            "simulateTransaction",
+            json!([BASE64_STANDARD.encode(wire), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+            }]),
+        )?;
+        let value = &result["value"];
+        Ok(SimulationResult {
+            err: (!value["err"].is_null()).then(|| value["err"].clone()),
+            logs: serde_json::from_value(value["logs"].clone()).unwrap_or_default(),
+            units_consumed: value["unitsConsumed"].as_u64(),
+        })
+    }
+
+    /// Status of a submitted signature; `None` while the cluster has not seen it.
+    pub fn get_signature_status(&self, signature: &str) -> Result<Option<SignatureStatus>> {
+        let result: Value = self.call("getSignatureStatuses", json!([[signature]]))?;
+        let status = &result["value"][0];
+        if status.is_null() {
+            return Ok(None);
+        }
+        let level = status["confirmationStatus"].as_str().unwrap_or_default();
+        Ok(Some(SignatureStatus {
+            confirmed: match self.commitment.as_str() {
+                "finalized" => level == "finalized",
+                "confirmed" => level == "confirmed" || level == "finalized",
+                _ => true,
+            },
+            err: (!status["err"].is_null()).then(|| status["err"].clone()),
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SignatureStatus {
+    /// Reached the client's commitment level
+    pub confirmed: bool,
+    /// Transaction error, if it failed
+    pub err: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl AccountFetcher for RpcClient {
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result: Value = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": self.commitment }]),
+        )?;
+        match &result["value"] {
+            Value::Null => Ok(None),
+            account => decode_account_data(account).map(Some),
+        }
+    }
+
+    fn get_account_owner(&self, address: &Pubkey) -> Result<Option<Pubkey>> {
+        let result: Value = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "dataSlice": { "offset": 0, "length": 0 },
+            }]),
+        )?;
+        match &result["value"] {
+            Value::Null => Ok(None),
+            account => {
+                let owner = account["owner"]
+                    .as_str()
+                    .ok_or_else(|| ClientError::InvalidResponse("missing owner".into()))?;
+                Pubkey::from_str(owner)
+                    .map(Some)
+                    .map_err(|e| ClientError::InvalidResponse(e.to_string()))
+            }
+        }
+    }
+
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        prefix: &[u8],
+    ) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+        let filter = json!({ "memcmp": {
+            "offset": 0,
+            "bytes": BASE64_STANDARD.encode(prefix),
+            "encoding": "base64",
+        }});
+        let result: Vec<Value> = self.call(
+            "getProgramAccounts",
+            json!([program_id.to_string(), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "filters": [filter],
+            }]),
+        )?;
+        result
+            .iter()
+            .map(|entry| {
+                let pubkey = entry["pubkey"].as_str().unwrap_or_default();
+                let address = Pubkey::from_str(pubkey)
+                    .map_err(|e| ClientError::InvalidResponse(e.to_string()))?;
+                Ok((address, decode_account_data(&entry["account"])?))
+            })
+            .collect()
+    }
+}
+
+/// Decode the `["<base64>", "base64"]` data field of an account object.
+fn decode_account_data(account: &Value) -> Result<Vec<u8>> {
+    let encoded = account["data"][0]
+        .as_str()
+        .ok_or_else(|| ClientError::InvalidResponse("missing account data".into()))?;
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|e| ClientError::InvalidResponse(e.to_string()))
+}
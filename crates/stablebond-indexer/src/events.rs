@@ -0,0 +1,312 @@
+//! Event decoding and per-event table layout. Every `#[event]` on
+//! stablebond-core and stablebond-yield is listed once in `events!`, which
+//! generates the `Event` enum, its decoder and the column list of its table.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use rusqlite::types::Value;
+use stablebond_client::stablebond_types::{ConversionDirection, FreezeReason, LimitWindow};
+use stablebond_client::{stablebond_core, stablebond_yield, Pubkey};
+
+/// How an event field is stored.
+pub trait Column {
+    const SQL_TYPE: &'static str;
+    /// Pubkey columns are matched by wallet queries
+    const IS_KEY: bool = false;
+
+    fn value(&self) -> Value;
+}
+
+macro_rules! integer_column {
+    ($($ty:ty),*) => {$(
+        impl Column for $ty {
+            const SQL_TYPE: &'static str = "INTEGER";
+
+            fn value(&self) -> Value {
+                Value::Integer(i64::from(*self))
+            }
+        }
+    )*};
+}
+
+integer_column!(u8, u16, u32, i64, bool);
+
+impl Column for u64 {
+    const SQL_TYPE: &'static str = "INTEGER";
+
+    /// Values above `i64::MAX` do not fit SQLite's INTEGER and are stored as text.
+    fn value(&self) -> Value {
+        i64::try_from(*self)
+            .map(Value::Integer)
+            .unwrap_or_else(|_| Value::Text(self.to_string()))
+    }
+}
+
+impl Column for Pubkey {
+    const SQL_TYPE: &'static str = "TEXT";
+    const IS_KEY: bool = true;
+
+    fn value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl<T: Column> Column for Option<T> {
+    const SQL_TYPE: &'static str = T::SQL_TYPE;
+
+    fn value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, Column::value)
+    }
+}
+
+impl Column for String {
+    const SQL_TYPE: &'static str = "TEXT";
+
+    fn value(&self) -> Value {
+        Value::Text(self.clone())
+    }
+}
+
+impl Column for Vec<Pubkey> {
+    const SQL_TYPE: &'static str = "TEXT";
+
+    fn value(&self) -> Value {
+        let keys: Vec<_> = self.iter().map(Pubkey::to_string).collect();
+        Value::Text(keys.join(","))
+    }
+}
+
+/// Byte arrays (hashes, Merkle roots, names) are stored as hex.
+impl<const N: usize> Column for [u8; N] {
+    const SQL_TYPE: &'static str = "TEXT";
+
+    fn value(&self) -> Value {
+        Value::Text(self.iter().map(|b| format!("{b:02x}")).collect())
+    }
+}
+
+macro_rules! debug_column {
+    ($($ty:ty),*) => {$(
+        impl Column for $ty {
+            const SQL_TYPE: &'static str = "TEXT";
+
+            fn value(&self) -> Value {
+                Value::Text(format!("{self:?}"))
+            }
+        }
+    )*};
+}
+
+debug_column!(ConversionDirection, FreezeReason, [LimitWindow; 5]);
+
+/// A column of an event table.
+pub struct ColumnDef {
+    pub name: &'static str,
+    pub sql_type: &'static str,
+    pub is_key: bool,
+}
+
+fn column_def<E, T: Column>(name: &'static str, _field: fn(&E) -> &T) -> ColumnDef {
+    ColumnDef {
+        name,
+        sql_type: T::SQL_TYPE,
+        is_key: T::IS_KEY,
+    }
+}
+
+/// Table layout of one event type.
+pub struct EventTable {
+    pub name: &'static str,
+    pub columns: Vec<ColumnDef>,
+}
+
+impl EventTable {
+    /// SQL table name: the event name in snake_case.
+    pub fn table(&self) -> String {
+        let mut table = String::new();
+        for (i, c) in self.name.char_indices() {
+            if c.is_ascii_uppercase() && i > 0 {
+                table.push('_');
+            }
+            table.push(c.to_ascii_lowercase());
+        }
+        table
+    }
+}
+
+macro_rules! events {
+    ($($program:ident :: $name:ident { $($field:ident),* $(,)? })*) => {
+        pub enum Event {
+            $($name($program::events::$name),)*
+        }
+
+        impl Event {
+            /// Decode a `Program data:` payload emitted by `program_id`.
+            pub fn decode(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+                $(
+                    if *program_id == $program::ID
+                        && data.starts_with($program::events::$name::DISCRIMINATOR)
+                    {
+                        let mut body = &data[$program::events::$name::DISCRIMINATOR.len()..];
+                        return AnchorDeserialize::deserialize(&mut body).ok().map(Event::$name);
+                    }
+                )*
+                None
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Event::$name(_) => stringify!($name),)*
+                }
+            }
+
+            /// Field values, in `EventTable::columns` order.
+            pub fn values(&self) -> Vec<Value> {
+                match self {
+                    $(Event::$name(event) => vec![$(event.$field.value()),*],)*
+                }
+            }
+        }
+
+        /// Every event table, in declaration order.
+        pub fn tables() -> Vec<EventTable> {
+            vec![$(
+                EventTable {
+                    name: stringify!($name),
+                    columns: vec![$(
+                        column_def::<$program::events::$name, _>(
+                            stringify!($field),
+                            |event| &event.$field,
+                        )
+                    ),*],
+                },
+            )*]
+        }
+    };
+}
+
+events! {
+    // ─── stablebond-core ─────────────────────────────────────────────────────
+    stablebond_core::ProtocolInitialized { authority, usdc_mint, timestamp }
+    stablebond_core::BondRegistered { bond_type, currency_mint, default_apy_bps, min_tier, timestamp }
+    stablebond_core::YieldSourceRegistered { yield_source, name, source_type, bond_type, token_mint, timestamp }
+    stablebond_core::DepositInitiated { user, pending_deposit, bond_type, source_amount, min_output, nonce, timestamp }
+    stablebond_core::DirectDeposit { user, bond_type, amount, shares_received, timestamp }
+    stablebond_core::ConversionExecuted {
+        user, bond_type, source_amount, settlement_received, exchange_rate, fee_paid,
+        shares_issued, nonce, timestamp,
+    }
+    stablebond_core::ConversionRecordCreated {
+        user, bond_type, source_amount, settlement_amount, exchange_rate, direction, nonce,
+        timestamp,
+    }
+    stablebond_core::WithdrawalExecuted { user, bond_type, shares_burned, amount_received, timestamp }
+    stablebond_core::YieldClaimed { user, bond_type, yield_amount, performance_fee, net_yield, timestamp }
+    stablebond_core::NavUpdated { yield_source, bond_type, old_nav, new_nav, timestamp }
+    stablebond_core::WithdrawalRequested { user, bond_type, shares, amount_out, claimable_at, nonce, timestamp }
+    stablebond_core::WithdrawalCancelled { user, bond_type, shares_returned, nonce, timestamp }
+    stablebond_core::ProtocolConfigUpdated {
+        authority, old_treasury, new_treasury, old_conversion_fee_bps, new_conversion_fee_bps,
+        old_management_fee_bps, new_management_fee_bps, old_performance_fee_bps,
+        new_performance_fee_bps, old_tier_limit_windows, new_tier_limit_windows, timestamp,
+    }
+    stablebond_core::YieldSourceUpdated {
+        yield_source, bond_type, authority, old_allocation_weight_bps, new_allocation_weight_bps,
+        old_min_deposit, new_min_deposit, old_max_allocation, new_max_allocation, old_is_active,
+        new_is_active, old_max_nav_change_bps, new_max_nav_change_bps,
+        old_max_daily_nav_change_bps, new_max_daily_nav_change_bps, timestamp,
+    }
+    stablebond_core::ProtocolPaused { authority, timestamp }
+    stablebond_core::ProtocolResumed { authority, timestamp }
+    stablebond_core::PositionFrozen { user, bond_type, reason, compliance_officer, timestamp }
+    stablebond_core::PositionUnfrozen { user, bond_type, compliance_officer, frozen_since, timestamp }
+    stablebond_core::ComplianceOfficerUpdated { authority, old_officer, new_officer, timestamp }
+    stablebond_core::AuthorityTransferProposed { authority, pending_authority, timestamp }
+    stablebond_core::AuthorityTransferAccepted { old_authority, new_authority, timestamp }
+    stablebond_core::RolesUpdated { authority, member, old_roles, new_roles, timestamp }
+    stablebond_core::ConfigChangeQueued { change_id, kind, proposer, eta, timestamp }
+    stablebond_core::ConfigChangeExecuted { change_id, kind, executor, timestamp }
+    stablebond_core::ConfigChangeCancelled { change_id, kind, authority, timestamp }
+    stablebond_core::TimelockDelayUpdated { old_delay, new_delay, timestamp }
+    stablebond_core::PauseFlagsUpdated {
+        scope, bond_type, old_flags, new_flags, paused, resumed, authority, timestamp,
+    }
+    stablebond_core::CircuitBreakerTripped {
+        yield_source, bond_type, old_nav, attempted_nav, change_bps, daily_change_bps,
+        max_change_bps, max_daily_change_bps, paused, timestamp,
+    }
+    stablebond_core::CircuitBreakerReset {
+        yield_source, bond_type, tripped_nav, nav_accepted, nav_per_share, resumed, authority,
+        timestamp,
+    }
+    stablebond_core::AccountMigrated { account, old_len, new_len, payer, timestamp }
+
+    // ─── stablebond-yield ────────────────────────────────────────────────────
+    stablebond_yield::ComplianceOfficerSet { vault, bond_type, old_officer, new_officer, timestamp }
+    stablebond_yield::SharesFrozen {
+        vault, bond_type, owner, token_account, reason, compliance_officer, timestamp,
+    }
+    stablebond_yield::SharesThawed { vault, bond_type, owner, token_account, compliance_officer, timestamp }
+    stablebond_yield::ShareMetadataUpdated { vault, bond_type, name, symbol, uri, authority, timestamp }
+    stablebond_yield::VaultAuthorityProposed { vault, bond_type, authority, pending_authority, timestamp }
+    stablebond_yield::VaultAuthorityAccepted { vault, bond_type, old_authority, new_authority, timestamp }
+    stablebond_yield::VaultRolesUpdated { vault, bond_type, member, old_roles, new_roles, timestamp }
+    stablebond_yield::VaultChangeQueued { vault, change_id, kind, proposer, eta, timestamp }
+    stablebond_yield::VaultChangeExecuted { vault, change_id, kind, executor, timestamp }
+    stablebond_yield::VaultChangeCancelled { vault, change_id, kind, authority, timestamp }
+    stablebond_yield::VaultTimelockDelayUpdated { vault, old_delay, new_delay, timestamp }
+    stablebond_yield::VaultPauseFlagsUpdated {
+        vault, bond_type, old_flags, new_flags, paused, resumed, authority, timestamp,
+    }
+    stablebond_yield::ReserveShortfall {
+        vault, bond_type, attested_reserve, coverage_bps, min_coverage_bps, timestamp,
+    }
+    stablebond_yield::MinCoverageUpdated {
+        vault, old_min_coverage_bps, new_min_coverage_bps, authority, timestamp,
+    }
+    stablebond_yield::ReserveHoldingVerified { vault, security_id, quantity, price, merkle_root, attested_at }
+    stablebond_yield::AttestorSetConfigured {
+        vault, attestor_set, attestors, threshold, window, tolerance_bps, authority, timestamp,
+    }
+    stablebond_yield::QuorumAttestationSubmitted {
+        vault, attestor, attested_reserve, submissions, threshold, round_start, timestamp,
+    }
+    stablebond_yield::QuorumAttestationRecorded {
+        vault, attested_reserve, highest_reserve, spread_bps, submissions, timestamp,
+    }
+    stablebond_yield::AttestationDisagreement {
+        vault, lowest_reserve, highest_reserve, spread_bps, tolerance_bps, timestamp,
+    }
+    stablebond_yield::ReserveAttestationRecorded {
+        vault, seq, attestor, attested_reserve, merkle_root, nav_per_share, total_shares,
+        coverage_bps, timestamp,
+    }
+    stablebond_yield::VaultInitialized {
+        vault, bond_type, authority, currency_mint, share_mint, target_apy_bps, coupon_rate_bps,
+        maturity_date, timestamp,
+    }
+    stablebond_yield::VaultMigrated {
+        vault, bond_type, legacy_vault, amount, legacy_shares, reserved_shares, nav_per_share,
+        authority, timestamp,
+    }
+    stablebond_yield::SharesMigrated { vault, user, legacy_shares, shares, timestamp }
+    stablebond_yield::VaultDeposited {
+        vault, user, amount, shares, nav_per_share, total_deposits, total_shares, timestamp,
+    }
+    stablebond_yield::VaultWithdrawn {
+        vault, user, shares, amount_out, nav_per_share, total_deposits, total_shares, timestamp,
+    }
+    stablebond_yield::UserSharesSynced { vault, user, old_shares, new_shares, timestamp }
+    stablebond_yield::YieldAccrued {
+        vault, bond_type, old_nav, new_nav, apy_bps, oracle_priced, oracle_price, elapsed, keeper,
+        keeper_reward, timestamp,
+    }
+    stablebond_yield::VaultApyUpdated { vault, old_apy_bps, new_apy_bps, authority, timestamp }
+    stablebond_yield::OracleConfigured {
+        vault, old_oracle_feed, new_oracle_feed, old_enabled, new_enabled, authority, timestamp,
+    }
+    stablebond_yield::ReserveAttestorConfigured {
+        vault, old_attestor, new_attestor, old_max_staleness, new_max_staleness, authority,
+        timestamp,
+    }
+    stablebond_yield::ImmediateWithdrawSet { vault, allow, authority, timestamp }
+}
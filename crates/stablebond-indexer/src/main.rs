@@ -0,0 +1,194 @@
+//! stablebond-indexer — decodes stablebond-core and stablebond-yield events
+//! from transaction logs into a SQLite database with one table per event
+//! type, and answers wallet-activity and NAV-history queries from it.
+
+mod events;
+mod source;
+mod store;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+use anyhow::ensure;
+use clap::{Parser, Subcommand};
+use rusqlite::types::Value;
+use stablebond_client::stablebond_types::BondType;
+use stablebond_client::{Pubkey, RpcClient};
+
+use source::{fetch_transaction, finalized_slot, read_dump, signatures_since, PROGRAMS};
+use store::Store;
+
+#[derive(Parser)]
+#[command(version, about = "Stablebond event indexer")]
+struct Args {
+    /// SQLite database path (created if missing)
+    #[arg(long, global = true, default_value = "stablebond-events.db")]
+    db: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index new transactions from an RPC node. The first run backfills the
+    /// full history; later runs resume from the last finalized transaction.
+    Sync {
+        #[arg(long, default_value = "http://127.0.0.1:8899")]
+        url: String,
+        /// `confirmed` indexes ahead of finality; those rows are re-checked next sync
+        #[arg(long, default_value = "confirmed")]
+        commitment: String,
+        /// Keep polling after catching up
+        #[arg(long)]
+        follow: bool,
+        #[arg(long, default_value_t = 10)]
+        interval_secs: u64,
+        /// getSignaturesForAddress page size (max 1000)
+        #[arg(long, default_value_t = 1000)]
+        page_size: usize,
+    },
+    /// Index a JSON dump of `getTransaction` results (array or one per line)
+    Import { path: PathBuf },
+    /// Every event involving a wallet, oldest first
+    Wallet { address: Pubkey },
+    /// NAV history of a bond type (0 = US T-Bill, 1 = MX CETES, 2 = BR Tesouro,
+    /// 3 = JP JGB, 4 = Custom)
+    Nav { bond_type: u8 },
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let mut store = Store::open(&args.db)?;
+
+    match args.command {
+        Command::Sync {
+            url,
+            commitment,
+            follow,
+            interval_secs,
+            page_size,
+        } => {
+            ensure!(
+                (1..=1000).contains(&page_size),
+                "--page-size must be 1-1000"
+            );
+            let rpc = RpcClient::new_with_commitment(url, commitment);
+            loop {
+                if let Err(err) = sync(&rpc, &mut store, page_size) {
+                    if !follow {
+                        return Err(err);
+                    }
+                    eprintln!("[Indexer] Sync failed: {err:#}");
+                }
+                if !follow {
+                    return Ok(());
+                }
+                sleep(Duration::from_secs(interval_secs));
+            }
+        }
+        Command::Import { path } => {
+            let transactions = read_dump(&path)?;
+            let mut events = 0;
+            for indexed in &transactions {
+                store.insert(indexed, true, &[])?;
+                events += indexed.events.len();
+            }
+            println!(
+                "[Indexer] Imported {} transaction(s), {events} event(s)",
+                transactions.len()
+            );
+            Ok(())
+        }
+        Command::Wallet { address } => {
+            for row in store.wallet_activity(&address)? {
+                let fields: Vec<_> = row
+                    .fields
+                    .iter()
+                    .map(|(name, value)| format!("{name}={}", display(value)))
+                    .collect();
+                println!(
+                    "slot {} {} {} {}\n    {}",
+                    row.slot,
+                    row.block_time.map_or("-".to_string(), |t| t.to_string()),
+                    row.event,
+                    row.signature,
+                    fields.join(" ")
+                );
+            }
+            Ok(())
+        }
+        Command::Nav { bond_type } => {
+            ensure!(
+                bond_type <= BondType::Custom.as_u8(),
+                "unknown bond type {bond_type}"
+            );
+            println!(
+                "{:>12}  {:>12}  {:<12}  {:>10}  {:>10}  {:<44}  signature",
+                "slot", "timestamp", "source", "old nav", "new nav", "account"
+            );
+            for point in store.nav_history(bond_type)? {
+                println!(
+                    "{:>12}  {:>12}  {:<12}  {:>10}  {:>10}  {:<44}  {}",
+                    point.slot,
+                    point.timestamp,
+                    point.source,
+                    point.old_nav,
+                    point.new_nav,
+                    point.account,
+                    point.signature
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// One pass: roll back unfinalized rows, then index every signature newer
+/// than each program's cursor, oldest first.
+fn sync(rpc: &RpcClient, store: &mut Store, page_size: usize) -> anyhow::Result<()> {
+    let rolled_back = store.rollback_unfinalized()?;
+    if rolled_back > 0 {
+        println!("[Indexer] Re-checking {rolled_back} unfinalized transaction(s)");
+    }
+    let finalized = finalized_slot(rpc)?;
+
+    // A transaction touching both programs is listed twice; index it once
+    let mut pending: BTreeMap<(u64, String), Vec<Pubkey>> = BTreeMap::new();
+    for program in PROGRAMS {
+        let cursor = store.cursor(&program)?;
+        for info in signatures_since(rpc, &program, cursor.as_deref(), page_size)? {
+            pending
+                .entry((info.slot, info.signature))
+                .or_default()
+                .push(program);
+        }
+    }
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let (mut indexed_count, mut event_count) = (0, 0);
+    for ((slot, signature), programs) in &pending {
+        let indexed = fetch_transaction(rpc, signature)?;
+        store.insert(&indexed, *slot <= finalized, programs)?;
+        indexed_count += 1;
+        event_count += indexed.events.len();
+    }
+    println!(
+        "[Indexer] Indexed {indexed_count} transaction(s), {event_count} event(s) \
+         (finalized slot {finalized})"
+    );
+    Ok(())
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Integer(i) => i.to_string(),
+        Value::Real(f) => f.to_string(),
+        Value::Text(s) => s.clone(),
+        Value::Blob(b) => format!("<{} bytes>", b.len()),
+    }
+}
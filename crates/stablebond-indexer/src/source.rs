@@ -0,0 +1,341 @@
+//! Transaction sources: a JSON-RPC node (local validator or cluster) and
+//! JSON transaction dumps. Both yield `getTransaction`-shaped records whose
+//! log messages carry the Anchor events.
+
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde_json::{json, Value};
+use stablebond_client::{stablebond_core, stablebond_yield, Pubkey, RpcClient};
+
+use crate::events::Event;
+
+/// Programs whose transactions are indexed.
+pub const PROGRAMS: [Pubkey; 2] = [stablebond_core::ID, stablebond_yield::ID];
+
+/// A confirmed transaction and the events it emitted.
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// Failed transactions are recorded without events (their state changes
+    /// were rolled back)
+    pub failed: bool,
+    pub events: Vec<Event>,
+}
+
+impl IndexedTransaction {
+    /// Build from a `getTransaction` result (`json` encoding).
+    pub fn from_rpc(record: &Value) -> anyhow::Result<Self> {
+        let signature = record["transaction"]["signatures"][0]
+            .as_str()
+            .ok_or_else(|| anyhow!("missing transaction.signatures (use `json` encoding)"))?
+            .to_string();
+        let slot = record["slot"]
+            .as_u64()
+            .ok_or_else(|| anyhow!("{signature}: missing slot"))?;
+        let meta = &record["meta"];
+        let failed = !meta["err"].is_null();
+        let events = if failed {
+            Vec::new()
+        } else {
+            let logs: Vec<String> = serde_json::from_value(meta["logMessages"].clone())
+                .with_context(|| format!("{signature}: missing meta.logMessages"))?;
+            decode_logs(&signature, &logs)
+        };
+        Ok(Self {
+            signature,
+            slot,
+            block_time: record["blockTime"].as_i64(),
+            failed,
+            events,
+        })
+    }
+}
+
+/// Decode every stablebond event in a transaction's logs. `Program data:`
+/// lines are attributed to the innermost program on the invocation stack so
+/// events from other programs (and CPIs into ours) are told apart.
+fn decode_logs(signature: &str, logs: &[String]) -> Vec<Event> {
+    let mut stack: Vec<Pubkey> = Vec::new();
+    let mut events = Vec::new();
+    for line in logs {
+        if let Some(rest) = line.strip_prefix("Program data: ") {
+            let Some(program) = stack.last().filter(|p| PROGRAMS.contains(p)) else {
+                continue;
+            };
+            let Ok(data) = BASE64_STANDARD.decode(rest.trim()) else {
+                continue;
+            };
+            match Event::decode(program, &data) {
+                Some(event) => events.push(event),
+                None => eprintln!("[Indexer] {signature}: undecodable event from {program}"),
+            }
+        } else if line == "Log truncated" {
+            eprintln!("[Indexer] {signature}: logs truncated, later events are missing");
+            break;
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            let (Some(id), Some(action)) = (words.next(), words.next()) else {
+                continue;
+            };
+            match action {
+                "invoke" => {
+                    if let Ok(program) = Pubkey::from_str(id) {
+                        stack.push(program);
+                    }
+                }
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+
+/// Read a transaction dump: a JSON array of `getTransaction` results, or one
+/// result per line.
+pub fn read_dump(path: &Path) -> anyhow::Result<Vec<IndexedTransaction>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("reading dump {}", path.display()))?;
+    let records: Vec<Value> = match serde_json::from_str(&raw) {
+        Ok(Value::Array(records)) => records,
+        Ok(record @ Value::Object(_)) => vec![record],
+        _ => raw
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .with_context(|| format!("parsing dump {}", path.display()))?,
+    };
+    records.iter().map(IndexedTransaction::from_rpc).collect()
+}
+
+/// Signature listed by `getSignaturesForAddress`.
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+}
+
+/// Every signature involving `program` newer than `until`, oldest first.
+pub fn signatures_since(
+    rpc: &RpcClient,
+    program: &Pubkey,
+    until: Option<&str>,
+    page_size: usize,
+) -> anyhow::Result<Vec<SignatureInfo>> {
+    let mut all = Vec::new();
+    let mut before: Option<String> = None;
+    loop {
+        let page: Vec<Value> = rpc.call(
+            "getSignaturesForAddress",
+            json!([program.to_string(), {
+                "limit": page_size,
+                "before": before,
+                "until": until,
+                "commitment": rpc.commitment(),
+            }]),
+        )?;
+        for entry in &page {
+            let (Some(signature), Some(slot)) =
+                (entry["signature"].as_str(), entry["slot"].as_u64())
+            else {
+                bail!("malformed getSignaturesForAddress entry: {entry}");
+            };
+            all.push(SignatureInfo {
+                signature: signature.to_string(),
+                slot,
+            });
+        }
+        if page.len() < page_size {
+            break;
+        }
+        before = all.last().map(|s| s.signature.clone());
+    }
+    all.reverse();
+    Ok(all)
+}
+
+pub fn fetch_transaction(rpc: &RpcClient, signature: &str) -> anyhow::Result<IndexedTransaction> {
+    let record: Value = rpc.call(
+        "getTransaction",
+        json!([signature, {
+            "encoding": "json",
+            "commitment": rpc.commitment(),
+            "maxSupportedTransactionVersion": 0,
+        }]),
+    )?;
+    if record.is_null() {
+        bail!("{signature}: transaction not found");
+    }
+    IndexedTransaction::from_rpc(&record)
+}
+
+/// Highest slot the cluster has finalized; anything above it can still be
+/// rolled back by a fork switch.
+pub fn finalized_slot(rpc: &RpcClient) -> anyhow::Result<u64> {
+    Ok(rpc.call("getSlot", json!([{ "commitment": "finalized" }]))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Event as _;
+    use stablebond_client::stablebond_core::events::WithdrawalCancelled;
+    use stablebond_client::stablebond_yield::events::YieldAccrued;
+
+    use super::*;
+
+    const OTHER: Pubkey = Pubkey::new_from_array([9; 32]);
+
+    fn invoke(program: &Pubkey, depth: usize) -> String {
+        format!("Program {program} invoke [{depth}]")
+    }
+
+    fn success(program: &Pubkey) -> String {
+        format!("Program {program} success")
+    }
+
+    fn data(bytes: Vec<u8>) -> String {
+        format!("Program data: {}", BASE64_STANDARD.encode(bytes))
+    }
+
+    fn cancelled(nonce: u64) -> Vec<u8> {
+        WithdrawalCancelled {
+            user: Pubkey::new_from_array([1; 32]),
+            bond_type: 0,
+            shares_returned: 40_000_000,
+            nonce,
+            timestamp: 1_700_000_000,
+        }
+        .data()
+    }
+
+    fn accrued(new_nav: u64) -> Vec<u8> {
+        YieldAccrued {
+            vault: Pubkey::new_from_array([2; 32]),
+            bond_type: 0,
+            old_nav: 1_000_000,
+            new_nav,
+            apy_bps: 450,
+            oracle_priced: false,
+            oracle_price: 0,
+            elapsed: 86_400,
+            keeper: Pubkey::default(),
+            keeper_reward: 0,
+            timestamp: 1_700_000_000,
+        }
+        .data()
+    }
+
+    fn names(events: &[Event]) -> Vec<&'static str> {
+        events.iter().map(Event::name).collect()
+    }
+
+    #[test]
+    fn events_are_attributed_to_the_innermost_program() {
+        let core = stablebond_core::ID;
+        let yield_ = stablebond_yield::ID;
+        let logs = vec![
+            invoke(&core, 1),
+            data(cancelled(1)),
+            invoke(&yield_, 2),
+            data(accrued(1_000_123)),
+            success(&yield_),
+            data(cancelled(2)),
+            success(&core),
+        ];
+        let events = decode_logs("sig", &logs);
+        assert_eq!(
+            names(&events),
+            ["WithdrawalCancelled", "YieldAccrued", "WithdrawalCancelled"]
+        );
+        let Event::YieldAccrued(accrued) = &events[1] else {
+            panic!("expected YieldAccrued");
+        };
+        assert_eq!(accrued.new_nav, 1_000_123);
+        let Event::WithdrawalCancelled(cancelled) = &events[2] else {
+            panic!("expected WithdrawalCancelled");
+        };
+        assert_eq!(cancelled.nonce, 2);
+    }
+
+    #[test]
+    fn events_from_other_programs_are_ignored() {
+        let core = stablebond_core::ID;
+        let logs = vec![
+            // Another program emitting bytes that happen to be a core event
+            invoke(&OTHER, 1),
+            data(cancelled(1)),
+            success(&OTHER),
+            // A CPI out of core: its data belongs to the callee
+            invoke(&core, 1),
+            invoke(&OTHER, 2),
+            data(cancelled(2)),
+            success(&OTHER),
+            data(cancelled(3)),
+            success(&core),
+        ];
+        let events = decode_logs("sig", &logs);
+        assert_eq!(names(&events), ["WithdrawalCancelled"]);
+        let Event::WithdrawalCancelled(cancelled) = &events[0] else {
+            panic!("expected WithdrawalCancelled");
+        };
+        assert_eq!(cancelled.nonce, 3);
+    }
+
+    #[test]
+    fn events_are_decoded_only_against_the_emitting_program() {
+        // A core event logged while the yield program is on top of the stack
+        // does not match any yield discriminator
+        let yield_ = stablebond_yield::ID;
+        let logs = vec![invoke(&yield_, 1), data(cancelled(1)), success(&yield_)];
+        assert!(decode_logs("sig", &logs).is_empty());
+    }
+
+    #[test]
+    fn failed_inner_invocations_pop_the_stack() {
+        let core = stablebond_core::ID;
+        let logs = vec![
+            invoke(&core, 1),
+            invoke(&OTHER, 2),
+            format!("Program {OTHER} failed: custom program error: 0x1"),
+            data(cancelled(1)),
+            success(&core),
+        ];
+        assert_eq!(names(&decode_logs("sig", &logs)), ["WithdrawalCancelled"]);
+    }
+
+    #[test]
+    fn truncated_logs_stop_decoding() {
+        let core = stablebond_core::ID;
+        let logs = vec![
+            invoke(&core, 1),
+            data(cancelled(1)),
+            "Log truncated".to_string(),
+            data(cancelled(2)),
+        ];
+        assert_eq!(decode_logs("sig", &logs).len(), 1);
+    }
+
+    #[test]
+    fn failed_transactions_carry_no_events() {
+        let core = stablebond_core::ID;
+        let record = json!({
+            "slot": 42,
+            "blockTime": 1_700_000_000,
+            "transaction": { "signatures": ["sig"] },
+            "meta": {
+                "err": { "InstructionError": [0, { "Custom": 1 }] },
+                "logMessages": [invoke(&core, 1), data(cancelled(1))],
+            },
+        });
+        let indexed = IndexedTransaction::from_rpc(&record).unwrap();
+        assert!(indexed.failed);
+        assert!(indexed.events.is_empty());
+        assert_eq!(indexed.slot, 42);
+    }
+}
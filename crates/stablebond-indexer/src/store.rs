@@ -0,0 +1,387 @@
+//! SQLite storage: one table per event type plus transaction bookkeeping and
+//! per-program resume cursors.
+//!
+//! Rows from transactions above the finalized slot are flagged
+//! `finalized = 0`. They are deleted and re-fetched on the next sync, so a
+//! fork switch can never leave events from a dropped block behind.
+
+use std::path::Path;
+
+use anyhow::Context;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use stablebond_client::Pubkey;
+
+use crate::events::{tables, EventTable};
+use crate::source::IndexedTransaction;
+
+/// Columns shared by every event table, ahead of the event's own fields.
+const EVENT_COLUMNS: &str = "signature TEXT NOT NULL, log_index INTEGER NOT NULL, \
+                             slot INTEGER NOT NULL, block_time INTEGER";
+
+pub struct Store {
+    conn: Connection,
+    tables: Vec<EventTable>,
+}
+
+/// One event row, for activity listings.
+pub struct ActivityRow {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub signature: String,
+    pub log_index: i64,
+    pub event: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+/// A NAV observation from `NavUpdated` (core) or `YieldAccrued` (yield).
+pub struct NavPoint {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub source: String,
+    pub account: String,
+    pub old_nav: i64,
+    pub new_nav: i64,
+    pub signature: String,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let conn = Connection::open(path)
+            .with_context(|| format!("opening database {}", path.display()))?;
+        let store = Self {
+            conn,
+            tables: tables(),
+        };
+        store.migrate()?;
+        Ok(store)
+    }
+
+    fn migrate(&self) -> anyhow::Result<()> {
+        let mut sql = String::from(
+            "PRAGMA journal_mode = WAL;
+             CREATE TABLE IF NOT EXISTS transactions (
+                 signature  TEXT PRIMARY KEY,
+                 slot       INTEGER NOT NULL,
+                 block_time INTEGER,
+                 failed     INTEGER NOT NULL,
+                 finalized  INTEGER NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS transactions_slot ON transactions (slot);
+             CREATE TABLE IF NOT EXISTS cursors (
+                 program   TEXT PRIMARY KEY,
+                 signature TEXT NOT NULL,
+                 slot      INTEGER NOT NULL
+             );\n",
+        );
+        for table in &self.tables {
+            let name = table.table();
+            let columns: Vec<_> = table
+                .columns
+                .iter()
+                .map(|c| format!("\"{}\" {}", c.name, c.sql_type))
+                .collect();
+            sql.push_str(&format!(
+                "CREATE TABLE IF NOT EXISTS \"{name}\" ({EVENT_COLUMNS}, {}, \
+                 PRIMARY KEY (signature, log_index));\n\
+                 CREATE INDEX IF NOT EXISTS \"{name}_slot\" ON \"{name}\" (slot);\n",
+                columns.join(", ")
+            ));
+        }
+        sql.push_str(
+            "CREATE VIEW IF NOT EXISTS nav_history AS
+                 SELECT slot, block_time, signature, log_index, bond_type, 'yield_source' AS source,
+                        yield_source AS account, old_nav, new_nav, timestamp
+                   FROM nav_updated
+                 UNION ALL
+                 SELECT slot, block_time, signature, log_index, bond_type, 'bond_vault' AS source,
+                        vault AS account, old_nav, new_nav, timestamp
+                   FROM yield_accrued;",
+        );
+        self.conn.execute_batch(&sql)?;
+        Ok(())
+    }
+
+    /// Drop everything recorded above the finalized slot at the time it was
+    /// indexed; returns the number of transactions rolled back.
+    pub fn rollback_unfinalized(&mut self) -> anyhow::Result<usize> {
+        let tx = self.conn.transaction()?;
+        for table in &self.tables {
+            tx.execute(
+                &format!(
+                    "DELETE FROM \"{}\" WHERE signature IN \
+                     (SELECT signature FROM transactions WHERE finalized = 0)",
+                    table.table()
+                ),
+                [],
+            )?;
+        }
+        let removed = tx.execute("DELETE FROM transactions WHERE finalized = 0", [])?;
+        tx.commit()?;
+        Ok(removed)
+    }
+
+    /// Newest finalized signature indexed for `program`.
+    pub fn cursor(&self, program: &Pubkey) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT signature FROM cursors WHERE program = ?1",
+                [program.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Record `indexed` and its events atomically, advancing the cursors of
+    /// `programs` when the transaction is finalized.
+    pub fn insert(
+        &mut self,
+        indexed: &IndexedTransaction,
+        finalized: bool,
+        programs: &[Pubkey],
+    ) -> anyhow::Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT OR REPLACE INTO transactions (signature, slot, block_time, failed, finalized)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                indexed.signature,
+                indexed.slot as i64,
+                indexed.block_time,
+                indexed.failed,
+                finalized
+            ],
+        )?;
+        for (log_index, event) in indexed.events.iter().enumerate() {
+            let table = self
+                .tables
+                .iter()
+                .find(|t| t.name == event.name())
+                .expect("every event has a table");
+            let placeholders: Vec<_> = (1..=table.columns.len() + 4)
+                .map(|i| format!("?{i}"))
+                .collect();
+            let columns: Vec<_> = table
+                .columns
+                .iter()
+                .map(|c| format!("\"{}\"", c.name))
+                .collect();
+            let mut values = vec![
+                Value::Text(indexed.signature.clone()),
+                Value::Integer(log_index as i64),
+                Value::Integer(indexed.slot as i64),
+                indexed.block_time.map_or(Value::Null, Value::Integer),
+            ];
+            values.extend(event.values());
+            tx.execute(
+                &format!(
+                    "INSERT OR REPLACE INTO \"{}\" (signature, log_index, slot, block_time, {}) \
+                     VALUES ({})",
+                    table.table(),
+                    columns.join(", "),
+                    placeholders.join(", ")
+                ),
+                params_from_iter(values),
+            )?;
+        }
+        if finalized {
+            for program in programs {
+                tx.execute(
+                    "INSERT INTO cursors (program, signature, slot) VALUES (?1, ?2, ?3)
+                     ON CONFLICT (program) DO UPDATE SET signature = ?2, slot = ?3
+                     WHERE excluded.slot >= cursors.slot",
+                    params![program.to_string(), indexed.signature, indexed.slot as i64],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Every event naming `wallet` in any pubkey field, oldest first.
+    pub fn wallet_activity(&self, wallet: &Pubkey) -> anyhow::Result<Vec<ActivityRow>> {
+        let wallet = wallet.to_string();
+        let mut rows = Vec::new();
+        for table in &self.tables {
+            let keys: Vec<_> = table
+                .columns
+                .iter()
+                .filter(|c| c.is_key)
+                .map(|c| format!("\"{}\" = ?1", c.name))
+                .collect();
+            if keys.is_empty() {
+                continue;
+            }
+            let columns: Vec<_> = table
+                .columns
+                .iter()
+                .map(|c| format!("\"{}\"", c.name))
+                .collect();
+            let mut stmt = self.conn.prepare(&format!(
+                "SELECT slot, block_time, signature, log_index, {} FROM \"{}\" WHERE {}",
+                columns.join(", "),
+                table.table(),
+                keys.join(" OR ")
+            ))?;
+            let found = stmt.query_map([&wallet], |row| {
+                let fields = table
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| Ok((c.name, row.get::<_, Value>(i + 4)?)))
+                    .collect::<rusqlite::Result<_>>()?;
+                Ok(ActivityRow {
+                    slot: row.get::<_, i64>(0)? as u64,
+                    block_time: row.get(1)?,
+                    signature: row.get(2)?,
+                    log_index: row.get(3)?,
+                    event: table.name,
+                    fields,
+                })
+            })?;
+            for row in found {
+                rows.push(row?);
+            }
+        }
+        rows.sort_by(|a, b| {
+            (a.slot, &a.signature, a.log_index).cmp(&(b.slot, &b.signature, b.log_index))
+        });
+        Ok(rows)
+    }
+
+    /// NAV history of a bond type from both programs, oldest first.
+    pub fn nav_history(&self, bond_type: u8) -> anyhow::Result<Vec<NavPoint>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT slot, timestamp, source, account, old_nav, new_nav, signature
+               FROM nav_history WHERE bond_type = ?1 ORDER BY slot, signature, log_index",
+        )?;
+        let points = stmt
+            .query_map([bond_type], |row| {
+                Ok(NavPoint {
+                    slot: row.get::<_, i64>(0)? as u64,
+                    timestamp: row.get(1)?,
+                    source: row.get(2)?,
+                    account: row.get(3)?,
+                    old_nav: row.get(4)?,
+                    new_nav: row.get(5)?,
+                    signature: row.get(6)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stablebond_client::stablebond_core;
+    use stablebond_client::stablebond_core::events::WithdrawalCancelled;
+
+    use super::*;
+    use crate::events::Event;
+
+    const USER: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    fn transaction(signature: &str, slot: u64) -> IndexedTransaction {
+        IndexedTransaction {
+            signature: signature.to_string(),
+            slot,
+            block_time: Some(1_700_000_000),
+            failed: false,
+            events: vec![Event::WithdrawalCancelled(WithdrawalCancelled {
+                user: USER,
+                bond_type: 0,
+                shares_returned: 40_000_000,
+                nonce: slot,
+                timestamp: 1_700_000_000,
+            })],
+        }
+    }
+
+    fn count(store: &Store, table: &str) -> i64 {
+        store
+            .conn
+            .query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+    }
+
+    fn activity_signatures(store: &Store) -> Vec<String> {
+        store
+            .wallet_activity(&USER)
+            .unwrap()
+            .into_iter()
+            .map(|row| row.signature)
+            .collect()
+    }
+
+    #[test]
+    fn rollback_removes_only_unfinalized_transactions_and_events() {
+        let mut store = Store::open(Path::new(":memory:")).unwrap();
+        let programs = [stablebond_core::ID];
+        store
+            .insert(&transaction("a", 10), true, &programs)
+            .unwrap();
+        store
+            .insert(&transaction("b", 20), false, &programs)
+            .unwrap();
+        store
+            .insert(&transaction("c", 30), false, &programs)
+            .unwrap();
+        assert_eq!(activity_signatures(&store), ["a", "b", "c"]);
+        // Unfinalized transactions never advance the cursor
+        assert_eq!(
+            store.cursor(&stablebond_core::ID).unwrap().as_deref(),
+            Some("a")
+        );
+
+        assert_eq!(store.rollback_unfinalized().unwrap(), 2);
+        assert_eq!(count(&store, "transactions"), 1);
+        assert_eq!(count(&store, "withdrawal_cancelled"), 1);
+        assert_eq!(activity_signatures(&store), ["a"]);
+        assert_eq!(
+            store.cursor(&stablebond_core::ID).unwrap().as_deref(),
+            Some("a")
+        );
+
+        // Nothing left to roll back
+        assert_eq!(store.rollback_unfinalized().unwrap(), 0);
+    }
+
+    #[test]
+    fn refetched_transactions_replace_their_unfinalized_rows() {
+        let mut store = Store::open(Path::new(":memory:")).unwrap();
+        let programs = [stablebond_core::ID];
+        store
+            .insert(&transaction("a", 10), false, &programs)
+            .unwrap();
+        store
+            .insert(&transaction("a", 10), true, &programs)
+            .unwrap();
+        assert_eq!(count(&store, "transactions"), 1);
+        assert_eq!(count(&store, "withdrawal_cancelled"), 1);
+        assert_eq!(store.rollback_unfinalized().unwrap(), 0);
+        assert_eq!(
+            store.cursor(&stablebond_core::ID).unwrap().as_deref(),
+            Some("a")
+        );
+    }
+
+    #[test]
+    fn cursors_never_move_backwards() {
+        let mut store = Store::open(Path::new(":memory:")).unwrap();
+        let programs = [stablebond_core::ID];
+        store
+            .insert(&transaction("b", 20), true, &programs)
+            .unwrap();
+        store
+            .insert(&transaction("a", 10), true, &programs)
+            .unwrap();
+        assert_eq!(
+            store.cursor(&stablebond_core::ID).unwrap().as_deref(),
+            Some("b")
+        );
+    }
+}
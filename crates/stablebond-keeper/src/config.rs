@@ -0,0 +1,121 @@
+use std::path::Path;
+
+use anyhow::{ensure, Context};
+use serde::Deserialize;
+
+/// Keeper configuration, loaded from TOML. See `keeper.example.toml`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeeperConfig {
+    pub rpc_url: String,
+    #[serde(default = "default_commitment")]
+    pub commitment: String,
+    /// Solana CLI JSON keypair for the keeper signer
+    pub keypair_path: String,
+    /// Pass the keeper's role assignment (set unless the keeper is the authority)
+    #[serde(default = "default_true")]
+    pub with_role: bool,
+    #[serde(default)]
+    pub nav: NavConfig,
+    #[serde(default)]
+    pub conversion: ConversionConfig,
+    #[serde(default)]
+    pub priority: PriorityConfig,
+    #[serde(default)]
+    pub retry: RetryConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NavConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    /// Use `accrue_yield_incentivized` (claims the keeper reward) for vaults
+    /// without an oracle; oracle-priced vaults always use `accrue_yield`
+    pub incentivized: bool,
+}
+
+impl Default for NavConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 60,
+            incentivized: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConversionConfig {
+    pub enabled: bool,
+    pub interval_secs: u64,
+    /// Skip deposits expiring within this many seconds
+    pub expiry_margin_secs: i64,
+}
+
+impl Default for ConversionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 10,
+            expiry_margin_secs: 30,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PriorityConfig {
+    /// Priority fee in micro-lamports per compute unit (0 = none)
+    pub compute_unit_price: u64,
+    /// Compute unit limit (0 = runtime default)
+    pub compute_unit_limit: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// How long to wait for confirmation before resending
+    pub confirm_timeout_secs: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 10_000,
+            confirm_timeout_secs: 30,
+        }
+    }
+}
+
+fn default_commitment() -> String {
+    "confirmed".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl KeeperConfig {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("reading config {}", path.display()))?;
+        let config: Self =
+            toml::from_str(&raw).with_context(|| format!("parsing config {}", path.display()))?;
+        ensure!(
+            config.retry.max_attempts > 0,
+            "retry.max_attempts must be at least 1"
+        );
+        ensure!(
+            config.nav.interval_secs > 0 && config.conversion.interval_secs > 0,
+            "crank intervals must be non-zero"
+        );
+        Ok(config)
+    }
+}
@@ -0,0 +1,73 @@
+//! Cross-currency conversions. Executes every `PendingDeposit` still in
+//! `Pending` status, soonest expiry first, while it can still land before
+//! `expires_at`.
+
+use stablebond_client::accounts::{
+    fetch_all, fetch_all_partial, fetch_protocol_config, fetch_token_program,
+};
+use stablebond_client::instructions::protocol::{self, Settlement};
+use stablebond_client::stablebond_core::state::{PendingDeposit, YieldSource};
+use stablebond_client::stablebond_types::DepositStatus;
+use stablebond_client::RpcClient;
+
+use crate::config::KeeperConfig;
+use crate::sender::TxSender;
+
+pub fn run(rpc: &RpcClient, sender: &TxSender, config: &KeeperConfig) -> anyhow::Result<()> {
+    let mut pending: Vec<_> = fetch_all::<PendingDeposit>(rpc)?
+        .into_iter()
+        .filter(|(_, d)| d.status == DepositStatus::Pending)
+        .collect();
+    if pending.is_empty() {
+        return Ok(());
+    }
+    pending.sort_by_key(|(_, d)| d.expires_at);
+    println!("[ConversionBot] Found {} pending deposit(s)", pending.len());
+
+    // Deposits into a legacy yield source wait until it is migrated
+    let sources = fetch_all_partial::<YieldSource>(rpc)?.accounts;
+    let usdc_mint = fetch_protocol_config(rpc)?.usdc_mint;
+    let settlement = Settlement {
+        mint: usdc_mint,
+        token_program: fetch_token_program(rpc, &usdc_mint)?,
+    };
+    let now = rpc.get_cluster_time()?;
+    let keeper = sender.pubkey();
+
+    for (address, deposit) in &pending {
+        let label = deposit.bond_type.as_str();
+        if deposit.expires_at <= now + config.conversion.expiry_margin_secs {
+            eprintln!(
+                "[ConversionBot] Skipping {address} ({label}): expires at {}",
+                deposit.expires_at
+            );
+            continue;
+        }
+        let Some((_, source)) = sources
+            .iter()
+            .find(|(_, s)| s.bond_type == deposit.bond_type && s.is_active)
+        else {
+            eprintln!("[ConversionBot] No active yield source for {label}, skipping {address}");
+            continue;
+        };
+
+        let ix = protocol::execute_conversion(
+            keeper,
+            *address,
+            deposit,
+            source.oracle_feed,
+            source,
+            &settlement,
+            config.with_role,
+        );
+        match sender.send(&[ix]) {
+            Ok(signature) => println!(
+                "[ConversionBot] Conversion executed: {signature} (deposit={address}, \
+                 user={}, bondType={label}, nonce={})",
+                deposit.user, deposit.nonce
+            ),
+            Err(err) => eprintln!("[ConversionBot] Failed to convert deposit {address}: {err:#}"),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! stablebond-keeper — cranks yield accrual, NAV sync and cross-currency
+//! conversions for every bond. Replaces the TypeScript `NavUpdater` and
+//! `ConversionBot` in `packages/sdk/src/keeper`.
+
+mod config;
+mod conversion;
+mod nav;
+mod sender;
+
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use clap::Parser;
+use solana_sdk::signature::{read_keypair_file, Signer};
+use stablebond_client::RpcClient;
+
+use config::KeeperConfig;
+use sender::TxSender;
+
+#[derive(Parser)]
+#[command(version, about = "Stablebond keeper daemon")]
+struct Args {
+    /// Path to the keeper TOML config
+    #[arg(long, default_value = "keeper.toml")]
+    config: PathBuf,
+    /// Run each enabled crank once and exit
+    #[arg(long)]
+    once: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let config = KeeperConfig::load(&args.config)?;
+    let keypair = read_keypair_file(&config.keypair_path)
+        .map_err(|e| anyhow!("reading keypair {}: {e}", config.keypair_path))?;
+    let rpc = RpcClient::new_with_commitment(&config.rpc_url, &config.commitment);
+
+    println!("[Keeper] RPC: {}", config.rpc_url);
+    println!("[Keeper] Keeper: {}", keypair.pubkey());
+    let sender = TxSender::new(&rpc, keypair, &config.priority, &config.retry);
+
+    let nav_interval = Duration::from_secs(config.nav.interval_secs);
+    let conversion_interval = Duration::from_secs(config.conversion.interval_secs);
+    let mut next_nav = Instant::now();
+    let mut next_conversion = Instant::now();
+
+    loop {
+        if config.nav.enabled && Instant::now() >= next_nav {
+            if let Err(err) = nav::run(&rpc, &sender, &config) {
+                eprintln!("[NavUpdater] Error during update: {err:#}");
+            }
+            next_nav = Instant::now() + nav_interval;
+        }
+        if config.conversion.enabled && Instant::now() >= next_conversion {
+            if let Err(err) = conversion::run(&rpc, &sender, &config) {
+                eprintln!("[ConversionBot] Error during scan: {err:#}");
+            }
+            next_conversion = Instant::now() + conversion_interval;
+        }
+        if args.once {
+            return Ok(());
+        }
+
+        let wake = match (config.nav.enabled, config.conversion.enabled) {
+            (true, true) => next_nav.min(next_conversion),
+            (true, false) => next_nav,
+            (false, true) => next_conversion,
+            (false, false) => return Err(anyhow!("no cranks enabled")),
+        };
+        sleep(wake.saturating_duration_since(Instant::now()));
+    }
+}
@@ -0,0 +1,64 @@
+//! Yield accrual and NAV sync. For every active `BondVault`, accrue yield on
+//! stablebond-yield, then push the new NAV into each linked `YieldSource`.
+
+use stablebond_client::accounts::fetch_all_partial;
+use stablebond_client::instructions::vault::VaultTokens;
+use stablebond_client::instructions::{protocol, vault};
+use stablebond_client::pda::find_associated_token_address_with_program;
+use stablebond_client::stablebond_core::state::YieldSource;
+use stablebond_client::stablebond_yield::state::BondVault;
+use stablebond_client::RpcClient;
+
+use crate::config::KeeperConfig;
+use crate::sender::TxSender;
+
+pub fn run(rpc: &RpcClient, sender: &TxSender, config: &KeeperConfig) -> anyhow::Result<()> {
+    let vaults = fetch_all_partial::<BondVault>(rpc)?;
+    let sources = fetch_all_partial::<YieldSource>(rpc)?;
+    for address in vaults.undecodable.iter().chain(&sources.undecodable) {
+        eprintln!("[NavUpdater] Skipping {address}: legacy account layout, awaiting migration");
+    }
+    let keeper = sender.pubkey();
+
+    for (address, bond_vault) in vaults.accounts.iter().filter(|(_, v)| v.is_active) {
+        let label = bond_vault.bond_type.as_str();
+
+        // Oracle-priced vaults must go through accrue_yield; the incentivized
+        // crank only accrues at the target APY
+        let accrue = if config.nav.incentivized && !bond_vault.oracle_enabled {
+            let tokens = VaultTokens::fetch(rpc, bond_vault)?;
+            let keeper_token = find_associated_token_address_with_program(
+                &keeper,
+                &tokens.currency_mint,
+                &tokens.token_program,
+            );
+            vault::accrue_yield_incentivized(keeper, bond_vault.bond_type, &tokens, keeper_token)
+        } else {
+            vault::accrue_yield(bond_vault.bond_type, bond_vault.oracle_feed)
+        };
+        match sender.send(&[accrue]) {
+            Ok(signature) => println!("[NavUpdater] Yield accrued for {label}: {signature}"),
+            Err(err) => eprintln!("[NavUpdater] Accrual failed for {label}: {err:#}"),
+        }
+
+        let linked = sources
+            .accounts
+            .iter()
+            .filter(|(_, s)| s.bond_vault == *address && s.is_active);
+        for (source_address, source) in linked {
+            if source.circuit_breaker_tripped {
+                eprintln!(
+                    "[NavUpdater] Circuit breaker tripped on {source_address} ({label}), \
+                     awaiting authority reset"
+                );
+                continue;
+            }
+            let update = protocol::update_nav(keeper, source, config.with_role);
+            match sender.send(&[update]) {
+                Ok(signature) => println!("[NavUpdater] NAV updated for {label}: {signature}"),
+                Err(err) => eprintln!("[NavUpdater] NAV update failed for {label}: {err:#}"),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,345 @@
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use stablebond_client::{ClientError, RpcClient, SignatureStatus};
+
+use crate::config::{PriorityConfig, RetryConfig};
+
+/// JSON-RPC code for a failed preflight simulation
+const PREFLIGHT_FAILURE: i64 = -32002;
+
+/// The RPC calls [`TxSender`] makes. Implemented by [`RpcClient`].
+pub trait TxSubmitter {
+    fn get_latest_blockhash(&self) -> stablebond_client::Result<Hash>;
+    fn send_transaction(&self, wire: &[u8]) -> stablebond_client::Result<String>;
+    fn get_signature_status(
+        &self,
+        signature: &str,
+    ) -> stablebond_client::Result<Option<SignatureStatus>>;
+}
+
+impl TxSubmitter for RpcClient {
+    fn get_latest_blockhash(&self) -> stablebond_client::Result<Hash> {
+        RpcClient::get_latest_blockhash(self)
+    }
+
+    fn send_transaction(&self, wire: &[u8]) -> stablebond_client::Result<String> {
+        RpcClient::send_transaction(self, wire)
+    }
+
+    fn get_signature_status(
+        &self,
+        signature: &str,
+    ) -> stablebond_client::Result<Option<SignatureStatus>> {
+        RpcClient::get_signature_status(self, signature)
+    }
+}
+
+/// Outcome of one submission attempt.
+enum Attempt {
+    Landed(String),
+    /// Worth resending with a fresh blockhash
+    Transient(anyhow::Error),
+    /// Deterministic failure (program error); resending will not help
+    Fatal(anyhow::Error),
+}
+
+/// Signs, submits and confirms keeper transactions with priority fees and
+/// exponential backoff on transient failures.
+pub struct TxSender<'a, R: TxSubmitter = RpcClient> {
+    rpc: &'a R,
+    payer: Keypair,
+    priority: &'a PriorityConfig,
+    retry: &'a RetryConfig,
+}
+
+impl<'a, R: TxSubmitter> TxSender<'a, R> {
+    pub fn new(
+        rpc: &'a R,
+        payer: Keypair,
+        priority: &'a PriorityConfig,
+        retry: &'a RetryConfig,
+    ) -> Self {
+        Self {
+            rpc,
+            payer,
+            priority,
+            retry,
+        }
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    /// Send `instructions` in one transaction; returns the signature.
+    pub fn send(&self, instructions: &[Instruction]) -> anyhow::Result<String> {
+        let mut backoff = Duration::from_millis(self.retry.initial_backoff_ms);
+        let max_backoff = Duration::from_millis(self.retry.max_backoff_ms);
+        let mut attempt = 1;
+        loop {
+            match self.try_send(instructions) {
+                Attempt::Landed(signature) => return Ok(signature),
+                Attempt::Fatal(err) => return Err(err),
+                Attempt::Transient(err) if attempt >= self.retry.max_attempts => {
+                    return Err(err.context(format!("gave up after {attempt} attempts")));
+                }
+                Attempt::Transient(err) => {
+                    eprintln!(
+                        "[Sender] Attempt {attempt} failed, retrying in {backoff:?}: {err:#}"
+                    );
+                    sleep(backoff);
+                    backoff = (backoff * 2).min(max_backoff);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn try_send(&self, instructions: &[Instruction]) -> Attempt {
+        let blockhash = match self.rpc.get_latest_blockhash() {
+            Ok(blockhash) => blockhash,
+            Err(err) => return Attempt::Transient(err.into()),
+        };
+        let mut all = self.budget_instructions();
+        all.extend_from_slice(instructions);
+        let tx = Transaction::new_signed_with_payer(
+            &all,
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        );
+        let wire = match bincode::serialize(&tx) {
+            Ok(wire) => wire,
+            Err(err) => return Attempt::Fatal(err.into()),
+        };
+
+        let signature = match self.rpc.send_transaction(&wire) {
+            Ok(signature) => signature,
+            Err(ClientError::Rpc { code, message })
+                if code == PREFLIGHT_FAILURE && !message.contains("Blockhash not found") =>
+            {
+                return Attempt::Fatal(anyhow!("preflight failed: {message}"));
+            }
+            Err(err) => return Attempt::Transient(err.into()),
+        };
+
+        match self.confirm(&signature) {
+            Ok(()) => Attempt::Landed(signature),
+            Err(err) => err,
+        }
+    }
+
+    fn confirm(&self, signature: &str) -> Result<(), Attempt> {
+        let deadline = Instant::now() + Duration::from_secs(self.retry.confirm_timeout_secs);
+        while Instant::now() < deadline {
+            match self.rpc.get_signature_status(signature) {
+                Ok(Some(SignatureStatus { err: Some(err), .. })) => {
+                    return Err(Attempt::Fatal(anyhow!("{signature} failed: {err}")));
+                }
+                Ok(Some(status)) if status.confirmed => return Ok(()),
+                Ok(_) => {}
+                Err(err) => eprintln!("[Sender] Status check for {signature} failed: {err}"),
+            }
+            sleep(Duration::from_millis(500));
+        }
+        Err(Attempt::Transient(anyhow!(
+            "{signature} not confirmed in time"
+        )))
+    }
+
+    fn budget_instructions(&self) -> Vec<Instruction> {
+        let mut ixs = Vec::new();
+        if self.priority.compute_unit_limit > 0 {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_limit(
+                self.priority.compute_unit_limit,
+            ));
+        }
+        if self.priority.compute_unit_price > 0 {
+            ixs.push(ComputeBudgetInstruction::set_compute_unit_price(
+                self.priority.compute_unit_price,
+            ));
+        }
+        ixs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    type Reply<T> = stablebond_client::Result<T>;
+
+    /// Replays scripted RPC replies in order, then answers every call with
+    /// success: a fresh blockhash, signature `"sig"`, confirmed.
+    #[derive(Default)]
+    struct ScriptedRpc {
+        blockhashes: RefCell<VecDeque<Reply<Hash>>>,
+        sends: RefCell<VecDeque<Reply<String>>>,
+        statuses: RefCell<VecDeque<Reply<Option<SignatureStatus>>>>,
+        send_calls: Cell<u32>,
+    }
+
+    fn next<T>(
+        script: &RefCell<VecDeque<Reply<T>>>,
+        default: impl FnOnce() -> Reply<T>,
+    ) -> Reply<T> {
+        script.borrow_mut().pop_front().unwrap_or_else(default)
+    }
+
+    impl TxSubmitter for ScriptedRpc {
+        fn get_latest_blockhash(&self) -> Reply<Hash> {
+            next(&self.blockhashes, || Ok(Hash::default()))
+        }
+
+        fn send_transaction(&self, _wire: &[u8]) -> Reply<String> {
+            self.send_calls.set(self.send_calls.get() + 1);
+            next(&self.sends, || Ok("sig".into()))
+        }
+
+        fn get_signature_status(&self, _signature: &str) -> Reply<Option<SignatureStatus>> {
+            next(&self.statuses, || Ok(Some(confirmed())))
+        }
+    }
+
+    fn confirmed() -> SignatureStatus {
+        SignatureStatus {
+            confirmed: true,
+            err: None,
+        }
+    }
+
+    fn rpc_error(code: i64, message: &str) -> ClientError {
+        ClientError::Rpc {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn retry(max_attempts: u32, confirm_timeout_secs: u64) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            initial_backoff_ms: 0,
+            max_backoff_ms: 0,
+            confirm_timeout_secs,
+        }
+    }
+
+    fn send(rpc: &ScriptedRpc, retry: &RetryConfig) -> anyhow::Result<String> {
+        let priority = PriorityConfig {
+            compute_unit_price: 1,
+            compute_unit_limit: 200_000,
+        };
+        TxSender::new(rpc, Keypair::new(), &priority, retry).send(&[])
+    }
+
+    #[test]
+    fn lands_on_the_first_attempt() {
+        let rpc = ScriptedRpc::default();
+        assert_eq!(send(&rpc, &retry(3, 30)).unwrap(), "sig");
+        assert_eq!(rpc.send_calls.get(), 1);
+    }
+
+    #[test]
+    fn program_error_in_preflight_is_fatal() {
+        let rpc = ScriptedRpc::default();
+        rpc.sends.borrow_mut().push_back(Err(rpc_error(
+            PREFLIGHT_FAILURE,
+            "Transaction simulation failed: custom program error: 0x1771",
+        )));
+
+        let err = send(&rpc, &retry(5, 30)).unwrap_err();
+        assert!(err.to_string().contains("preflight failed"), "{err:#}");
+        assert_eq!(rpc.send_calls.get(), 1);
+    }
+
+    #[test]
+    fn expired_blockhash_in_preflight_is_retried() {
+        let rpc = ScriptedRpc::default();
+        rpc.sends.borrow_mut().push_back(Err(rpc_error(
+            PREFLIGHT_FAILURE,
+            "Transaction simulation failed: Blockhash not found",
+        )));
+
+        assert_eq!(send(&rpc, &retry(5, 30)).unwrap(), "sig");
+        assert_eq!(rpc.send_calls.get(), 2);
+    }
+
+    #[test]
+    fn transport_and_node_errors_are_retried() {
+        let rpc = ScriptedRpc::default();
+        rpc.sends.borrow_mut().extend([
+            Err(ClientError::Transport("connection reset".into())),
+            Err(rpc_error(-32005, "Node is behind by 42 slots")),
+        ]);
+
+        assert_eq!(send(&rpc, &retry(5, 30)).unwrap(), "sig");
+        assert_eq!(rpc.send_calls.get(), 3);
+    }
+
+    #[test]
+    fn blockhash_failure_is_retried_without_sending() {
+        let rpc = ScriptedRpc::default();
+        rpc.blockhashes
+            .borrow_mut()
+            .push_back(Err(ClientError::Transport("timed out".into())));
+
+        assert_eq!(send(&rpc, &retry(5, 30)).unwrap(), "sig");
+        assert_eq!(rpc.send_calls.get(), 1);
+    }
+
+    #[test]
+    fn failed_transaction_is_fatal() {
+        let rpc = ScriptedRpc::default();
+        rpc.statuses
+            .borrow_mut()
+            .push_back(Ok(Some(SignatureStatus {
+                confirmed: true,
+                err: Some(serde_json::json!({ "InstructionError": [2, { "Custom": 6001 }] })),
+            })));
+
+        let err = send(&rpc, &retry(5, 30)).unwrap_err();
+        assert!(err.to_string().contains("sig failed"), "{err:#}");
+        assert_eq!(rpc.send_calls.get(), 1);
+    }
+
+    #[test]
+    fn unconfirmed_transaction_is_resent_until_attempts_run_out() {
+        // A zero timeout never sees the transaction confirm
+        let rpc = ScriptedRpc::default();
+
+        let err = send(&rpc, &retry(3, 0)).unwrap_err();
+        assert!(
+            err.to_string().contains("gave up after 3 attempts"),
+            "{err:#}"
+        );
+        assert!(format!("{err:#}").contains("not confirmed in time"));
+        assert_eq!(rpc.send_calls.get(), 3);
+    }
+
+    #[test]
+    fn status_poll_errors_keep_waiting_for_confirmation() {
+        let rpc = ScriptedRpc::default();
+        rpc.statuses.borrow_mut().extend([
+            Err(ClientError::Transport("timed out".into())),
+            Ok(None),
+            Ok(Some(SignatureStatus {
+                confirmed: false,
+                err: None,
+            })),
+        ]);
+
+        assert_eq!(send(&rpc, &retry(1, 30)).unwrap(), "sig");
+        assert_eq!(rpc.send_calls.get(), 1);
+    }
+}
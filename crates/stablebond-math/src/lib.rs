@@ -0,0 +1,138 @@
+//! Checked fixed-point math shared by the Stablebond programs and off-chain
+//! tools.
+//!
+//! Every operation widens to u128, returns an error instead of wrapping or
+//! truncating on the way back to u64, and rounds in an explicit direction.
+//! The named helpers fix that direction in the vault's favour: shares minted
+//! and amounts paid out round down, fees and liabilities round up.
+
+#![no_std]
+
+use core::fmt;
+
+/// NAV and bond price scale: 1_000_000 = 1.000000.
+pub const NAV_SCALE: u64 = 1_000_000;
+/// FX rate scale: 1_000_000 = 1 unit of source currency per settlement unit.
+pub const FX_SCALE: u64 = 1_000_000;
+/// Basis points in 100%.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit in a u64 (or an intermediate in a u128)
+    Overflow,
+    DivisionByZero,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => f.write_str("arithmetic overflow"),
+            MathError::DivisionByZero => f.write_str("division by zero"),
+        }
+    }
+}
+
+pub type MathResult<T> = Result<T, MathError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+// ─── Primitives ───
+
+/// `a * b / denominator`, rounded as requested.
+pub fn mul_div(a: u64, b: u64, denominator: u64, rounding: Rounding) -> MathResult<u64> {
+    div_u128(a as u128 * b as u128, denominator as u128, rounding)
+}
+
+/// `numerator / denominator` narrowed to u64, rounded as requested.
+fn div_u128(numerator: u128, denominator: u128, rounding: Rounding) -> MathResult<u64> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let mut quotient = numerator / denominator;
+    if rounding == Rounding::Up && quotient * denominator != numerator {
+        // Cannot overflow: quotient < numerator when there is a remainder
+        quotient += 1;
+    }
+    u64::try_from(quotient).map_err(|_| MathError::Overflow)
+}
+
+/// `part` as basis points of `whole`, rounded as requested.
+pub fn ratio_bps(part: u64, whole: u64, rounding: Rounding) -> MathResult<u64> {
+    mul_div(part, BPS_DENOMINATOR, whole, rounding)
+}
+
+// ─── Shares and NAV ───
+
+/// Shares minted for a deposit of `amount` at `nav`. Rounds down.
+pub fn shares_for_amount(amount: u64, nav: u64) -> MathResult<u64> {
+    mul_div(amount, NAV_SCALE, nav, Rounding::Down)
+}
+
+/// Settlement currency paid out for `shares` at `nav`. Rounds down.
+pub fn amount_for_shares(shares: u64, nav: u64) -> MathResult<u64> {
+    mul_div(shares, nav, NAV_SCALE, Rounding::Down)
+}
+
+/// Value owed on `shares` at `nav`, for liability and coverage checks.
+/// Rounds up.
+pub fn liability_for_shares(shares: u64, nav: u64) -> MathResult<u64> {
+    mul_div(shares, nav, NAV_SCALE, Rounding::Up)
+}
+
+// ─── Fees and conversions ───
+
+/// Fee of `fee_bps` on `amount`. Rounds up.
+pub fn fee(amount: u64, fee_bps: u16) -> MathResult<u64> {
+    mul_div(amount, fee_bps as u64, BPS_DENOMINATOR, Rounding::Up)
+}
+
+/// `bps` of `amount` paid out by the vault (rewards, incentives). Rounds down.
+pub fn payout_bps(amount: u64, bps: u64) -> MathResult<u64> {
+    mul_div(amount, bps, BPS_DENOMINATOR, Rounding::Down)
+}
+
+/// Settlement currency bought with `source_amount` at `rate` (source
+/// currency per settlement unit, `FX_SCALE`-scaled). Rounds down.
+pub fn convert_at_rate(source_amount: u64, rate: u64) -> MathResult<u64> {
+    mul_div(source_amount, FX_SCALE, rate, Rounding::Down)
+}
+
+// ─── Accrual ───
+
+/// Interest on `nav` at `apy_bps` over `elapsed` seconds:
+/// nav * apy * elapsed / (10000 * SECONDS_PER_YEAR). Rounds down.
+pub fn accrual(nav: u64, apy_bps: u64, elapsed: u64) -> MathResult<u64> {
+    let numerator = (nav as u128 * apy_bps as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(MathError::Overflow)?;
+    div_u128(
+        numerator,
+        BPS_DENOMINATOR as u128 * SECONDS_PER_YEAR as u128,
+        Rounding::Down,
+    )
+}
+
+/// `nav` after accruing `apy_bps` for `elapsed` seconds. Rounds down.
+pub fn accrued_nav(nav: u64, apy_bps: u64, elapsed: u64) -> MathResult<u64> {
+    nav.checked_add(accrual(nav, apy_bps, elapsed)?)
+        .ok_or(MathError::Overflow)
+}
+
+/// Annual yield implied by a bond `price` (`NAV_SCALE` = par) paying
+/// `coupon_bps`: the coupon plus the discount to par, or less the premium
+/// over par, each as a share of the price. Rounds down.
+pub fn implied_apy_bps(price: u64, coupon_bps: u64) -> MathResult<u64> {
+    if price < NAV_SCALE {
+        let discount = ratio_bps(NAV_SCALE - price, price, Rounding::Down)?;
+        Ok(discount.saturating_add(coupon_bps))
+    } else {
+        let premium = ratio_bps(price - NAV_SCALE, price, Rounding::Up)?;
+        Ok(coupon_bps.saturating_sub(premium))
+    }
+}
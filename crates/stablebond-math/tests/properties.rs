@@ -0,0 +1,212 @@
+use proptest::prelude::*;
+use stablebond_math::*;
+
+/// NAV from 0.01 to 1_000_000.00
+fn nav() -> impl Strategy<Value = u64> {
+    NAV_SCALE / 100..=NAV_SCALE * 1_000_000
+}
+
+fn exact_product(a: u64, b: u64) -> u128 {
+    a as u128 * b as u128
+}
+
+proptest! {
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction(
+        a in any::<u64>(),
+        b in any::<u64>(),
+        d in 1..=u64::MAX,
+    ) {
+        let exact = exact_product(a, b);
+        let floor = exact / d as u128;
+        let ceil = floor + u128::from(!exact.is_multiple_of(d as u128));
+
+        match mul_div(a, b, d, Rounding::Down) {
+            Ok(down) => prop_assert_eq!(down as u128, floor),
+            Err(e) => {
+                prop_assert_eq!(e, MathError::Overflow);
+                prop_assert!(floor > u64::MAX as u128);
+            }
+        }
+        match mul_div(a, b, d, Rounding::Up) {
+            Ok(up) => prop_assert_eq!(up as u128, ceil),
+            Err(e) => {
+                prop_assert_eq!(e, MathError::Overflow);
+                prop_assert!(ceil > u64::MAX as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_denominator(a in any::<u64>(), b in any::<u64>()) {
+        prop_assert_eq!(mul_div(a, b, 0, Rounding::Down), Err(MathError::DivisionByZero));
+        prop_assert_eq!(mul_div(a, b, 0, Rounding::Up), Err(MathError::DivisionByZero));
+    }
+
+    // ─── Shares and NAV ───
+
+    #[test]
+    fn deposit_then_redeem_never_pays_out_more(amount in 0..=u64::MAX / 2, nav in nav()) {
+        if let Ok(shares) = shares_for_amount(amount, nav) {
+            let redeemed = amount_for_shares(shares, nav).unwrap();
+            prop_assert!(redeemed <= amount);
+            // Loses at most one share's worth plus one unit to rounding
+            prop_assert!(amount - redeemed <= nav / NAV_SCALE + 1);
+        }
+    }
+
+    #[test]
+    fn redeem_then_deposit_never_mints_more(shares in any::<u64>(), nav in nav()) {
+        if let Ok(amount) = amount_for_shares(shares, nav) {
+            prop_assert!(shares_for_amount(amount, nav).unwrap() <= shares);
+        }
+    }
+
+    #[test]
+    fn liability_covers_redemption(shares in any::<u64>(), nav in any::<u64>()) {
+        let exact = exact_product(shares, nav);
+        match liability_for_shares(shares, nav) {
+            Ok(owed) => {
+                let paid = amount_for_shares(shares, nav).unwrap();
+                prop_assert!(owed >= paid);
+                prop_assert!(owed - paid <= 1);
+                prop_assert!(owed as u128 * NAV_SCALE as u128 >= exact);
+            }
+            Err(e) => prop_assert_eq!(e, MathError::Overflow),
+        }
+    }
+
+    #[test]
+    fn shares_are_monotonic(a in any::<u64>(), b in any::<u64>(), nav in nav()) {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        if let Ok(hi_shares) = shares_for_amount(hi, nav) {
+            prop_assert!(shares_for_amount(lo, nav).unwrap() <= hi_shares);
+        }
+    }
+
+    // ─── Fees and conversions ───
+
+    #[test]
+    fn fee_rounds_up_and_never_exceeds_amount(amount in any::<u64>(), bps in 0..=10_000u16) {
+        let charged = fee(amount, bps).unwrap();
+        prop_assert!(charged <= amount);
+        let exact = exact_product(amount, bps as u64);
+        prop_assert!(charged as u128 * BPS_DENOMINATOR as u128 >= exact);
+        prop_assert!(charged - payout_bps(amount, bps as u64).unwrap() <= 1);
+        if bps > 0 && amount > 0 {
+            prop_assert!(charged > 0);
+        }
+    }
+
+    #[test]
+    fn fee_never_panics(amount in any::<u64>(), bps in any::<u16>()) {
+        let _ = fee(amount, bps);
+    }
+
+    #[test]
+    fn conversion_rounds_down(source in any::<u64>(), rate in 1..=u64::MAX) {
+        let exact = exact_product(source, FX_SCALE) / rate as u128;
+        match convert_at_rate(source, rate) {
+            Ok(settled) => prop_assert_eq!(settled as u128, exact),
+            Err(e) => {
+                prop_assert_eq!(e, MathError::Overflow);
+                prop_assert!(exact > u64::MAX as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn ratio_of_self_is_whole(x in 1..=u64::MAX) {
+        prop_assert_eq!(ratio_bps(x, x, Rounding::Down).unwrap(), BPS_DENOMINATOR);
+        prop_assert_eq!(ratio_bps(x, x, Rounding::Up).unwrap(), BPS_DENOMINATOR);
+        prop_assert_eq!(ratio_bps(0, x, Rounding::Up).unwrap(), 0);
+    }
+
+    // ─── Accrual ───
+
+    #[test]
+    fn accrual_never_decreases_nav(
+        nav in any::<u64>(),
+        apy in any::<u64>(),
+        elapsed in any::<u64>(),
+    ) {
+        match accrued_nav(nav, apy, elapsed) {
+            Ok(next) => prop_assert!(next >= nav),
+            Err(e) => prop_assert_eq!(e, MathError::Overflow),
+        }
+    }
+
+    #[test]
+    fn accrual_is_monotonic(
+        nav in nav(),
+        apy in 0..=50_000u64,
+        a in 0..=10 * SECONDS_PER_YEAR,
+        b in 0..=10 * SECONDS_PER_YEAR,
+    ) {
+        let (short, long) = if a <= b { (a, b) } else { (b, a) };
+        let full = accrued_nav(nav, apy, long).unwrap();
+        prop_assert!(accrued_nav(nav, apy, short).unwrap() <= full);
+        prop_assert!(accrued_nav(nav, apy / 2, long).unwrap() <= full);
+    }
+
+    #[test]
+    fn split_accrual_never_beats_one_shot(
+        nav in nav(),
+        apy in 0..=50_000u64,
+        a in 0..=SECONDS_PER_YEAR,
+        b in 0..=SECONDS_PER_YEAR,
+    ) {
+        // Two cranks compound, but each rounds down, so the result never
+        // exceeds the exact compounded NAV
+        let split = accrued_nav(accrued_nav(nav, apy, a).unwrap(), apy, b).unwrap();
+        let growth = |t: u64| 1.0 + apy as f64 * t as f64 / 10_000.0 / SECONDS_PER_YEAR as f64;
+        let exact_growth = nav as f64 * growth(a) * growth(b);
+        prop_assert!(split as f64 <= exact_growth + 1.0);
+    }
+
+    #[test]
+    fn full_year_accrues_the_apy(nav in nav(), apy in 0..=50_000u64) {
+        let interest = accrual(nav, apy, SECONDS_PER_YEAR).unwrap();
+        prop_assert_eq!(interest, payout_bps(nav, apy).unwrap());
+    }
+
+    // ─── Implied APY ───
+
+    #[test]
+    fn discount_adds_to_coupon(price in 1..NAV_SCALE, coupon in 0..=10_000u64) {
+        prop_assert!(implied_apy_bps(price, coupon).unwrap() >= coupon);
+    }
+
+    #[test]
+    fn premium_reduces_coupon(price in NAV_SCALE..=u64::MAX, coupon in 0..=10_000u64) {
+        let apy = implied_apy_bps(price, coupon).unwrap();
+        prop_assert!(apy <= coupon);
+        if price == NAV_SCALE {
+            prop_assert_eq!(apy, coupon);
+        }
+    }
+}
+
+#[test]
+fn extremes() {
+    assert_eq!(shares_for_amount(u64::MAX, NAV_SCALE), Ok(u64::MAX));
+    assert_eq!(
+        shares_for_amount(u64::MAX, NAV_SCALE - 1),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(shares_for_amount(1, 0), Err(MathError::DivisionByZero));
+    assert_eq!(
+        amount_for_shares(u64::MAX, u64::MAX),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(liability_for_shares(1, 1), Ok(1));
+    assert_eq!(amount_for_shares(1, 1), Ok(0));
+    assert_eq!(fee(1, 1), Ok(1));
+    assert_eq!(fee(u64::MAX, 10_000), Ok(u64::MAX));
+    assert_eq!(convert_at_rate(1, 0), Err(MathError::DivisionByZero));
+    assert_eq!(implied_apy_bps(0, 0), Err(MathError::DivisionByZero));
+    assert_eq!(
+        accrual(u64::MAX, u64::MAX, u64::MAX),
+        Err(MathError::Overflow)
+    );
+}
@@ -0,0 +1,985 @@
+//! Deterministic scenario replay. State lives in the programs' own account
+//! structs and every NAV, share, fee and accrual figure comes from their
+//! helper methods, so the simulator cannot drift from the on-chain math.
+//! Token balances, KYC, tier limits, timelocks and role checks are outside
+//! the model.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::AccountDeserialize;
+use stablebond_core::errors::StablebondError;
+use stablebond_core::oracle::settlement_for_source;
+use stablebond_core::state::{ProtocolConfig, UserPosition, WithdrawalRequest, YieldSource};
+use stablebond_math::{ratio_bps, Rounding, NAV_SCALE};
+use stablebond_types::{withdrawal_cooldown_seconds, PauseFlag};
+use stablebond_yield::errors::BondVaultError;
+use stablebond_yield::state::{AccrualHalt, BondVault, UserShares};
+
+use crate::scenario::{ActionKind, Scenario};
+
+/// Stand-in key for the vault's reserve attestor.
+const ATTESTOR: Pubkey = Pubkey::new_from_array([1; 32]);
+
+/// Queue order within one timestamp: claims, attestations, scenario actions,
+/// then the keeper crank so its snapshot reflects everything before it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Event {
+    Claim { user: String, nonce: u64 },
+    Attest,
+    Action(usize),
+    Crank,
+}
+
+pub struct User {
+    pub position: UserPosition,
+    pub vault_shares: UserShares,
+    pub requests: Vec<WithdrawalRequest>,
+    /// Settlement currency paid in, gross of conversion fees
+    pub deposited: u64,
+    pub withdrawn: u64,
+    /// Yield received net of performance fees
+    pub yield_received: u64,
+    pub fees_paid: u64,
+}
+
+impl User {
+    fn new() -> Self {
+        Self {
+            position: zeroed(UserPosition::LEN),
+            vault_shares: zeroed(UserShares::LEN),
+            requests: Vec::new(),
+            deposited: 0,
+            withdrawn: 0,
+            yield_received: 0,
+            fees_paid: 0,
+        }
+    }
+
+    fn pending(&self) -> impl Iterator<Item = &WithdrawalRequest> {
+        self.requests.iter().filter(|r| !r.is_claimed)
+    }
+}
+
+#[derive(Default)]
+pub struct Fees {
+    pub conversion: u64,
+    pub performance: u64,
+    pub keeper_rewards: u64,
+}
+
+pub struct LogEntry {
+    pub at: i64,
+    pub message: String,
+}
+
+/// State after a keeper crank.
+pub struct Snapshot {
+    pub at: i64,
+    pub vault_nav: u64,
+    pub source_nav: u64,
+    /// APY applied by the last accrual
+    pub apy_bps: u64,
+    pub halt: Option<AccrualHalt>,
+    pub breaker_tripped: bool,
+    /// Share value plus pending withdrawals owed by the core yield source
+    pub core_liabilities: u64,
+    pub core_assets: u64,
+    /// Net yield paid out of core assets so far. Claims do not burn shares,
+    /// so it is still counted in `core_liabilities`
+    pub core_yield_paid: u64,
+    pub vault_liabilities: u64,
+    pub vault_assets: u64,
+    /// Last attested reserve over current vault liabilities; None without an attestor
+    pub attested_coverage_bps: Option<u64>,
+}
+
+impl Snapshot {
+    pub fn core_solvency_bps(&self) -> u64 {
+        solvency_bps(self.core_assets, self.core_liabilities)
+    }
+
+    pub fn vault_solvency_bps(&self) -> u64 {
+        solvency_bps(self.vault_assets, self.vault_liabilities)
+    }
+}
+
+pub struct Simulation<'a> {
+    scenario: &'a Scenario,
+    now: i64,
+    pub vault: BondVault,
+    pub source: YieldSource,
+    config: ProtocolConfig,
+    pub users: BTreeMap<String, User>,
+    /// Settlement currency behind core positions (deposit vault plus what
+    /// the underlying has earned)
+    core_assets: u64,
+    core_yield_paid: u64,
+    /// Settlement currency behind bond vault shares
+    vault_assets: u64,
+    underlying_apy_bps: u64,
+    oracle_price: u64,
+    apy_bps: u64,
+    attesting: bool,
+    halt: Option<AccrualHalt>,
+    stalled_claims: BTreeSet<(String, u64)>,
+    queue: BinaryHeap<Reverse<(i64, Event)>>,
+    pub fees: Fees,
+    pub log: Vec<LogEntry>,
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(scenario: &'a Scenario) -> anyhow::Result<Self> {
+        let bond_type = scenario.bond_type()?;
+        let bond = &scenario.bond;
+        let target_apy_bps = bond.target_apy_bps.unwrap_or(bond_type.default_apy_bps());
+        anyhow::ensure!(
+            target_apy_bps as u64 <= BondVault::MAX_APY_BPS,
+            "target_apy_bps above {}",
+            BondVault::MAX_APY_BPS
+        );
+
+        let mut vault: BondVault = zeroed(BondVault::LEN);
+        vault.bond_type = bond_type;
+        vault.target_apy_bps = target_apy_bps;
+        vault.coupon_rate_bps = bond.coupon_rate_bps.unwrap_or(target_apy_bps);
+        vault.maturity_date = bond.maturity_day.map_or(0, |day| scenario.at(day));
+        vault.nav_per_share = NAV_SCALE;
+        vault.last_oracle_price = NAV_SCALE;
+        vault.last_accrual = scenario.start;
+        vault.is_active = true;
+        vault.total_deposits = BondVault::LOCKED_SHARES;
+        vault.total_shares = BondVault::LOCKED_SHARES;
+        if let Some(attestation) = &scenario.attestation {
+            vault.reserve_attestor = ATTESTOR;
+            vault.attestation_max_staleness = attestation.max_staleness_secs;
+            vault.min_coverage_bps = attestation.min_coverage_bps;
+        }
+
+        let mut source: YieldSource = zeroed(YieldSource::LEN);
+        source.bond_type = bond_type;
+        source.nav_per_share = NAV_SCALE;
+        source.current_apy_bps = target_apy_bps;
+        source.coupon_rate_bps = vault.coupon_rate_bps;
+        source.maturity_date = vault.maturity_date;
+        source.min_deposit = bond.min_deposit;
+        source.max_nav_change_bps = bond.max_nav_change_bps;
+        source.max_daily_nav_change_bps = bond.max_daily_nav_change_bps;
+        source.nav_day_start = YieldSource::utc_day_start(scenario.start);
+        source.nav_day_open = NAV_SCALE;
+        source.last_nav_update = scenario.start;
+        source.is_active = true;
+
+        let mut config: ProtocolConfig = zeroed(ProtocolConfig::LEN);
+        config.conversion_fee_bps = scenario.fees.conversion_fee_bps;
+        config.performance_fee_bps = scenario.fees.performance_fee_bps;
+        config.is_active = true;
+
+        let mut sim = Self {
+            scenario,
+            now: scenario.start,
+            vault,
+            source,
+            config,
+            users: BTreeMap::new(),
+            core_assets: 0,
+            core_yield_paid: 0,
+            vault_assets: BondVault::LOCKED_SHARES,
+            underlying_apy_bps: bond.underlying_apy_bps.unwrap_or(target_apy_bps) as u64,
+            oracle_price: NAV_SCALE,
+            apy_bps: 0,
+            attesting: scenario.attestation.is_some(),
+            halt: None,
+            stalled_claims: BTreeSet::new(),
+            queue: BinaryHeap::new(),
+            fees: Fees::default(),
+            log: Vec::new(),
+            snapshots: Vec::new(),
+        };
+        sim.schedule_every(scenario.keeper.interval_secs, Event::Crank);
+        if let Some(attestation) = &scenario.attestation {
+            sim.schedule_every(attestation.interval_secs, Event::Attest);
+        }
+        for (i, action) in scenario.actions.iter().enumerate() {
+            sim.schedule(scenario.at(action.day), Event::Action(i));
+        }
+        Ok(sim)
+    }
+
+    /// Process every event through the end of the scenario.
+    pub fn run(&mut self) {
+        while let Some(Reverse((at, event))) = self.queue.pop() {
+            self.advance(at);
+            match event {
+                Event::Claim { user, nonce } => self.claim_withdrawal(user, nonce),
+                Event::Attest => {
+                    if self.attesting {
+                        self.attest(None);
+                    }
+                }
+                Event::Action(i) => self.action(i),
+                Event::Crank => self.crank(),
+            }
+        }
+    }
+
+    /// Current value of a user's holdings: core shares, pending withdrawals
+    /// and bond vault shares.
+    pub fn holdings_value(&self, user: &User) -> u64 {
+        let pending: u64 = user.pending().map(|r| r.amount_out).sum();
+        let core = self
+            .source
+            .amount_for_shares(user.position.current_shares)
+            .unwrap_or(u64::MAX);
+        let vault = self
+            .vault
+            .amount_for_shares(user.vault_shares.shares)
+            .unwrap_or(u64::MAX);
+        core.saturating_add(pending).saturating_add(vault)
+    }
+
+    // ─── Scheduling ───
+
+    fn schedule(&mut self, at: i64, event: Event) {
+        if at <= self.scenario.end() {
+            self.queue.push(Reverse((at, event)));
+        }
+    }
+
+    /// Schedule `event` every `interval` seconds from the start, and once
+    /// more at the end if the interval does not divide the duration.
+    fn schedule_every(&mut self, interval: i64, event: Event) {
+        let mut at = self.scenario.start;
+        while at < self.scenario.end() {
+            self.schedule(at, event.clone());
+            at += interval;
+        }
+        self.schedule(self.scenario.end(), event);
+    }
+
+    fn record(&mut self, message: String) {
+        self.log.push(LogEntry {
+            at: self.now,
+            message,
+        });
+    }
+
+    /// Move the clock to `to`, growing the backing assets at the underlying
+    /// rate over the elapsed time.
+    fn advance(&mut self, to: i64) {
+        let elapsed = (to - self.now) as u64;
+        let grow = |assets: u64| {
+            stablebond_math::accrued_nav(assets, self.underlying_apy_bps, elapsed)
+                .unwrap_or(u64::MAX)
+        };
+        self.core_assets = grow(self.core_assets);
+        self.vault_assets = grow(self.vault_assets);
+        self.now = to;
+    }
+
+    // ─── Scenario actions ───
+
+    fn action(&mut self, i: usize) {
+        let result = match &self.scenario.actions[i].kind {
+            ActionKind::Deposit { user, amount } => self.deposit(user, *amount),
+            ActionKind::CrossCurrencyDeposit {
+                user,
+                source_amount,
+                fx_rate,
+            } => self.cross_currency_deposit(user, *source_amount, *fx_rate),
+            ActionKind::VaultDeposit { user, amount } => self.vault_deposit(user, *amount),
+            ActionKind::RequestWithdrawal { user, shares } => {
+                self.request_withdrawal(user, *shares)
+            }
+            ActionKind::ClaimYield { user } => self.claim_yield(user),
+            ActionKind::SetApy { apy_bps } => self.set_apy(*apy_bps),
+            ActionKind::OraclePrice { price } => self.set_oracle_price(*price),
+            ActionKind::DisableOracle => {
+                self.vault.oracle_enabled = false;
+                Ok("oracle disabled, accruing at target APY".to_string())
+            }
+            ActionKind::Attest { reserve } => {
+                self.attest(*reserve);
+                return;
+            }
+            ActionKind::PauseAttestations => {
+                self.attesting = false;
+                Ok("attestations paused".to_string())
+            }
+            ActionKind::ResumeAttestations => {
+                self.attesting = true;
+                self.attest(None);
+                Ok("attestations resumed".to_string())
+            }
+            ActionKind::ResetBreaker { accept_nav } => self.reset_breaker(*accept_nav),
+        };
+        let message = match result {
+            Ok(message) => message,
+            Err(err) => format!(
+                "rejected {:?}: {}",
+                self.scenario.actions[i].kind,
+                reason(&err)
+            ),
+        };
+        self.record(message);
+    }
+
+    /// `handle_deposit_direct`
+    fn deposit(&mut self, name: &str, amount: u64) -> Result<String> {
+        self.config.require_unpaused(PauseFlag::Deposits)?;
+        require!(amount > 0, StablebondError::ZeroDeposit);
+        require!(self.source.is_active, StablebondError::YieldSourceNotActive);
+        self.source.require_unpaused(PauseFlag::Deposits)?;
+        require!(
+            amount >= self.source.min_deposit,
+            StablebondError::BelowMinDeposit
+        );
+
+        let shares = self.source.shares_for_amount(amount)?;
+        require!(shares > 0, StablebondError::ZeroShares);
+        self.credit_core_shares(name, amount, shares)?;
+        let user = self.users.entry(name.to_string()).or_insert_with(User::new);
+        let position = &mut user.position;
+        position.total_deposited = position
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(StablebondError::MathOverflow)?;
+        position.deposit_count += 1;
+        position.last_deposit_at = self.now;
+        user.deposited += amount;
+        self.core_assets += amount;
+        Ok(format!("{name} deposited {amount} for {shares} shares"))
+    }
+
+    /// `handle_deposit_cross_currency` followed at once by
+    /// `handle_execute_conversion`
+    fn cross_currency_deposit(
+        &mut self,
+        name: &str,
+        source_amount: u64,
+        fx_rate: u64,
+    ) -> Result<String> {
+        self.config.require_unpaused(PauseFlag::Deposits)?;
+        self.config.require_unpaused(PauseFlag::Conversions)?;
+        require!(source_amount > 0, StablebondError::ZeroDeposit);
+        require!(self.source.is_active, StablebondError::YieldSourceNotActive);
+        self.source.require_unpaused(PauseFlag::Deposits)?;
+        require!(fx_rate > 0, StablebondError::InvalidOraclePrice);
+
+        let gross = settlement_for_source(source_amount, fx_rate)?;
+        let fee = self.config.conversion_fee(gross)?;
+        let received = gross
+            .checked_sub(fee)
+            .ok_or(StablebondError::MathOverflow)?;
+        let shares = self.source.shares_for_amount(received)?;
+        require!(shares > 0, StablebondError::ZeroShares);
+        self.credit_core_shares(name, received, shares)?;
+        let user = self.users.entry(name.to_string()).or_insert_with(User::new);
+        user.deposited += gross;
+        user.fees_paid += fee;
+        self.fees.conversion += fee;
+        self.core_assets += received;
+        Ok(format!(
+            "{name} converted {source_amount} at {fx_rate} to {received} (fee {fee}) for \
+             {shares} shares"
+        ))
+    }
+
+    /// Share and cost-basis bookkeeping shared by both core deposit paths.
+    fn credit_core_shares(&mut self, name: &str, settlement: u64, shares: u64) -> Result<()> {
+        let source = &mut self.source;
+        source.total_deposited = source
+            .total_deposited
+            .checked_add(settlement)
+            .ok_or(StablebondError::MathOverflow)?;
+        source.total_shares = source
+            .total_shares
+            .checked_add(shares)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.config.total_deposits = self
+            .config
+            .total_deposits
+            .checked_add(settlement)
+            .ok_or(StablebondError::MathOverflow)?;
+
+        let position = &mut self
+            .users
+            .entry(name.to_string())
+            .or_insert_with(User::new)
+            .position;
+        if position.created_at == 0 {
+            position.bond_type = self.source.bond_type;
+            position.created_at = self.now;
+        }
+        position.current_shares = position
+            .current_shares
+            .checked_add(shares)
+            .ok_or(StablebondError::MathOverflow)?;
+        position.cost_basis = position
+            .cost_basis
+            .checked_add(settlement)
+            .ok_or(StablebondError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// `stablebond_yield::deposit`
+    fn vault_deposit(&mut self, name: &str, amount: u64) -> Result<String> {
+        self.vault.require_unpaused(PauseFlag::Deposits)?;
+        require!(
+            !self.vault.has_reserve_shortfall(),
+            BondVaultError::ReserveShortfall
+        );
+        require!(amount > 0, BondVaultError::ZeroDeposit);
+
+        let shares = self.vault.shares_for_amount(amount)?;
+        require!(shares > 0, BondVaultError::ZeroShares);
+        self.vault.total_deposits = self
+            .vault
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(BondVaultError::MathOverflow)?;
+        self.vault.total_shares = self
+            .vault
+            .total_shares
+            .checked_add(shares)
+            .ok_or(BondVaultError::MathOverflow)?;
+        self.vault.record_inflow(amount);
+        let user = self.users.entry(name.to_string()).or_insert_with(User::new);
+        let vault_shares = &mut user.vault_shares;
+        vault_shares.shares = vault_shares
+            .shares
+            .checked_add(shares)
+            .ok_or(BondVaultError::MathOverflow)?;
+        vault_shares.deposited_amount = vault_shares
+            .deposited_amount
+            .checked_add(amount)
+            .ok_or(BondVaultError::MathOverflow)?;
+        vault_shares.last_deposit_at = self.now;
+        user.deposited += amount;
+        self.vault_assets += amount;
+        Ok(format!(
+            "{name} deposited {amount} into the bond vault for {shares} shares"
+        ))
+    }
+
+    /// `handle_request_withdrawal`; the claim is queued for the end of the
+    /// cooldown.
+    fn request_withdrawal(&mut self, name: &str, shares: Option<u64>) -> Result<String> {
+        self.config
+            .require_unpaused(PauseFlag::WithdrawalRequests)?;
+        self.source
+            .require_unpaused(PauseFlag::WithdrawalRequests)?;
+        let Some(user) = self.users.get_mut(name) else {
+            return err!(StablebondError::InsufficientShares);
+        };
+        let position = &mut user.position;
+        let shares = shares.unwrap_or(position.current_shares);
+        require!(shares > 0, StablebondError::ZeroWithdrawal);
+        require!(
+            position.current_shares >= shares,
+            StablebondError::InsufficientShares
+        );
+
+        // Lock NAV at request time
+        let amount_out = self.source.amount_for_shares(shares)?;
+        let claimable_at = self.now + withdrawal_cooldown_seconds(self.source.bond_type);
+        let nonce = position.withdrawal_nonce + 1;
+        position.current_shares = position
+            .current_shares
+            .checked_sub(shares)
+            .ok_or(StablebondError::MathOverflow)?;
+        position.withdrawal_nonce = nonce;
+
+        let mut request: WithdrawalRequest = zeroed(WithdrawalRequest::LEN);
+        request.bond_type = self.source.bond_type;
+        request.shares = shares;
+        request.amount_out = amount_out;
+        request.requested_at = self.now;
+        request.claimable_at = claimable_at;
+        request.nonce = nonce;
+        user.requests.push(request);
+        self.schedule(
+            claimable_at,
+            Event::Claim {
+                user: name.to_string(),
+                nonce,
+            },
+        );
+        Ok(format!(
+            "{name} requested withdrawal of {shares} shares for {amount_out}"
+        ))
+    }
+
+    /// `handle_claim_withdrawal`. A claim that fails (paused, or not enough
+    /// assets in the deposit vault) is retried every keeper interval.
+    fn claim_withdrawal(&mut self, name: String, nonce: u64) {
+        match self.try_claim_withdrawal(&name, nonce) {
+            Ok(amount_out) => {
+                self.stalled_claims.remove(&(name.clone(), nonce));
+                self.record(format!("{name} claimed withdrawal #{nonce}: {amount_out}"));
+            }
+            Err(err) => {
+                if self.stalled_claims.insert((name.clone(), nonce)) {
+                    self.record(format!(
+                        "{name} withdrawal #{nonce} claim failed: {} (retrying)",
+                        reason(&err)
+                    ));
+                }
+                let retry_at = self.now + self.scenario.keeper.interval_secs;
+                self.schedule(retry_at, Event::Claim { user: name, nonce });
+            }
+        }
+    }
+
+    fn try_claim_withdrawal(&mut self, name: &str, nonce: u64) -> Result<u64> {
+        self.config.require_unpaused(PauseFlag::WithdrawalClaims)?;
+        self.source.require_unpaused(PauseFlag::WithdrawalClaims)?;
+        let user = self.users.get_mut(name).expect("request owner exists");
+        let request = user
+            .requests
+            .iter_mut()
+            .find(|r| r.nonce == nonce)
+            .expect("queued request exists");
+        require!(
+            self.now >= request.claimable_at,
+            StablebondError::WithdrawalCooldownActive
+        );
+        let amount_out = request.amount_out;
+        if self.core_assets < amount_out {
+            return Err(ProgramError::InsufficientFunds.into());
+        }
+
+        request.is_claimed = true;
+        self.core_assets -= amount_out;
+        self.source.total_shares = self
+            .source
+            .total_shares
+            .checked_sub(request.shares)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.source.total_deposited = self.source.total_deposited.saturating_sub(amount_out);
+        self.config.total_deposits = self.config.total_deposits.saturating_sub(amount_out);
+        user.position.withdrawal_count += 1;
+        user.position.last_withdrawal_at = self.now;
+        user.withdrawn += amount_out;
+        Ok(amount_out)
+    }
+
+    /// `handle_claim_yield`
+    fn claim_yield(&mut self, name: &str) -> Result<String> {
+        self.config.require_unpaused(PauseFlag::YieldClaims)?;
+        self.source.require_unpaused(PauseFlag::YieldClaims)?;
+        let Some(user) = self.users.get_mut(name) else {
+            return err!(StablebondError::NoYieldToClaim);
+        };
+
+        let current_value = self
+            .source
+            .amount_for_shares(user.position.current_shares)?;
+        let yield_amount = user.position.claimable_yield(current_value);
+        require!(yield_amount > 0, StablebondError::NoYieldToClaim);
+
+        let performance_fee = self.config.performance_fee(yield_amount)?;
+        let net_yield = yield_amount
+            .checked_sub(performance_fee)
+            .ok_or(StablebondError::MathOverflow)?;
+        if self.core_assets < net_yield {
+            return Err(ProgramError::InsufficientFunds.into());
+        }
+
+        self.core_assets -= net_yield;
+        self.core_yield_paid += net_yield;
+        user.position.realized_yield = user
+            .position
+            .realized_yield
+            .checked_add(yield_amount)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.config.total_yield_earned = self
+            .config
+            .total_yield_earned
+            .checked_add(yield_amount)
+            .ok_or(StablebondError::MathOverflow)?;
+        user.yield_received += net_yield;
+        user.fees_paid += performance_fee;
+        self.fees.performance += performance_fee;
+        Ok(format!(
+            "{name} claimed {yield_amount} yield ({net_yield} net, {performance_fee} fee)"
+        ))
+    }
+
+    /// An executed `VaultChange::Apy`
+    fn set_apy(&mut self, apy_bps: u16) -> Result<String> {
+        require!(
+            apy_bps as u64 <= BondVault::MAX_APY_BPS,
+            BondVaultError::InvalidApy
+        );
+        let old = self.vault.target_apy_bps;
+        self.vault.target_apy_bps = apy_bps;
+        Ok(format!("target APY {old} → {apy_bps} bps"))
+    }
+
+    /// Publish a bond price and enable oracle pricing (an executed
+    /// `VaultChange::Oracle`). The simulated feed is always fresh.
+    fn set_oracle_price(&mut self, price: u64) -> Result<String> {
+        require!(price > 0, BondVaultError::InvalidOracle);
+        self.oracle_price = price;
+        self.vault.oracle_enabled = true;
+        Ok(format!(
+            "bond price {} (implied APY {} bps)",
+            fixed(price),
+            self.vault.oracle_apy_bps(price)?
+        ))
+    }
+
+    /// `submit_reserve_attestation` of `reserve`, or of the simulated assets.
+    fn attest(&mut self, reserve: Option<u64>) {
+        let result: Result<()> = (|| {
+            require!(
+                self.vault.reserve_attestor != Pubkey::default(),
+                BondVaultError::NoAttestorConfigured
+            );
+            let reserve = reserve.unwrap_or(self.vault_assets);
+            self.vault.record_attestation(reserve, None, self.now);
+            Ok(())
+        })();
+        if let Err(err) = result {
+            self.record(format!("attestation rejected: {}", reason(&err)));
+        } else if reserve.is_some() {
+            self.record(format!(
+                "attested reserve {} ({} bps coverage)",
+                self.vault.attested_reserve, self.vault.attested_coverage_bps
+            ));
+        }
+    }
+
+    /// `handle_reset_circuit_breaker`
+    fn reset_breaker(&mut self, accept_nav: bool) -> Result<String> {
+        require!(
+            self.source.circuit_breaker_tripped,
+            StablebondError::CircuitBreakerNotTripped
+        );
+        self.source.reset_circuit_breaker(accept_nav, self.now);
+        Ok(format!(
+            "circuit breaker reset (NAV {})",
+            fixed(self.source.nav_per_share)
+        ))
+    }
+
+    // ─── Keeper crank ───
+
+    /// `accrue_yield` (or `accrue_yield_incentivized`) then `update_nav`,
+    /// followed by a snapshot.
+    fn crank(&mut self) {
+        let halt = self.vault.halt_accrual(self.now);
+        if halt.as_ref().map(std::mem::discriminant)
+            != self.halt.as_ref().map(std::mem::discriminant)
+        {
+            let message = match halt {
+                Some(AccrualHalt::Matured) => "bond matured, accrual stopped".to_string(),
+                Some(AccrualHalt::StaleAttestation { staleness }) => {
+                    format!("attestation stale ({staleness}s), accrual paused")
+                }
+                Some(AccrualHalt::ReserveShortfall) => format!(
+                    "reserve coverage {} bps below minimum {} bps, accrual paused",
+                    self.vault.current_coverage_bps(),
+                    self.vault.min_coverage_bps
+                ),
+                None => "accrual resumed".to_string(),
+            };
+            self.record(message);
+        }
+        self.halt = halt;
+        if halt.is_none() {
+            if let Err(err) = self.accrue() {
+                self.record(format!("accrual failed: {}", reason(&err)));
+            }
+        }
+        self.update_nav();
+        self.snapshot();
+    }
+
+    fn accrue(&mut self) -> Result<()> {
+        let elapsed = (self.now - self.vault.last_accrual) as u64;
+        if elapsed == 0 || self.vault.total_shares == 0 {
+            return Ok(());
+        }
+
+        let incentivized = self.scenario.keeper.incentivized && !self.vault.oracle_enabled;
+        let apy_bps = if incentivized {
+            require!(elapsed >= 30, BondVaultError::CrankTooFrequent);
+            self.vault.target_apy_bps as u64
+        } else if self.vault.oracle_enabled {
+            self.vault.last_oracle_price = self.oracle_price;
+            self.vault
+                .oracle_apy_bps(self.oracle_price)?
+                .min(BondVault::MAX_APY_BPS)
+        } else {
+            (self.vault.target_apy_bps as u64).min(BondVault::MAX_APY_BPS)
+        };
+        self.vault.nav_per_share = self.vault.accrued_nav(apy_bps, elapsed)?;
+        self.vault.last_accrual = self.now;
+        self.apy_bps = apy_bps;
+
+        if incentivized {
+            let reward = self.vault.keeper_reward()?;
+            if reward > 0 && self.vault_assets > reward {
+                self.vault_assets -= reward;
+                self.vault.record_outflow(reward);
+                self.fees.keeper_rewards += reward;
+            }
+        }
+        Ok(())
+    }
+
+    /// `handle_update_nav`: copy the vault NAV into the yield source unless
+    /// the move trips the circuit breaker.
+    fn update_nav(&mut self) {
+        let source = &mut self.source;
+        if source.circuit_breaker_tripped {
+            return;
+        }
+        let new_nav = self.vault.nav_per_share;
+        source.roll_nav_day(self.now);
+        let nav_move = source.nav_move(new_nav);
+        if nav_move.exceeds_bounds {
+            let old_nav = source.nav_per_share;
+            source.trip_circuit_breaker(new_nav);
+            self.record(format!(
+                "circuit breaker tripped: NAV {} → {} ({} bps, {} bps today)",
+                fixed(old_nav),
+                fixed(new_nav),
+                nav_move.change_bps,
+                nav_move.daily_change_bps
+            ));
+            return;
+        }
+        source.nav_per_share = new_nav;
+        source.current_apy_bps = self.vault.target_apy_bps;
+        source.last_nav_update = self.now;
+    }
+
+    fn snapshot(&mut self) {
+        let (pending_shares, pending_amount) = self
+            .users
+            .values()
+            .flat_map(User::pending)
+            .fold((0u64, 0u64), |(shares, amount), r| {
+                (shares + r.shares, amount + r.amount_out)
+            });
+        let share_value = self
+            .source
+            .amount_for_shares(self.source.total_shares - pending_shares)
+            .unwrap_or(u64::MAX);
+        self.snapshots.push(Snapshot {
+            at: self.now,
+            vault_nav: self.vault.nav_per_share,
+            source_nav: self.source.nav_per_share,
+            apy_bps: self.apy_bps,
+            halt: self.halt,
+            breaker_tripped: self.source.circuit_breaker_tripped,
+            core_liabilities: share_value.saturating_add(pending_amount),
+            core_assets: self.core_assets,
+            core_yield_paid: self.core_yield_paid,
+            vault_liabilities: self.vault.liabilities(),
+            vault_assets: self.vault_assets,
+            attested_coverage_bps: (self.vault.reserve_attestor != Pubkey::default())
+                .then_some(self.vault.current_coverage_bps()),
+        });
+    }
+}
+
+/// An account struct with every field zeroed, as `init` leaves it.
+fn zeroed<T: AccountDeserialize>(len: usize) -> T {
+    let data = vec![0u8; len];
+    T::try_deserialize_unchecked(&mut &data[..]).expect("zeroed account data decodes")
+}
+
+fn solvency_bps(assets: u64, liabilities: u64) -> u64 {
+    ratio_bps(assets, liabilities, Rounding::Down).unwrap_or(u64::MAX)
+}
+
+/// Program error message without Anchor's log framing.
+fn reason(err: &anchor_lang::error::Error) -> String {
+    match err {
+        anchor_lang::error::Error::AnchorError(e) => e.error_msg.clone(),
+        anchor_lang::error::Error::ProgramError(e) => e.program_error.to_string(),
+    }
+}
+
+/// 1e6-scaled fixed point value.
+pub fn fixed(value: u64) -> String {
+    format!("{}.{:06}", value / 1_000_000, value % 1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    /// Accrual timing and rounding drift between the simulated underlying
+    /// and the cranked NAV.
+    const DRIFT_BPS: u64 = 10;
+
+    fn scenario_path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("scenarios")
+            .join(format!("{name}.toml"))
+    }
+
+    fn load(name: &str) -> Scenario {
+        Scenario::load(&scenario_path(name)).unwrap()
+    }
+
+    fn run(scenario: &Scenario) -> Simulation<'_> {
+        let mut sim = Simulation::new(scenario).unwrap();
+        sim.run();
+        sim
+    }
+
+    /// Core solvency with yield already paid out counted as assets.
+    fn net_core_solvency_bps(s: &Snapshot) -> u64 {
+        solvency_bps(s.core_assets + s.core_yield_paid, s.core_liabilities)
+    }
+
+    fn logged(sim: &Simulation, prefix: &str) -> bool {
+        sim.log.iter().any(|e| e.message.starts_with(prefix))
+    }
+
+    #[test]
+    fn bundled_scenarios_run_to_the_end() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+        let mut names: Vec<_> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            ["attestation-lapse", "baseline", "maturity", "oracle-path"]
+        );
+        for name in &names {
+            let scenario = load(name);
+            let sim = run(&scenario);
+            assert_eq!(sim.snapshots.last().unwrap().at, scenario.end(), "{name}");
+        }
+    }
+
+    /// `claim_yield` pays gains out without burning shares and withdrawals
+    /// still pay full share value, so yield claimed earlier is owed again at
+    /// exit: alice's final withdrawal stalls short by at most what she claimed.
+    #[test]
+    fn baseline_is_solvent_net_of_claimed_yield() {
+        let scenario = load("baseline");
+        let sim = run(&scenario);
+        let claim = scenario.at(180.0);
+        for s in &sim.snapshots {
+            assert!(s.vault_solvency_bps() >= 10_000, "vault at {}", s.at);
+            assert!(
+                net_core_solvency_bps(s) >= 10_000 - DRIFT_BPS,
+                "core at {}",
+                s.at
+            );
+            if s.at < claim {
+                assert_eq!(s.core_yield_paid, 0);
+            }
+        }
+        let last = sim.snapshots.last().unwrap();
+        let alice = &sim.users["alice"];
+        assert_eq!(last.core_yield_paid, alice.yield_received);
+        assert!(sim.stalled_claims.contains(&("alice".to_string(), 1)));
+        let owed = alice.pending().map(|r| r.amount_out).sum::<u64>();
+        assert!(last.core_assets < owed);
+        assert!(last.core_assets + last.core_yield_paid >= owed);
+        assert!(last.vault_nav > NAV_SCALE);
+        assert_eq!(last.source_nav, last.vault_nav);
+    }
+
+    #[test]
+    fn attestation_lapse_halts_accrual_and_deposits() {
+        let scenario = load("attestation-lapse");
+        let sim = run(&scenario);
+        for s in &sim.snapshots {
+            assert!(s.vault_solvency_bps() >= 10_000, "vault at {}", s.at);
+            assert!(
+                s.core_solvency_bps() >= 10_000 - DRIFT_BPS,
+                "core at {}",
+                s.at
+            );
+        }
+        // The first lapse (days 40-60) freezes the NAV once the last
+        // attestation goes stale
+        let lapse: Vec<_> = sim
+            .snapshots
+            .iter()
+            .filter(|s| s.at < scenario.at(60.0))
+            .filter(|s| matches!(s.halt, Some(AccrualHalt::StaleAttestation { .. })))
+            .collect();
+        assert!(!lapse.is_empty());
+        assert!(lapse.iter().all(|s| s.vault_nav == lapse[0].vault_nav));
+        // and resumes from there: the paused days are skipped, not credited
+        // by the first crank after the lapse
+        let resumed = sim
+            .snapshots
+            .iter()
+            .find(|s| s.at > lapse[lapse.len() - 1].at && s.halt.is_none())
+            .unwrap();
+        let one_crank = lapse[0].vault_nav * 500 * scenario.keeper.interval_secs as u64
+            / (10_000 * stablebond_math::SECONDS_PER_YEAR)
+            + 1;
+        assert!(resumed.vault_nav - lapse[0].vault_nav <= one_crank);
+        assert!(sim
+            .snapshots
+            .iter()
+            .any(|s| s.halt == Some(AccrualHalt::ReserveShortfall) && s.at > scenario.at(90.0)));
+        assert!(logged(&sim, "rejected VaultDeposit"));
+        assert!(!sim.users.contains_key("erin"));
+    }
+
+    #[test]
+    fn maturity_stops_accrual_and_keeper_rewards_come_from_the_vault() {
+        let scenario = load("maturity");
+        let sim = run(&scenario);
+        let maturity = scenario.at(180.0);
+        for s in &sim.snapshots {
+            assert!(s.core_solvency_bps() >= 10_000, "core at {}", s.at);
+            // Hourly accrual at 80 bps truncates to zero, so the only drain
+            // on the vault is the keeper reward
+            assert_eq!(s.vault_nav, NAV_SCALE);
+            if s.at >= maturity {
+                assert_eq!(s.halt, Some(AccrualHalt::Matured));
+            }
+        }
+        let last = sim.snapshots.last().unwrap();
+        assert!(sim.fees.keeper_rewards > 0);
+        assert!(last.vault_assets + sim.fees.keeper_rewards >= last.vault_liabilities);
+        assert_eq!(sim.users["gen"].withdrawn, 10_000_000_000);
+    }
+
+    #[test]
+    fn oracle_path_breaker_bounds_the_core() {
+        let scenario = load("oracle-path");
+        let sim = run(&scenario);
+        let tripped: Vec<_> = sim.snapshots.iter().filter(|s| s.breaker_tripped).collect();
+        assert!(!tripped.is_empty());
+        assert!(logged(&sim, "circuit breaker reset"));
+        // The core NAV holds while the breaker is tripped, whatever the vault does
+        for pair in tripped.windows(2) {
+            if pair[1].at < scenario.at(61.0) || pair[0].at >= scenario.at(61.0) {
+                assert_eq!(pair[0].source_nav, pair[1].source_nav);
+            }
+        }
+        assert!(logged(&sim, "rejected RequestWithdrawal"));
+        // Oracle-priced accrual above the underlying rate is unfunded: the
+        // vault ends short, while the breaker keeps the core within ~1%
+        let last = sim.snapshots.last().unwrap();
+        assert!(last.vault_solvency_bps() < 10_000);
+        for s in &sim.snapshots {
+            assert!(net_core_solvency_bps(s) >= 9_900, "core at {}", s.at);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Plain-text run report and CSV export of the crank snapshots.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Context;
+use stablebond_yield::state::AccrualHalt;
+
+use crate::engine::{fixed, Simulation, Snapshot};
+use crate::scenario::{Scenario, SECONDS_PER_DAY};
+
+pub fn print(scenario: &Scenario, sim: &Simulation, every_days: u32) {
+    println!(
+        "Scenario {} — {}, {} days, keeper every {}s",
+        scenario.name,
+        sim.vault.bond_type.as_str(),
+        scenario.duration_days,
+        scenario.keeper.interval_secs
+    );
+
+    println!();
+    println!("Timeline");
+    let header = [
+        "day",
+        "vault nav",
+        "source nav",
+        "apy",
+        "core assets",
+        "core owed",
+        "core solv",
+        "vault assets",
+        "vault owed",
+        "vault solv",
+        "coverage",
+        "status",
+    ];
+    let mut rows = vec![header.map(str::to_string).to_vec()];
+    for snapshot in sampled(&sim.snapshots, scenario.start, every_days) {
+        rows.push(vec![
+            day(scenario, snapshot.at),
+            fixed(snapshot.vault_nav),
+            fixed(snapshot.source_nav),
+            format!("{} bps", snapshot.apy_bps),
+            snapshot.core_assets.to_string(),
+            snapshot.core_liabilities.to_string(),
+            percent(snapshot.core_solvency_bps()),
+            snapshot.vault_assets.to_string(),
+            snapshot.vault_liabilities.to_string(),
+            percent(snapshot.vault_solvency_bps()),
+            snapshot
+                .attested_coverage_bps
+                .map_or("-".to_string(), percent),
+            status(snapshot).to_string(),
+        ]);
+    }
+    print_rows(&rows);
+
+    println!();
+    println!("Events");
+    for entry in &sim.log {
+        println!("  day {:>8}  {}", day(scenario, entry.at), entry.message);
+    }
+
+    println!();
+    println!("Fees");
+    println!("  conversion      {}", sim.fees.conversion);
+    println!("  performance     {}", sim.fees.performance);
+    println!("  keeper rewards  {}", sim.fees.keeper_rewards);
+
+    println!();
+    println!("Users");
+    let mut rows = vec![[
+        "user",
+        "deposited",
+        "withdrawn",
+        "yield (net)",
+        "fees",
+        "holdings",
+        "p&l",
+        "return",
+    ]
+    .map(str::to_string)
+    .to_vec()];
+    for (name, user) in &sim.users {
+        let holdings = sim.holdings_value(user);
+        let pnl = user.withdrawn as i128 + user.yield_received as i128 + holdings as i128
+            - user.deposited as i128;
+        let ret = if user.deposited == 0 {
+            "-".to_string()
+        } else {
+            signed_percent(pnl * 10_000 / user.deposited as i128)
+        };
+        rows.push(vec![
+            name.clone(),
+            user.deposited.to_string(),
+            user.withdrawn.to_string(),
+            user.yield_received.to_string(),
+            user.fees_paid.to_string(),
+            holdings.to_string(),
+            pnl.to_string(),
+            ret,
+        ]);
+    }
+    print_rows(&rows);
+
+    println!();
+    println!("Solvency");
+    solvency_line(scenario, sim, "core ", Snapshot::core_solvency_bps);
+    solvency_line(scenario, sim, "vault", Snapshot::vault_solvency_bps);
+}
+
+pub fn write_csv(path: &Path, sim: &Simulation) -> anyhow::Result<()> {
+    let mut out = String::from(
+        "timestamp,vault_nav,source_nav,apy_bps,core_assets,core_liabilities,core_yield_paid,\
+         vault_assets,vault_liabilities,attested_coverage_bps,status\n",
+    );
+    for s in &sim.snapshots {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            s.at,
+            s.vault_nav,
+            s.source_nav,
+            s.apy_bps,
+            s.core_assets,
+            s.core_liabilities,
+            s.core_yield_paid,
+            s.vault_assets,
+            s.vault_liabilities,
+            s.attested_coverage_bps
+                .map_or(String::new(), |bps| bps.to_string()),
+            status(s)
+        )?;
+    }
+    std::fs::write(path, out).with_context(|| format!("writing {}", path.display()))
+}
+
+/// The first snapshot at or after each `every_days` boundary, plus the last.
+fn sampled(snapshots: &[Snapshot], start: i64, every_days: u32) -> Vec<&Snapshot> {
+    let step = every_days.max(1) as i64 * SECONDS_PER_DAY;
+    let mut next = start;
+    let mut picked = Vec::new();
+    for snapshot in snapshots {
+        if snapshot.at >= next {
+            picked.push(snapshot);
+            while next <= snapshot.at {
+                next += step;
+            }
+        }
+    }
+    if let Some(last) = snapshots.last() {
+        if !picked.last().is_some_and(|s| std::ptr::eq(*s, last)) {
+            picked.push(last);
+        }
+    }
+    picked
+}
+
+fn solvency_line(scenario: &Scenario, sim: &Simulation, label: &str, ratio: fn(&Snapshot) -> u64) {
+    let Some(last) = sim.snapshots.last() else {
+        return;
+    };
+    let worst = sim
+        .snapshots
+        .iter()
+        .min_by_key(|s| ratio(s))
+        .expect("non-empty");
+    println!(
+        "  {label}  final {}, worst {} at day {}",
+        percent(ratio(last)),
+        percent(ratio(worst)),
+        day(scenario, worst.at)
+    );
+}
+
+fn status(snapshot: &Snapshot) -> &'static str {
+    match snapshot.halt {
+        _ if snapshot.breaker_tripped => "breaker tripped",
+        Some(AccrualHalt::Matured) => "matured",
+        Some(AccrualHalt::StaleAttestation { .. }) => "attestation stale",
+        Some(AccrualHalt::ReserveShortfall) => "reserve shortfall",
+        None => "accruing",
+    }
+}
+
+fn day(scenario: &Scenario, at: i64) -> String {
+    format!(
+        "{:.2}",
+        (at - scenario.start) as f64 / SECONDS_PER_DAY as f64
+    )
+}
+
+fn percent(bps: u64) -> String {
+    if bps == u64::MAX {
+        "-".to_string()
+    } else {
+        format!("{}.{:02}%", bps / 100, bps % 100)
+    }
+}
+
+fn signed_percent(bps: i128) -> String {
+    let sign = if bps < 0 { "-" } else { "" };
+    let bps = bps.unsigned_abs();
+    format!("{sign}{}.{:02}%", bps / 100, bps % 100)
+}
+
+/// Right-aligned columns, first column left-aligned.
+fn print_rows(rows: &[Vec<String>]) {
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|r| r[i].len()).max().unwrap_or(0))
+        .collect();
+    for row in rows {
+        let mut line = String::from(" ");
+        for (i, cell) in row.iter().enumerate() {
+            let width = widths[i];
+            if i == 0 {
+                let _ = write!(line, " {cell:<width$}");
+            } else {
+                let _ = write!(line, "  {cell:>width$}");
+            }
+        }
+        println!("{line}");
+    }
+}
@@ -0,0 +1,234 @@
+//! Scenario files: bond and fee parameters, keeper and attestation cadence,
+//! and a list of timed actions. See `scenarios/` for examples.
+
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context};
+use serde::Deserialize;
+use stablebond_core::state::YieldSource;
+use stablebond_types::BondType;
+use stablebond_yield::state::BondVault;
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(default)]
+    pub name: String,
+    /// Unix timestamp of day 0
+    #[serde(default = "default_start")]
+    pub start: i64,
+    pub duration_days: u32,
+    #[serde(default)]
+    pub bond: BondParams,
+    #[serde(default)]
+    pub fees: FeeParams,
+    #[serde(default)]
+    pub keeper: KeeperParams,
+    /// Reserve attestations; omit to run the vault without an attestor
+    pub attestation: Option<AttestationParams>,
+    #[serde(default, rename = "action")]
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BondParams {
+    /// `us-tbill`, `mx-cetes`, `br-tesouro`, `jp-jgb` or `custom`
+    pub bond_type: String,
+    /// Defaults to the bond type's default APY
+    pub target_apy_bps: Option<u16>,
+    pub coupon_rate_bps: Option<u16>,
+    /// Accrual stops from this day on; omit for a rolling bond
+    pub maturity_day: Option<f64>,
+    pub min_deposit: u64,
+    pub max_nav_change_bps: u16,
+    pub max_daily_nav_change_bps: u16,
+    /// What the backing assets actually earn; defaults to the target APY
+    pub underlying_apy_bps: Option<u16>,
+}
+
+impl Default for BondParams {
+    fn default() -> Self {
+        Self {
+            bond_type: "us-tbill".to_string(),
+            target_apy_bps: None,
+            coupon_rate_bps: None,
+            maturity_day: None,
+            min_deposit: 0,
+            max_nav_change_bps: YieldSource::DEFAULT_MAX_NAV_CHANGE_BPS,
+            max_daily_nav_change_bps: YieldSource::DEFAULT_MAX_DAILY_NAV_CHANGE_BPS,
+            underlying_apy_bps: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeeParams {
+    pub conversion_fee_bps: u16,
+    pub performance_fee_bps: u16,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeeperParams {
+    /// Seconds between accrual + NAV sync cranks
+    pub interval_secs: i64,
+    /// Crank with `accrue_yield_incentivized` (pays the keeper reward) while
+    /// the oracle is disabled
+    pub incentivized: bool,
+}
+
+impl Default for KeeperParams {
+    fn default() -> Self {
+        Self {
+            interval_secs: 3_600,
+            incentivized: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AttestationParams {
+    /// Seconds between attestations while attesting
+    pub interval_secs: i64,
+    pub max_staleness_secs: i64,
+    pub min_coverage_bps: u16,
+}
+
+impl Default for AttestationParams {
+    fn default() -> Self {
+        Self {
+            interval_secs: SECONDS_PER_DAY,
+            max_staleness_secs: BondVault::DEFAULT_ATTESTATION_STALENESS,
+            min_coverage_bps: BondVault::DEFAULT_MIN_COVERAGE_BPS,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Action {
+    /// Days after `start` (fractions allowed)
+    pub day: f64,
+    #[serde(flatten)]
+    pub kind: ActionKind,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionKind {
+    /// `deposit_direct` into the core yield source
+    Deposit {
+        user: String,
+        amount: u64,
+    },
+    /// `deposit_cross_currency` converted immediately at `fx_rate`
+    /// (source currency per settlement unit, 1e6-scaled)
+    CrossCurrencyDeposit {
+        user: String,
+        source_amount: u64,
+        fx_rate: u64,
+    },
+    /// Deposit straight into the yield program's bond vault
+    VaultDeposit {
+        user: String,
+        amount: u64,
+    },
+    /// Request a withdrawal of `shares` (all shares if omitted); claimed
+    /// automatically once the cooldown has passed
+    RequestWithdrawal {
+        user: String,
+        shares: Option<u64>,
+    },
+    ClaimYield {
+        user: String,
+    },
+    SetApy {
+        apy_bps: u16,
+    },
+    /// Publish a bond price (1e6 = par) and switch to oracle pricing
+    OraclePrice {
+        price: u64,
+    },
+    DisableOracle,
+    /// One-off attestation of `reserve` (the simulated assets if omitted)
+    Attest {
+        reserve: Option<u64>,
+    },
+    /// Stop the scheduled attestations (an attestor outage)
+    PauseAttestations,
+    ResumeAttestations,
+    ResetBreaker {
+        accept_nav: bool,
+    },
+}
+
+impl Scenario {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("reading scenario {}", path.display()))?;
+        let mut scenario: Scenario =
+            toml::from_str(&raw).with_context(|| format!("parsing {}", path.display()))?;
+        if scenario.name.is_empty() {
+            scenario.name = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        ensure!(self.duration_days > 0, "duration_days must be positive");
+        ensure!(
+            self.keeper.interval_secs > 0,
+            "keeper.interval_secs must be positive"
+        );
+        if let Some(attestation) = &self.attestation {
+            ensure!(
+                attestation.interval_secs > 0,
+                "attestation.interval_secs must be positive"
+            );
+        }
+        self.bond_type()?;
+        for action in &self.actions {
+            ensure!(
+                action.day >= 0.0 && action.day <= self.duration_days as f64,
+                "action at day {} is outside the scenario",
+                action.day
+            );
+        }
+        Ok(())
+    }
+
+    pub fn end(&self) -> i64 {
+        self.start + self.duration_days as i64 * SECONDS_PER_DAY
+    }
+
+    pub fn at(&self, day: f64) -> i64 {
+        self.start + (day * SECONDS_PER_DAY as f64).round() as i64
+    }
+
+    pub fn bond_type(&self) -> anyhow::Result<BondType> {
+        Ok(match self.bond.bond_type.as_str() {
+            "us-tbill" => BondType::UsTBill,
+            "mx-cetes" => BondType::MxCetes,
+            "br-tesouro" => BondType::BrTesouro,
+            "jp-jgb" => BondType::JpJgb,
+            "custom" => BondType::Custom,
+            other => bail!(
+                "unknown bond type `{other}` (expected us-tbill, mx-cetes, br-tesouro, \
+                 jp-jgb or custom)"
+            ),
+        })
+    }
+}
+
+/// 2026-01-01T00:00:00Z
+fn default_start() -> i64 {
+    1_767_225_600
+}
@@ -0,0 +1,65 @@
+//! Token transfers shared by the Stablebond programs, for SPL Token and
+//! Token-2022 mints alike.
+//!
+//! Errors raised here are `MathError`s converted into the calling program's
+//! error type, so each program keeps its own error codes.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_math::MathError;
+
+/// A `transfer_checked` out of `from`, for SPL Token and Token-2022 mints
+/// alike. Extra accounts required by a transfer-hook mint are resolved from
+/// `hook_accounts` (the instruction's remaining accounts).
+pub struct TokenTransfer<'a, 'info> {
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub from: &'a InterfaceAccount<'info, TokenAccount>,
+    pub authority: AccountInfo<'info>,
+    pub hook_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'info> TokenTransfer<'_, 'info> {
+    /// Send `amount` to `to`. With a transfer-fee mint, `to` receives less
+    /// than `amount`.
+    pub fn send(
+        &self,
+        to: &InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        invoke_transfer_checked(
+            &self.token_program.key(),
+            self.from.to_account_info(),
+            self.mint.to_account_info(),
+            to.to_account_info(),
+            self.authority.clone(),
+            self.hook_accounts,
+            amount,
+            self.mint.decimals,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    /// Send `amount` to `to` and return what it actually received, net of
+    /// any Token-2022 transfer fee. Deposits credit this amount. A balance
+    /// that went down fails with `E::from(MathError::Overflow)`.
+    pub fn send_net<E>(
+        &self,
+        to: &mut InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64>
+    where
+        E: From<MathError> + Into<Error>,
+    {
+        let before = to.amount;
+        self.send(to, amount, signer_seeds)?;
+        to.reload()?;
+        to.amount
+            .checked_sub(before)
+            .ok_or_else(|| E::from(MathError::Overflow).into())
+    }
+}
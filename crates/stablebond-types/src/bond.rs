@@ -21,6 +21,22 @@ impl BondType {
         }
     }
 
+    /// Name of the bond vault's share token, e.g. "Stablebond US T-Bill Share".
+    pub fn share_name(&self) -> String {
+        format!("Stablebond {} Share", self.as_str())
+    }
+
+    /// Symbol of the bond vault's share token, e.g. `sbUSTB`.
+    pub fn share_symbol(&self) -> &'static str {
+        match self {
+            BondType::UsTBill => "sbUSTB",
+            BondType::MxCetes => "sbCETES",
+            BondType::BrTesouro => "sbTESOURO",
+            BondType::JpJgb => "sbJGB",
+            BondType::Custom => "sbCUSTOM",
+        }
+    }
+
     /// Returns the 3-letter ISO currency code for the bond's denomination.
     pub fn denomination_currency(&self) -> [u8; 3] {
         match self {
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Reason code recorded when a compliance officer freezes a position.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FreezeReason {
+    Sanctions,          // OFAC/UN/EU sanctions list match
+    LegalHold,          // Court order or regulator-mandated hold
+    FraudInvestigation, // Suspected fraud or account takeover
+    KycRevoked,         // Identity verification withdrawn
+    Other,
+}
+
+impl FreezeReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FreezeReason::Sanctions => "Sanctions",
+            FreezeReason::LegalHold => "Legal Hold",
+            FreezeReason::FraudInvestigation => "Fraud Investigation",
+            FreezeReason::KycRevoked => "KYC Revoked",
+            FreezeReason::Other => "Other",
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{allowed_bond_types, BondType};
+
+/// Failure reading an Accredit whitelist entry or Sovereign identity.
+/// Each program maps it onto its own error code of the same name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KycError {
+    KycRequired,
+    KycExpired,
+    JurisdictionRestricted,
+    SovereignIdentityNotFound,
+    TierTooLow,
+    BondTypeNotAllowed,
+    InvalidAccountData,
+}
+
+/// Accredit jurisdiction code for the USA, which is blocked.
+const JURISDICTION_USA: u8 = 4;
+
+/// Validate KYC via Accredit WhitelistEntry (manual deserialization).
+/// Shared by core deposits, vault share deposits and the share transfer
+/// hook, so all three read one layout.
+pub fn validate_kyc<E>(whitelist_entry: &AccountInfo, user: &Pubkey) -> Result<()>
+where
+    E: From<KycError> + Into<Error>,
+{
+    let fail = |e: KycError| -> Error { E::from(e).into() };
+    let data = whitelist_entry.try_borrow_data()?;
+    if data.len() < 83 {
+        return Err(fail(KycError::KycRequired));
+    }
+
+    // Skip 8-byte discriminator
+    let owner =
+        Pubkey::try_from(&data[8..40]).map_err(|_| fail(KycError::InvalidAccountData))?;
+    if owner != *user {
+        return Err(fail(KycError::KycRequired));
+    }
+
+    let is_active = data[72] != 0;
+    if !is_active {
+        return Err(fail(KycError::KycRequired));
+    }
+
+    let jurisdiction = data[74];
+    if jurisdiction == JURISDICTION_USA {
+        return Err(fail(KycError::JurisdictionRestricted));
+    }
+
+    let expires_at = i64::from_le_bytes(data[75..83].try_into().unwrap());
+    let now = Clock::get()?.unix_timestamp;
+    if expires_at <= now {
+        return Err(fail(KycError::KycExpired));
+    }
+
+    Ok(())
+}
+
+/// Read sovereign tier from SovereignIdentity PDA (manual deserialization).
+pub fn read_sovereign_tier<E>(sovereign_identity: &AccountInfo, user: &Pubkey) -> Result<u8>
+where
+    E: From<KycError> + Into<Error>,
+{
+    let fail = |e: KycError| -> Error { E::from(e).into() };
+    let data = sovereign_identity.try_borrow_data()?;
+    if data.len() < 41 {
+        return Err(fail(KycError::SovereignIdentityNotFound));
+    }
+
+    let owner =
+        Pubkey::try_from(&data[8..40]).map_err(|_| fail(KycError::InvalidAccountData))?;
+    if owner != *user {
+        return Err(fail(KycError::SovereignIdentityNotFound));
+    }
+
+    Ok(data[40])
+}
+
+/// Require `wallet` to hold an Accredit whitelist entry owned by
+/// `kyc_registry` and a Sovereign identity owned by `sovereign_program`
+/// whose tier allows `bond_type`. The check the share transfer hook applies
+/// to both sides of a transfer and vault share deposits apply at mint time.
+pub fn require_eligible_wallet<E>(
+    wallet: &Pubkey,
+    whitelist_entry: &AccountInfo,
+    sovereign_identity: &AccountInfo,
+    kyc_registry: &Pubkey,
+    sovereign_program: &Pubkey,
+    bond_type: BondType,
+) -> Result<()>
+where
+    E: From<KycError> + Into<Error>,
+{
+    let fail = |e: KycError| -> Error { E::from(e).into() };
+    if whitelist_entry.owner != kyc_registry {
+        return Err(fail(KycError::KycRequired));
+    }
+    validate_kyc::<E>(whitelist_entry, wallet)?;
+
+    if sovereign_identity.owner != sovereign_program {
+        return Err(fail(KycError::SovereignIdentityNotFound));
+    }
+    let tier = read_sovereign_tier::<E>(sovereign_identity, wallet)?;
+    if tier == 0 {
+        return Err(fail(KycError::TierTooLow));
+    }
+    if !allowed_bond_types(tier).contains(&bond_type) {
+        return Err(fail(KycError::BondTypeNotAllowed));
+    }
+    Ok(())
+}
@@ -1,9 +1,19 @@
 pub mod bond;
+pub mod compliance;
 pub mod deposit;
+pub mod kyc;
+pub mod limit_window;
+pub mod pause;
+pub mod role;
 pub mod tier;
 pub mod yield_source;
 
 pub use bond::*;
+pub use compliance::*;
 pub use deposit::*;
+pub use kyc::*;
+pub use limit_window::*;
+pub use pause::*;
+pub use role::*;
 pub use tier::*;
 pub use yield_source::*;
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Number of daily buckets backing the rolling deposit window.
+pub const ROLLING_WINDOW_DAYS: usize = 30;
+
+/// How monthly deposit limits are measured for a Sovereign tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LimitWindow {
+    /// Usage resets at 00:00 UTC on the first day of each calendar month.
+    #[default]
+    CalendarMonth,
+    /// Usage is the sum of the last 30 days (today inclusive), tracked in daily buckets.
+    Rolling30Day,
+}
+
+/// Returns the unix timestamp of 00:00 UTC on the first day of the month containing `ts`.
+pub fn utc_month_start(ts: i64) -> i64 {
+    let days = ts.div_euclid(SECONDS_PER_DAY);
+
+    // Civil-from-days (proleptic Gregorian), only the day-of-month is needed.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day_of_month = doy - (153 * mp + 2) / 5 + 1;
+
+    (days - (day_of_month - 1)) * SECONDS_PER_DAY
+}
+
+/// Per-day deposit totals for the rolling 30-day window.
+/// Bucket `day % 30` holds the total for the most recent `day` written to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DailyDeposits {
+    /// Deposit totals indexed by unix day modulo 30
+    pub buckets: [u64; ROLLING_WINDOW_DAYS],
+    /// Unix day of the most recent deposit (0 = never)
+    pub last_day: i64,
+}
+
+impl DailyDeposits {
+    pub const LEN: usize = 8 * ROLLING_WINDOW_DAYS // buckets
+        + 8;                                        // last_day
+
+    fn bucket_index(day: i64) -> usize {
+        day.rem_euclid(ROLLING_WINDOW_DAYS as i64) as usize
+    }
+
+    /// Total deposited over the 30 days ending at `now` (today inclusive).
+    pub fn rolling_sum(&self, now: i64) -> u64 {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        let oldest = today - (ROLLING_WINDOW_DAYS as i64 - 1);
+
+        (0..ROLLING_WINDOW_DAYS as i64)
+            .map(|k| self.last_day - k)
+            .filter(|day| *day >= oldest && *day <= today)
+            .fold(0u64, |sum, day| {
+                sum.saturating_add(self.buckets[Self::bucket_index(day)])
+            })
+    }
+
+    /// Add `amount` to today's bucket, clearing buckets for any skipped days.
+    /// Returns `None` on overflow.
+    pub fn record(&mut self, amount: u64, now: i64) -> Option<()> {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+
+        if today > self.last_day {
+            let stale = (today - self.last_day).min(ROLLING_WINDOW_DAYS as i64);
+            for k in 0..stale {
+                self.buckets[Self::bucket_index(today - k)] = 0;
+            }
+            self.last_day = today;
+        }
+
+        let bucket = &mut self.buckets[Self::bucket_index(self.last_day)];
+        *bucket = bucket.checked_add(amount)?;
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = SECONDS_PER_DAY;
+    /// 2024-01-31 00:00:00 UTC
+    const JAN_31_2024: i64 = 1_706_659_200;
+    /// 2024-02-01 00:00:00 UTC
+    const FEB_1_2024: i64 = 1_706_745_600;
+
+    #[test]
+    fn month_start_at_month_and_year_boundaries() {
+        // 2023-12-31 23:59:59 -> 2023-12-01
+        assert_eq!(utc_month_start(1_704_067_199), 1_701_388_800);
+        // 2024-01-01 00:00:00 is its own month start
+        assert_eq!(utc_month_start(1_704_067_200), 1_704_067_200);
+        assert_eq!(utc_month_start(JAN_31_2024), 1_704_067_200);
+        assert_eq!(utc_month_start(FEB_1_2024), FEB_1_2024);
+        // Before the epoch: 1969-12-31 23:59:59 -> 1969-12-01
+        assert_eq!(utc_month_start(-1), -2_678_400);
+    }
+
+    #[test]
+    fn month_start_in_leap_and_common_februaries() {
+        // 2024-02-29 12:00 -> 2024-02-01, and 2024-03-01 starts a new month
+        assert_eq!(utc_month_start(1_709_208_000), FEB_1_2024);
+        assert_eq!(utc_month_start(1_709_251_200), 1_709_251_200);
+        // 2023-02-28 -> 2023-02-01; 2023-03-01 follows directly
+        assert_eq!(utc_month_start(1_677_542_400), 1_675_209_600);
+        assert_eq!(utc_month_start(1_677_628_800), 1_677_628_800);
+        // 2000 is a leap year (divisible by 400): 2000-02-29 -> 2000-02-01
+        assert_eq!(utc_month_start(951_782_400), 949_363_200);
+        // 2100 is not: 2100-03-01 is a month start
+        assert_eq!(utc_month_start(4_107_542_400), 4_107_542_400);
+    }
+
+    #[test]
+    fn rolling_sum_counts_the_last_30_days_only() {
+        let mut deposits = DailyDeposits::default();
+        deposits.record(100, JAN_31_2024).unwrap();
+        deposits.record(50, JAN_31_2024 + 5 * DAY).unwrap();
+
+        assert_eq!(deposits.rolling_sum(JAN_31_2024 + 5 * DAY), 150);
+        // Day 29 after the first deposit is still inside its window
+        assert_eq!(deposits.rolling_sum(JAN_31_2024 + 29 * DAY), 150);
+        assert_eq!(deposits.rolling_sum(JAN_31_2024 + 30 * DAY), 50);
+        assert_eq!(deposits.rolling_sum(JAN_31_2024 + 35 * DAY), 0);
+    }
+
+    #[test]
+    fn buckets_expire_after_a_gap_of_more_than_30_days() {
+        let mut deposits = DailyDeposits::default();
+        for day in 0..10 {
+            deposits.record(10, JAN_31_2024 + day * DAY).unwrap();
+        }
+
+        // Day 45 shares bucket `day % 30` with day 15 of the previous cycle
+        let later = JAN_31_2024 + 45 * DAY;
+        deposits.record(7, later).unwrap();
+        assert_eq!(deposits.rolling_sum(later), 7);
+        assert_eq!(deposits.buckets.iter().sum::<u64>(), 7);
+
+        // Exactly one cycle later reuses the same bucket without carrying it over
+        deposits.record(3, later + 30 * DAY).unwrap();
+        assert_eq!(deposits.rolling_sum(later + 30 * DAY), 3);
+    }
+
+    #[test]
+    fn rolling_window_blocks_twice_the_limit_across_a_month_boundary() {
+        let limit = 1_000;
+        let mut deposits = DailyDeposits::default();
+
+        // Full limit on the last day of the month...
+        deposits.record(limit, JAN_31_2024 + 23 * 3_600).unwrap();
+        // ...still counts on the first day of the next, where a calendar
+        // window would have reset
+        assert!(utc_month_start(FEB_1_2024) > utc_month_start(JAN_31_2024));
+        assert_eq!(deposits.rolling_sum(FEB_1_2024), limit);
+        assert_eq!(deposits.rolling_sum(FEB_1_2024 + 28 * DAY), limit);
+        assert_eq!(deposits.rolling_sum(FEB_1_2024 + 29 * DAY), 0);
+    }
+
+    #[test]
+    fn record_reports_overflow() {
+        let mut deposits = DailyDeposits::default();
+        deposits.record(u64::MAX, JAN_31_2024).unwrap();
+        assert_eq!(deposits.record(1, JAN_31_2024), None);
+        // A new day starts a fresh bucket; the sum saturates instead of wrapping
+        deposits.record(1, FEB_1_2024).unwrap();
+        assert_eq!(deposits.rolling_sum(FEB_1_2024), u64::MAX);
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Operations that can be paused independently, at protocol level or per
+/// bond (YieldSource / BondVault). Pause sets are stored as a `u8` bitmask.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseFlag {
+    Deposits,
+    Conversions,
+    WithdrawalRequests, // Also covers cancelling a pending request
+    WithdrawalClaims,
+    YieldClaims,
+    NavUpdates, // Core NAV sync and yield-vault accrual cranks
+}
+
+impl PauseFlag {
+    /// Bitmask covering every pausable operation.
+    pub const ALL_MASK: u8 = 0b11_1111;
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PauseFlag::Deposits => "Deposits",
+            PauseFlag::Conversions => "Conversions",
+            PauseFlag::WithdrawalRequests => "Withdrawal Requests",
+            PauseFlag::WithdrawalClaims => "Withdrawal Claims",
+            PauseFlag::YieldClaims => "Yield Claims",
+            PauseFlag::NavUpdates => "NAV Updates",
+        }
+    }
+
+    pub fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+
+    /// Returns true if `mask` includes this operation.
+    pub fn is_in(&self, mask: u8) -> bool {
+        mask & self.bit() != 0
+    }
+}
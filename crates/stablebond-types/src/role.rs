@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Operational capabilities that can be delegated away from the protocol
+/// authority. Role sets are stored on-chain as a `u8` bitmask.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Pauser,        // Pause/resume, emergency withdraw toggle
+    FeeManager,    // Fee and yield source parameters
+    OracleAdmin,   // Oracle feeds and fallback APY
+    AttestorAdmin, // Minimum reserve coverage
+    Keeper,        // NAV updates and conversion cranks
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [
+        Role::Pauser,
+        Role::FeeManager,
+        Role::OracleAdmin,
+        Role::AttestorAdmin,
+        Role::Keeper,
+    ];
+
+    /// Bitmask covering every defined role.
+    pub const ALL_MASK: u8 = 0b1_1111;
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Pauser => "Pauser",
+            Role::FeeManager => "Fee Manager",
+            Role::OracleAdmin => "Oracle Admin",
+            Role::AttestorAdmin => "Attestor Admin",
+            Role::Keeper => "Keeper",
+        }
+    }
+
+    pub fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+
+    /// Returns true if `mask` includes this role.
+    pub fn is_in(&self, mask: u8) -> bool {
+        mask & self.bit() != 0
+    }
+}
@@ -6,6 +6,19 @@ use crate::yield_source::YieldSourceType;
 /// Tier 2 = Silver (enhanced KYC)
 /// Tier 3 = Gold (accredited investor)
 /// Tier 4 = Diamond (institutional)
+///
+/// Aggregate monthly deposit limit across all bond types for a Sovereign tier.
+/// Returns amount in USD minor units (6 decimals). Enforced in addition to the
+/// per-bond `monthly_limit`, with each deposit converted to USD via the FX oracle.
+pub fn aggregate_monthly_limit_usd(tier: u8) -> u64 {
+    match tier {
+        1 => 5_000_000_000,         // $5,000
+        2 => 50_000_000_000,        // $50,000
+        3 => 500_000_000_000,       // $500,000
+        4 => 10_000_000_000_000,    // $10,000,000
+        _ => 0,
+    }
+}
 
 /// Multi-currency monthly deposit limit by Sovereign tier and bond type.
 /// Returns amount in minor units (6 decimals) of the bond's native currency.
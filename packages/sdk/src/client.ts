@@ -13,12 +13,17 @@ import {
 import {
   findProtocolConfigPda,
   findBondRegistryPda,
+  findUserLimitPda,
   findYieldSourcePda,
   findUserPositionPda,
   findPendingDepositPda,
   findUsdcVaultPda,
   findBondVaultPda,
   findWithdrawalRequestPda,
+  findRoleAssignmentPda,
+  findAttestorSetPda,
+  findAttestationHistoryPda,
+  findPendingVaultChangePda,
 } from "./pda";
 
 export interface WithdrawalRequest {
@@ -35,6 +40,15 @@ export interface WithdrawalRequest {
   bump: number;
 }
 
+export interface AttestationRecord {
+  timestamp: bigint;
+  attestedReserve: bigint;
+  attestor: PublicKey;
+  merkleRoot: Buffer;
+  navPerShare: bigint;
+  totalShares: bigint;
+}
+
 export interface BondVaultExtended {
   authority: PublicKey;
   currencyMint: PublicKey;
@@ -181,14 +195,7 @@ export class StablebondClient {
   }
 
   async getBondVault(bondType: BondType): Promise<any | null> {
-    const config = await this.getProtocolConfig();
-    if (!config) return null;
-
-    const [vaultPda] = findBondVaultPda(
-      config.authority,
-      bondType,
-      this.programIds.yield
-    );
+    const [vaultPda] = findBondVaultPda(bondType, this.programIds.yield);
     const info = await this.connection.getAccountInfo(vaultPda);
     if (!info) return null;
 
@@ -233,20 +240,40 @@ export class StablebondClient {
 
   /** Get the extended bond vault data including oracle and attestation fields. */
   async getBondVaultExtended(bondType: BondType): Promise<BondVaultExtended | null> {
-    const config = await this.getProtocolConfig();
-    if (!config) return null;
-
-    const [vaultPda] = findBondVaultPda(
-      config.authority,
-      bondType,
-      this.programIds.yield
-    );
+    const [vaultPda] = findBondVaultPda(bondType, this.programIds.yield);
     const info = await this.connection.getAccountInfo(vaultPda);
     if (!info) return null;
 
     return this.deserializeBondVaultExtended(info.data);
   }
 
+  /** Get a bond vault's recorded attestations, oldest first (up to the last 32). */
+  async getAttestationHistory(bondType: BondType): Promise<AttestationRecord[]> {
+    const [vaultPda] = findBondVaultPda(bondType, this.programIds.yield);
+    const [historyPda] = findAttestationHistoryPda(vaultPda, this.programIds.yield);
+
+    const program = this.getYieldProgram();
+    const history: any = await program.account.attestationHistory.fetchNullable(historyPda);
+    if (!history) return [];
+
+    const capacity = history.entries.length;
+    const count = Number(history.count.toString());
+    const start = Math.max(0, count - capacity);
+    const records: AttestationRecord[] = [];
+    for (let seq = start; seq < count; seq++) {
+      const e = history.entries[seq % capacity];
+      records.push({
+        timestamp: BigInt(e.timestamp.toString()),
+        attestedReserve: BigInt(e.attestedReserve.toString()),
+        attestor: e.attestor,
+        merkleRoot: Buffer.from(e.merkleRoot),
+        navPerShare: BigInt(e.navPerShare.toString()),
+        totalShares: BigInt(e.totalShares.toString()),
+      });
+    }
+    return records;
+  }
+
   // ─── Transaction Methods ─────────────────────────────────────────────────────
 
   /**
@@ -259,7 +286,11 @@ export class StablebondClient {
     accounts: {
       yieldSourceMint: PublicKey;
       depositVault: PublicKey;
+      /** Mint of `depositVault` (USDC or the bond's local stablecoin) */
+      settlementMint: PublicKey;
       userToken: PublicKey;
+      /** Token program owning `settlementMint`; defaults to SPL Token */
+      tokenProgram?: PublicKey;
     }
   ): Promise<string> {
     const user = this.provider.wallet.publicKey;
@@ -312,7 +343,11 @@ export class StablebondClient {
     accounts: {
       yieldSourceMint: PublicKey;
       depositVault: PublicKey;
+      /** Mint of `depositVault` (USDC or the bond's local stablecoin) */
+      settlementMint: PublicKey;
       userToken: PublicKey;
+      /** Token program owning `settlementMint`; defaults to SPL Token */
+      tokenProgram?: PublicKey;
     }
   ): Promise<string> {
     const user = this.provider.wallet.publicKey;
@@ -344,8 +379,9 @@ export class StablebondClient {
         userPosition: userPositionPda,
         withdrawalRequest: withdrawalRequestPda,
         depositVault: accounts.depositVault,
+        settlementMint: accounts.settlementMint,
         userToken: accounts.userToken,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: accounts.tokenProgram ?? TOKEN_PROGRAM_ID,
       })
       .rpc();
 
@@ -403,8 +439,14 @@ export class StablebondClient {
       yieldSourceMint: PublicKey;
       userToken: PublicKey;
       depositVault: PublicKey;
+      /** Mint of `depositVault` (USDC or the bond's local stablecoin) */
+      settlementMint: PublicKey;
+      /** Token program owning `settlementMint`; defaults to SPL Token */
+      tokenProgram?: PublicKey;
       whitelistEntry: PublicKey;
       sovereignIdentity: PublicKey;
+      /** FX oracle for the bond's currency vs USD (ignored for USD bonds) */
+      fxOracle: PublicKey;
     }
   ): Promise<string> {
     const user = this.provider.wallet.publicKey;
@@ -419,6 +461,9 @@ export class StablebondClient {
       bondType,
       this.programIds.core
     );
+    const [registryPda] = findBondRegistryPda(this.configPda, this.programIds.core);
+    const [userLimitPda] = findUserLimitPda(this.configPda, user, this.programIds.core);
+    const [bondVaultPda] = findBondVaultPda(bondType, this.programIds.yield);
 
     const program = this.getCoreProgram();
     const tx = await program.methods
@@ -427,12 +472,17 @@ export class StablebondClient {
         user,
         protocolConfig: this.configPda,
         yieldSource: yieldSourcePda,
+        bondVault: bondVaultPda,
         userToken: accounts.userToken,
         depositVault: accounts.depositVault,
+        settlementMint: accounts.settlementMint,
         userPosition: userPositionPda,
+        bondRegistry: registryPda,
+        userLimit: userLimitPda,
+        fxOracle: accounts.fxOracle,
         whitelistEntry: accounts.whitelistEntry,
         sovereignIdentity: accounts.sovereignIdentity,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: accounts.tokenProgram ?? TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -450,7 +500,11 @@ export class StablebondClient {
     accounts: {
       yieldSourceMint: PublicKey;
       depositVault: PublicKey;
+      /** Mint of `depositVault` (USDC or the bond's local stablecoin) */
+      settlementMint: PublicKey;
       userToken: PublicKey;
+      /** Token program owning `settlementMint`; defaults to SPL Token */
+      tokenProgram?: PublicKey;
     }
   ): Promise<string> {
     const user = this.provider.wallet.publicKey;
@@ -475,8 +529,9 @@ export class StablebondClient {
         yieldSource: yieldSourcePda,
         userPosition: userPositionPda,
         depositVault: accounts.depositVault,
+        settlementMint: accounts.settlementMint,
         userToken: accounts.userToken,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: accounts.tokenProgram ?? TOKEN_PROGRAM_ID,
       })
       .rpc();
 
@@ -488,7 +543,11 @@ export class StablebondClient {
     accounts: {
       yieldSourceMint: PublicKey;
       depositVault: PublicKey;
+      /** Mint of `depositVault` (USDC or the bond's local stablecoin) */
+      settlementMint: PublicKey;
       userToken: PublicKey;
+      /** Token program owning `settlementMint`; defaults to SPL Token */
+      tokenProgram?: PublicKey;
     }
   ): Promise<string> {
     const user = this.provider.wallet.publicKey;
@@ -513,14 +572,37 @@ export class StablebondClient {
         yieldSource: yieldSourcePda,
         userPosition: userPositionPda,
         depositVault: accounts.depositVault,
+        settlementMint: accounts.settlementMint,
         userToken: accounts.userToken,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: accounts.tokenProgram ?? TOKEN_PROGRAM_ID,
       })
       .rpc();
 
     return tx;
   }
 
+  /**
+   * Admin: replace the role bitmask for a key (see `roleMask`). 0 revokes all roles.
+   */
+  async setMemberRoles(member: PublicKey, roles: number): Promise<string> {
+    const [roleAssignmentPda] = findRoleAssignmentPda(
+      this.configPda,
+      member,
+      this.programIds.core
+    );
+
+    const program = this.getCoreProgram();
+    return program.methods
+      .setMemberRoles(member, roles)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        protocolConfig: this.configPda,
+        roleAssignment: roleAssignmentPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
   // ─── Yield Program Admin Methods ────────────────────────────────────────────
 
   private yieldProgram: Program | null = null;
@@ -541,52 +623,125 @@ export class StablebondClient {
   }
 
   /**
-   * Admin: configure oracle feed for a bond vault.
+   * Admin: queue an oracle feed change for a bond vault. It can be executed
+   * with `executeVaultChange` once the vault timelock has elapsed.
+   * @returns the queued change id
    */
-  async configureOracle(
+  async queueOracleChange(
     bondType: BondType,
     oracleFeed: PublicKey,
     enabled: boolean
-  ): Promise<string> {
-    const config = await this.getProtocolConfig();
-    if (!config) throw new Error("Protocol config not found");
+  ): Promise<bigint> {
+    return this.queueVaultChange(bondType, { oracle: { oracleFeed, enabled } });
+  }
 
-    const [bondVaultPda] = findBondVaultPda(
-      config.authority,
-      bondType,
+  /**
+   * Admin: queue a single reserve attestor change for a bond vault. Rejected
+   * once the vault uses a quorum attestor set.
+   * @returns the queued change id
+   */
+  async queueReserveAttestorChange(
+    bondType: BondType,
+    attestor: PublicKey,
+    maxStaleness: BN
+  ): Promise<bigint> {
+    return this.queueVaultChange(bondType, {
+      reserveAttestor: { attestor, maxStaleness },
+    });
+  }
+
+  /**
+   * Admin: queue a switch to M-of-N quorum reserve attestation (threshold at
+   * least 2) for a bond vault.
+   * @returns the queued change id
+   */
+  async queueAttestorSetChange(
+    bondType: BondType,
+    attestors: PublicKey[],
+    threshold: number,
+    windowSeconds: BN,
+    toleranceBps: number
+  ): Promise<bigint> {
+    return this.queueVaultChange(bondType, {
+      attestorSet: { attestors, threshold, window: windowSeconds, toleranceBps },
+    });
+  }
+
+  private async queueVaultChange(
+    bondType: BondType,
+    change: object
+  ): Promise<bigint> {
+    const [bondVaultPda] = findBondVaultPda(bondType, this.programIds.yield);
+
+    const program = this.getYieldProgram();
+    const vault: any = await program.account.bondVault.fetch(bondVaultPda);
+    const changeId = BigInt(vault.nextChangeId.toString());
+    const [pendingChangePda] = findPendingVaultChangePda(
+      bondVaultPda,
+      changeId,
       this.programIds.yield
     );
 
-    const program = this.getYieldProgram();
-    return program.methods
-      .configureOracle(oracleFeed, enabled)
+    await program.methods
+      .queueVaultChange(change)
       .accounts({
-        authority: this.provider.wallet.publicKey,
+        proposer: this.provider.wallet.publicKey,
         vaultConfig: bondVaultPda,
+        pendingChange: pendingChangePda,
+        roleAssignment: null,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
+    return changeId;
   }
 
   /**
-   * Admin: configure reserve attestor for a bond vault.
+   * Apply a queued bond vault change once its eta has passed (permissionless).
+   * The executor pays for the attestor set account on its first configuration.
    */
-  async configureReserveAttestor(
+  async executeVaultChange(
     bondType: BondType,
-    attestor: PublicKey,
-    maxStaleness: BN
+    changeId: bigint
   ): Promise<string> {
-    const config = await this.getProtocolConfig();
-    if (!config) throw new Error("Protocol config not found");
-
-    const [bondVaultPda] = findBondVaultPda(
-      config.authority,
-      bondType,
+    const [bondVaultPda] = findBondVaultPda(bondType, this.programIds.yield);
+    const [pendingChangePda] = findPendingVaultChangePda(
+      bondVaultPda,
+      changeId,
       this.programIds.yield
     );
 
     const program = this.getYieldProgram();
+    const pending: any = await program.account.pendingVaultChange.fetch(
+      pendingChangePda
+    );
+    const attestorSet = pending.change.attestorSet
+      ? findAttestorSetPda(bondVaultPda, this.programIds.yield)[0]
+      : null;
     return program.methods
-      .configureReserveAttestor(attestor, maxStaleness)
+      .executeVaultChange()
+      .accounts({
+        executor: this.provider.wallet.publicKey,
+        vaultConfig: bondVaultPda,
+        pendingChange: pendingChangePda,
+        proposer: pending.proposer,
+        attestorSet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  /**
+   * Admin: set the minimum reserve coverage ratio (bps) for a bond vault.
+   */
+  async setMinCoverage(
+    bondType: BondType,
+    minCoverageBps: number
+  ): Promise<string> {
+    const [bondVaultPda] = findBondVaultPda(bondType, this.programIds.yield);
+
+    const program = this.getYieldProgram();
+    return program.methods
+      .setMinCoverage(minCoverageBps)
       .accounts({
         authority: this.provider.wallet.publicKey,
         vaultConfig: bondVaultPda,
@@ -601,14 +756,7 @@ export class StablebondClient {
     bondType: BondType,
     allow: boolean
   ): Promise<string> {
-    const config = await this.getProtocolConfig();
-    if (!config) throw new Error("Protocol config not found");
-
-    const [bondVaultPda] = findBondVaultPda(
-      config.authority,
-      bondType,
-      this.programIds.yield
-    );
+    const [bondVaultPda] = findBondVaultPda(bondType, this.programIds.yield);
 
     const program = this.getYieldProgram();
     return program.methods
@@ -1,8 +1,14 @@
 export { StablebondClient } from "./client";
-export type { StablebondProgramIds, WithdrawalRequest, BondVaultExtended } from "./client";
+export type {
+  StablebondProgramIds,
+  WithdrawalRequest,
+  BondVaultExtended,
+  AttestationRecord,
+} from "./client";
 export * from "./pda";
 export * from "./utils/tier-limits";
 export * from "./utils/yield-math";
+export * from "./utils/reserve-proof";
 export * from "./lib/compliance";
 export {
   buildTierMembershipTree,
@@ -10,8 +16,6 @@ export {
   createMonthlyDepositTracker,
   restoreDepositTracker,
   buildBondRegistryTree,
-  buildProofOfReserveTree,
-  getReserveProof,
   verifyReserveCoverage,
 } from "./lib/stratum-utils";
 export {
@@ -9,6 +9,7 @@ import {
   findUserPositionPda,
   findUsdcVaultPda,
   findYieldSourcePda,
+  findRoleAssignmentPda,
 } from "../pda";
 
 /**
@@ -154,6 +155,9 @@ export class ConversionBot {
       console.warn("[ConversionBot] Protocol config not found, skipping");
       return;
     }
+    // usdc_mint follows authority and treasury
+    const usdcMint = new PublicKey(configInfo.data.subarray(72, 104));
+    const usdcMintInfo = await this.connection.getAccountInfo(usdcMint);
 
     console.log(
       `[ConversionBot] Executing conversion for deposit ${pendingDepositPda.toBase58()} ` +
@@ -165,12 +169,18 @@ export class ConversionBot {
       .accounts({
         keeper: this.keeper.publicKey,
         protocolConfig: configPda,
+        roleAssignment: findRoleAssignmentPda(
+          configPda,
+          this.keeper.publicKey,
+          this.programId
+        )[0],
         pendingDeposit: pendingDepositPda,
         userPosition: userPositionPda,
         conversionRecord: conversionRecordPda,
         user,
         usdcVault,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        usdcMint,
+        tokenProgram: usdcMintInfo?.owner ?? TOKEN_PROGRAM_ID,
       })
       .signers([this.keeper])
       .rpc();
@@ -9,6 +9,7 @@ import {
   findBondVaultPda,
   findBondShareMintPda,
   findBondCurrencyVaultPda,
+  findRoleAssignmentPda,
 } from "../pda";
 
 /**
@@ -105,20 +106,7 @@ export class NavUpdater {
    * Now passes the bond price oracle account for oracle-driven NAV calculation.
    */
   private async accrueYield(bond: BondConfig): Promise<void> {
-    const [configPda] = findProtocolConfigPda(this.coreProgramId);
-    const configInfo = await this.connection.getAccountInfo(configPda);
-    if (!configInfo) {
-      console.warn("[NavUpdater] Protocol config not found, skipping");
-      return;
-    }
-    // Parse authority from config (offset: 8 discriminator + 0 = authority at byte 8)
-    const authority = new PublicKey(configInfo.data.subarray(8, 40));
-
-    const [bondVaultPda] = findBondVaultPda(
-      authority,
-      bond.bondType,
-      this.yieldProgramId
-    );
+    const [bondVaultPda] = findBondVaultPda(bond.bondType, this.yieldProgramId);
 
     // Read BondVault to get oracle_feed address
     // oracle_feed is after the original fields at the new offset
@@ -163,7 +151,6 @@ export class NavUpdater {
       console.warn("[NavUpdater] Protocol config not found, skipping");
       return;
     }
-    const authority = new PublicKey(configInfo.data.subarray(8, 40));
 
     const [yieldSourcePda] = findYieldSourcePda(
       configPda,
@@ -180,20 +167,20 @@ export class NavUpdater {
       return;
     }
 
-    const [bondVaultPda] = findBondVaultPda(
-      authority,
-      bond.bondType,
-      this.yieldProgramId
-    );
+    const [bondVaultPda] = findBondVaultPda(bond.bondType, this.yieldProgramId);
 
     const tx = await this.coreProgram.methods
       .updateNav()
       .accounts({
         keeper: this.keeper.publicKey,
         protocolConfig: configPda,
+        roleAssignment: findRoleAssignmentPda(
+          configPda,
+          this.keeper.publicKey,
+          this.coreProgramId
+        )[0],
         yieldSource: yieldSourcePda,
-        bondVault: bondVaultPda,
-        bondVaultProgram: this.yieldProgramId,
+        bondVaultConfig: bondVaultPda,
       })
       .signers([this.keeper])
       .rpc();
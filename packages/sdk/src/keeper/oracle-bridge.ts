@@ -1,9 +1,9 @@
 import { Program, BN } from "@coral-xyz/anchor";
-import { Connection, PublicKey, Keypair } from "@solana/web3.js";
+import { Connection, PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { BondType } from "@stablebond/types";
 import {
-  findProtocolConfigPda,
   findBondVaultPda,
+  findPendingVaultChangePda,
 } from "../pda";
 
 // ─── Well-known Pyth devnet feed addresses (placeholders) ────────────────────
@@ -70,43 +70,40 @@ export class OracleBridge {
   }
 
   /**
-   * Configure the oracle feed for a specific bond type on-chain.
-   * Calls the configureOracle instruction with the given feed address.
+   * Queue an oracle feed change for a specific bond type on-chain.
+   * The change is timelocked; it must be executed (`executeVaultChange`)
+   * once the vault's `timelock_delay` has elapsed.
    */
   async configureBondOracle(
     bondType: BondType,
     oracleFeed: PublicKey
   ): Promise<void> {
-    // Read authority from ProtocolConfig account
-    // Layout: discriminator(8) + authority(32)
-    const [configPda] = findProtocolConfigPda(this.yieldProgramId);
-    const configInfo = await this.connection.getAccountInfo(configPda);
-    if (!configInfo) {
-      console.warn(
-        "[OracleBridge] Protocol config not found, skipping oracle configuration"
-      );
-      return;
-    }
-    const authority = new PublicKey(configInfo.data.subarray(8, 40));
-
-    const [bondVaultPda] = findBondVaultPda(
-      authority,
-      bondType,
+    const [bondVaultPda] = findBondVaultPda(bondType, this.yieldProgramId);
+    const vault: any = await this.yieldProgram.account.bondVault.fetch(
+      bondVaultPda
+    );
+    const changeId = BigInt(vault.nextChangeId.toString());
+    const [pendingChangePda] = findPendingVaultChangePda(
+      bondVaultPda,
+      changeId,
       this.yieldProgramId
     );
 
     const tx = await this.yieldProgram.methods
-      .configureOracle(oracleFeed, true)
+      .queueVaultChange({ oracle: { oracleFeed, enabled: true } })
       .accounts({
-        authority: this.authorityKeypair.publicKey,
+        proposer: this.authorityKeypair.publicKey,
         vaultConfig: bondVaultPda,
+        pendingChange: pendingChangePda,
+        roleAssignment: null,
+        systemProgram: SystemProgram.programId,
       })
       .signers([this.authorityKeypair])
       .rpc();
 
     console.log(
-      `[OracleBridge] Oracle configured for bond type ${bondType}: ${tx} ` +
-        `(feed=${oracleFeed.toBase58()})`
+      `[OracleBridge] Oracle change ${changeId} queued for bond type ${bondType}: ${tx} ` +
+        `(feed=${oracleFeed.toBase58()}, timelock=${vault.timelockDelay.toString()}s)`
     );
   }
 
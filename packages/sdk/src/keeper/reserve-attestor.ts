@@ -2,14 +2,16 @@ import { Program, BN } from "@coral-xyz/anchor";
 import { Connection, PublicKey, Keypair } from "@solana/web3.js";
 import { BondType, BondConfig } from "@stablebond/types";
 import {
-  findProtocolConfigPda,
   findBondRegistryPda,
   findBondVaultPda,
 } from "../pda";
+import { ReserveHolding, buildProofOfReserveTree } from "../utils/reserve-proof";
 
 interface CustodianReserve {
   holdings: bigint;
   timestamp: number;
+  /** Individual bond lots, committed on-chain as a Merkle root when present */
+  lots?: ReserveHolding[];
 }
 
 /**
@@ -102,31 +104,25 @@ export class ReserveAttestor {
         `holdings=${reserve.holdings}, timestamp=${reserve.timestamp}`
     );
 
-    // Read authority from ProtocolConfig account
-    // Layout: discriminator(8) + authority(32)
-    const [configPda] = findProtocolConfigPda(this.yieldProgramId);
-    const configInfo = await this.connection.getAccountInfo(configPda);
-    if (!configInfo) {
-      console.warn(
-        "[ReserveAttestor] Protocol config not found, skipping attestation"
-      );
-      return;
-    }
-    const authority = new PublicKey(configInfo.data.subarray(8, 40));
-
-    const [bondVaultPda] = findBondVaultPda(
-      authority,
-      bondType,
-      this.yieldProgramId
-    );
+    const [bondVaultPda] = findBondVaultPda(bondType, this.yieldProgramId);
 
     // Convert holdings to minor units (6 decimal places)
     const attestedReserve = new BN(reserve.holdings.toString()).mul(
       new BN(1_000_000)
     );
 
+    // Commit to the individual lots so anyone can prove inclusion on-chain
+    let commitment = null;
+    if (reserve.lots && reserve.lots.length > 0) {
+      const { root } = buildProofOfReserveTree(reserve.lots);
+      commitment = {
+        merkleRoot: Array.from(root),
+        holdingsCount: reserve.lots.length,
+      };
+    }
+
     const tx = await this.yieldProgram.methods
-      .submitReserveAttestation(attestedReserve)
+      .submitReserveAttestation(attestedReserve, commitment)
       .accounts({
         attestor: this.attestorKeypair.publicKey,
         vaultConfig: bondVaultPda,
@@ -141,7 +137,8 @@ export class ReserveAttestor {
 
   /**
    * Fetch reserve data from a custodian API endpoint.
-   * Expects JSON response: { holdings: number, currency: string, timestamp: string }
+   * Expects JSON response: { holdings: number, currency: string, timestamp: string,
+   *   lots?: { securityId: string, quantity: string, price: string }[] }
    * @returns Parsed reserve with holdings as bigint and timestamp as unix seconds.
    */
   async fetchCustodianReserve(endpoint: string): Promise<CustodianReserve> {
@@ -157,11 +154,17 @@ export class ReserveAttestor {
       holdings: number;
       currency: string;
       timestamp: string;
+      lots?: { securityId: string; quantity: string; price: string }[];
     };
 
     return {
       holdings: BigInt(Math.floor(data.holdings)),
       timestamp: Math.floor(new Date(data.timestamp).getTime() / 1000),
+      lots: data.lots?.map((lot) => ({
+        securityId: lot.securityId,
+        quantity: BigInt(lot.quantity),
+        price: BigInt(lot.price),
+      })),
     };
   }
 }
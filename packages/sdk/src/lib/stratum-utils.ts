@@ -85,54 +85,12 @@ export function buildBondRegistryTree(
 }
 
 // ---------------------------------------------------------------------------
-// Proof of Reserve (PoR) Merkle Tree
+// Proof of Reserve (PoR)
 // ---------------------------------------------------------------------------
-
-/**
- * Build a Proof-of-Reserve merkle tree from off-chain custodian holdings.
- * Each leaf encodes `bondType:custodian:isin:faceValue:holdings:timestamp`.
- *
- * This tree can be verified on-chain against the attested reserve in BondVault.
- * The root hash should match the custodian's signed attestation.
- */
-export function buildProofOfReserveTree(
-  holdings: Array<{
-    bondType: string;
-    custodian: string;
-    isin: string;
-    faceValue: string;
-    holdings: string;
-    timestamp: string;
-  }>,
-): MerkleTree {
-  const leaves = holdings.map(
-    (h) =>
-      `${h.bondType}:${h.custodian}:${h.isin}:${h.faceValue}:${h.holdings}:${h.timestamp}`,
-  );
-  return new MerkleTree(leaves);
-}
-
-/**
- * Generate a proof for a specific bond holding in the PoR tree.
- */
-export function getReserveProof(
-  tree: MerkleTree,
-  bondType: string,
-  custodian: string,
-  isin: string,
-  faceValue: string,
-  holdings: string,
-  timestamp: string,
-): { proof: number[][]; root: number[]; index: number } {
-  const leaf = `${bondType}:${custodian}:${isin}:${faceValue}:${holdings}:${timestamp}`;
-  const index = tree.findLeafIndex(leaf);
-  if (index < 0) throw new Error('Holding not found in PoR tree');
-  return {
-    proof: tree.getProofArray(index),
-    root: tree.rootArray,
-    index,
-  };
-}
+//
+// The holdings tree itself (`buildProofOfReserveTree`, `getReserveProof`)
+// lives in `utils/reserve-proof.ts`: it must hash exactly like the on-chain
+// `ReserveHolding::verify`, which the generic MerkleTree does not.
 
 /**
  * Verify that total attested reserves cover total vault deposits.
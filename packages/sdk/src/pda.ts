@@ -49,6 +49,17 @@ export function findUserPositionPda(
   );
 }
 
+export function findUserLimitPda(
+  config: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("user_limit"), config.toBuffer(), owner.toBuffer()],
+    programId
+  );
+}
+
 export function findPendingDepositPda(
   config: PublicKey,
   user: PublicKey,
@@ -87,6 +98,30 @@ export function findConversionRecordPda(
   );
 }
 
+export function findRoleAssignmentPda(
+  config: PublicKey,
+  member: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("role"), config.toBuffer(), member.toBuffer()],
+    programId
+  );
+}
+
+export function findPendingChangePda(
+  config: PublicKey,
+  changeId: bigint,
+  programId: PublicKey
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(changeId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_change"), config.toBuffer(), idBuffer],
+    programId
+  );
+}
+
 export function findUsdcVaultPda(programId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("stablebond_usdc_vault")],
@@ -97,42 +132,85 @@ export function findUsdcVaultPda(programId: PublicKey): [PublicKey, number] {
 // ─── stablebond-yield PDAs ──────────────────────────────────────────────────────
 
 export function findBondVaultPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("bond_vault"), authority.toBuffer(), Buffer.from([bondType])],
+    [Buffer.from("bond_vault"), Buffer.from([bondType])],
     programId
   );
 }
 
 export function findBondShareMintPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("bond_share_mint"),
-      authority.toBuffer(),
-      Buffer.from([bondType]),
-    ],
+    [Buffer.from("bond_share_mint"), Buffer.from([bondType])],
     programId
   );
 }
 
 export function findBondCurrencyVaultPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("bond_currency_vault"),
-      authority.toBuffer(),
-      Buffer.from([bondType]),
-    ],
+    [Buffer.from("bond_currency_vault"), Buffer.from([bondType])],
+    programId
+  );
+}
+
+export function findBondLockedSharesPda(
+  bondType: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bond_locked_shares"), Buffer.from([bondType])],
+    programId
+  );
+}
+
+export function findVaultRolePda(
+  vault: PublicKey,
+  member: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_role"), vault.toBuffer(), member.toBuffer()],
+    programId
+  );
+}
+
+export function findPendingVaultChangePda(
+  vault: PublicKey,
+  changeId: bigint,
+  programId: PublicKey
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(changeId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_change"), vault.toBuffer(), idBuffer],
+    programId
+  );
+}
+
+export function findAttestorSetPda(
+  vault: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("attestor_set"), vault.toBuffer()],
+    programId
+  );
+}
+
+export function findAttestationHistoryPda(
+  vault: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("attestation_history"), vault.toBuffer()],
     programId
   );
 }
@@ -161,3 +239,14 @@ export function findWithdrawalRequestPda(
     programId
   );
 }
+
+/** `ExtraAccountMetaList` of a share mint using the KYC transfer hook. */
+export function findExtraAccountMetasPda(
+  shareMint: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), shareMint.toBuffer()],
+    programId
+  );
+}
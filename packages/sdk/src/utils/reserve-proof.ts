@@ -0,0 +1,115 @@
+import { createHash } from "crypto";
+
+/** A custodian bond lot, matching `ReserveHolding` on stablebond-yield. */
+export interface ReserveHolding {
+  /** ISIN, or a CUSIP (right-padded with zero bytes to 12) */
+  securityId: string;
+  /** Face-value quantity held, in minor units */
+  quantity: bigint;
+  /** Price as a fraction of par, scaled 1e6 */
+  price: bigint;
+}
+
+/** Encode a security identifier as the 12-byte on-chain field. */
+export function encodeSecurityId(securityId: string): Buffer {
+  const bytes = Buffer.from(securityId, "ascii");
+  if (bytes.length > 12) {
+    throw new Error(`Security id longer than 12 bytes: ${securityId}`);
+  }
+  return Buffer.concat([bytes, Buffer.alloc(12 - bytes.length)]);
+}
+
+function u64le(value: bigint): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(value);
+  return buf;
+}
+
+function sha256(...parts: Buffer[]): Buffer {
+  const hash = createHash("sha256");
+  for (const part of parts) hash.update(part);
+  return hash.digest();
+}
+
+/** Leaf hash: sha256(0x00 || security_id || quantity_le || price_le). */
+export function reserveHoldingLeaf(holding: ReserveHolding): Buffer {
+  return sha256(
+    Buffer.from([0x00]),
+    encodeSecurityId(holding.securityId),
+    u64le(holding.quantity),
+    u64le(holding.price)
+  );
+}
+
+/** Inner node: sha256(0x01 || min(a, b) || max(a, b)). */
+function hashPair(a: Buffer, b: Buffer): Buffer {
+  const [lo, hi] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return sha256(Buffer.from([0x01]), lo, hi);
+}
+
+/** Proof-of-Reserve tree: the root plus a proof (leaf to root) per holding. */
+export interface ReserveTree {
+  root: Buffer;
+  leaves: Buffer[];
+  proofs: Buffer[][];
+}
+
+/**
+ * Build the Proof-of-Reserve tree over custodian lots that the attestor
+ * commits on-chain and `verify_reserve_holding` checks proofs against
+ * (`ReserveHolding::verify`). An unpaired node is promoted to the next level
+ * unchanged, so it adds no step to its proofs.
+ */
+export function buildProofOfReserveTree(holdings: ReserveHolding[]): ReserveTree {
+  if (holdings.length === 0) {
+    throw new Error("Cannot build a reserve tree without holdings");
+  }
+  const leaves = holdings.map(reserveHoldingLeaf);
+  let level = leaves;
+  // Position of each original leaf within the current level
+  let positions = holdings.map((_, i) => i);
+  const proofs: Buffer[][] = holdings.map(() => []);
+
+  while (level.length > 1) {
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      next.push(i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]);
+    }
+    positions = positions.map((pos, leaf) => {
+      const sibling = pos ^ 1;
+      if (sibling < level.length) proofs[leaf].push(level[sibling]);
+      return pos >> 1;
+    });
+    level = next;
+  }
+
+  return { root: level[0], leaves, proofs };
+}
+
+/**
+ * Proof for one holding in the PoR tree, as the `proof` and root byte arrays
+ * `verify_reserve_holding` takes.
+ */
+export function getReserveProof(
+  tree: ReserveTree,
+  holding: ReserveHolding
+): { proof: number[][]; root: number[]; index: number } {
+  const leaf = reserveHoldingLeaf(holding);
+  const index = tree.leaves.findIndex((l) => l.equals(leaf));
+  if (index < 0) throw new Error("Holding not found in PoR tree");
+  return {
+    proof: tree.proofs[index].map((node) => Array.from(node)),
+    root: Array.from(tree.root),
+    index,
+  };
+}
+
+/** Off-chain mirror of the on-chain proof check. */
+export function verifyReserveHoldingProof(
+  holding: ReserveHolding,
+  proof: Buffer[],
+  root: Buffer
+): boolean {
+  const computed = proof.reduce(hashPair, reserveHoldingLeaf(holding));
+  return computed.equals(root);
+}
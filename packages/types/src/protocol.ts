@@ -1,6 +1,33 @@
 import { PublicKey } from "@solana/web3.js";
 import { BondType } from "./bond";
 
+/** Operational roles; stored on-chain as a bitmask (`1 << Role`). */
+export enum Role {
+  Pauser = 0,
+  FeeManager = 1,
+  OracleAdmin = 2,
+  AttestorAdmin = 3,
+  Keeper = 4,
+}
+
+export function roleMask(...roles: Role[]): number {
+  return roles.reduce((mask, role) => mask | (1 << role), 0);
+}
+
+/** Independently pausable operations; stored on-chain as a bitmask (`1 << PauseFlag`). */
+export enum PauseFlag {
+  Deposits = 0,
+  Conversions = 1,
+  WithdrawalRequests = 2,
+  WithdrawalClaims = 3,
+  YieldClaims = 4,
+  NavUpdates = 5,
+}
+
+export function pauseMask(...flags: PauseFlag[]): number {
+  return flags.reduce((mask, flag) => mask | (1 << flag), 0);
+}
+
 export interface ProtocolConfig {
   authority: PublicKey;
   treasury: PublicKey;
@@ -25,6 +25,14 @@ export const TIER_COLORS: Record<Tier, string> = {
   [Tier.Diamond]: "#B9F2FF",
 };
 
+/** How monthly deposit limits are measured for a tier (matches on-chain `LimitWindow`). */
+export enum LimitWindow {
+  /** Resets at 00:00 UTC on the 1st of each month. */
+  CalendarMonth = 0,
+  /** Sum of the last 30 days, tracked in daily buckets. */
+  Rolling30Day = 1,
+}
+
 /** Monthly deposit limits by tier and bond type (minor units, 6 decimals). */
 export function monthlyLimit(tier: Tier, bondType: BondType): bigint {
   const limits: Record<string, bigint> = {
@@ -52,6 +60,17 @@ export function monthlyLimit(tier: Tier, bondType: BondType): bigint {
   return limits[`${tier}-${bondType}`] ?? 0n;
 }
 
+/** Aggregate monthly limit across all bond types (USD minor units, 6 decimals). */
+export function aggregateMonthlyLimitUsd(tier: Tier): bigint {
+  const limits: Record<number, bigint> = {
+    [Tier.Bronze]: 5_000_000_000n,
+    [Tier.Silver]: 50_000_000_000n,
+    [Tier.Gold]: 500_000_000_000n,
+    [Tier.Diamond]: 10_000_000_000_000n,
+  };
+  return limits[tier] ?? 0n;
+}
+
 /** Bond types allowed per tier. */
 export const TIER_BOND_TYPES: Record<Tier, BondType[]> = {
   [Tier.Unverified]: [],
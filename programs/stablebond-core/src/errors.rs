@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use stablebond_math::MathError;
+use stablebond_types::KycError;
 
 #[error_code]
 pub enum StablebondError {
@@ -94,4 +96,81 @@ pub enum StablebondError {
 
     #[msg("Withdrawal request has been cancelled")]
     WithdrawalCancelled,
+
+    #[msg("Monthly USD-equivalent limit exceeded across all bond positions")]
+    AggregateMonthlyLimitExceeded,
+
+    #[msg("Position is frozen by the compliance officer")]
+    PositionFrozen,
+
+    #[msg("Position is not frozen")]
+    PositionNotFrozen,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer does not hold the role required for this action")]
+    MissingRole,
+
+    #[msg("Role mask contains undefined role bits")]
+    InvalidRoleMask,
+
+    #[msg("Timelock has not elapsed for this change")]
+    TimelockNotElapsed,
+
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+
+    #[msg("Account does not match the queued change")]
+    ChangeTargetMismatch,
+
+    #[msg("This operation is paused protocol-wide")]
+    OperationPaused,
+
+    #[msg("This operation is paused for this bond")]
+    BondOperationPaused,
+
+    #[msg("Pause mask contains undefined operation bits")]
+    InvalidPauseMask,
+
+    #[msg("NAV circuit breaker has tripped: awaiting authority review")]
+    CircuitBreakerActive,
+
+    #[msg("NAV circuit breaker is not tripped")]
+    CircuitBreakerNotTripped,
+
+    #[msg("Invalid NAV change bound")]
+    InvalidNavBound,
+
+    #[msg("Bond vault does not match this yield source")]
+    BondVaultMismatch,
+
+    #[msg("Deposit is too small to mint any shares")]
+    ZeroShares,
+
+    #[msg("Account is not a legacy-layout account of this type")]
+    NotLegacyAccount,
+
+    #[msg("Bond vault reserves are below the minimum coverage: deposits paused")]
+    ReserveShortfall,
+}
+
+impl From<MathError> for StablebondError {
+    fn from(_: MathError) -> Self {
+        StablebondError::MathOverflow
+    }
+}
+
+impl From<KycError> for StablebondError {
+    fn from(e: KycError) -> Self {
+        match e {
+            KycError::KycRequired => StablebondError::KycRequired,
+            KycError::KycExpired => StablebondError::KycExpired,
+            KycError::JurisdictionRestricted => StablebondError::JurisdictionRestricted,
+            KycError::SovereignIdentityNotFound => StablebondError::SovereignIdentityNotFound,
+            KycError::TierTooLow => StablebondError::TierTooLow,
+            KycError::BondTypeNotAllowed => StablebondError::BondTypeNotAllowed,
+            KycError::InvalidAccountData => StablebondError::InvalidAccountData,
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use stablebond_types::ConversionDirection;
+use stablebond_types::{ConversionDirection, FreezeReason, LimitWindow};
 
 #[event]
 pub struct ProtocolInitialized {
@@ -120,6 +120,44 @@ pub struct WithdrawalCancelled {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ProtocolConfigUpdated {
+    /// Signer (or timelock proposer) that made the change
+    pub authority: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub old_conversion_fee_bps: u16,
+    pub new_conversion_fee_bps: u16,
+    pub old_management_fee_bps: u16,
+    pub new_management_fee_bps: u16,
+    pub old_performance_fee_bps: u16,
+    pub new_performance_fee_bps: u16,
+    pub old_tier_limit_windows: [LimitWindow; 5],
+    pub new_tier_limit_windows: [LimitWindow; 5],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct YieldSourceUpdated {
+    pub yield_source: Pubkey,
+    pub bond_type: u8,
+    /// Signer (or timelock proposer) that made the change
+    pub authority: Pubkey,
+    pub old_allocation_weight_bps: u16,
+    pub new_allocation_weight_bps: u16,
+    pub old_min_deposit: u64,
+    pub new_min_deposit: u64,
+    pub old_max_allocation: u64,
+    pub new_max_allocation: u64,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+    pub old_max_nav_change_bps: u16,
+    pub new_max_nav_change_bps: u16,
+    pub old_max_daily_nav_change_bps: u16,
+    pub new_max_daily_nav_change_bps: u16,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ProtocolPaused {
     pub authority: Pubkey,
@@ -131,3 +169,143 @@ pub struct ProtocolResumed {
     pub authority: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct PositionFrozen {
+    pub user: Pubkey,
+    pub bond_type: u8,
+    pub reason: FreezeReason,
+    pub compliance_officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionUnfrozen {
+    pub user: Pubkey,
+    pub bond_type: u8,
+    pub compliance_officer: Pubkey,
+    pub frozen_since: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceOfficerUpdated {
+    pub authority: Pubkey,
+    pub old_officer: Pubkey,
+    pub new_officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub authority: Pubkey,
+    pub member: Pubkey,
+    pub old_roles: u8,
+    pub new_roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub change_id: u64,
+    pub kind: u8,
+    pub proposer: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub change_id: u64,
+    pub kind: u8,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub change_id: u64,
+    pub kind: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimelockDelayUpdated {
+    pub old_delay: i64,
+    pub new_delay: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseFlagsUpdated {
+    /// ProtocolConfig for protocol-wide flags, YieldSource for per-bond flags
+    pub scope: Pubkey,
+    /// Bond type for per-bond flags, None for protocol-wide
+    pub bond_type: Option<u8>,
+    pub old_flags: u8,
+    pub new_flags: u8,
+    /// Operations newly paused by this change
+    pub paused: u8,
+    /// Operations newly resumed by this change
+    pub resumed: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub yield_source: Pubkey,
+    pub bond_type: u8,
+    pub old_nav: u64,
+    /// Rejected NAV reported by the bond vault
+    pub attempted_nav: u64,
+    /// Move versus the last accepted NAV
+    pub change_bps: u64,
+    /// Move versus the NAV at the start of the UTC day
+    pub daily_change_bps: u64,
+    pub max_change_bps: u16,
+    pub max_daily_change_bps: u16,
+    /// Operations paused by the breaker
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerReset {
+    pub yield_source: Pubkey,
+    pub bond_type: u8,
+    pub tripped_nav: u64,
+    /// Whether the tripped NAV was accepted as the new NAV
+    pub nav_accepted: bool,
+    pub nav_per_share: u64,
+    /// Operations resumed by the reset
+    pub resumed: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    /// ProtocolConfig, YieldSource or UserPosition grown to its current layout
+    pub account: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+    /// Signer that paid the extra rent
+    pub payer: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,72 +1,388 @@
 use anchor_lang::prelude::*;
+use stablebond_types::{LimitWindow, PauseFlag, Role};
 
 use crate::errors::StablebondError;
-use crate::events::{ProtocolPaused, ProtocolResumed};
-use crate::state::{ProtocolConfig, YieldSource};
+use crate::events::{
+    AuthorityTransferAccepted, AuthorityTransferProposed, CircuitBreakerReset,
+    ComplianceOfficerUpdated, PauseFlagsUpdated, ProtocolConfigUpdated, ProtocolPaused,
+    ProtocolResumed, YieldSourceUpdated,
+};
+use crate::state::{require_role, ProtocolConfig, RoleAssignment, YieldSource};
 
 // ─── Update Protocol Config ────────────────────────────────────────────────────
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct UpdateProtocolConfigParams {
     pub treasury: Option<Pubkey>,
     pub conversion_fee_bps: Option<u16>,
     pub management_fee_bps: Option<u16>,
     pub performance_fee_bps: Option<u16>,
+    /// Monthly limit window mode per Sovereign tier (indexed by tier 0-4)
+    pub tier_limit_windows: Option<[LimitWindow; 5]>,
+    /// Compliance officer allowed to freeze positions
+    pub compliance_officer: Option<Pubkey>,
 }
 
+impl UpdateProtocolConfigParams {
+    /// Maximum serialized size (every option set).
+    pub const MAX_LEN: usize = 33  // treasury
+        + 3   // conversion_fee_bps
+        + 3   // management_fee_bps
+        + 3   // performance_fee_bps
+        + 6   // tier_limit_windows
+        + 33; // compliance_officer
+
+    /// Treasury, limit windows and the compliance officer stay authority-only;
+    /// fee changes may be delegated to a FeeManager.
+    pub fn authorize(
+        &self,
+        config: &ProtocolConfig,
+        signer: &Pubkey,
+        role_assignment: Option<&RoleAssignment>,
+    ) -> Result<()> {
+        if self.treasury.is_some()
+            || self.tier_limit_windows.is_some()
+            || self.compliance_officer.is_some()
+        {
+            require!(*signer == config.authority, StablebondError::Unauthorized);
+        }
+        if self.conversion_fee_bps.is_some()
+            || self.management_fee_bps.is_some()
+            || self.performance_fee_bps.is_some()
+        {
+            require_role(&config.authority, signer, role_assignment, Role::FeeManager)?;
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Some(fee) = self.conversion_fee_bps {
+            require!(fee <= 1000, StablebondError::InvalidFee);
+        }
+        if let Some(fee) = self.management_fee_bps {
+            require!(fee <= 500, StablebondError::InvalidFee);
+        }
+        if let Some(fee) = self.performance_fee_bps {
+            require!(fee <= 5000, StablebondError::InvalidFee);
+        }
+        Ok(())
+    }
+
+    /// Write the set fields into `config`. `actor` is recorded in audit events.
+    pub fn apply(&self, config: &mut ProtocolConfig, actor: Pubkey, now: i64) {
+        let old = (
+            config.treasury,
+            config.conversion_fee_bps,
+            config.management_fee_bps,
+            config.performance_fee_bps,
+            config.tier_limit_windows,
+        );
+        if let Some(treasury) = self.treasury {
+            config.treasury = treasury;
+        }
+        if let Some(fee) = self.conversion_fee_bps {
+            config.conversion_fee_bps = fee;
+        }
+        if let Some(fee) = self.management_fee_bps {
+            config.management_fee_bps = fee;
+        }
+        if let Some(fee) = self.performance_fee_bps {
+            config.performance_fee_bps = fee;
+        }
+        if let Some(windows) = self.tier_limit_windows {
+            config.tier_limit_windows = windows;
+        }
+        if let Some(officer) = self.compliance_officer {
+            emit!(ComplianceOfficerUpdated {
+                authority: actor,
+                old_officer: config.compliance_officer,
+                new_officer: officer,
+                timestamp: now,
+            });
+            config.compliance_officer = officer;
+        }
+        config.updated_at = now;
+
+        emit!(ProtocolConfigUpdated {
+            authority: actor,
+            old_treasury: old.0,
+            new_treasury: config.treasury,
+            old_conversion_fee_bps: old.1,
+            new_conversion_fee_bps: config.conversion_fee_bps,
+            old_management_fee_bps: old.2,
+            new_management_fee_bps: config.management_fee_bps,
+            old_performance_fee_bps: old.3,
+            new_performance_fee_bps: config.performance_fee_bps,
+            old_tier_limit_windows: old.4,
+            new_tier_limit_windows: config.tier_limit_windows,
+            timestamp: now,
+        });
+    }
+}
+
+// ─── Update Yield Source ────────────────────────────────────────────────────────
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateYieldSourceParams {
+    pub allocation_weight_bps: Option<u16>,
+    pub min_deposit: Option<u64>,
+    pub max_allocation: Option<u64>,
+    pub is_active: Option<bool>,
+    /// Circuit breaker: max NAV move per update in bps (0 = unchecked)
+    pub max_nav_change_bps: Option<u16>,
+    /// Circuit breaker: max NAV move per UTC day in bps (0 = unchecked)
+    pub max_daily_nav_change_bps: Option<u16>,
+}
+
+impl UpdateYieldSourceParams {
+    /// Maximum serialized size (every option set).
+    pub const MAX_LEN: usize = 3  // allocation_weight_bps
+        + 9   // min_deposit
+        + 9   // max_allocation
+        + 2   // is_active
+        + 3   // max_nav_change_bps
+        + 3;  // max_daily_nav_change_bps
+
+    /// Circuit breaker bounds stay authority-only; the remaining fields may
+    /// be delegated to a FeeManager.
+    pub fn authorize(
+        &self,
+        config: &ProtocolConfig,
+        signer: &Pubkey,
+        role_assignment: Option<&RoleAssignment>,
+    ) -> Result<()> {
+        if self.max_nav_change_bps.is_some() || self.max_daily_nav_change_bps.is_some() {
+            require!(*signer == config.authority, StablebondError::Unauthorized);
+        }
+        require_role(&config.authority, signer, role_assignment, Role::FeeManager)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for bound in [self.max_nav_change_bps, self.max_daily_nav_change_bps]
+            .into_iter()
+            .flatten()
+        {
+            require!(bound <= 10_000, StablebondError::InvalidNavBound);
+        }
+        Ok(())
+    }
+
+    /// Write the set fields into `ys`. `actor` is recorded in the audit event.
+    pub fn apply(&self, ys: &mut Account<YieldSource>, actor: Pubkey, now: i64) {
+        let old = (
+            ys.allocation_weight_bps,
+            ys.min_deposit,
+            ys.max_allocation,
+            ys.is_active,
+            ys.max_nav_change_bps,
+            ys.max_daily_nav_change_bps,
+        );
+        if let Some(weight) = self.allocation_weight_bps {
+            ys.allocation_weight_bps = weight;
+        }
+        if let Some(min) = self.min_deposit {
+            ys.min_deposit = min;
+        }
+        if let Some(max) = self.max_allocation {
+            ys.max_allocation = max;
+        }
+        if let Some(active) = self.is_active {
+            ys.is_active = active;
+        }
+        if let Some(bound) = self.max_nav_change_bps {
+            ys.max_nav_change_bps = bound;
+        }
+        if let Some(bound) = self.max_daily_nav_change_bps {
+            ys.max_daily_nav_change_bps = bound;
+        }
+
+        emit!(YieldSourceUpdated {
+            yield_source: ys.key(),
+            bond_type: ys.bond_type.as_u8(),
+            authority: actor,
+            old_allocation_weight_bps: old.0,
+            new_allocation_weight_bps: ys.allocation_weight_bps,
+            old_min_deposit: old.1,
+            new_min_deposit: ys.min_deposit,
+            old_max_allocation: old.2,
+            new_max_allocation: ys.max_allocation,
+            old_is_active: old.3,
+            new_is_active: ys.is_active,
+            old_max_nav_change_bps: old.4,
+            new_max_nav_change_bps: ys.max_nav_change_bps,
+            old_max_daily_nav_change_bps: old.5,
+            new_max_daily_nav_change_bps: ys.max_daily_nav_change_bps,
+            timestamp: now,
+        });
+    }
+}
+
+// ─── Pause / Resume Protocol ────────────────────────────────────────────────────
+
 #[derive(Accounts)]
-pub struct UpdateProtocolConfig<'info> {
+pub struct PauseProtocol<'info> {
+    /// Protocol authority or a Pauser
     pub authority: Signer<'info>,
 
     #[account(
         mut,
         seeds = [ProtocolConfig::SEED],
         bump = protocol_config.bump,
-        has_one = authority @ StablebondError::Unauthorized,
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Signer's role assignment; not needed when the signer is the authority
+    #[account(
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
-pub fn handle_update_protocol_config(
-    ctx: Context<UpdateProtocolConfig>,
-    params: UpdateProtocolConfigParams,
-) -> Result<()> {
-    let config = &mut ctx.accounts.protocol_config;
+impl PauseProtocol<'_> {
+    fn require_pauser(&self) -> Result<()> {
+        require_role(
+            &self.protocol_config.authority,
+            &self.authority.key(),
+            self.role_assignment.as_deref(),
+            Role::Pauser,
+        )
+    }
+}
+
+pub fn handle_pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+    ctx.accounts.require_pauser()?;
     let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.protocol_config.is_active = false;
+    ctx.accounts.protocol_config.updated_at = now;
 
-    if let Some(treasury) = params.treasury {
-        config.treasury = treasury;
-    }
-    if let Some(fee) = params.conversion_fee_bps {
-        require!(fee <= 1000, StablebondError::InvalidFee);
-        config.conversion_fee_bps = fee;
-    }
-    if let Some(fee) = params.management_fee_bps {
-        require!(fee <= 500, StablebondError::InvalidFee);
-        config.management_fee_bps = fee;
-    }
-    if let Some(fee) = params.performance_fee_bps {
-        require!(fee <= 5000, StablebondError::InvalidFee);
-        config.performance_fee_bps = fee;
-    }
+    emit!(ProtocolPaused {
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!("Protocol paused");
+    Ok(())
+}
+
+pub fn handle_resume_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+    ctx.accounts.require_pauser()?;
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.protocol_config.is_active = true;
+    ctx.accounts.protocol_config.updated_at = now;
+
+    emit!(ProtocolResumed {
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!("Protocol resumed");
+    Ok(())
+}
 
+/// Replace the protocol-wide pause bitmask (see `PauseFlag`).
+pub fn handle_set_pause_flags(ctx: Context<PauseProtocol>, paused_ops: u8) -> Result<()> {
+    ctx.accounts.require_pauser()?;
+    require!(
+        paused_ops & !PauseFlag::ALL_MASK == 0,
+        StablebondError::InvalidPauseMask
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.protocol_config;
+    let old_flags = config.paused_ops;
+    config.paused_ops = paused_ops;
     config.updated_at = now;
-    msg!("Protocol config updated");
+
+    emit!(PauseFlagsUpdated {
+        scope: config.key(),
+        bond_type: None,
+        old_flags,
+        new_flags: paused_ops,
+        paused: paused_ops & !old_flags,
+        resumed: old_flags & !paused_ops,
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!("Protocol pause flags: {:#08b} → {:#08b}", old_flags, paused_ops);
     Ok(())
 }
 
-// ─── Update Yield Source ────────────────────────────────────────────────────────
+#[derive(Accounts)]
+pub struct SetYieldSourcePauseFlags<'info> {
+    /// Protocol authority or a Pauser
+    pub authority: Signer<'info>,
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct UpdateYieldSourceParams {
-    pub allocation_weight_bps: Option<u16>,
-    pub min_deposit: Option<u64>,
-    pub max_allocation: Option<u64>,
-    pub is_active: Option<bool>,
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            YieldSource::SEED,
+            protocol_config.key().as_ref(),
+            yield_source.token_mint.as_ref(),
+        ],
+        bump = yield_source.bump,
+    )]
+    pub yield_source: Account<'info, YieldSource>,
+
+    /// Signer's role assignment; not needed when the signer is the authority
+    #[account(
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
 }
 
+/// Replace the pause bitmask for a single bond's yield source.
+pub fn handle_set_yield_source_pause_flags(
+    ctx: Context<SetYieldSourcePauseFlags>,
+    paused_ops: u8,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.protocol_config.authority,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.role_assignment.as_deref(),
+        Role::Pauser,
+    )?;
+    require!(
+        paused_ops & !PauseFlag::ALL_MASK == 0,
+        StablebondError::InvalidPauseMask
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let ys = &mut ctx.accounts.yield_source;
+    let old_flags = ys.set_paused_ops(paused_ops)?;
+
+    emit!(PauseFlagsUpdated {
+        scope: ys.key(),
+        bond_type: Some(ys.bond_type.as_u8()),
+        old_flags,
+        new_flags: paused_ops,
+        paused: paused_ops & !old_flags,
+        resumed: old_flags & !paused_ops,
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "{} pause flags: {:#08b} → {:#08b}",
+        ys.bond_type.as_str(),
+        old_flags,
+        paused_ops
+    );
+    Ok(())
+}
+
+// ─── NAV Circuit Breaker ────────────────────────────────────────────────────────
+
 #[derive(Accounts)]
-pub struct UpdateYieldSource<'info> {
+pub struct ResetCircuitBreaker<'info> {
     pub authority: Signer<'info>,
 
     #[account(
@@ -88,33 +404,42 @@ pub struct UpdateYieldSource<'info> {
     pub yield_source: Account<'info, YieldSource>,
 }
 
-pub fn handle_update_yield_source(
-    ctx: Context<UpdateYieldSource>,
-    params: UpdateYieldSourceParams,
+/// Clear a tripped breaker after review and resume the operations it paused.
+/// With `accept_nav` the rejected NAV is applied; otherwise the prior NAV stays.
+pub fn handle_reset_circuit_breaker(
+    ctx: Context<ResetCircuitBreaker>,
+    accept_nav: bool,
 ) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
     let ys = &mut ctx.accounts.yield_source;
+    require!(ys.circuit_breaker_tripped, StablebondError::CircuitBreakerNotTripped);
 
-    if let Some(weight) = params.allocation_weight_bps {
-        ys.allocation_weight_bps = weight;
-    }
-    if let Some(min) = params.min_deposit {
-        ys.min_deposit = min;
-    }
-    if let Some(max) = params.max_allocation {
-        ys.max_allocation = max;
-    }
-    if let Some(active) = params.is_active {
-        ys.is_active = active;
-    }
+    let tripped_nav = ys.tripped_nav;
+    let resumed = ys.reset_circuit_breaker(accept_nav, now);
 
-    msg!("Yield source updated");
+    emit!(CircuitBreakerReset {
+        yield_source: ys.key(),
+        bond_type: ys.bond_type.as_u8(),
+        tripped_nav,
+        nav_accepted: accept_nav,
+        nav_per_share: ys.nav_per_share,
+        resumed,
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Circuit breaker reset for {} (NAV {})",
+        ys.bond_type.as_str(),
+        ys.nav_per_share
+    );
     Ok(())
 }
 
-// ─── Pause / Resume Protocol ────────────────────────────────────────────────────
+// ─── Authority Transfer ─────────────────────────────────────────────────────────
 
 #[derive(Accounts)]
-pub struct PauseProtocol<'info> {
+pub struct ProposeAuthority<'info> {
     pub authority: Signer<'info>,
 
     #[account(
@@ -126,30 +451,60 @@ pub struct PauseProtocol<'info> {
     pub protocol_config: Account<'info, ProtocolConfig>,
 }
 
-pub fn handle_pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+/// Step 1: the current authority nominates a successor.
+/// Proposing Pubkey::default() cancels a pending transfer.
+pub fn handle_propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    ctx.accounts.protocol_config.is_active = false;
-    ctx.accounts.protocol_config.updated_at = now;
+    let config = &mut ctx.accounts.protocol_config;
+    config.pending_authority = new_authority;
+    config.updated_at = now;
 
-    emit!(ProtocolPaused {
-        authority: ctx.accounts.authority.key(),
+    emit!(AuthorityTransferProposed {
+        authority: config.authority,
+        pending_authority: new_authority,
         timestamp: now,
     });
 
-    msg!("Protocol paused");
+    msg!("Authority transfer proposed to {}", new_authority);
     Ok(())
 }
 
-pub fn handle_resume_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Step 2: the nominee signs to take over, proving control of the new key.
+pub fn handle_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    ctx.accounts.protocol_config.is_active = true;
-    ctx.accounts.protocol_config.updated_at = now;
+    let config = &mut ctx.accounts.protocol_config;
+    require!(
+        config.pending_authority != Pubkey::default(),
+        StablebondError::NoPendingAuthority
+    );
+    require!(
+        ctx.accounts.new_authority.key() == config.pending_authority,
+        StablebondError::Unauthorized
+    );
 
-    emit!(ProtocolResumed {
-        authority: ctx.accounts.authority.key(),
+    let old_authority = config.authority;
+    config.authority = config.pending_authority;
+    config.pending_authority = Pubkey::default();
+    config.updated_at = now;
+
+    emit!(AuthorityTransferAccepted {
+        old_authority,
+        new_authority: config.authority,
         timestamp: now,
     });
 
-    msg!("Protocol resumed");
+    msg!("Protocol authority transferred to {}", config.authority);
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use stablebond_types::BondType;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_token::TokenTransfer;
+use stablebond_types::{BondType, PauseFlag};
 
 use crate::errors::StablebondError;
 use crate::events::YieldClaimed;
@@ -46,24 +47,35 @@ pub struct ClaimYield<'info> {
         mut,
         constraint = deposit_vault.key() == yield_source.deposit_vault,
     )]
-    pub deposit_vault: Account<'info, TokenAccount>,
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Settlement currency mint held by the deposit vault
+    #[account(address = deposit_vault.mint @ StablebondError::InvalidAccountData)]
+    pub settlement_mint: InterfaceAccount<'info, Mint>,
 
     /// User's settlement currency token account
     #[account(
         mut,
         constraint = user_token.owner == user.key(),
     )]
-    pub user_token: Account<'info, TokenAccount>,
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_claim_yield(ctx: Context<ClaimYield>, bond_type: BondType) -> Result<()> {
+pub fn handle_claim_yield<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimYield<'info>>,
+    bond_type: BondType,
+) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    require!(config.is_active, StablebondError::ProtocolNotActive);
+    config.require_unpaused(PauseFlag::YieldClaims)?;
+    ctx.accounts
+        .yield_source
+        .require_unpaused(PauseFlag::YieldClaims)?;
 
     let user_pos = &ctx.accounts.user_position;
     let ys = &ctx.accounts.yield_source;
+    require!(!user_pos.is_frozen, StablebondError::PositionFrozen);
 
     require!(
         user_pos.bond_type == bond_type,
@@ -71,43 +83,29 @@ pub fn handle_claim_yield(ctx: Context<ClaimYield>, bond_type: BondType) -> Resu
     );
 
     // Calculate current value of user's shares
-    let current_value = (user_pos.current_shares as u128)
-        .checked_mul(ys.nav_per_share as u128)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(1_000_000)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let current_value = ys.amount_for_shares(user_pos.current_shares)?;
 
     // Yield = current_value - cost_basis - already_realized
-    let cost_basis = user_pos.cost_basis;
-    let total_gain = current_value.saturating_sub(cost_basis);
-    let yield_amount = total_gain.saturating_sub(user_pos.realized_yield);
+    let yield_amount = user_pos.claimable_yield(current_value);
 
     require!(yield_amount > 0, StablebondError::NoYieldToClaim);
 
     // Deduct performance fee
-    let performance_fee = (yield_amount as u128)
-        .checked_mul(config.performance_fee_bps as u128)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(10_000)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let performance_fee = config.performance_fee(yield_amount)?;
     let net_yield = yield_amount
         .checked_sub(performance_fee)
         .ok_or(StablebondError::MathOverflow)?;
 
     // Transfer net yield from deposit vault to user
     let config_seeds: &[&[u8]] = &[ProtocolConfig::SEED, &[config.bump]];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.deposit_vault.to_account_info(),
-                to: ctx.accounts.user_token.to_account_info(),
-                authority: ctx.accounts.protocol_config.to_account_info(),
-            },
-            &[config_seeds],
-        ),
-        net_yield,
-    )?;
+    TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.settlement_mint,
+        from: &ctx.accounts.deposit_vault,
+        authority: ctx.accounts.protocol_config.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send(&ctx.accounts.user_token, net_yield, &[config_seeds])?;
 
     let now = Clock::get()?.unix_timestamp;
 
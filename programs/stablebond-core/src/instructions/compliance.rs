@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use stablebond_types::FreezeReason;
+
+use crate::errors::StablebondError;
+use crate::events::{PositionFrozen, PositionUnfrozen};
+use crate::state::{ProtocolConfig, UserPosition};
+
+// ─── Freeze / Unfreeze Position (compliance officer) ────────────────────────
+
+#[derive(Accounts)]
+pub struct SetPositionFreeze<'info> {
+    pub compliance_officer: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = compliance_officer @ StablebondError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            UserPosition::SEED,
+            protocol_config.key().as_ref(),
+            user_position.owner.as_ref(),
+            &[user_position.bond_type.as_u8()],
+        ],
+        bump = user_position.bump,
+    )]
+    pub user_position: Account<'info, UserPosition>,
+}
+
+/// Freeze a position for sanctions or legal holds. Blocks deposits,
+/// withdrawal requests/claims and yield claims until unfrozen.
+pub fn handle_freeze_position(ctx: Context<SetPositionFreeze>, reason: FreezeReason) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pos = &mut ctx.accounts.user_position;
+
+    pos.is_frozen = true;
+    pos.freeze_reason = reason;
+    pos.frozen_at = now;
+
+    emit!(PositionFrozen {
+        user: pos.owner,
+        bond_type: pos.bond_type.as_u8(),
+        reason,
+        compliance_officer: ctx.accounts.compliance_officer.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Position frozen: {} {} ({})",
+        pos.owner,
+        pos.bond_type.as_str(),
+        reason.as_str()
+    );
+    Ok(())
+}
+
+pub fn handle_unfreeze_position(ctx: Context<SetPositionFreeze>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pos = &mut ctx.accounts.user_position;
+    require!(pos.is_frozen, StablebondError::PositionNotFrozen);
+
+    let frozen_since = pos.frozen_at;
+    pos.is_frozen = false;
+
+    emit!(PositionUnfrozen {
+        user: pos.owner,
+        bond_type: pos.bond_type.as_u8(),
+        compliance_officer: ctx.accounts.compliance_officer.key(),
+        frozen_since,
+        timestamp: now,
+    });
+
+    msg!("Position unfrozen: {} {}", pos.owner, pos.bond_type.as_str());
+    Ok(())
+}
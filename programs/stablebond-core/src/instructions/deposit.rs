@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use stablebond_types::{allowed_bond_types, monthly_limit, allowed_yield_sources, BondType, DepositStatus};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_token::TokenTransfer;
+use stablebond_types::{
+    allowed_bond_types, allowed_yield_sources, monthly_limit, read_sovereign_tier, validate_kyc,
+    BondType, DepositStatus, LimitWindow, PauseFlag,
+};
+use stablebond_yield::state::BondVault;
 
 use crate::errors::StablebondError;
 use crate::events::{DepositInitiated, DirectDeposit};
-use crate::state::{PendingDeposit, ProtocolConfig, UserPosition, YieldSource};
+use crate::oracle::{read_fx_rate, usd_equivalent};
+use crate::state::{BondRegistry, PendingDeposit, ProtocolConfig, UserLimit, UserPosition, YieldSource};
 
 // ─── Generalized Deposit (cross-currency, creates PendingDeposit) ───────────
 
@@ -21,19 +27,42 @@ pub struct DepositCrossCurrency<'info> {
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
-    /// Source currency mint (e.g. JPY, MXN, BRL stablecoin)
-    /// CHECK: Validated in transfer
-    pub source_mint: AccountInfo<'info>,
+    /// Yield source the deposit will be converted into
+    #[account(
+        seeds = [
+            YieldSource::SEED,
+            protocol_config.key().as_ref(),
+            yield_source.token_mint.as_ref(),
+        ],
+        bump = yield_source.bump,
+    )]
+    pub yield_source: Box<Account<'info, YieldSource>>,
+
+    /// Linked BondVault; deposits stop while its reserves fall short
+    #[account(
+        address = yield_source.bond_vault @ StablebondError::BondVaultMismatch,
+        seeds = [BondVault::SEED, &[yield_source.bond_type.as_u8()]],
+        bump = bond_vault.bump,
+        seeds::program = stablebond_yield::ID,
+    )]
+    pub bond_vault: Box<Account<'info, BondVault>>,
+
+    /// Source currency mint (e.g. JPY, MXN, BRL stablecoin); SPL Token or Token-2022
+    pub source_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// User's source currency token account
-    /// CHECK: Validated in transfer
-    #[account(mut)]
-    pub user_source_ata: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = source_mint,
+    )]
+    pub user_source_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Protocol vault for the source currency
-    /// CHECK: Validated via constraint
-    #[account(mut)]
-    pub source_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = source_mint,
+    )]
+    pub source_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init_if_needed,
@@ -63,6 +92,26 @@ pub struct DepositCrossCurrency<'info> {
     )]
     pub pending_deposit: Account<'info, PendingDeposit>,
 
+    #[account(
+        seeds = [BondRegistry::SEED, protocol_config.key().as_ref()],
+        bump = bond_registry.bump,
+    )]
+    pub bond_registry: Box<Account<'info, BondRegistry>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserLimit::LEN,
+        seeds = [UserLimit::SEED, protocol_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_limit: Box<Account<'info, UserLimit>>,
+
+    /// FX oracle for the source currency vs USD: the yield source's
+    /// conversion feed, which values the source amount for the aggregate limit
+    /// CHECK: Validated against the yield source's oracle_feed
+    pub fx_oracle: AccountInfo<'info>,
+
     /// Accredit WhitelistEntry PDA for this user.
     /// CHECK: Manually deserialized and validated.
     pub whitelist_entry: AccountInfo<'info>,
@@ -71,9 +120,8 @@ pub struct DepositCrossCurrency<'info> {
     /// CHECK: Manually deserialized to extract tier.
     pub sovereign_identity: AccountInfo<'info>,
 
-    /// Token program for the source currency transfer
-    /// CHECK: Program ID check
-    pub token_program: AccountInfo<'info>,
+    /// Token program of the source currency mint
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub system_program: Program<'info, System>,
 }
@@ -104,18 +152,31 @@ pub struct DepositDirect<'info> {
     )]
     pub yield_source: Box<Account<'info, YieldSource>>,
 
+    /// Linked BondVault; deposits stop while its reserves fall short
+    #[account(
+        address = yield_source.bond_vault @ StablebondError::BondVaultMismatch,
+        seeds = [BondVault::SEED, &[yield_source.bond_type.as_u8()]],
+        bump = bond_vault.bump,
+        seeds::program = stablebond_yield::ID,
+    )]
+    pub bond_vault: Box<Account<'info, BondVault>>,
+
     #[account(
         mut,
         constraint = user_token.owner == user.key(),
     )]
-    pub user_token: Account<'info, TokenAccount>,
+    pub user_token: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Yield source deposit vault
     #[account(
         mut,
         constraint = deposit_vault.key() == yield_source.deposit_vault,
     )]
-    pub deposit_vault: Account<'info, TokenAccount>,
+    pub deposit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Settlement currency mint held by the deposit vault
+    #[account(address = deposit_vault.mint @ StablebondError::InvalidAccountData)]
+    pub settlement_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
@@ -131,6 +192,25 @@ pub struct DepositDirect<'info> {
     )]
     pub user_position: Box<Account<'info, UserPosition>>,
 
+    #[account(
+        seeds = [BondRegistry::SEED, protocol_config.key().as_ref()],
+        bump = bond_registry.bump,
+    )]
+    pub bond_registry: Box<Account<'info, BondRegistry>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserLimit::LEN,
+        seeds = [UserLimit::SEED, protocol_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_limit: Box<Account<'info, UserLimit>>,
+
+    /// FX oracle for the bond's currency vs USD (not read for USD-denominated bonds)
+    /// CHECK: Validated against the bond registry's oracle_feed
+    pub fx_oracle: AccountInfo<'info>,
+
     /// Accredit WhitelistEntry PDA
     /// CHECK: Manually validated
     pub whitelist_entry: AccountInfo<'info>,
@@ -139,63 +219,98 @@ pub struct DepositDirect<'info> {
     /// CHECK: Manually validated
     pub sovereign_identity: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-// ─── Shared KYC/Tier validation (same pattern as Exodus) ────────────────────
-
-/// Validate KYC via Accredit WhitelistEntry (manual deserialization).
-fn validate_kyc(whitelist_entry: &AccountInfo, user: &Pubkey) -> Result<()> {
-    let data = whitelist_entry.try_borrow_data()?;
-    require!(data.len() >= 83, StablebondError::KycRequired);
-
-    // Skip 8-byte discriminator
-    let owner = Pubkey::try_from(&data[8..40]).map_err(|_| StablebondError::InvalidAccountData)?;
-    require!(owner == *user, StablebondError::KycRequired);
-
-    let is_active = data[72] != 0;
-    require!(is_active, StablebondError::KycRequired);
-
-    let jurisdiction = data[74];
-    // Block USA jurisdiction (value 4 in Accredit)
-    require!(jurisdiction != 4, StablebondError::JurisdictionRestricted);
+/// Value a deposit in USD minor units for the aggregate cross-bond limit.
+/// USD-denominated bonds are valued 1:1 without reading the oracle.
+fn deposit_usd_value(
+    registry: &BondRegistry,
+    bond_type: BondType,
+    fx_oracle: &AccountInfo,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    let bond = registry
+        .find(bond_type)
+        .ok_or(StablebondError::BondTypeNotFound)?;
+    if bond.denomination_currency == *b"USD" {
+        return Ok(amount);
+    }
 
-    let expires_at = i64::from_le_bytes(data[75..83].try_into().unwrap());
-    let now = Clock::get()?.unix_timestamp;
-    require!(expires_at > now, StablebondError::KycExpired);
+    require!(
+        fx_oracle.key() == bond.oracle_feed,
+        StablebondError::InvalidOraclePrice
+    );
+    let fx_rate = read_fx_rate(fx_oracle, now)?;
+    usd_equivalent(amount, fx_rate)
+}
 
-    Ok(())
+/// Value a cross-currency deposit in USD minor units for the aggregate
+/// cross-bond limit, at the rate of the yield source's conversion feed
+/// (source currency per USD settlement unit).
+fn source_usd_value(
+    conversion_feed: &Pubkey,
+    fx_oracle: &AccountInfo,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    require!(
+        fx_oracle.key() == *conversion_feed,
+        StablebondError::InvalidOraclePrice
+    );
+    let fx_rate = read_fx_rate(fx_oracle, now)?;
+    usd_equivalent(amount, fx_rate)
 }
 
-/// Read sovereign tier from SovereignIdentity PDA (manual deserialization).
-fn read_sovereign_tier(sovereign_identity: &AccountInfo, user: &Pubkey) -> Result<u8> {
-    let data = sovereign_identity.try_borrow_data()?;
-    require!(data.len() >= 41, StablebondError::SovereignIdentityNotFound);
+/// Enforce the per-bond monthly limit under the tier's configured window and
+/// record the deposit on the position. Shared by both deposit instructions.
+fn apply_bond_limit(
+    user_pos: &mut UserPosition,
+    window: LimitWindow,
+    tier: u8,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    user_pos.sovereign_tier = tier;
 
-    let owner = Pubkey::try_from(&data[8..40]).map_err(|_| StablebondError::InvalidAccountData)?;
-    require!(owner == *user, StablebondError::SovereignIdentityNotFound);
+    let limit = monthly_limit(tier, user_pos.bond_type);
+    let new_usage = user_pos
+        .window_usage(window, now)
+        .checked_add(amount)
+        .ok_or(StablebondError::MathOverflow)?;
+    require!(new_usage <= limit, StablebondError::MonthlyLimitExceeded);
 
-    Ok(data[40])
+    user_pos.record_window_deposit(amount, now)
 }
 
 // ─── Cross-currency deposit handler ─────────────────────────────────────────
 
-pub fn handle_deposit_cross_currency(
-    ctx: Context<DepositCrossCurrency>,
+pub fn handle_deposit_cross_currency<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositCrossCurrency<'info>>,
     amount: u64,
     bond_type: BondType,
     min_output: u64,
 ) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    require!(config.is_active, StablebondError::ProtocolNotActive);
+    config.require_unpaused(PauseFlag::Deposits)?;
     require!(amount > 0, StablebondError::ZeroDeposit);
 
+    let ys = &ctx.accounts.yield_source;
+    require!(ys.is_active, StablebondError::YieldSourceNotActive);
+    ys.require_unpaused(PauseFlag::Deposits)?;
+    require!(
+        !ctx.accounts.bond_vault.has_reserve_shortfall(),
+        StablebondError::ReserveShortfall
+    );
+    require!(ys.bond_type == bond_type, StablebondError::BondTypeNotFound);
+
     // 1. Validate KYC via Accredit WhitelistEntry
-    validate_kyc(&ctx.accounts.whitelist_entry, &ctx.accounts.user.key())?;
+    validate_kyc::<StablebondError>(&ctx.accounts.whitelist_entry, &ctx.accounts.user.key())?;
 
     // 2. Read Sovereign tier and validate bond type access
-    let tier = read_sovereign_tier(&ctx.accounts.sovereign_identity, &ctx.accounts.user.key())?;
+    let tier = read_sovereign_tier::<StablebondError>(&ctx.accounts.sovereign_identity, &ctx.accounts.user.key())?;
     require!(tier > 0, StablebondError::TierTooLow);
 
     let allowed = allowed_bond_types(tier);
@@ -213,59 +328,51 @@ pub fn handle_deposit_cross_currency(
         user_pos.protocol_config = config.key();
         user_pos.bond_type = bond_type;
         user_pos.created_at = now;
-        user_pos.month_start = now;
         user_pos.bump = ctx.bumps.user_position;
     }
-
-    user_pos.maybe_reset_monthly(now);
-    user_pos.sovereign_tier = tier;
+    require!(!user_pos.is_frozen, StablebondError::PositionFrozen);
 
     // 3. Check monthly limit for this bond type
-    let limit = monthly_limit(tier, bond_type);
-    let new_monthly = user_pos
-        .monthly_deposited
-        .checked_add(amount)
-        .ok_or(StablebondError::MathOverflow)?;
-    require!(new_monthly <= limit, StablebondError::MonthlyLimitExceeded);
-
-    // 4. Transfer source currency from user to vault
-    let transfer_ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id: ctx.accounts.token_program.key(),
-        accounts: vec![
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                ctx.accounts.user_source_ata.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                ctx.accounts.source_vault.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-                ctx.accounts.user.key(), true,
-            ),
-        ],
-        data: {
-            // SPL Token transfer instruction data: [3] + [amount as le bytes]
-            let mut data = vec![3u8];
-            data.extend_from_slice(&amount.to_le_bytes());
-            data
-        },
-    };
-    anchor_lang::solana_program::program::invoke(
-        &transfer_ix,
-        &[
-            ctx.accounts.user_source_ata.to_account_info(),
-            ctx.accounts.source_vault.to_account_info(),
-            ctx.accounts.user.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-        ],
+    let window = config.limit_window(tier);
+    apply_bond_limit(user_pos, window, tier, amount, now)?;
+
+    // 4. Check aggregate USD-equivalent limit across all bond positions.
+    // `amount` is in the source currency, so it is valued with the feed
+    // `execute_conversion` will convert it at, not the bond's own feed
+    let usd_value = source_usd_value(
+        &ctx.accounts.yield_source.oracle_feed,
+        &ctx.accounts.fx_oracle,
+        amount,
+        now,
     )?;
+    let user_limit = &mut ctx.accounts.user_limit;
+    if user_limit.created_at == 0 {
+        user_limit.owner = ctx.accounts.user.key();
+        user_limit.protocol_config = config.key();
+        user_limit.created_at = now;
+        user_limit.bump = ctx.bumps.user_limit;
+    }
+    user_limit.record_deposit(window, tier, usd_value, now)?;
+
+    // 5. Transfer source currency from user to vault; a transfer-fee mint
+    // delivers less than `amount`, and only what arrives is credited
+    let received = TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.source_mint,
+        from: &ctx.accounts.user_source_ata,
+        authority: ctx.accounts.user.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send_net::<StablebondError>(&mut ctx.accounts.source_vault, amount, &[])?;
+    require!(received > 0, StablebondError::ZeroDeposit);
 
-    // 5. Init PendingDeposit
+    // 6. Init PendingDeposit
     let nonce = config.deposit_nonce + 1;
     let pending = &mut ctx.accounts.pending_deposit;
     pending.user = ctx.accounts.user.key();
     pending.protocol_config = config.key();
     pending.bond_type = bond_type;
-    pending.source_amount = amount;
+    pending.source_amount = received;
     pending.min_output = min_output;
     pending.deposited_at = now;
     pending.expires_at = now + PendingDeposit::EXPIRY_SECONDS;
@@ -276,11 +383,10 @@ pub fn handle_deposit_cross_currency(
     pending.nonce = nonce;
     pending.bump = ctx.bumps.pending_deposit;
 
-    // 6. Update UserPosition monthly tracking
-    user_pos.monthly_deposited = new_monthly;
+    // 7. Update UserPosition totals
     user_pos.total_deposited = user_pos
         .total_deposited
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     user_pos.deposit_count = user_pos
         .deposit_count
@@ -289,12 +395,12 @@ pub fn handle_deposit_cross_currency(
     user_pos.last_deposit_at = now;
     user_pos.deposit_nonce = nonce;
 
-    // 7. Update protocol config
+    // 8. Update protocol config
     let config_mut = &mut ctx.accounts.protocol_config;
     config_mut.deposit_nonce = nonce;
     config_mut.pending_conversion = config_mut
         .pending_conversion
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     config_mut.updated_at = now;
 
@@ -302,7 +408,7 @@ pub fn handle_deposit_cross_currency(
         user: ctx.accounts.user.key(),
         pending_deposit: pending.key(),
         bond_type: bond_type.as_u8(),
-        source_amount: amount,
+        source_amount: received,
         min_output,
         nonce,
         timestamp: now,
@@ -310,7 +416,7 @@ pub fn handle_deposit_cross_currency(
 
     msg!(
         "Cross-currency deposit initiated: {} for {}, nonce {}",
-        amount,
+        received,
         bond_type.as_str(),
         nonce
     );
@@ -319,25 +425,30 @@ pub fn handle_deposit_cross_currency(
 
 // ─── Direct deposit handler (settlement currency == bond currency) ──────────
 
-pub fn handle_deposit_direct(
-    ctx: Context<DepositDirect>,
+pub fn handle_deposit_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositDirect<'info>>,
     amount: u64,
     bond_type: BondType,
 ) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    require!(config.is_active, StablebondError::ProtocolNotActive);
+    config.require_unpaused(PauseFlag::Deposits)?;
     require!(amount > 0, StablebondError::ZeroDeposit);
 
     let ys = &ctx.accounts.yield_source;
     require!(ys.is_active, StablebondError::YieldSourceNotActive);
+    ys.require_unpaused(PauseFlag::Deposits)?;
+    require!(
+        !ctx.accounts.bond_vault.has_reserve_shortfall(),
+        StablebondError::ReserveShortfall
+    );
     require!(amount >= ys.min_deposit, StablebondError::BelowMinDeposit);
     require!(ys.bond_type == bond_type, StablebondError::BondTypeNotFound);
 
     // KYC check
-    validate_kyc(&ctx.accounts.whitelist_entry, &ctx.accounts.user.key())?;
+    validate_kyc::<StablebondError>(&ctx.accounts.whitelist_entry, &ctx.accounts.user.key())?;
 
     // Sovereign tier check
-    let tier = read_sovereign_tier(&ctx.accounts.sovereign_identity, &ctx.accounts.user.key())?;
+    let tier = read_sovereign_tier::<StablebondError>(&ctx.accounts.sovereign_identity, &ctx.accounts.user.key())?;
     require!(tier > 0, StablebondError::TierTooLow);
 
     // Check bond type allowed for tier
@@ -362,45 +473,49 @@ pub fn handle_deposit_direct(
         user_pos.protocol_config = config.key();
         user_pos.bond_type = bond_type;
         user_pos.created_at = now;
-        user_pos.month_start = now;
         user_pos.bump = ctx.bumps.user_position;
     }
-    user_pos.maybe_reset_monthly(now);
-    user_pos.sovereign_tier = tier;
-
-    let limit = monthly_limit(tier, bond_type);
-    let new_monthly = user_pos
-        .monthly_deposited
-        .checked_add(amount)
-        .ok_or(StablebondError::MathOverflow)?;
-    require!(new_monthly <= limit, StablebondError::MonthlyLimitExceeded);
-
-    // Calculate shares from yield source NAV
-    let nav = ys.nav_per_share;
-    let shares = (amount as u128)
-        .checked_mul(1_000_000)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(nav as u128)
-        .ok_or(StablebondError::MathOverflow)? as u64;
-
-    // Transfer from user to yield source deposit vault
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.user_token.to_account_info(),
-                to: ctx.accounts.deposit_vault.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
+    require!(!user_pos.is_frozen, StablebondError::PositionFrozen);
+    let window = config.limit_window(tier);
+    apply_bond_limit(user_pos, window, tier, amount, now)?;
+
+    // Aggregate USD-equivalent limit across all bond positions
+    let usd_value = deposit_usd_value(
+        &ctx.accounts.bond_registry,
+        bond_type,
+        &ctx.accounts.fx_oracle,
         amount,
+        now,
     )?;
+    let user_limit = &mut ctx.accounts.user_limit;
+    if user_limit.created_at == 0 {
+        user_limit.owner = ctx.accounts.user.key();
+        user_limit.protocol_config = config.key();
+        user_limit.created_at = now;
+        user_limit.bump = ctx.bumps.user_limit;
+    }
+    user_limit.record_deposit(window, tier, usd_value, now)?;
+
+    // Transfer from user to yield source deposit vault; shares are issued
+    // on what arrives, net of any transfer fee
+    let received = TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.settlement_mint,
+        from: &ctx.accounts.user_token,
+        authority: ctx.accounts.user.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send_net::<StablebondError>(&mut ctx.accounts.deposit_vault, amount, &[])?;
+
+    // Calculate shares from yield source NAV
+    let shares = ys.shares_for_amount(received)?;
+    require!(shares > 0, StablebondError::ZeroShares);
 
     // Update yield source
     let ys_mut = &mut ctx.accounts.yield_source;
     ys_mut.total_deposited = ys_mut
         .total_deposited
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     ys_mut.total_shares = ys_mut
         .total_shares
@@ -408,10 +523,9 @@ pub fn handle_deposit_direct(
         .ok_or(StablebondError::MathOverflow)?;
 
     // Update user position
-    user_pos.monthly_deposited = new_monthly;
     user_pos.total_deposited = user_pos
         .total_deposited
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     user_pos.current_shares = user_pos
         .current_shares
@@ -419,7 +533,7 @@ pub fn handle_deposit_direct(
         .ok_or(StablebondError::MathOverflow)?;
     user_pos.cost_basis = user_pos
         .cost_basis
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     user_pos.deposit_count = user_pos
         .deposit_count
@@ -431,23 +545,58 @@ pub fn handle_deposit_direct(
     let config_mut = &mut ctx.accounts.protocol_config;
     config_mut.total_deposits = config_mut
         .total_deposits
-        .checked_add(amount)
+        .checked_add(received)
         .ok_or(StablebondError::MathOverflow)?;
     config_mut.updated_at = now;
 
     emit!(DirectDeposit {
         user: ctx.accounts.user.key(),
         bond_type: bond_type.as_u8(),
-        amount,
+        amount: received,
         shares_received: shares,
         timestamp: now,
     });
 
     msg!(
         "Direct deposit: {} for {}, {} shares issued",
-        amount,
+        received,
         bond_type.as_str(),
         shares
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// PriceFeed account: discriminator, authority, price, last update.
+    fn with_feed<R>(key: Pubkey, price: u64, f: impl FnOnce(&AccountInfo) -> R) -> R {
+        let mut data = vec![0u8; 8 + 32];
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&NOW.to_le_bytes());
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        f(&info)
+    }
+
+    #[test]
+    fn cross_currency_source_is_valued_with_its_own_feed() {
+        // 15,000 JPY at 150 JPY per USD into a USD-denominated bond: the
+        // bond-currency valuation would have counted it as $15,000
+        let jpy_feed = Pubkey::new_unique();
+        let usd = with_feed(jpy_feed, 150_000_000, |feed| {
+            source_usd_value(&jpy_feed, feed, 15_000_000_000, NOW).unwrap()
+        });
+        assert_eq!(usd, 100_000_000);
+
+        // A feed other than the yield source's conversion feed is rejected
+        let other = Pubkey::new_unique();
+        with_feed(other, 1_000_000, |feed| {
+            assert!(source_usd_value(&jpy_feed, feed, 15_000_000_000, NOW).is_err());
+        });
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use stablebond_types::{aggregate_monthly_limit_usd, monthly_limit, BondType, LimitWindow};
+
+use crate::state::{ProtocolConfig, UserLimit, UserPosition};
+
+/// Remaining monthly deposit capacity for a user, returned via return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DepositCapacity {
+    /// Sovereign tier recorded at the user's last deposit
+    pub tier: u8,
+    /// Limit window mode configured for the tier
+    pub window: LimitWindow,
+    /// Per-bond monthly limit (bond's native currency minor units)
+    pub bond_limit: u64,
+    /// Remaining per-bond capacity this month (native minor units)
+    pub bond_remaining: u64,
+    /// Aggregate monthly limit across all bonds (USD minor units)
+    pub usd_limit: u64,
+    /// Remaining aggregate capacity this month (USD minor units)
+    pub usd_remaining: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(bond_type: BondType)]
+pub struct GetDepositCapacity<'info> {
+    /// Wallet whose capacity is being queried
+    /// CHECK: Only used as a PDA seed
+    pub owner: AccountInfo<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [UserLimit::SEED, protocol_config.key().as_ref(), owner.key().as_ref()],
+        bump = user_limit.bump,
+    )]
+    pub user_limit: Account<'info, UserLimit>,
+
+    /// Position for the queried bond type, if the user has deposited into it
+    #[account(
+        seeds = [
+            UserPosition::SEED,
+            protocol_config.key().as_ref(),
+            owner.key().as_ref(),
+            &[bond_type.as_u8()],
+        ],
+        bump = user_position.bump,
+    )]
+    pub user_position: Option<Account<'info, UserPosition>>,
+}
+
+pub fn handle_get_deposit_capacity(
+    ctx: Context<GetDepositCapacity>,
+    bond_type: BondType,
+) -> Result<DepositCapacity> {
+    let now = Clock::get()?.unix_timestamp;
+    let user_limit = &ctx.accounts.user_limit;
+    let tier = user_limit.sovereign_tier;
+
+    let window = ctx.accounts.protocol_config.limit_window(tier);
+
+    let bond_limit = monthly_limit(tier, bond_type);
+    let bond_remaining = match &ctx.accounts.user_position {
+        Some(pos) => bond_limit.saturating_sub(pos.window_usage(window, now)),
+        None => bond_limit,
+    };
+
+    Ok(DepositCapacity {
+        tier,
+        window,
+        bond_limit,
+        bond_remaining,
+        usd_limit: aggregate_monthly_limit_usd(tier),
+        usd_remaining: user_limit.remaining_usd(window, now),
+    })
+}
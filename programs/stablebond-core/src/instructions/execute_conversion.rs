@@ -1,23 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use stablebond_types::{ConversionDirection, DepositStatus};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_token::TokenTransfer;
+use stablebond_types::{ConversionDirection, DepositStatus, PauseFlag, Role};
 
 use crate::errors::StablebondError;
 use crate::events::{ConversionExecuted, ConversionRecordCreated};
-use crate::state::{ConversionRecord, PendingDeposit, ProtocolConfig, UserPosition, YieldSource};
-
-/// Oracle PriceFeed — simplified Meridian-style layout.
-/// After 8-byte discriminator:
-///   - authority: Pubkey (32)
-///   - current_price: u64 (8)  — source per settlement, scaled 1e6
-///   - last_update_time: i64 (8)
-const ORACLE_PRICE_OFFSET: usize = 8 + 32;
-const ORACLE_UPDATE_OFFSET: usize = ORACLE_PRICE_OFFSET + 8;
-const MAX_ORACLE_STALENESS: i64 = 300; // 5 minutes
+use crate::oracle::{read_fx_rate, settlement_for_source};
+use crate::state::{
+    require_role, ConversionRecord, PendingDeposit, ProtocolConfig, RoleAssignment, UserPosition,
+    YieldSource,
+};
 
 #[derive(Accounts)]
 pub struct ExecuteConversion<'info> {
-    /// Keeper that triggers the conversion
+    /// Allowlisted keeper (Keeper role) that triggers the conversion
     #[account(mut)]
     pub keeper: Signer<'info>,
 
@@ -52,7 +48,11 @@ pub struct ExecuteConversion<'info> {
         mut,
         constraint = usdc_vault.key() == protocol_config.usdc_vault @ StablebondError::InvalidAccountData,
     )]
-    pub usdc_vault: Account<'info, TokenAccount>,
+    pub usdc_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Settlement currency mint held by the USDC vault
+    #[account(address = protocol_config.usdc_mint @ StablebondError::InvalidAccountData)]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Oracle PriceFeed PDA for the bond's currency pair
     /// CHECK: Manually deserialized
@@ -74,7 +74,7 @@ pub struct ExecuteConversion<'info> {
         mut,
         constraint = yield_deposit_vault.key() == yield_source.deposit_vault,
     )]
-    pub yield_deposit_vault: Account<'info, TokenAccount>,
+    pub yield_deposit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// ConversionRecord to create
     #[account(
@@ -91,11 +91,33 @@ pub struct ExecuteConversion<'info> {
     )]
     pub conversion_record: Box<Account<'info, ConversionRecord>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Keeper's role assignment; not needed when the keeper is the authority
+    #[account(
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), keeper.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Box<Account<'info, RoleAssignment>>>,
 }
 
-pub fn handle_execute_conversion(ctx: Context<ExecuteConversion>) -> Result<()> {
+pub fn handle_execute_conversion<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteConversion<'info>>,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.protocol_config.authority,
+        &ctx.accounts.keeper.key(),
+        ctx.accounts.role_assignment.as_deref().map(|a| &**a),
+        Role::Keeper,
+    )?;
+    ctx.accounts
+        .protocol_config
+        .require_unpaused(PauseFlag::Conversions)?;
+    ctx.accounts
+        .yield_source
+        .require_unpaused(PauseFlag::Conversions)?;
+
     let now = Clock::get()?.unix_timestamp;
     let pending = &ctx.accounts.pending_deposit;
 
@@ -110,78 +132,42 @@ pub fn handle_execute_conversion(ctx: Context<ExecuteConversion>) -> Result<()>
     );
 
     // 3. Read oracle price
-    let oracle_data = ctx.accounts.oracle.try_borrow_data()?;
-    require!(
-        oracle_data.len() >= ORACLE_UPDATE_OFFSET + 8,
-        StablebondError::InvalidOraclePrice
-    );
-
-    let exchange_rate = u64::from_le_bytes(
-        oracle_data[ORACLE_PRICE_OFFSET..ORACLE_PRICE_OFFSET + 8]
-            .try_into()
-            .unwrap(),
-    );
-    let last_update = i64::from_le_bytes(
-        oracle_data[ORACLE_UPDATE_OFFSET..ORACLE_UPDATE_OFFSET + 8]
-            .try_into()
-            .unwrap(),
-    );
-    drop(oracle_data);
-
-    require!(exchange_rate > 0, StablebondError::InvalidOraclePrice);
-    require!(
-        now - last_update <= MAX_ORACLE_STALENESS,
-        StablebondError::StalePriceOracle
-    );
+    let exchange_rate = read_fx_rate(&ctx.accounts.oracle, now)?;
 
     // 4. Calculate settlement output
     // exchange_rate = source currency per settlement unit, scaled 1e6
     // settlement_out = source_amount * 1_000_000 / exchange_rate
     let source_amount = pending.source_amount;
-    let gross_settlement = (source_amount as u128)
-        .checked_mul(1_000_000)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(exchange_rate as u128)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let gross_settlement = settlement_for_source(source_amount, exchange_rate)?;
 
     // 5. Deduct conversion fee
     let config = &ctx.accounts.protocol_config;
-    let fee = (gross_settlement as u128)
-        .checked_mul(config.conversion_fee_bps as u128)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(10_000)
-        .ok_or(StablebondError::MathOverflow)? as u64;
-    let settlement_received = gross_settlement
+    let fee = config.conversion_fee(gross_settlement)?;
+    let settlement_out = gross_settlement
         .checked_sub(fee)
         .ok_or(StablebondError::MathOverflow)?;
 
-    // 6. Slippage check
+    // 6. Transfer settlement currency from protocol vault to yield source;
+    // with a transfer-fee mint the yield source receives less than sent
+    let config_seeds: &[&[u8]] = &[ProtocolConfig::SEED, &[config.bump]];
+    let settlement_received = TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.usdc_mint,
+        from: &ctx.accounts.usdc_vault,
+        authority: ctx.accounts.protocol_config.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send_net::<StablebondError>(&mut ctx.accounts.yield_deposit_vault, settlement_out, &[config_seeds])?;
+
+    // 7. Slippage check on what actually arrived
     require!(
         settlement_received >= pending.min_output,
         StablebondError::SlippageExceeded
     );
 
-    // 7. Transfer settlement currency from protocol vault to yield source
-    let config_seeds: &[&[u8]] = &[ProtocolConfig::SEED, &[config.bump]];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.usdc_vault.to_account_info(),
-                to: ctx.accounts.yield_deposit_vault.to_account_info(),
-                authority: ctx.accounts.protocol_config.to_account_info(),
-            },
-            &[config_seeds],
-        ),
-        settlement_received,
-    )?;
-
     // 8. Calculate shares from yield source NAV
-    let shares = (settlement_received as u128)
-        .checked_mul(1_000_000)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(ys.nav_per_share as u128)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let shares = ys.shares_for_amount(settlement_received)?;
+    require!(shares > 0, StablebondError::ZeroShares);
 
     // 9. Update PendingDeposit
     let pending_mut = &mut ctx.accounts.pending_deposit;
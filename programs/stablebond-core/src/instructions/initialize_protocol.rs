@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_types::LimitWindow;
 
 use crate::errors::StablebondError;
 use crate::events::ProtocolInitialized;
@@ -38,8 +39,8 @@ pub struct InitializeProtocol<'info> {
     )]
     pub bond_registry: Account<'info, BondRegistry>,
 
-    /// USDC mint (SPL Token) — primary settlement currency
-    pub usdc_mint: Account<'info, Mint>,
+    /// USDC mint (SPL Token or Token-2022) — primary settlement currency
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
@@ -48,10 +49,11 @@ pub struct InitializeProtocol<'info> {
         bump,
         token::mint = usdc_mint,
         token::authority = protocol_config,
+        token::token_program = token_program,
     )]
-    pub usdc_vault: Account<'info, TokenAccount>,
+    pub usdc_vault: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -84,6 +86,12 @@ pub fn handle_initialize_protocol(ctx: Context<InitializeProtocol>, params: Init
     config.updated_at = now;
     config.bump = ctx.bumps.protocol_config;
     config.usdc_vault_bump = ctx.bumps.usdc_vault;
+    config.tier_limit_windows = [LimitWindow::CalendarMonth; 5];
+    config.compliance_officer = Pubkey::default();
+    config.pending_authority = Pubkey::default();
+    config.timelock_delay = ProtocolConfig::MIN_TIMELOCK_DELAY;
+    config.next_change_id = 0;
+    config.paused_ops = 0;
 
     // Initialize bond registry
     let registry = &mut ctx.accounts.bond_registry;
@@ -0,0 +1,569 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use stablebond_yield::state::BondVault;
+
+use crate::errors::StablebondError;
+use crate::events::AccountMigrated;
+use crate::state::{ProtocolConfig, UserPosition, YieldSource};
+
+// ─── Account layout migrations ──────────────────────────────────────────────────
+//
+// ProtocolConfig, YieldSource and UserPosition gained fields at the end of
+// their layouts. Accounts created by the original program are `LEGACY_LEN`
+// bytes and cannot be deserialized as the current type, so every instruction
+// that takes them fails until they are grown to `LEN`. The new bytes start
+// zeroed, which is the correct default for every added field except those
+// set explicitly below.
+
+/// Grow a legacy-layout account of type `T` from `legacy_len` to `new_len`,
+/// topping up rent from `payer`, then let `migrate` fill in the new fields.
+fn grow_legacy_account<'info, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    legacy_len: usize,
+    new_len: usize,
+    migrate: impl FnOnce(&mut T) -> Result<()>,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator,
+{
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            account.owner == &crate::ID
+                && data.len() == legacy_len
+                && data.starts_with(T::DISCRIMINATOR),
+            StablebondError::NotLegacyAccount
+        );
+    }
+
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+    if current < rent {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - current,
+        )?;
+    }
+    account.resize(new_len)?;
+
+    let mut value = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    migrate(&mut value)?;
+    value.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_len: legacy_len as u32,
+        new_len: new_len as u32,
+        payer: payer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// ─── Migrate Protocol Config ────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateProtocolConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Legacy-layout ProtocolConfig; validated in `grow_legacy_account`
+    #[account(mut, seeds = [ProtocolConfig::SEED], bump)]
+    pub protocol_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow the config to the current layout. Must run before any other
+/// migration, since they load the config as a `ProtocolConfig`.
+pub fn handle_migrate_protocol_config(ctx: Context<MigrateProtocolConfig>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    grow_legacy_account::<ProtocolConfig>(
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        ProtocolConfig::LEGACY_LEN,
+        ProtocolConfig::LEN,
+        |config| {
+            require!(config.authority == authority, StablebondError::Unauthorized);
+            fill_protocol_config_tail(config);
+            Ok(())
+        },
+    )?;
+
+    msg!("Protocol config migrated");
+    Ok(())
+}
+
+/// Zeroed windows decode as calendar months, the original behaviour. Legacy
+/// configs had no timelock, so they start at the floor rather than at zero.
+fn fill_protocol_config_tail(config: &mut ProtocolConfig) {
+    config.timelock_delay = ProtocolConfig::MIN_TIMELOCK_DELAY;
+}
+
+// ─── Migrate Yield Source ───────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateYieldSource<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = authority @ StablebondError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Legacy-layout YieldSource; validated in `grow_legacy_account`
+    #[account(mut)]
+    pub yield_source: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a yield source to the current layout, arming the NAV circuit breaker
+/// with its default bounds and linking the bond type's vault.
+pub fn handle_migrate_yield_source(ctx: Context<MigrateYieldSource>) -> Result<()> {
+    let config_key = ctx.accounts.protocol_config.key();
+    let now = Clock::get()?.unix_timestamp;
+    grow_legacy_account::<YieldSource>(
+        &ctx.accounts.yield_source,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        YieldSource::LEGACY_LEN,
+        YieldSource::LEN,
+        |ys| {
+            require!(
+                ys.protocol_config == config_key,
+                StablebondError::Unauthorized
+            );
+            fill_yield_source_tail(ys, now);
+            Ok(())
+        },
+    )?;
+
+    msg!("Yield source migrated");
+    Ok(())
+}
+
+fn fill_yield_source_tail(ys: &mut YieldSource, now: i64) {
+    ys.max_nav_change_bps = YieldSource::DEFAULT_MAX_NAV_CHANGE_BPS;
+    ys.max_daily_nav_change_bps = YieldSource::DEFAULT_MAX_DAILY_NAV_CHANGE_BPS;
+    ys.nav_day_start = YieldSource::utc_day_start(now);
+    ys.nav_day_open = ys.nav_per_share;
+    ys.bond_vault = Pubkey::find_program_address(
+        &[BondVault::SEED, &[ys.bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+    .0;
+}
+
+// ─── Migrate User Position ──────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateUserPosition<'info> {
+    /// Anyone may pay to migrate a position; only its size changes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy-layout UserPosition; validated in `grow_legacy_account`
+    #[account(mut)]
+    pub user_position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a position to the current layout, unfrozen. Usage from the legacy
+/// 30-day window carries over into both the calendar-month and rolling windows.
+pub fn handle_migrate_user_position(ctx: Context<MigrateUserPosition>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    grow_legacy_account::<UserPosition>(
+        &ctx.accounts.user_position,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        UserPosition::LEGACY_LEN,
+        UserPosition::LEN,
+        |position| position.adopt_legacy_window(now),
+    )?;
+
+    msg!("User position migrated");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serialize `value`, keep its first `legacy_len` bytes as an original
+    /// program would have written them, and zero-extend to `new_len`.
+    fn zero_extended<T: AccountSerialize + AccountDeserialize>(
+        value: &T,
+        legacy_len: usize,
+        new_len: usize,
+    ) -> T {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), new_len);
+        data.truncate(legacy_len);
+        data.resize(new_len, 0);
+        T::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn legacy_lengths_match_the_original_layouts() {
+        assert_eq!(ProtocolConfig::LEGACY_LEN, 290);
+        assert_eq!(YieldSource::LEGACY_LEN, 300);
+        assert_eq!(UserPosition::LEGACY_LEN, 171);
+    }
+
+    fn config_fixture(key: Pubkey) -> ProtocolConfig {
+        ProtocolConfig {
+            authority: key,
+            treasury: key,
+            usdc_mint: key,
+            usdc_vault: key,
+            kyc_registry: key,
+            sovereign_program: key,
+            bond_registry: key,
+            conversion_fee_bps: 30,
+            management_fee_bps: 50,
+            performance_fee_bps: 1000,
+            total_deposits: 7,
+            total_yield_earned: 0,
+            pending_conversion: 0,
+            deposit_nonce: 3,
+            num_supported_bonds: 4,
+            is_active: true,
+            created_at: 1,
+            updated_at: 2,
+            bump: 254,
+            usdc_vault_bump: 253,
+            tier_limit_windows: [stablebond_types::LimitWindow::Rolling30Day; 5],
+            compliance_officer: key,
+            pending_authority: key,
+            timelock_delay: 86_400,
+            next_change_id: 9,
+            paused_ops: 0b11,
+        }
+    }
+
+    #[test]
+    fn zeroed_tail_decodes_with_legacy_fields_intact() {
+        let key = Pubkey::new_unique();
+        let mut config = zero_extended(
+            &config_fixture(key),
+            ProtocolConfig::LEGACY_LEN,
+            ProtocolConfig::LEN,
+        );
+        assert_eq!(config.usdc_vault_bump, 253);
+        assert_eq!(
+            config.tier_limit_windows,
+            [stablebond_types::LimitWindow::CalendarMonth; 5]
+        );
+        assert_eq!(config.compliance_officer, Pubkey::default());
+        assert_eq!(config.timelock_delay, 0);
+        assert_eq!(config.paused_ops, 0);
+
+        fill_protocol_config_tail(&mut config);
+        assert_eq!(config.timelock_delay, ProtocolConfig::MIN_TIMELOCK_DELAY);
+    }
+
+    fn legacy_position(month_start: i64, monthly_deposited: u64) -> UserPosition {
+        zero_extended(
+            &position_fixture(Pubkey::new_unique(), month_start, monthly_deposited),
+            UserPosition::LEGACY_LEN,
+            UserPosition::LEN,
+        )
+    }
+
+    fn position_fixture(key: Pubkey, month_start: i64, monthly_deposited: u64) -> UserPosition {
+        UserPosition {
+            owner: key,
+            protocol_config: key,
+            bond_type: stablebond_types::BondType::UsTBill,
+            total_deposited: monthly_deposited,
+            current_shares: monthly_deposited,
+            cost_basis: monthly_deposited,
+            realized_yield: 0,
+            sovereign_tier: 2,
+            monthly_deposited,
+            month_start,
+            deposit_count: 1,
+            withdrawal_count: 0,
+            last_deposit_at: month_start,
+            last_withdrawal_at: 0,
+            deposit_nonce: 1,
+            withdrawal_nonce: 0,
+            created_at: month_start,
+            bump: 255,
+            daily_deposits: Default::default(),
+            is_frozen: false,
+            freeze_reason: stablebond_types::FreezeReason::Other,
+            frozen_at: 0,
+        }
+    }
+
+    #[test]
+    fn migrated_usage_carries_into_both_windows() {
+        use stablebond_types::LimitWindow;
+
+        /// 2024-01-20, 2024-02-05 and 2023-12-15, 00:00 UTC
+        const JAN_20: i64 = 1_705_708_800;
+        const FEB_5: i64 = 1_707_091_200;
+        const DEC_15: i64 = 1_702_598_400;
+
+        // The legacy window (Jan 20 - Feb 19) overlaps February: usage is kept
+        let mut position = legacy_position(JAN_20, 4_000);
+        assert_eq!(position.window_usage(LimitWindow::CalendarMonth, FEB_5), 0);
+        position.adopt_legacy_window(FEB_5).unwrap();
+        assert_eq!(position.window_usage(LimitWindow::CalendarMonth, FEB_5), 4_000);
+        assert_eq!(position.window_usage(LimitWindow::Rolling30Day, FEB_5), 4_000);
+        position.record_window_deposit(1_000, FEB_5).unwrap();
+        assert_eq!(position.window_usage(LimitWindow::CalendarMonth, FEB_5), 5_000);
+
+        // The legacy window (Dec 15 - Jan 14) ended before February
+        let mut position = legacy_position(DEC_15, 4_000);
+        position.adopt_legacy_window(FEB_5).unwrap();
+        assert_eq!(position.window_usage(LimitWindow::CalendarMonth, FEB_5), 0);
+        assert_eq!(position.window_usage(LimitWindow::Rolling30Day, FEB_5), 0);
+    }
+
+    /// Run the migration fills on a zero-extended legacy account and return
+    /// the bytes the handler would write back.
+    fn migrated_data<T: AccountSerialize + AccountDeserialize>(
+        value: &T,
+        legacy_len: usize,
+        new_len: usize,
+        fill: impl FnOnce(&mut T),
+    ) -> Vec<u8> {
+        let mut migrated = zero_extended(value, legacy_len, new_len);
+        fill(&mut migrated);
+        let mut data = Vec::new();
+        migrated.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), new_len);
+        data
+    }
+
+    #[test]
+    fn migrated_accounts_load_through_instruction_contexts() {
+        use std::collections::BTreeSet;
+
+        use stablebond_types::{BondType, LimitWindow, YieldSourceType};
+
+        use crate::instructions::{SetPositionFreeze, SetYieldSourcePauseFlags};
+
+        /// 2024-01-20 and 2024-02-05, 00:00 UTC
+        const JAN_20: i64 = 1_705_708_800;
+        const FEB_5: i64 = 1_707_091_200;
+
+        let program_id = crate::ID;
+        let officer = Pubkey::new_unique();
+        let (config_key, config_bump) =
+            Pubkey::find_program_address(&[ProtocolConfig::SEED], &program_id);
+        let mut config_data = migrated_data(
+            &ProtocolConfig {
+                bump: config_bump,
+                ..config_fixture(Pubkey::new_unique())
+            },
+            ProtocolConfig::LEGACY_LEN,
+            ProtocolConfig::LEN,
+            |config| {
+                fill_protocol_config_tail(config);
+                // As a queued config change would, once migrated
+                config.compliance_officer = officer;
+            },
+        );
+
+        let mint = Pubkey::new_unique();
+        let (ys_key, ys_bump) = Pubkey::find_program_address(
+            &[YieldSource::SEED, config_key.as_ref(), mint.as_ref()],
+            &program_id,
+        );
+        let mut ys_data = migrated_data(
+            &YieldSource {
+                protocol_config: config_key,
+                name: [0; 32],
+                source_type: YieldSourceType::SovereignBond,
+                token_mint: mint,
+                deposit_vault: Pubkey::new_unique(),
+                yield_token_vault: Pubkey::new_unique(),
+                current_apy_bps: 450,
+                total_deposited: 1_000,
+                total_shares: 1_000,
+                allocation_weight_bps: 10_000,
+                min_deposit: 1,
+                max_allocation: u64::MAX,
+                is_active: true,
+                last_nav_update: JAN_20,
+                nav_per_share: 1_020_000,
+                bond_type: BondType::UsTBill,
+                currency_mint: mint,
+                oracle_feed: Pubkey::new_unique(),
+                coupon_rate_bps: 450,
+                maturity_date: 0,
+                haircut_bps: 0,
+                bump: ys_bump,
+                paused_ops: 0b1,
+                max_nav_change_bps: 1,
+                max_daily_nav_change_bps: 1,
+                nav_day_start: 1,
+                nav_day_open: 1,
+                circuit_breaker_tripped: true,
+                tripped_nav: 1,
+                breaker_paused_ops: 0b1,
+                bond_vault: Pubkey::new_unique(),
+            },
+            YieldSource::LEGACY_LEN,
+            YieldSource::LEN,
+            |ys| fill_yield_source_tail(ys, FEB_5),
+        );
+
+        let owner = Pubkey::new_unique();
+        let (position_key, position_bump) = Pubkey::find_program_address(
+            &[
+                UserPosition::SEED,
+                config_key.as_ref(),
+                owner.as_ref(),
+                &[BondType::UsTBill.as_u8()],
+            ],
+            &program_id,
+        );
+        let mut position_data = migrated_data(
+            &UserPosition {
+                owner,
+                protocol_config: config_key,
+                bump: position_bump,
+                ..position_fixture(owner, JAN_20, 4_000)
+            },
+            UserPosition::LEGACY_LEN,
+            UserPosition::LEN,
+            |position| position.adopt_legacy_window(FEB_5).unwrap(),
+        );
+
+        // Before migration the same config does not load at all
+        let mut legacy_data = config_data[..ProtocolConfig::LEGACY_LEN].to_vec();
+        let mut legacy_lamports = 0;
+        let legacy_info = AccountInfo::new(
+            &config_key,
+            false,
+            true,
+            &mut legacy_lamports,
+            &mut legacy_data,
+            &program_id,
+            false,
+            0,
+        );
+        assert!(Account::<ProtocolConfig>::try_from(&legacy_info).is_err());
+
+        let (mut officer_lamports, mut config_lamports) = (0, 0);
+        let (mut ys_lamports, mut position_lamports, mut none_lamports) = (0, 0, 0);
+        let system_program = anchor_lang::system_program::ID;
+        let officer_info = AccountInfo::new(
+            &officer,
+            true,
+            false,
+            &mut officer_lamports,
+            &mut [],
+            &system_program,
+            false,
+            0,
+        );
+        let config_info = AccountInfo::new(
+            &config_key,
+            false,
+            true,
+            &mut config_lamports,
+            &mut config_data,
+            &program_id,
+            false,
+            0,
+        );
+        let ys_info = AccountInfo::new(
+            &ys_key,
+            false,
+            true,
+            &mut ys_lamports,
+            &mut ys_data,
+            &program_id,
+            false,
+            0,
+        );
+        let position_info = AccountInfo::new(
+            &position_key,
+            false,
+            true,
+            &mut position_lamports,
+            &mut position_data,
+            &program_id,
+            false,
+            0,
+        );
+        // An omitted optional account is passed as the program id
+        let none_info = AccountInfo::new(
+            &program_id,
+            false,
+            false,
+            &mut none_lamports,
+            &mut [],
+            &system_program,
+            true,
+            0,
+        );
+
+        let infos = [
+            officer_info.clone(),
+            config_info.clone(),
+            ys_info,
+            none_info,
+        ];
+        let loaded = SetYieldSourcePauseFlags::try_accounts(
+            &program_id,
+            &mut &infos[..],
+            &[],
+            &mut Default::default(),
+            &mut BTreeSet::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.protocol_config.timelock_delay,
+            ProtocolConfig::MIN_TIMELOCK_DELAY
+        );
+        assert_eq!(loaded.yield_source.paused_ops, 0);
+        assert!(!loaded.yield_source.circuit_breaker_tripped);
+        assert_eq!(loaded.yield_source.nav_day_open, 1_020_000);
+        assert_eq!(
+            loaded.yield_source.bond_vault,
+            Pubkey::find_program_address(
+                &[BondVault::SEED, &[BondType::UsTBill.as_u8()]],
+                &stablebond_yield::ID,
+            )
+            .0
+        );
+
+        let infos = [officer_info, config_info, position_info];
+        let loaded = SetPositionFreeze::try_accounts(
+            &program_id,
+            &mut &infos[..],
+            &[],
+            &mut Default::default(),
+            &mut BTreeSet::new(),
+        )
+        .unwrap();
+        assert!(!loaded.user_position.is_frozen);
+        assert_eq!(
+            loaded
+                .user_position
+                .window_usage(LimitWindow::Rolling30Day, FEB_5),
+            4_000
+        );
+    }
+}
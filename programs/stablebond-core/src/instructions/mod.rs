@@ -1,19 +1,29 @@
 pub mod admin;
 pub mod claim_yield;
+pub mod compliance;
 pub mod deposit;
+pub mod deposit_capacity;
 pub mod execute_conversion;
 pub mod initialize_protocol;
+pub mod migrate;
 pub mod register_bond;
 pub mod register_yield_source;
+pub mod roles;
+pub mod timelock;
 pub mod update_nav;
 pub mod withdraw;
 
 pub use admin::*;
 pub use claim_yield::*;
+pub use compliance::*;
 pub use deposit::*;
+pub use deposit_capacity::*;
 pub use execute_conversion::*;
 pub use initialize_protocol::*;
+pub use migrate::*;
 pub use register_bond::*;
 pub use register_yield_source::*;
+pub use roles::*;
+pub use timelock::*;
 pub use update_nav::*;
 pub use withdraw::*;
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
 use stablebond_types::{BondType, YieldSourceType};
+use stablebond_yield::state::BondVault;
 
 use crate::errors::StablebondError;
 use crate::events::YieldSourceRegistered;
-use crate::state::{ProtocolConfig, YieldSource};
+use crate::state::{ProtocolConfig, YieldSource, NAV_SCALE};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RegisterYieldSourceParams {
@@ -36,7 +37,7 @@ pub struct RegisterYieldSource<'info> {
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
-    pub token_mint: Account<'info, Mint>,
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init,
@@ -67,8 +68,9 @@ pub fn handle_register_yield_source(ctx: Context<RegisterYieldSource>, params: R
     ys.min_deposit = params.min_deposit;
     ys.max_allocation = params.max_allocation;
     ys.is_active = true;
+    ys.paused_ops = 0;
     ys.last_nav_update = now;
-    ys.nav_per_share = 1_000_000; // 1.000000
+    ys.nav_per_share = NAV_SCALE; // 1.000000
     // Bond-specific fields
     ys.bond_type = params.bond_type;
     ys.currency_mint = params.currency_mint;
@@ -77,6 +79,19 @@ pub fn handle_register_yield_source(ctx: Context<RegisterYieldSource>, params: R
     ys.maturity_date = params.maturity_date;
     ys.haircut_bps = params.haircut_bps;
     ys.bump = ctx.bumps.yield_source;
+    // NAV circuit breaker
+    ys.max_nav_change_bps = YieldSource::DEFAULT_MAX_NAV_CHANGE_BPS;
+    ys.max_daily_nav_change_bps = YieldSource::DEFAULT_MAX_DAILY_NAV_CHANGE_BPS;
+    ys.nav_day_start = YieldSource::utc_day_start(now);
+    ys.nav_day_open = ys.nav_per_share;
+    ys.circuit_breaker_tripped = false;
+    ys.tripped_nav = 0;
+    ys.breaker_paused_ops = 0;
+    ys.bond_vault = Pubkey::find_program_address(
+        &[BondVault::SEED, &[params.bond_type.as_u8()]],
+        &stablebond_yield::ID,
+    )
+    .0;
 
     emit!(YieldSourceRegistered {
         yield_source: ys.key(),
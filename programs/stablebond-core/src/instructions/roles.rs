@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use stablebond_types::Role;
+
+use crate::errors::StablebondError;
+use crate::events::RolesUpdated;
+use crate::state::{ProtocolConfig, RoleAssignment};
+
+// ─── Set Member Roles ───────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct SetMemberRoles<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = authority @ StablebondError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoleAssignment::LEN,
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), member.as_ref()],
+        bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the role bitmask for `member`. A mask of 0 revokes every role.
+pub fn handle_set_member_roles(
+    ctx: Context<SetMemberRoles>,
+    member: Pubkey,
+    roles: u8,
+) -> Result<()> {
+    require!(roles & !Role::ALL_MASK == 0, StablebondError::InvalidRoleMask);
+
+    let now = Clock::get()?.unix_timestamp;
+    let assignment = &mut ctx.accounts.role_assignment;
+    let old_roles = assignment.roles;
+
+    assignment.member = member;
+    assignment.protocol_config = ctx.accounts.protocol_config.key();
+    assignment.roles = roles;
+    assignment.granted_by = ctx.accounts.authority.key();
+    assignment.updated_at = now;
+    assignment.bump = ctx.bumps.role_assignment;
+
+    emit!(RolesUpdated {
+        authority: ctx.accounts.authority.key(),
+        member,
+        old_roles,
+        new_roles: roles,
+        timestamp: now,
+    });
+
+    msg!("Roles for {} set to {:#07b}", member, roles);
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::StablebondError;
+use crate::events::{
+    ConfigChangeCancelled, ConfigChangeExecuted, ConfigChangeQueued, TimelockDelayUpdated,
+};
+use crate::state::{ConfigChange, PendingChange, ProtocolConfig, RoleAssignment, YieldSource};
+
+// ─── Queue Config Change ────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    /// Authority, or a FeeManager for fee/yield-source changes
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingChange::LEN,
+        seeds = [
+            PendingChange::SEED,
+            protocol_config.key().as_ref(),
+            &protocol_config.next_change_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// Proposer's role assignment; not needed when the proposer is the authority
+    #[account(
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), proposer.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, RoleAssignment>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a parameter change that becomes executable after `timelock_delay`.
+/// Parameters are validated now so a queued change cannot fail on execution.
+pub fn handle_queue_config_change(
+    ctx: Context<QueueConfigChange>,
+    change: ConfigChange,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposer = ctx.accounts.proposer.key();
+    let config = &mut ctx.accounts.protocol_config;
+    let role_assignment = ctx.accounts.role_assignment.as_deref();
+
+    match &change {
+        ConfigChange::ProtocolConfig(params) => {
+            params.authorize(config, &proposer, role_assignment)?;
+            params.validate()?;
+        }
+        ConfigChange::YieldSource { params, .. } => {
+            params.authorize(config, &proposer, role_assignment)?;
+            params.validate()?;
+        }
+        ConfigChange::TimelockDelay(delay) => {
+            require!(proposer == config.authority, StablebondError::Unauthorized);
+            require!(
+                (ProtocolConfig::MIN_TIMELOCK_DELAY..=ProtocolConfig::MAX_TIMELOCK_DELAY)
+                    .contains(delay),
+                StablebondError::InvalidTimelockDelay
+            );
+        }
+    }
+
+    let id = config.next_change_id;
+    config.next_change_id = id.checked_add(1).ok_or(StablebondError::MathOverflow)?;
+    let eta = now
+        .checked_add(config.timelock_delay)
+        .ok_or(StablebondError::MathOverflow)?;
+
+    let pending = &mut ctx.accounts.pending_change;
+    pending.id = id;
+    pending.protocol_config = config.key();
+    pending.proposer = proposer;
+    pending.queued_at = now;
+    pending.eta = eta;
+    pending.bump = ctx.bumps.pending_change;
+
+    emit!(ConfigChangeQueued {
+        change_id: id,
+        kind: change.kind(),
+        proposer,
+        eta,
+        timestamp: now,
+    });
+
+    pending.change = change;
+
+    msg!("Config change {} queued, executable at {}", id, eta);
+    Ok(())
+}
+
+// ─── Execute Config Change ──────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// Anyone may execute a change once its eta has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingChange::SEED,
+            protocol_config.key().as_ref(),
+            &pending_change.id.to_le_bytes(),
+        ],
+        bump = pending_change.bump,
+        has_one = proposer @ StablebondError::ChangeTargetMismatch,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// CHECK: Rent refund destination, verified against pending_change.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// Target yield source, required for yield source changes
+    #[account(
+        mut,
+        seeds = [
+            YieldSource::SEED,
+            protocol_config.key().as_ref(),
+            yield_source.token_mint.as_ref(),
+        ],
+        bump = yield_source.bump,
+    )]
+    pub yield_source: Option<Account<'info, YieldSource>>,
+}
+
+pub fn handle_execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &ctx.accounts.pending_change;
+    require!(now >= pending.eta, StablebondError::TimelockNotElapsed);
+
+    let config = &mut ctx.accounts.protocol_config;
+    match &pending.change {
+        ConfigChange::ProtocolConfig(params) => {
+            params.apply(config, pending.proposer, now);
+        }
+        ConfigChange::YieldSource {
+            yield_source,
+            params,
+        } => {
+            let ys = ctx
+                .accounts
+                .yield_source
+                .as_mut()
+                .ok_or(StablebondError::ChangeTargetMismatch)?;
+            require!(
+                ys.key() == *yield_source,
+                StablebondError::ChangeTargetMismatch
+            );
+            params.apply(ys, pending.proposer, now);
+        }
+        ConfigChange::TimelockDelay(delay) => {
+            emit!(TimelockDelayUpdated {
+                old_delay: config.timelock_delay,
+                new_delay: *delay,
+                timestamp: now,
+            });
+            config.timelock_delay = *delay;
+            config.updated_at = now;
+        }
+    }
+
+    emit!(ConfigChangeExecuted {
+        change_id: pending.id,
+        kind: pending.change.kind(),
+        executor: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+
+    msg!("Config change {} executed", pending.id);
+    Ok(())
+}
+
+// ─── Cancel Config Change ───────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = authority @ StablebondError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingChange::SEED,
+            protocol_config.key().as_ref(),
+            &pending_change.id.to_le_bytes(),
+        ],
+        bump = pending_change.bump,
+        has_one = proposer @ StablebondError::ChangeTargetMismatch,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    /// CHECK: Rent refund destination, verified against pending_change.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+pub fn handle_cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &ctx.accounts.pending_change;
+
+    emit!(ConfigChangeCancelled {
+        change_id: pending.id,
+        kind: pending.change.kind(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    msg!("Config change {} cancelled", pending.id);
+    Ok(())
+}
+
+// ─── Increase Timelock Delay ────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct IncreaseTimelockDelay<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = authority @ StablebondError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Lengthening the delay only protects depositors, so it applies immediately.
+/// Shortening it goes through `queue_config_change`.
+pub fn handle_increase_timelock_delay(
+    ctx: Context<IncreaseTimelockDelay>,
+    new_delay: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.protocol_config;
+    require!(
+        new_delay > config.timelock_delay && new_delay <= ProtocolConfig::MAX_TIMELOCK_DELAY,
+        StablebondError::InvalidTimelockDelay
+    );
+
+    emit!(TimelockDelayUpdated {
+        old_delay: config.timelock_delay,
+        new_delay,
+        timestamp: now,
+    });
+
+    config.timelock_delay = new_delay;
+    config.updated_at = now;
+
+    msg!("Timelock delay increased to {}s", new_delay);
+    Ok(())
+}
@@ -1,21 +1,14 @@
 use anchor_lang::prelude::*;
+use stablebond_types::{PauseFlag, Role};
+use stablebond_yield::state::BondVault;
 
 use crate::errors::StablebondError;
-use crate::events::NavUpdated;
-use crate::state::{ProtocolConfig, YieldSource};
-
-/// Reads the BondVault's NAV per share.
-/// BondVault layout after 8-byte discriminator:
-///   authority(32) + currency_mint(32) + share_mint(32) + currency_vault(32)
-///   + bond_type(1) + coupon_rate_bps(2) + maturity_date(8)
-///   + target_apy_bps(2) + total_deposits(8) + total_shares(8)
-///   + nav_per_share(8) ...
-const BOND_VAULT_NAV_OFFSET: usize = 8 + 32 + 32 + 32 + 32 + 1 + 2 + 8 + 2 + 8 + 8;
-const BOND_VAULT_APY_OFFSET: usize = 8 + 32 + 32 + 32 + 32 + 1 + 2 + 8;
+use crate::events::{CircuitBreakerTripped, NavUpdated};
+use crate::state::{require_role, NavMove, ProtocolConfig, RoleAssignment, YieldSource};
 
 #[derive(Accounts)]
 pub struct UpdateNav<'info> {
-    /// Keeper that triggers the NAV update
+    /// Allowlisted keeper (Keeper role) that triggers the NAV update
     pub keeper: Signer<'info>,
 
     #[account(
@@ -36,45 +29,91 @@ pub struct UpdateNav<'info> {
     )]
     pub yield_source: Account<'info, YieldSource>,
 
-    /// The BondVault account to read NAV from
-    /// CHECK: Manually deserialized
-    pub bond_vault_config: AccountInfo<'info>,
+    /// The linked BondVault to read NAV from (owned by stablebond-yield)
+    #[account(
+        address = yield_source.bond_vault @ StablebondError::BondVaultMismatch,
+        seeds = [BondVault::SEED, &[yield_source.bond_type.as_u8()]],
+        bump = bond_vault_config.bump,
+        seeds::program = stablebond_yield::ID,
+    )]
+    pub bond_vault_config: Box<Account<'info, BondVault>>,
+
+    /// Keeper's role assignment; not needed when the keeper is the authority
+    #[account(
+        seeds = [RoleAssignment::SEED, protocol_config.key().as_ref(), keeper.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Box<Account<'info, RoleAssignment>>>,
 }
 
 pub fn handle_update_nav(ctx: Context<UpdateNav>) -> Result<()> {
-    let now = Clock::get()?.unix_timestamp;
-
-    // Read NAV from bond vault
-    let vault_data = ctx.accounts.bond_vault_config.try_borrow_data()?;
+    require_role(
+        &ctx.accounts.protocol_config.authority,
+        &ctx.accounts.keeper.key(),
+        ctx.accounts.role_assignment.as_deref().map(|a| &**a),
+        Role::Keeper,
+    )?;
+    ctx.accounts
+        .protocol_config
+        .require_unpaused(PauseFlag::NavUpdates)?;
+    ctx.accounts
+        .yield_source
+        .require_unpaused(PauseFlag::NavUpdates)?;
     require!(
-        vault_data.len() >= BOND_VAULT_NAV_OFFSET + 8,
-        StablebondError::InvalidAccountData
+        !ctx.accounts.yield_source.circuit_breaker_tripped,
+        StablebondError::CircuitBreakerActive
     );
 
-    let new_nav = u64::from_le_bytes(
-        vault_data[BOND_VAULT_NAV_OFFSET..BOND_VAULT_NAV_OFFSET + 8]
-            .try_into()
-            .unwrap(),
-    );
+    let now = Clock::get()?.unix_timestamp;
 
-    // Also read APY
-    let apy_bps = if vault_data.len() >= BOND_VAULT_APY_OFFSET + 2 {
-        u16::from_le_bytes(
-            vault_data[BOND_VAULT_APY_OFFSET..BOND_VAULT_APY_OFFSET + 2]
-                .try_into()
-                .unwrap(),
-        )
-    } else {
-        0
-    };
-    drop(vault_data);
+    // Owner, discriminator and PDA are verified by the account constraints
+    let vault = &ctx.accounts.bond_vault_config;
+    let new_nav = vault.nav_per_share;
+    let apy_bps = vault.target_apy_bps;
 
     require!(new_nav > 0, StablebondError::InvalidAccountData);
 
     let old_nav = ctx.accounts.yield_source.nav_per_share;
+    let ys = &mut ctx.accounts.yield_source;
+
+    // Roll the daily window
+    ys.roll_nav_day(now);
+
+    // Circuit breaker: an abnormal move is not applied. Deposits and
+    // withdrawals pause for this bond until the authority resets the breaker.
+    let NavMove {
+        change_bps,
+        daily_change_bps,
+        exceeds_bounds,
+    } = ys.nav_move(new_nav);
+    if exceeds_bounds {
+        let paused = ys.trip_circuit_breaker(new_nav);
+
+        emit!(CircuitBreakerTripped {
+            yield_source: ys.key(),
+            bond_type: ys.bond_type.as_u8(),
+            old_nav,
+            attempted_nav: new_nav,
+            change_bps,
+            daily_change_bps,
+            max_change_bps: ys.max_nav_change_bps,
+            max_daily_change_bps: ys.max_daily_nav_change_bps,
+            paused,
+            timestamp: now,
+        });
+
+        msg!(
+            "Circuit breaker tripped for {}: {} → {} ({} bps, {} bps today)",
+            ys.bond_type.as_str(),
+            old_nav,
+            new_nav,
+            change_bps,
+            daily_change_bps
+        );
+        return Ok(());
+    }
 
     // Update yield source NAV
-    let ys = &mut ctx.accounts.yield_source;
     ys.nav_per_share = new_nav;
     ys.current_apy_bps = apy_bps;
     ys.last_nav_update = now;
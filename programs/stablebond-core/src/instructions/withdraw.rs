@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use stablebond_types::{withdrawal_cooldown_seconds, BondType};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use stablebond_token::TokenTransfer;
+use stablebond_types::{withdrawal_cooldown_seconds, BondType, PauseFlag};
 
 use crate::errors::StablebondError;
-use crate::events::WithdrawalExecuted;
+use crate::events::{WithdrawalCancelled, WithdrawalExecuted, WithdrawalRequested};
 use crate::state::{ProtocolConfig, UserPosition, WithdrawalRequest, YieldSource};
 
 // ─── Request Withdrawal (creates a pending withdrawal with cooldown) ─────────
@@ -66,10 +67,14 @@ pub fn handle_request_withdrawal(
     bond_type: BondType,
 ) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    require!(config.is_active, StablebondError::ProtocolNotActive);
+    config.require_unpaused(PauseFlag::WithdrawalRequests)?;
+    ctx.accounts
+        .yield_source
+        .require_unpaused(PauseFlag::WithdrawalRequests)?;
     require!(shares > 0, StablebondError::ZeroWithdrawal);
 
     let user_pos = &ctx.accounts.user_position;
+    require!(!user_pos.is_frozen, StablebondError::PositionFrozen);
     require!(
         user_pos.current_shares >= shares,
         StablebondError::InsufficientShares
@@ -81,11 +86,7 @@ pub fn handle_request_withdrawal(
 
     // Lock NAV at request time
     let ys = &ctx.accounts.yield_source;
-    let amount_out = (shares as u128)
-        .checked_mul(ys.nav_per_share as u128)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(1_000_000)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let amount_out = ys.amount_for_shares(shares)?;
 
     let now = Clock::get()?.unix_timestamp;
     let cooldown = withdrawal_cooldown_seconds(bond_type);
@@ -113,6 +114,16 @@ pub fn handle_request_withdrawal(
         .ok_or(StablebondError::MathOverflow)?;
     user_pos_mut.withdrawal_nonce = nonce;
 
+    emit!(WithdrawalRequested {
+        user: ctx.accounts.user.key(),
+        bond_type: bond_type.as_u8(),
+        shares,
+        amount_out,
+        claimable_at: request.claimable_at,
+        nonce,
+        timestamp: now,
+    });
+
     msg!(
         "Withdrawal requested: {} shares for {} {} (claimable at {})",
         shares,
@@ -181,26 +192,40 @@ pub struct ClaimWithdrawal<'info> {
         mut,
         constraint = deposit_vault.key() == yield_source.deposit_vault,
     )]
-    pub deposit_vault: Account<'info, TokenAccount>,
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Settlement currency mint held by the deposit vault
+    #[account(address = deposit_vault.mint @ StablebondError::InvalidAccountData)]
+    pub settlement_mint: InterfaceAccount<'info, Mint>,
 
     /// User's settlement currency token account
     #[account(
         mut,
         constraint = user_token.owner == user.key(),
     )]
-    pub user_token: Account<'info, TokenAccount>,
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_claim_withdrawal(
-    ctx: Context<ClaimWithdrawal>,
+pub fn handle_claim_withdrawal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimWithdrawal<'info>>,
     bond_type: BondType,
     _nonce: u64,
 ) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let request = &ctx.accounts.withdrawal_request;
 
+    ctx.accounts
+        .protocol_config
+        .require_unpaused(PauseFlag::WithdrawalClaims)?;
+    ctx.accounts
+        .yield_source
+        .require_unpaused(PauseFlag::WithdrawalClaims)?;
+    require!(
+        !ctx.accounts.user_position.is_frozen,
+        StablebondError::PositionFrozen
+    );
     require!(
         now >= request.claimable_at,
         StablebondError::WithdrawalCooldownActive
@@ -212,18 +237,14 @@ pub fn handle_claim_withdrawal(
     // Transfer from yield source vault to user
     let config = &ctx.accounts.protocol_config;
     let config_seeds: &[&[u8]] = &[ProtocolConfig::SEED, &[config.bump]];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.deposit_vault.to_account_info(),
-                to: ctx.accounts.user_token.to_account_info(),
-                authority: ctx.accounts.protocol_config.to_account_info(),
-            },
-            &[config_seeds],
-        ),
-        amount_out,
-    )?;
+    TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.settlement_mint,
+        from: &ctx.accounts.deposit_vault,
+        authority: ctx.accounts.protocol_config.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send(&ctx.accounts.user_token, amount_out, &[config_seeds])?;
 
     // Mark request as claimed
     let request_mut = &mut ctx.accounts.withdrawal_request;
@@ -281,6 +302,16 @@ pub struct CancelWithdrawal<'info> {
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
+    #[account(
+        seeds = [
+            YieldSource::SEED,
+            protocol_config.key().as_ref(),
+            yield_source.token_mint.as_ref(),
+        ],
+        bump = yield_source.bump,
+    )]
+    pub yield_source: Account<'info, YieldSource>,
+
     #[account(
         mut,
         seeds = [
@@ -311,9 +342,16 @@ pub struct CancelWithdrawal<'info> {
 
 pub fn handle_cancel_withdrawal(
     ctx: Context<CancelWithdrawal>,
-    _bond_type: BondType,
+    bond_type: BondType,
     _nonce: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .protocol_config
+        .require_unpaused(PauseFlag::WithdrawalRequests)?;
+    let ys = &ctx.accounts.yield_source;
+    ys.require_unpaused(PauseFlag::WithdrawalRequests)?;
+    require!(ys.bond_type == bond_type, StablebondError::BondTypeNotFound);
+
     let request = &ctx.accounts.withdrawal_request;
     let shares = request.shares;
 
@@ -328,6 +366,14 @@ pub fn handle_cancel_withdrawal(
     let request_mut = &mut ctx.accounts.withdrawal_request;
     request_mut.is_cancelled = true;
 
+    emit!(WithdrawalCancelled {
+        user: ctx.accounts.user.key(),
+        bond_type: request_mut.bond_type.as_u8(),
+        shares_returned: shares,
+        nonce: request_mut.nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Withdrawal request cancelled, {} shares returned", shares);
     Ok(())
 }
@@ -375,24 +421,38 @@ pub struct Withdraw<'info> {
         mut,
         constraint = deposit_vault.key() == yield_source.deposit_vault,
     )]
-    pub deposit_vault: Account<'info, TokenAccount>,
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Settlement currency mint held by the deposit vault
+    #[account(address = deposit_vault.mint @ StablebondError::InvalidAccountData)]
+    pub settlement_mint: InterfaceAccount<'info, Mint>,
 
     /// User's settlement currency token account
     #[account(
         mut,
         constraint = user_token.owner == user.key(),
     )]
-    pub user_token: Account<'info, TokenAccount>,
+    pub user_token: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_withdraw(ctx: Context<Withdraw>, shares: u64, bond_type: BondType) -> Result<()> {
+pub fn handle_withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    shares: u64,
+    bond_type: BondType,
+) -> Result<()> {
+    // Legacy immediate withdraw is both a request and a claim
     let config = &ctx.accounts.protocol_config;
-    require!(config.is_active, StablebondError::ProtocolNotActive);
+    let ys = &ctx.accounts.yield_source;
+    for op in [PauseFlag::WithdrawalRequests, PauseFlag::WithdrawalClaims] {
+        config.require_unpaused(op)?;
+        ys.require_unpaused(op)?;
+    }
     require!(shares > 0, StablebondError::ZeroWithdrawal);
 
     let user_pos = &ctx.accounts.user_position;
+    require!(!user_pos.is_frozen, StablebondError::PositionFrozen);
     require!(
         user_pos.current_shares >= shares,
         StablebondError::InsufficientShares
@@ -404,26 +464,18 @@ pub fn handle_withdraw(ctx: Context<Withdraw>, shares: u64, bond_type: BondType)
 
     // Calculate settlement currency out based on NAV
     let ys = &ctx.accounts.yield_source;
-    let amount_out = (shares as u128)
-        .checked_mul(ys.nav_per_share as u128)
-        .ok_or(StablebondError::MathOverflow)?
-        .checked_div(1_000_000)
-        .ok_or(StablebondError::MathOverflow)? as u64;
+    let amount_out = ys.amount_for_shares(shares)?;
 
     // Transfer from yield source vault to user
     let config_seeds: &[&[u8]] = &[ProtocolConfig::SEED, &[config.bump]];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.deposit_vault.to_account_info(),
-                to: ctx.accounts.user_token.to_account_info(),
-                authority: ctx.accounts.protocol_config.to_account_info(),
-            },
-            &[config_seeds],
-        ),
-        amount_out,
-    )?;
+    TokenTransfer {
+        token_program: &ctx.accounts.token_program,
+        mint: &ctx.accounts.settlement_mint,
+        from: &ctx.accounts.deposit_vault,
+        authority: ctx.accounts.protocol_config.to_account_info(),
+        hook_accounts: ctx.remaining_accounts,
+    }
+    .send(&ctx.accounts.user_token, amount_out, &[config_seeds])?;
 
     let now = Clock::get()?.unix_timestamp;
 
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
-use stablebond_types::{BondConfig, BondType};
+use stablebond_types::{BondConfig, BondType, FreezeReason};
 
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod oracle;
 pub mod state;
 
 use instructions::*;
+use state::ConfigChange;
 
 declare_id!("3fnWkVPz51AJjYodQY5VCzteD5enRmkWBTsu3gPedaYs");
 
@@ -34,8 +36,8 @@ pub mod stablebond_core {
 
     /// Cross-currency deposit (e.g., MXN → CETES, JPY → JGB).
     /// Creates a PendingDeposit for keeper conversion.
-    pub fn deposit_cross_currency(
-        ctx: Context<DepositCrossCurrency>,
+    pub fn deposit_cross_currency<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositCrossCurrency<'info>>,
         amount: u64,
         bond_type: BondType,
         min_output: u64,
@@ -45,20 +47,34 @@ pub mod stablebond_core {
 
     /// Direct deposit when settlement currency matches bond's currency.
     /// Immediately allocates to yield source.
-    pub fn deposit_direct(
-        ctx: Context<DepositDirect>,
+    pub fn deposit_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositDirect<'info>>,
         amount: u64,
         bond_type: BondType,
     ) -> Result<()> {
         instructions::deposit::handle_deposit_direct(ctx, amount, bond_type)
     }
 
-    pub fn execute_conversion(ctx: Context<ExecuteConversion>) -> Result<()> {
+    /// View: remaining per-bond and aggregate USD-equivalent monthly capacity.
+    pub fn get_deposit_capacity(
+        ctx: Context<GetDepositCapacity>,
+        bond_type: BondType,
+    ) -> Result<DepositCapacity> {
+        instructions::deposit_capacity::handle_get_deposit_capacity(ctx, bond_type)
+    }
+
+    pub fn execute_conversion<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteConversion<'info>>,
+    ) -> Result<()> {
         instructions::execute_conversion::handle_execute_conversion(ctx)
     }
 
     /// Legacy immediate withdrawal (kept for backward compatibility).
-    pub fn withdraw(ctx: Context<Withdraw>, shares: u64, bond_type: BondType) -> Result<()> {
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        shares: u64,
+        bond_type: BondType,
+    ) -> Result<()> {
         instructions::withdraw::handle_withdraw(ctx, shares, bond_type)
     }
 
@@ -73,8 +89,8 @@ pub mod stablebond_core {
     }
 
     /// Claim a withdrawal after the cooldown period has elapsed.
-    pub fn claim_withdrawal(
-        ctx: Context<ClaimWithdrawal>,
+    pub fn claim_withdrawal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimWithdrawal<'info>>,
         bond_type: BondType,
         nonce: u64,
     ) -> Result<()> {
@@ -90,7 +106,10 @@ pub mod stablebond_core {
         instructions::withdraw::handle_cancel_withdrawal(ctx, bond_type, nonce)
     }
 
-    pub fn claim_yield(ctx: Context<ClaimYield>, bond_type: BondType) -> Result<()> {
+    pub fn claim_yield<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimYield<'info>>,
+        bond_type: BondType,
+    ) -> Result<()> {
         instructions::claim_yield::handle_claim_yield(ctx, bond_type)
     }
 
@@ -98,18 +117,14 @@ pub mod stablebond_core {
         instructions::update_nav::handle_update_nav(ctx)
     }
 
-    pub fn update_protocol_config(
-        ctx: Context<UpdateProtocolConfig>,
-        params: UpdateProtocolConfigParams,
-    ) -> Result<()> {
-        instructions::admin::handle_update_protocol_config(ctx, params)
+    /// Compliance officer: freeze a position (sanctions, legal hold, etc.).
+    pub fn freeze_position(ctx: Context<SetPositionFreeze>, reason: FreezeReason) -> Result<()> {
+        instructions::compliance::handle_freeze_position(ctx, reason)
     }
 
-    pub fn update_yield_source(
-        ctx: Context<UpdateYieldSource>,
-        params: UpdateYieldSourceParams,
-    ) -> Result<()> {
-        instructions::admin::handle_update_yield_source(ctx, params)
+    /// Compliance officer: lift a position freeze.
+    pub fn unfreeze_position(ctx: Context<SetPositionFreeze>) -> Result<()> {
+        instructions::compliance::handle_unfreeze_position(ctx)
     }
 
     pub fn pause_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
@@ -119,4 +134,87 @@ pub mod stablebond_core {
     pub fn resume_protocol(ctx: Context<PauseProtocol>) -> Result<()> {
         instructions::admin::handle_resume_protocol(ctx)
     }
+
+    /// Pauser: set the protocol-wide pause bitmask (bits of `PauseFlag`).
+    pub fn set_pause_flags(ctx: Context<PauseProtocol>, paused_ops: u8) -> Result<()> {
+        instructions::admin::handle_set_pause_flags(ctx, paused_ops)
+    }
+
+    /// Pauser: set the pause bitmask for a single bond's yield source.
+    pub fn set_yield_source_pause_flags(
+        ctx: Context<SetYieldSourcePauseFlags>,
+        paused_ops: u8,
+    ) -> Result<()> {
+        instructions::admin::handle_set_yield_source_pause_flags(ctx, paused_ops)
+    }
+
+    /// Authority: clear a tripped NAV circuit breaker, optionally accepting
+    /// the NAV that tripped it.
+    pub fn reset_circuit_breaker(
+        ctx: Context<ResetCircuitBreaker>,
+        accept_nav: bool,
+    ) -> Result<()> {
+        instructions::admin::handle_reset_circuit_breaker(ctx, accept_nav)
+    }
+
+    /// Grant or revoke operational roles (bitmask of `Role`) for a key.
+    pub fn set_member_roles(
+        ctx: Context<SetMemberRoles>,
+        member: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        instructions::roles::handle_set_member_roles(ctx, member, roles)
+    }
+
+    /// Queue a timelocked parameter change (config, yield source or delay).
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        change: ConfigChange,
+    ) -> Result<()> {
+        instructions::timelock::handle_queue_config_change(ctx, change)
+    }
+
+    /// Apply a queued change once its eta has passed (permissionless).
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::timelock::handle_execute_config_change(ctx)
+    }
+
+    /// Admin: drop a queued change before it executes.
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::timelock::handle_cancel_config_change(ctx)
+    }
+
+    /// Admin: lengthen the timelock delay immediately.
+    pub fn increase_timelock_delay(
+        ctx: Context<IncreaseTimelockDelay>,
+        new_delay: i64,
+    ) -> Result<()> {
+        instructions::timelock::handle_increase_timelock_delay(ctx, new_delay)
+    }
+
+    /// Propose a new protocol authority (step 1 of a two-step transfer).
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::admin::handle_propose_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending protocol authority transfer (signed by the nominee).
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::admin::handle_accept_authority(ctx)
+    }
+
+    /// Authority: grow a ProtocolConfig created by an earlier program version
+    /// to the current layout.
+    pub fn migrate_protocol_config(ctx: Context<MigrateProtocolConfig>) -> Result<()> {
+        instructions::migrate::handle_migrate_protocol_config(ctx)
+    }
+
+    /// Authority: grow a legacy YieldSource to the current layout.
+    pub fn migrate_yield_source(ctx: Context<MigrateYieldSource>) -> Result<()> {
+        instructions::migrate::handle_migrate_yield_source(ctx)
+    }
+
+    /// Anyone: grow a legacy UserPosition to the current layout.
+    pub fn migrate_user_position(ctx: Context<MigrateUserPosition>) -> Result<()> {
+        instructions::migrate::handle_migrate_user_position(ctx)
+    }
 }
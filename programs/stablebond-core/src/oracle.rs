@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::StablebondError;
+
+/// Oracle PriceFeed — simplified Meridian-style layout.
+/// After 8-byte discriminator:
+///   - authority: Pubkey (32)
+///   - current_price: u64 (8)  — source per settlement, scaled 1e6
+///   - last_update_time: i64 (8)
+const ORACLE_PRICE_OFFSET: usize = 8 + 32;
+const ORACLE_UPDATE_OFFSET: usize = ORACLE_PRICE_OFFSET + 8;
+const MAX_ORACLE_STALENESS: i64 = 300; // 5 minutes
+
+/// FX rate scale: 1_000_000 = 1 unit of source currency per USD.
+pub use stablebond_math::FX_SCALE;
+
+/// Read a fresh FX rate (source currency per settlement unit, scaled 1e6)
+/// from an oracle PriceFeed account. Rejects zero and stale prices.
+pub fn read_fx_rate(oracle: &AccountInfo, now: i64) -> Result<u64> {
+    let oracle_data = oracle.try_borrow_data()?;
+    require!(
+        oracle_data.len() >= ORACLE_UPDATE_OFFSET + 8,
+        StablebondError::InvalidOraclePrice
+    );
+
+    let exchange_rate = u64::from_le_bytes(
+        oracle_data[ORACLE_PRICE_OFFSET..ORACLE_PRICE_OFFSET + 8]
+            .try_into()
+            .unwrap(),
+    );
+    let last_update = i64::from_le_bytes(
+        oracle_data[ORACLE_UPDATE_OFFSET..ORACLE_UPDATE_OFFSET + 8]
+            .try_into()
+            .unwrap(),
+    );
+
+    require!(exchange_rate > 0, StablebondError::InvalidOraclePrice);
+    require!(
+        now - last_update <= MAX_ORACLE_STALENESS,
+        StablebondError::StalePriceOracle
+    );
+
+    Ok(exchange_rate)
+}
+
+/// Settlement currency bought by a conversion at `exchange_rate`, before fees.
+/// settlement = source_amount * 1_000_000 / exchange_rate, rounded down
+pub fn settlement_for_source(source_amount: u64, exchange_rate: u64) -> Result<u64> {
+    Ok(stablebond_math::convert_at_rate(source_amount, exchange_rate)
+        .map_err(StablebondError::from)?)
+}
+
+/// Convert an amount in a bond's native currency to USD minor units.
+/// usd = amount * 1_000_000 / fx_rate, rounded up so limits never undercount
+pub fn usd_equivalent(amount: u64, fx_rate: u64) -> Result<u64> {
+    Ok(
+        stablebond_math::mul_div(amount, FX_SCALE, fx_rate, stablebond_math::Rounding::Up)
+            .map_err(StablebondError::from)?,
+    )
+}
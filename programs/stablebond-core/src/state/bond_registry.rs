@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use stablebond_types::BondConfig;
+use stablebond_types::{BondConfig, BondType};
 
 /// Registry of all supported sovereign bond types.
 /// PDA seeds: ["bond_registry", protocol_config]
@@ -25,4 +25,9 @@ impl BondRegistry {
         + 1;                     // bump
 
     pub const SEED: &'static [u8] = b"bond_registry";
+
+    /// Look up the registered configuration for a bond type.
+    pub fn find(&self, bond_type: BondType) -> Option<&BondConfig> {
+        self.bonds.iter().find(|b| b.bond_type == bond_type)
+    }
 }
@@ -1,15 +1,21 @@
 pub mod bond_registry;
 pub mod conversion_record;
+pub mod pending_change;
 pub mod pending_deposit;
 pub mod protocol_config;
+pub mod role_assignment;
+pub mod user_limit;
 pub mod user_position;
 pub mod withdrawal_request;
 pub mod yield_source;
 
 pub use bond_registry::*;
 pub use conversion_record::*;
+pub use pending_change::*;
 pub use pending_deposit::*;
 pub use protocol_config::*;
+pub use role_assignment::*;
+pub use user_limit::*;
 pub use user_position::*;
 pub use withdrawal_request::*;
 pub use yield_source::*;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::admin::{UpdateProtocolConfigParams, UpdateYieldSourceParams};
+
+/// A parameter change waiting out the protocol timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum ConfigChange {
+    ProtocolConfig(UpdateProtocolConfigParams),
+    YieldSource {
+        yield_source: Pubkey,
+        params: UpdateYieldSourceParams,
+    },
+    /// Shortening the delay must itself wait out the current delay
+    TimelockDelay(i64),
+}
+
+impl ConfigChange {
+    pub const MAX_LEN: usize = 1 // variant tag
+        + UpdateProtocolConfigParams::MAX_LEN; // largest variant
+
+    pub fn kind(&self) -> u8 {
+        match self {
+            ConfigChange::ProtocolConfig(_) => 0,
+            ConfigChange::YieldSource { .. } => 1,
+            ConfigChange::TimelockDelay(_) => 2,
+        }
+    }
+}
+
+/// Queued config change. Executable by anyone once `eta` has passed;
+/// cancellable by the authority until then.
+/// PDA seeds: ["pending_change", config, &id.to_le_bytes()]
+#[account]
+#[derive(Debug)]
+pub struct PendingChange {
+    /// Sequential change ID (from ProtocolConfig.next_change_id)
+    pub id: u64,
+    /// Reference to ProtocolConfig
+    pub protocol_config: Pubkey,
+    /// Key that queued the change (receives rent back on execute/cancel)
+    pub proposer: Pubkey,
+    /// The queued change
+    pub change: ConfigChange,
+    /// Timestamp the change was queued
+    pub queued_at: i64,
+    /// Earliest execution timestamp
+    pub eta: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingChange {
+    pub const LEN: usize = 8  // discriminator
+        + 8   // id
+        + 32  // protocol_config
+        + 32  // proposer
+        + ConfigChange::MAX_LEN // change
+        + 8   // queued_at
+        + 8   // eta
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"pending_change";
+}
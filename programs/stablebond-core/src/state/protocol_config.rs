@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use stablebond_types::{LimitWindow, PauseFlag};
+
+use crate::errors::StablebondError;
 
 /// Global protocol configuration. PDA seeds: ["stablebond_config"]
 #[account]
@@ -8,7 +11,7 @@ pub struct ProtocolConfig {
     pub authority: Pubkey,
     /// Protocol treasury for fee collection
     pub treasury: Pubkey,
-    /// USDC mint (SPL Token) — primary settlement currency
+    /// USDC mint (SPL Token or Token-2022) — primary settlement currency
     pub usdc_mint: Pubkey,
     /// Protocol USDC vault token account
     pub usdc_vault: Pubkey,
@@ -44,6 +47,19 @@ pub struct ProtocolConfig {
     pub bump: u8,
     /// USDC vault PDA bump
     pub usdc_vault_bump: u8,
+    /// Monthly limit window mode per Sovereign tier (indexed by tier 0-4)
+    pub tier_limit_windows: [LimitWindow; 5],
+    /// Compliance officer allowed to freeze positions (distinct from authority).
+    /// Pubkey::default() = not configured.
+    pub compliance_officer: Pubkey,
+    /// Proposed new authority awaiting acceptance. Pubkey::default() = none pending.
+    pub pending_authority: Pubkey,
+    /// Delay (seconds) before queued parameter changes can execute. 0 = timelock disabled.
+    pub timelock_delay: i64,
+    /// Next PendingChange ID
+    pub next_change_id: u64,
+    /// Bitmask of `PauseFlag`s paused protocol-wide
+    pub paused_ops: u8,
 }
 
 impl ProtocolConfig {
@@ -67,8 +83,57 @@ impl ProtocolConfig {
         + 8   // created_at
         + 8   // updated_at
         + 1   // bump
-        + 1;  // usdc_vault_bump
+        + 1   // usdc_vault_bump
+        + 5   // tier_limit_windows
+        + 32  // compliance_officer
+        + 32  // pending_authority
+        + 8   // timelock_delay
+        + 8   // next_change_id
+        + 1;  // paused_ops
+
+    /// Size of configs created before the limit window, compliance, authority
+    /// transfer, timelock and pause fields (grown by `migrate_protocol_config`)
+    pub const LEGACY_LEN: usize = Self::LEN - 5 - 32 - 32 - 8 - 8 - 1;
 
     pub const SEED: &'static [u8] = b"stablebond_config";
     pub const USDC_VAULT_SEED: &'static [u8] = b"stablebond_usdc_vault";
+
+    /// Lower bound on the parameter-change timelock: 24 hours. Every
+    /// parameter change is queued; `localnet` builds shorten it so tests can
+    /// wait out the delay.
+    #[cfg(not(feature = "localnet"))]
+    pub const MIN_TIMELOCK_DELAY: i64 = 86_400;
+    #[cfg(feature = "localnet")]
+    pub const MIN_TIMELOCK_DELAY: i64 = 2;
+
+    /// Upper bound on the parameter-change timelock: 30 days
+    pub const MAX_TIMELOCK_DELAY: i64 = 30 * 86_400;
+
+    /// Limit window mode configured for a tier (calendar month if out of range).
+    pub fn limit_window(&self, tier: u8) -> LimitWindow {
+        self.tier_limit_windows
+            .get(tier as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Fails if the protocol is paused outright or `op` is paused protocol-wide.
+    pub fn require_unpaused(&self, op: PauseFlag) -> Result<()> {
+        require!(self.is_active, StablebondError::ProtocolNotActive);
+        require!(!op.is_in(self.paused_ops), StablebondError::OperationPaused);
+        Ok(())
+    }
+
+    /// Conversion fee on a cross-currency deposit's gross settlement output
+    /// (rounded up).
+    pub fn conversion_fee(&self, gross_settlement: u64) -> Result<u64> {
+        Ok(stablebond_math::fee(gross_settlement, self.conversion_fee_bps)
+            .map_err(StablebondError::from)?)
+    }
+
+    /// Performance fee on claimed yield (rounded up).
+    pub fn performance_fee(&self, yield_amount: u64) -> Result<u64> {
+        Ok(stablebond_math::fee(yield_amount, self.performance_fee_bps)
+            .map_err(StablebondError::from)?)
+    }
 }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use stablebond_types::Role;
+
+use crate::errors::StablebondError;
+
+/// Capabilities granted to an operational key by the protocol authority.
+/// PDA seeds: ["role", config, member]
+#[account]
+#[derive(Debug)]
+pub struct RoleAssignment {
+    /// Key holding the roles
+    pub member: Pubkey,
+    /// Reference to ProtocolConfig
+    pub protocol_config: Pubkey,
+    /// Bitmask of granted `Role`s (0 = revoked)
+    pub roles: u8,
+    /// Authority that last changed the roles
+    pub granted_by: Pubkey,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // member
+        + 32  // protocol_config
+        + 1   // roles
+        + 32  // granted_by
+        + 8   // updated_at
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"role";
+
+    pub fn has_role(&self, role: Role) -> bool {
+        role.is_in(self.roles)
+    }
+}
+
+/// Passes if `signer` is the protocol authority (which implicitly holds every
+/// role) or presents a role assignment that includes `role`.
+pub fn require_role(
+    authority: &Pubkey,
+    signer: &Pubkey,
+    assignment: Option<&RoleAssignment>,
+    role: Role,
+) -> Result<()> {
+    if signer == authority {
+        return Ok(());
+    }
+    match assignment {
+        Some(a) if a.member == *signer && a.has_role(role) => Ok(()),
+        _ => err!(StablebondError::MissingRole),
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use stablebond_types::{aggregate_monthly_limit_usd, utc_month_start, DailyDeposits, LimitWindow};
+
+use crate::errors::StablebondError;
+
+/// Per-user aggregate deposit tracking across every bond type.
+/// Deposits are converted to USD via the FX oracle so the tier's
+/// "Monthly Limit (USD equiv.)" applies on top of the per-bond caps.
+/// PDA seeds: ["user_limit", config, owner]
+#[account]
+#[derive(Debug)]
+pub struct UserLimit {
+    /// Account owner
+    pub owner: Pubkey,
+    /// Reference to ProtocolConfig
+    pub protocol_config: Pubkey,
+    /// User's Sovereign tier at last check
+    pub sovereign_tier: u8,
+    /// USD-equivalent deposited in current calendar month (minor units, 6 decimals)
+    pub monthly_deposited_usd: u64,
+    /// Timestamp of current calendar month start (00:00 UTC on the 1st)
+    pub month_start: i64,
+    /// Lifetime USD-equivalent deposited (minor units, 6 decimals)
+    pub total_deposited_usd: u64,
+    /// Last deposit timestamp
+    pub last_deposit_at: i64,
+    /// Account creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Daily USD deposit buckets backing the rolling 30-day limit window
+    pub daily_deposits_usd: DailyDeposits,
+}
+
+impl UserLimit {
+    pub const LEN: usize = 8   // discriminator
+        + 32  // owner
+        + 32  // protocol_config
+        + 1   // sovereign_tier
+        + 8   // monthly_deposited_usd
+        + 8   // month_start
+        + 8   // total_deposited_usd
+        + 8   // last_deposit_at
+        + 8   // created_at
+        + 1   // bump
+        + DailyDeposits::LEN; // daily_deposits_usd
+
+    pub const SEED: &'static [u8] = b"user_limit";
+
+    /// USD-equivalent deposited within the current limit window.
+    pub fn window_usage(&self, window: LimitWindow, now: i64) -> u64 {
+        match window {
+            LimitWindow::CalendarMonth if self.month_start == utc_month_start(now) => {
+                self.monthly_deposited_usd
+            }
+            LimitWindow::CalendarMonth => 0,
+            LimitWindow::Rolling30Day => self.daily_deposits_usd.rolling_sum(now),
+        }
+    }
+
+    /// Enforce the tier's aggregate monthly cap and record a USD-valued deposit.
+    pub fn record_deposit(
+        &mut self,
+        window: LimitWindow,
+        tier: u8,
+        usd_value: u64,
+        now: i64,
+    ) -> Result<()> {
+        self.sovereign_tier = tier;
+
+        let new_usage = self
+            .window_usage(window, now)
+            .checked_add(usd_value)
+            .ok_or(StablebondError::MathOverflow)?;
+        require!(
+            new_usage <= aggregate_monthly_limit_usd(tier),
+            StablebondError::AggregateMonthlyLimitExceeded
+        );
+
+        let month_start = utc_month_start(now);
+        if self.month_start != month_start {
+            self.monthly_deposited_usd = 0;
+            self.month_start = month_start;
+        }
+        self.monthly_deposited_usd = self
+            .monthly_deposited_usd
+            .checked_add(usd_value)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.daily_deposits_usd
+            .record(usd_value, now)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.total_deposited_usd = self
+            .total_deposited_usd
+            .checked_add(usd_value)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.last_deposit_at = now;
+        Ok(())
+    }
+
+    /// Remaining USD-equivalent capacity in the current window at the stored tier.
+    pub fn remaining_usd(&self, window: LimitWindow, now: i64) -> u64 {
+        aggregate_monthly_limit_usd(self.sovereign_tier)
+            .saturating_sub(self.window_usage(window, now))
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use stablebond_types::BondType;
+use stablebond_types::{utc_month_start, BondType, DailyDeposits, FreezeReason, LimitWindow};
+
+use crate::errors::StablebondError;
 
 /// Per-user per-bond-type position tracking.
 /// PDA seeds: ["user_position", config, owner, &[bond_type as u8]]
@@ -23,9 +25,9 @@ pub struct UserPosition {
     pub realized_yield: u64,
     /// User's Sovereign tier at last check
     pub sovereign_tier: u8,
-    /// Amount deposited in current month (minor units, settlement currency)
+    /// Amount deposited in current calendar month (minor units, settlement currency)
     pub monthly_deposited: u64,
-    /// Timestamp of current month start
+    /// Timestamp of current calendar month start (00:00 UTC on the 1st)
     pub month_start: i64,
     /// Total number of deposits
     pub deposit_count: u32,
@@ -43,6 +45,14 @@ pub struct UserPosition {
     pub created_at: i64,
     /// PDA bump
     pub bump: u8,
+    /// Daily deposit buckets backing the rolling 30-day limit window
+    pub daily_deposits: DailyDeposits,
+    /// Whether the compliance officer has frozen this position
+    pub is_frozen: bool,
+    /// Reason code for the current freeze (meaningful only while frozen)
+    pub freeze_reason: FreezeReason,
+    /// Timestamp the current freeze was applied
+    pub frozen_at: i64,
 }
 
 impl UserPosition {
@@ -64,16 +74,75 @@ impl UserPosition {
         + 8   // deposit_nonce
         + 8   // withdrawal_nonce
         + 8   // created_at
-        + 1;  // bump
+        + 1   // bump
+        + DailyDeposits::LEN // daily_deposits
+        + 1   // is_frozen
+        + 1   // freeze_reason (enum)
+        + 8;  // frozen_at
+
+    /// Size of positions created before the rolling-window buckets and freeze
+    /// fields (grown by `migrate_user_position`)
+    pub const LEGACY_LEN: usize = Self::LEN - DailyDeposits::LEN - 1 - 1 - 8;
 
     pub const SEED: &'static [u8] = b"user_position";
 
-    /// Reset monthly counters if we're in a new month (30-day rolling window).
-    pub fn maybe_reset_monthly(&mut self, now: i64) {
-        const MONTH_SECONDS: i64 = 30 * 24 * 60 * 60;
-        if now - self.month_start >= MONTH_SECONDS {
+    /// Length of the limit window used before calendar months: 30 days from
+    /// `month_start`, which held the first deposit time or its latest roll
+    pub const LEGACY_WINDOW_SECONDS: i64 = 30 * 86_400;
+
+    /// Amount deposited within the current limit window.
+    pub fn window_usage(&self, window: LimitWindow, now: i64) -> u64 {
+        match window {
+            LimitWindow::CalendarMonth if self.month_start == utc_month_start(now) => {
+                self.monthly_deposited
+            }
+            LimitWindow::CalendarMonth => 0,
+            LimitWindow::Rolling30Day => self.daily_deposits.rolling_sum(now),
+        }
+    }
+
+    /// Record a deposit in both the calendar-month total and the daily buckets,
+    /// so the tier's window mode can be switched without losing history.
+    pub fn record_window_deposit(&mut self, amount: u64, now: i64) -> Result<()> {
+        let month_start = utc_month_start(now);
+        if self.month_start != month_start {
             self.monthly_deposited = 0;
-            self.month_start = now;
+            self.month_start = month_start;
         }
+        self.monthly_deposited = self
+            .monthly_deposited
+            .checked_add(amount)
+            .ok_or(StablebondError::MathOverflow)?;
+        self.daily_deposits
+            .record(amount, now)
+            .ok_or(StablebondError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Carry a migrated position's usage into the current windows. A legacy
+    /// window overlapping the current calendar month keeps its total, which
+    /// also seeds today's bucket so the rolling window cannot start empty.
+    pub fn adopt_legacy_window(&mut self, now: i64) -> Result<()> {
+        let month_start = utc_month_start(now);
+        let legacy_end = self
+            .month_start
+            .checked_add(Self::LEGACY_WINDOW_SECONDS)
+            .ok_or(StablebondError::MathOverflow)?;
+        if legacy_end <= month_start {
+            self.monthly_deposited = 0;
+        }
+        self.month_start = month_start;
+        self.daily_deposits
+            .record(self.monthly_deposited, now)
+            .ok_or(StablebondError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Unclaimed yield given the current value of the position's shares:
+    /// gain over cost basis less what has already been realized.
+    pub fn claimable_yield(&self, current_value: u64) -> u64 {
+        current_value
+            .saturating_sub(self.cost_basis)
+            .saturating_sub(self.realized_yield)
     }
 }
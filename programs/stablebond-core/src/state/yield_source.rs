@@ -1,5 +1,12 @@
 use anchor_lang::prelude::*;
-use stablebond_types::{BondType, YieldSourceType};
+use stablebond_types::{BondType, PauseFlag, YieldSourceType};
+
+use stablebond_math::Rounding;
+
+use crate::errors::StablebondError;
+
+/// NAV fixed-point scale: 1_000_000 = 1.000000 settlement units per share.
+pub use stablebond_math::NAV_SCALE;
 
 /// Registered yield source with bond metadata.
 /// PDA seeds: ["yield_source", config, token_mint]
@@ -52,6 +59,25 @@ pub struct YieldSource {
     pub haircut_bps: u16,
     /// PDA bump
     pub bump: u8,
+    /// Bitmask of `PauseFlag`s paused for this bond only
+    pub paused_ops: u8,
+    // === NAV circuit breaker ===
+    /// Max NAV move per update in bps (0 = unchecked)
+    pub max_nav_change_bps: u16,
+    /// Max NAV move within one UTC day in bps (0 = unchecked)
+    pub max_daily_nav_change_bps: u16,
+    /// Start of the current UTC day for the daily bound
+    pub nav_day_start: i64,
+    /// NAV at the start of the current UTC day
+    pub nav_day_open: u64,
+    /// Whether the breaker has tripped and awaits authority review
+    pub circuit_breaker_tripped: bool,
+    /// Rejected NAV that tripped the breaker
+    pub tripped_nav: u64,
+    /// Pause bits set by the breaker (cleared again on reset)
+    pub breaker_paused_ops: u8,
+    /// Linked stablebond-yield BondVault that NAV is read from
+    pub bond_vault: Pubkey,
 }
 
 impl YieldSource {
@@ -77,7 +103,298 @@ impl YieldSource {
         + 2   // coupon_rate_bps
         + 8   // maturity_date
         + 2   // haircut_bps
-        + 1;  // bump
+        + 1   // bump
+        + 1   // paused_ops
+        + 2   // max_nav_change_bps
+        + 2   // max_daily_nav_change_bps
+        + 8   // nav_day_start
+        + 8   // nav_day_open
+        + 1   // circuit_breaker_tripped
+        + 8   // tripped_nav
+        + 1   // breaker_paused_ops
+        + 32; // bond_vault
+
+    /// Size of sources created before the pause mask, NAV circuit breaker and
+    /// bond vault link (grown by `migrate_yield_source`)
+    pub const LEGACY_LEN: usize = Self::LEN - 1 - 2 - 2 - 8 - 8 - 1 - 8 - 1 - 32;
 
     pub const SEED: &'static [u8] = b"yield_source";
+
+    /// Default per-update NAV bound: 2%
+    pub const DEFAULT_MAX_NAV_CHANGE_BPS: u16 = 200;
+    /// Default per-day NAV bound: 5%
+    pub const DEFAULT_MAX_DAILY_NAV_CHANGE_BPS: u16 = 500;
+    /// Operations paused when the breaker trips
+    pub const BREAKER_PAUSE_MASK: u8 = (1 << PauseFlag::Deposits as u8)
+        | (1 << PauseFlag::WithdrawalRequests as u8)
+        | (1 << PauseFlag::WithdrawalClaims as u8);
+
+    /// Start of the UTC day containing `ts`.
+    pub fn utc_day_start(ts: i64) -> i64 {
+        ts - ts.rem_euclid(86_400)
+    }
+
+    /// Absolute move from `from` to `to` in basis points of `from`, rounded
+    /// up so a move just past a bound trips it.
+    pub fn nav_change_bps(from: u64, to: u64) -> u64 {
+        stablebond_math::ratio_bps(from.abs_diff(to), from, Rounding::Up).unwrap_or(u64::MAX)
+    }
+
+    /// Fails if `op` is paused for this bond.
+    pub fn require_unpaused(&self, op: PauseFlag) -> Result<()> {
+        require!(!op.is_in(self.paused_ops), StablebondError::BondOperationPaused);
+        Ok(())
+    }
+
+    /// Replace the per-bond pause mask, returning the previous one. While the
+    /// breaker is tripped the operations it paused stay paused; only
+    /// `reset_circuit_breaker` resumes them.
+    pub fn set_paused_ops(&mut self, paused_ops: u8) -> Result<u8> {
+        require!(
+            !self.circuit_breaker_tripped
+                || paused_ops & self.breaker_paused_ops == self.breaker_paused_ops,
+            StablebondError::CircuitBreakerActive
+        );
+        let old_flags = self.paused_ops;
+        self.paused_ops = paused_ops;
+        Ok(old_flags)
+    }
+
+    /// Shares issued for `amount` of settlement currency at the current NAV
+    /// (rounded down).
+    pub fn shares_for_amount(&self, amount: u64) -> Result<u64> {
+        Ok(stablebond_math::shares_for_amount(amount, self.nav_per_share)
+            .map_err(StablebondError::from)?)
+    }
+
+    /// Settlement currency value of `shares` at the current NAV (rounded down).
+    pub fn amount_for_shares(&self, shares: u64) -> Result<u64> {
+        Ok(stablebond_math::amount_for_shares(shares, self.nav_per_share)
+            .map_err(StablebondError::from)?)
+    }
+
+    /// Start a new daily window if `now` falls on a later UTC day, opening it
+    /// at the current NAV.
+    pub fn roll_nav_day(&mut self, now: i64) {
+        let day_start = Self::utc_day_start(now);
+        if self.nav_day_start != day_start {
+            self.nav_day_start = day_start;
+            self.nav_day_open = self.nav_per_share;
+        }
+    }
+
+    /// Size of a move to `new_nav` against the per-update and per-day bounds.
+    pub fn nav_move(&self, new_nav: u64) -> NavMove {
+        let change_bps = Self::nav_change_bps(self.nav_per_share, new_nav);
+        let daily_change_bps = Self::nav_change_bps(self.nav_day_open, new_nav);
+        let exceeds_update =
+            self.max_nav_change_bps > 0 && change_bps > self.max_nav_change_bps as u64;
+        let exceeds_daily = self.max_daily_nav_change_bps > 0
+            && daily_change_bps > self.max_daily_nav_change_bps as u64;
+        NavMove {
+            change_bps,
+            daily_change_bps,
+            exceeds_bounds: exceeds_update || exceeds_daily,
+        }
+    }
+
+    /// Trip the circuit breaker on an out-of-bounds `attempted_nav`. Pauses
+    /// the breaker operations not already paused and returns that mask.
+    pub fn trip_circuit_breaker(&mut self, attempted_nav: u64) -> u8 {
+        let paused = Self::BREAKER_PAUSE_MASK & !self.paused_ops;
+        self.paused_ops |= paused;
+        self.breaker_paused_ops = paused;
+        self.circuit_breaker_tripped = true;
+        self.tripped_nav = attempted_nav;
+        paused
+    }
+
+    /// Clear a tripped breaker, resuming the operations it paused (returned
+    /// as a mask). With `accept_nav` the tripped NAV is applied; the daily
+    /// window restarts from the resulting NAV either way.
+    pub fn reset_circuit_breaker(&mut self, accept_nav: bool, now: i64) -> u8 {
+        let resumed = self.paused_ops & self.breaker_paused_ops;
+        self.paused_ops &= !self.breaker_paused_ops;
+        if accept_nav {
+            self.nav_per_share = self.tripped_nav;
+            self.last_nav_update = now;
+        }
+        self.nav_day_start = Self::utc_day_start(now);
+        self.nav_day_open = self.nav_per_share;
+        self.circuit_breaker_tripped = false;
+        self.tripped_nav = 0;
+        self.breaker_paused_ops = 0;
+        resumed
+    }
+}
+
+/// A proposed NAV update measured against the circuit breaker bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NavMove {
+    /// Move from the current NAV, in bps
+    pub change_bps: u64,
+    /// Move from the day's opening NAV, in bps
+    pub daily_change_bps: u64,
+    /// Either bound is configured and exceeded
+    pub exceeds_bounds: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+    const T0: i64 = 20_000 * DAY;
+
+    fn source() -> YieldSource {
+        YieldSource {
+            protocol_config: Pubkey::new_unique(),
+            name: [0; 32],
+            source_type: YieldSourceType::SovereignBond,
+            token_mint: Pubkey::new_unique(),
+            deposit_vault: Pubkey::new_unique(),
+            yield_token_vault: Pubkey::new_unique(),
+            current_apy_bps: 450,
+            total_deposited: 0,
+            total_shares: 0,
+            allocation_weight_bps: 10_000,
+            min_deposit: 0,
+            max_allocation: u64::MAX,
+            is_active: true,
+            last_nav_update: T0,
+            nav_per_share: NAV_SCALE,
+            bond_type: BondType::UsTBill,
+            currency_mint: Pubkey::new_unique(),
+            oracle_feed: Pubkey::default(),
+            coupon_rate_bps: 450,
+            maturity_date: 0,
+            haircut_bps: 0,
+            bump: 255,
+            paused_ops: 0,
+            max_nav_change_bps: YieldSource::DEFAULT_MAX_NAV_CHANGE_BPS,
+            max_daily_nav_change_bps: YieldSource::DEFAULT_MAX_DAILY_NAV_CHANGE_BPS,
+            nav_day_start: T0,
+            nav_day_open: NAV_SCALE,
+            circuit_breaker_tripped: false,
+            tripped_nav: 0,
+            breaker_paused_ops: 0,
+            bond_vault: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn per_update_bound_is_inclusive() {
+        let ys = source();
+        // 2% exactly is allowed, one unit past it is not
+        assert!(!ys.nav_move(1_020_000).exceeds_bounds);
+        assert!(ys.nav_move(1_020_001).exceeds_bounds);
+        assert!(!ys.nav_move(980_000).exceeds_bounds);
+        assert!(ys.nav_move(979_999).exceeds_bounds);
+    }
+
+    #[test]
+    fn daily_bound_accumulates_updates_within_a_day() {
+        let mut ys = source();
+        // Three 1.9% steps: each within the per-update bound, the third past 5% for the day
+        ys.nav_per_share = 1_019_000;
+        assert!(!ys.nav_move(1_038_000).exceeds_bounds);
+        ys.nav_per_share = 1_038_000;
+        let step = ys.nav_move(1_057_000);
+        assert_eq!(step.change_bps, 184);
+        assert_eq!(step.daily_change_bps, 570);
+        assert!(step.exceeds_bounds);
+    }
+
+    #[test]
+    fn zero_bounds_are_unchecked() {
+        let mut ys = source();
+        ys.max_nav_change_bps = 0;
+        ys.max_daily_nav_change_bps = 0;
+        assert!(!ys.nav_move(2 * NAV_SCALE).exceeds_bounds);
+    }
+
+    #[test]
+    fn day_rollover_reopens_at_current_nav() {
+        let mut ys = source();
+        ys.nav_per_share = 1_040_000;
+
+        ys.roll_nav_day(T0 + DAY - 1);
+        assert_eq!(ys.nav_day_open, NAV_SCALE);
+        assert!(ys.nav_move(1_059_000).exceeds_bounds);
+
+        ys.roll_nav_day(T0 + DAY);
+        assert_eq!(ys.nav_day_start, T0 + DAY);
+        assert_eq!(ys.nav_day_open, 1_040_000);
+        assert!(!ys.nav_move(1_059_000).exceeds_bounds);
+    }
+
+    #[test]
+    fn trip_pauses_only_operations_not_already_paused() {
+        let mut ys = source();
+        ys.paused_ops = PauseFlag::Deposits.bit() | PauseFlag::Conversions.bit();
+
+        let paused = ys.trip_circuit_breaker(1_100_000);
+        assert_eq!(
+            paused,
+            PauseFlag::WithdrawalRequests.bit() | PauseFlag::WithdrawalClaims.bit()
+        );
+        assert_eq!(ys.paused_ops, YieldSource::BREAKER_PAUSE_MASK | PauseFlag::Conversions.bit());
+        assert!(ys.circuit_breaker_tripped);
+        assert_eq!(ys.tripped_nav, 1_100_000);
+        assert_eq!(ys.nav_per_share, NAV_SCALE);
+
+        // Reset resumes what the breaker paused, not what the pauser had paused
+        let resumed = ys.reset_circuit_breaker(false, T0 + 60);
+        assert_eq!(resumed, paused);
+        assert_eq!(ys.paused_ops, PauseFlag::Deposits.bit() | PauseFlag::Conversions.bit());
+    }
+
+    #[test]
+    fn reset_without_accepting_keeps_prior_nav() {
+        let mut ys = source();
+        ys.trip_circuit_breaker(1_100_000);
+
+        let now = T0 + 2 * DAY + 5;
+        ys.reset_circuit_breaker(false, now);
+        assert_eq!(ys.nav_per_share, NAV_SCALE);
+        assert_eq!(ys.last_nav_update, T0);
+        assert_eq!(ys.nav_day_start, T0 + 2 * DAY);
+        assert_eq!(ys.nav_day_open, NAV_SCALE);
+        assert!(!ys.circuit_breaker_tripped);
+        assert_eq!(ys.tripped_nav, 0);
+        assert_eq!(ys.breaker_paused_ops, 0);
+        assert_eq!(ys.paused_ops, 0);
+    }
+
+    #[test]
+    fn reset_accepting_applies_tripped_nav_and_reopens_the_day() {
+        let mut ys = source();
+        ys.trip_circuit_breaker(1_100_000);
+
+        let now = T0 + 300;
+        ys.reset_circuit_breaker(true, now);
+        assert_eq!(ys.nav_per_share, 1_100_000);
+        assert_eq!(ys.last_nav_update, now);
+        assert_eq!(ys.nav_day_open, 1_100_000);
+        // The next update is measured from the accepted NAV
+        assert!(!ys.nav_move(1_110_000).exceeds_bounds);
+    }
+
+    #[test]
+    fn pause_flags_cannot_clear_breaker_bits_while_tripped() {
+        let mut ys = source();
+        ys.trip_circuit_breaker(1_100_000);
+
+        assert!(ys.set_paused_ops(0).is_err());
+        assert!(ys.set_paused_ops(PauseFlag::Deposits.bit()).is_err());
+        assert_eq!(ys.paused_ops, YieldSource::BREAKER_PAUSE_MASK);
+
+        // Adding bits is fine
+        let all = YieldSource::BREAKER_PAUSE_MASK | PauseFlag::Conversions.bit();
+        assert_eq!(ys.set_paused_ops(all).unwrap(), YieldSource::BREAKER_PAUSE_MASK);
+
+        ys.reset_circuit_breaker(false, T0);
+        assert_eq!(ys.paused_ops, PauseFlag::Conversions.bit());
+        assert!(ys.set_paused_ops(0).is_ok());
+    }
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use stablebond_types::KycError;
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("KYC verification required: no valid whitelist entry found")]
+    KycRequired,
+
+    #[msg("KYC verification expired")]
+    KycExpired,
+
+    #[msg("KYC jurisdiction not allowed (e.g., USA)")]
+    JurisdictionRestricted,
+
+    #[msg("Sovereign identity not found")]
+    SovereignIdentityNotFound,
+
+    #[msg("Tier too low for this operation")]
+    TierTooLow,
+
+    #[msg("Bond type not allowed for this wallet's tier")]
+    BondTypeNotAllowed,
+
+    #[msg("Invalid account data")]
+    InvalidAccountData,
+
+    #[msg("Extra accounts do not match the mint's ExtraAccountMetaList")]
+    ExtraAccountsMismatch,
+
+    #[msg("Share mint does not route transfers through this hook program")]
+    HookNotConfigured,
+}
+
+impl From<KycError> for TransferHookError {
+    fn from(e: KycError) -> Self {
+        match e {
+            KycError::KycRequired => TransferHookError::KycRequired,
+            KycError::KycExpired => TransferHookError::KycExpired,
+            KycError::JurisdictionRestricted => TransferHookError::JurisdictionRestricted,
+            KycError::SovereignIdentityNotFound => TransferHookError::SovereignIdentityNotFound,
+            KycError::TierTooLow => TransferHookError::TierTooLow,
+            KycError::BondTypeNotAllowed => TransferHookError::BondTypeNotAllowed,
+            KycError::InvalidAccountData => TransferHookError::InvalidAccountData,
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook as TransferHookExtension;
+use anchor_spl::token_interface::{get_mint_extension_data, Mint, TokenAccount};
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use stablebond_core::state::ProtocolConfig;
+use stablebond_types::{require_eligible_wallet, BondType};
+use stablebond_yield::share_mint::{HOOK_KYC_REGISTRY_META, HOOK_SOVEREIGN_PROGRAM_META};
+use stablebond_yield::state::BondVault;
+
+pub mod errors;
+
+use errors::TransferHookError;
+
+declare_id!("9gZEbcmHn89nKSmdLTR6WrJdNw5v2PxWxSFprSwdit39");
+
+/// Seed of the `ExtraAccountMetaList` PDA, fixed by the transfer-hook interface.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Extra accounts appended to every share transfer, after the interface's
+/// source, mint, destination, owner and `ExtraAccountMetaList` (indices 0-4).
+pub const EXTRA_ACCOUNT_COUNT: usize = 7;
+const INTERFACE_ACCOUNT_COUNT: u8 = 5;
+const KYC_REGISTRY_INDEX: u8 = INTERFACE_ACCOUNT_COUNT + HOOK_KYC_REGISTRY_META as u8;
+const SOVEREIGN_PROGRAM_INDEX: u8 = INTERFACE_ACCOUNT_COUNT + HOOK_SOVEREIGN_PROGRAM_META as u8;
+
+/// Token account layout: owner follows the 32-byte mint.
+const TOKEN_OWNER_OFFSET: u8 = 32;
+
+#[program]
+pub mod stablebond_transfer_hook {
+    use super::*;
+
+    /// Write the share mint's `ExtraAccountMetaList`, after which every
+    /// transfer of the mint carries the vault plus each wallet's Accredit
+    /// whitelist entry and Sovereign identity. Entries are resolved as
+    /// `[seed, wallet]` PDAs of the KYC registry and Sovereign programs in
+    /// the core `ProtocolConfig`. Signed by the bond vault authority.
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+        params: InitializeHookParams,
+    ) -> Result<()> {
+        let mint_info = ctx.accounts.share_mint.to_account_info();
+        let hook = get_mint_extension_data::<TransferHookExtension>(&mint_info)
+            .map_err(|_| TransferHookError::HookNotConfigured)?;
+        require!(
+            Option::<Pubkey>::from(hook.program_id) == Some(crate::ID),
+            TransferHookError::HookNotConfigured
+        );
+
+        let config = &ctx.accounts.protocol_config;
+        let metas = extra_account_metas(
+            ctx.accounts.bond_vault.key(),
+            config.kyc_registry,
+            config.sovereign_program,
+            &params,
+        )
+        .map_err(|_| TransferHookError::InvalidAccountData)?;
+
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+        msg!(
+            "Transfer hook configured for {} shares",
+            ctx.accounts.bond_vault.bond_type.as_str()
+        );
+        Ok(())
+    }
+
+    /// Rewrite the share mint's `ExtraAccountMetaList` from the current core
+    /// `ProtocolConfig`, after the KYC registry or Sovereign program there
+    /// (or their seed prefixes) change. Signed by the bond vault authority.
+    pub fn update_extra_account_meta_list(
+        ctx: Context<UpdateExtraAccountMetaList>,
+        params: InitializeHookParams,
+    ) -> Result<()> {
+        let config = &ctx.accounts.protocol_config;
+        let metas = extra_account_metas(
+            ctx.accounts.bond_vault.key(),
+            config.kyc_registry,
+            config.sovereign_program,
+            &params,
+        )
+        .map_err(|_| TransferHookError::InvalidAccountData)?;
+
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &metas)?;
+
+        msg!(
+            "Transfer hook accounts updated for {} shares",
+            ctx.accounts.bond_vault.bond_type.as_str()
+        );
+        Ok(())
+    }
+
+    /// Transfer-hook `Execute`: both the source and destination wallets need
+    /// an active, unexpired, non-US whitelist entry and a Sovereign tier that
+    /// allows the vault's bond type.
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        {
+            let data = ctx.accounts.extra_account_meta_list.try_borrow_data()?;
+            ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+                &ctx.accounts.to_account_infos(),
+                &TransferHookInstruction::Execute { amount }.pack(),
+                ctx.program_id,
+                &data,
+            )
+            .map_err(|_| TransferHookError::ExtraAccountsMismatch)?;
+        }
+
+        let accounts = &ctx.accounts;
+        let bond_type = accounts.bond_vault.bond_type;
+        check_wallet(
+            &accounts.source_token.owner,
+            &accounts.source_whitelist_entry,
+            &accounts.source_identity,
+            accounts,
+            bond_type,
+        )?;
+        check_wallet(
+            &accounts.destination_token.owner,
+            &accounts.destination_whitelist_entry,
+            &accounts.destination_identity,
+            accounts,
+            bond_type,
+        )?;
+        Ok(())
+    }
+}
+
+/// The `ExtraAccountMetaList` written for a share mint, in the order
+/// `TransferHook` expects them.
+pub fn extra_account_metas(
+    bond_vault: Pubkey,
+    kyc_registry: Pubkey,
+    sovereign_program: Pubkey,
+    params: &InitializeHookParams,
+) -> std::result::Result<Vec<ExtraAccountMeta>, ProgramError> {
+    let wallet_pda = |program_index: u8, seed: &[u8], token_index: u8| {
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            program_index,
+            &[
+                Seed::Literal {
+                    bytes: seed.to_vec(),
+                },
+                Seed::AccountData {
+                    account_index: token_index,
+                    data_index: TOKEN_OWNER_OFFSET,
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )
+    };
+    // Interface account indices
+    let (source, destination) = (0, 2);
+
+    // Vault share deposits read the two programs back at their `HOOK_*_META` positions
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(&bond_vault, false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&kyc_registry, false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&sovereign_program, false, false)?,
+        wallet_pda(KYC_REGISTRY_INDEX, &params.whitelist_seed, source)?,
+        wallet_pda(SOVEREIGN_PROGRAM_INDEX, &params.identity_seed, source)?,
+        wallet_pda(KYC_REGISTRY_INDEX, &params.whitelist_seed, destination)?,
+        wallet_pda(SOVEREIGN_PROGRAM_INDEX, &params.identity_seed, destination)?,
+    ])
+}
+
+// ─── Wallet validation (shared with vault share deposits) ────────────────────
+
+fn check_wallet(
+    wallet: &Pubkey,
+    whitelist_entry: &AccountInfo,
+    sovereign_identity: &AccountInfo,
+    accounts: &TransferHook,
+    bond_type: BondType,
+) -> Result<()> {
+    require_eligible_wallet::<TransferHookError>(
+        wallet,
+        whitelist_entry,
+        sovereign_identity,
+        accounts.kyc_registry.key,
+        accounts.sovereign_program.key,
+        bond_type,
+    )
+}
+
+// ─── Account Contexts ──────────────────────────────────────────────────────────
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeHookParams {
+    /// Seed prefix of Accredit whitelist entries (`[whitelist_seed, wallet]`)
+    pub whitelist_seed: Vec<u8>,
+    /// Seed prefix of Sovereign identities (`[identity_seed, wallet]`)
+    pub identity_seed: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority, has_one = share_mint)]
+    pub bond_vault: Account<'info, BondVault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        seeds::program = stablebond_core::ID,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Created here and written as a TLV `ExtraAccountMetaList`
+    #[account(
+        init,
+        payer = authority,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_COUNT)?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, share_mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(has_one = authority, has_one = share_mint)]
+    pub bond_vault: Account<'info, BondVault>,
+
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        seeds::program = stablebond_core::ID,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Address fixed by seeds; rewritten as a TLV `ExtraAccountMetaList`
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, share_mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+}
+
+/// Accounts in transfer-hook interface order; fields after
+/// `extra_account_meta_list` follow `extra_account_metas`.
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source owner or delegate; authorized by the token program
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Address fixed by seeds; contents checked in the handler
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(constraint = bond_vault.share_mint == mint.key() @ TransferHookError::InvalidAccountData)]
+    pub bond_vault: Account<'info, BondVault>,
+
+    /// CHECK: Accredit KYC registry program, fixed in the meta list
+    pub kyc_registry: UncheckedAccount<'info>,
+
+    /// CHECK: Sovereign identity program, fixed in the meta list
+    pub sovereign_program: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and layout checked in the handler
+    pub source_whitelist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and layout checked in the handler
+    pub source_identity: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and layout checked in the handler
+    pub destination_whitelist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: Owner and layout checked in the handler
+    pub destination_identity: UncheckedAccount<'info>,
+}
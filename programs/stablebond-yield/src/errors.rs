@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use stablebond_math::MathError;
+use stablebond_types::KycError;
 
 #[error_code]
 pub enum BondVaultError {
@@ -41,7 +43,7 @@ pub enum BondVaultError {
     #[msg("Invalid reserve attestation: amount must be > 0")]
     InvalidAttestation,
 
-    #[msg("Invalid attestation config: max_staleness must be > 0")]
+    #[msg("Invalid attestation config: max_staleness must be > 0 and the attestor cannot be the attestor set PDA")]
     InvalidAttestationConfig,
 
     #[msg("Incentivized crank called too frequently (min 30 seconds)")]
@@ -49,4 +51,114 @@ pub enum BondVaultError {
 
     #[msg("Immediate withdrawals are disabled — use the cooldown-based withdrawal flow")]
     ImmediateWithdrawDisabled,
+
+    #[msg("No compliance officer configured for this vault")]
+    NoComplianceOfficerConfigured,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Signer does not hold the role required for this action")]
+    MissingRole,
+
+    #[msg("Role mask contains undefined role bits")]
+    InvalidRoleMask,
+
+    #[msg("Timelock has not elapsed for this change")]
+    TimelockNotElapsed,
+
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+
+    #[msg("This operation is paused for this vault")]
+    OperationPaused,
+
+    #[msg("Pause mask contains undefined operation bits")]
+    InvalidPauseMask,
+
+    #[msg("Attested reserve coverage is below the vault minimum")]
+    ReserveShortfall,
+
+    #[msg("No reserve holdings root has been attested")]
+    NoReserveRoot,
+
+    #[msg("Invalid reserve commitment: root and holdings count must be non-zero")]
+    InvalidReserveCommitment,
+
+    #[msg("Reserve holding proof does not match the attested root")]
+    InvalidReserveProof,
+
+    #[msg("Invalid attestor set: need unique attestors and 2 <= threshold <= attestors <= 5, window > 0")]
+    InvalidAttestorSet,
+
+    #[msg("Quorum attestation is not active for this vault")]
+    QuorumNotActive,
+
+    #[msg("Deposit is too small to mint any shares")]
+    ZeroShares,
+
+    #[msg("A share transfer hook requires a Token-2022 share mint")]
+    TransferHookRequiresToken2022,
+
+    #[msg("Share metadata requires a Token-2022 share mint")]
+    ShareMetadataRequiresToken2022,
+
+    #[msg("Share metadata too long: name max 32, symbol max 10, URI max 200 bytes")]
+    InvalidShareMetadata,
+
+    #[msg("Only the program upgrade authority can create vaults")]
+    NotUpgradeAuthority,
+
+    #[msg("Not an active legacy bond vault for this authority, bond type and currency")]
+    InvalidLegacyVault,
+
+    #[msg("Quorum attestation is active: reconfigure the attestor set instead")]
+    QuorumActive,
+
+    #[msg("The attestor set account is required to apply this change")]
+    MissingAttestorSet,
+
+    #[msg("KYC verification required: no valid whitelist entry found")]
+    KycRequired,
+
+    #[msg("KYC verification expired")]
+    KycExpired,
+
+    #[msg("KYC jurisdiction not allowed (e.g., USA)")]
+    JurisdictionRestricted,
+
+    #[msg("Sovereign identity not found")]
+    SovereignIdentityNotFound,
+
+    #[msg("Tier too low for this operation")]
+    TierTooLow,
+
+    #[msg("Bond type not allowed for this wallet's tier")]
+    BondTypeNotAllowed,
+
+    #[msg("Invalid account data")]
+    InvalidAccountData,
+
+    #[msg("Share mint transfer hook accounts missing or not the mint's ExtraAccountMetaList")]
+    InvalidShareHookAccounts,
+}
+
+impl From<MathError> for BondVaultError {
+    fn from(_: MathError) -> Self {
+        BondVaultError::MathOverflow
+    }
+}
+
+impl From<KycError> for BondVaultError {
+    fn from(e: KycError) -> Self {
+        match e {
+            KycError::KycRequired => BondVaultError::KycRequired,
+            KycError::KycExpired => BondVaultError::KycExpired,
+            KycError::JurisdictionRestricted => BondVaultError::JurisdictionRestricted,
+            KycError::SovereignIdentityNotFound => BondVaultError::SovereignIdentityNotFound,
+            KycError::TierTooLow => BondVaultError::TierTooLow,
+            KycError::BondTypeNotAllowed => BondVaultError::BondTypeNotAllowed,
+            KycError::InvalidAccountData => BondVaultError::InvalidAccountData,
+        }
+    }
 }
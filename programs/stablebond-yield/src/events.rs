@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use stablebond_types::FreezeReason;
+
+#[event]
+pub struct ComplianceOfficerSet {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub old_officer: Pubkey,
+    pub new_officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SharesFrozen {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub reason: FreezeReason,
+    pub compliance_officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SharesThawed {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub compliance_officer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShareMetadataUpdated {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultAuthorityProposed {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultAuthorityAccepted {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultRolesUpdated {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub member: Pubkey,
+    pub old_roles: u8,
+    pub new_roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultChangeQueued {
+    pub vault: Pubkey,
+    pub change_id: u64,
+    pub kind: u8,
+    pub proposer: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultChangeExecuted {
+    pub vault: Pubkey,
+    pub change_id: u64,
+    pub kind: u8,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultChangeCancelled {
+    pub vault: Pubkey,
+    pub change_id: u64,
+    pub kind: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultTimelockDelayUpdated {
+    pub vault: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPauseFlagsUpdated {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub old_flags: u8,
+    pub new_flags: u8,
+    /// Operations newly paused by this change
+    pub paused: u8,
+    /// Operations newly resumed by this change
+    pub resumed: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveShortfall {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub attested_reserve: u64,
+    /// Last attested reserve / current (total_shares * NAV), in bps
+    pub coverage_bps: u64,
+    pub min_coverage_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinCoverageUpdated {
+    pub vault: Pubkey,
+    pub old_min_coverage_bps: u16,
+    pub new_min_coverage_bps: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveHoldingVerified {
+    pub vault: Pubkey,
+    pub security_id: [u8; 12],
+    pub quantity: u64,
+    pub price: u64,
+    pub merkle_root: [u8; 32],
+    /// Timestamp of the attestation the root belongs to
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct AttestorSetConfigured {
+    pub vault: Pubkey,
+    pub attestor_set: Pubkey,
+    pub attestors: Vec<Pubkey>,
+    pub threshold: u8,
+    pub window: i64,
+    pub tolerance_bps: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuorumAttestationSubmitted {
+    pub vault: Pubkey,
+    pub attestor: Pubkey,
+    pub attested_reserve: u64,
+    /// Submissions in the open round, including this one
+    pub submissions: u8,
+    pub threshold: u8,
+    pub round_start: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QuorumAttestationRecorded {
+    pub vault: Pubkey,
+    /// Lowest submission in the round, recorded as the vault's attested reserve
+    pub attested_reserve: u64,
+    pub highest_reserve: u64,
+    /// (highest - lowest) / lowest in bps
+    pub spread_bps: u64,
+    pub submissions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationDisagreement {
+    pub vault: Pubkey,
+    pub lowest_reserve: u64,
+    pub highest_reserve: u64,
+    pub spread_bps: u64,
+    pub tolerance_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveAttestationRecorded {
+    pub vault: Pubkey,
+    /// Sequence number in the vault's AttestationHistory
+    pub seq: u64,
+    pub attestor: Pubkey,
+    pub attested_reserve: u64,
+    pub merkle_root: [u8; 32],
+    pub nav_per_share: u64,
+    pub total_shares: u64,
+    pub coverage_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultMigrated {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub legacy_vault: Pubkey,
+    /// Currency received from the legacy vault
+    pub amount: u64,
+    /// Legacy share supply the reserve is owed to
+    pub legacy_shares: u64,
+    pub reserved_shares: u64,
+    pub nav_per_share: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SharesMigrated {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub legacy_shares: u64,
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultInitialized {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub authority: Pubkey,
+    pub currency_mint: Pubkey,
+    pub share_mint: Pubkey,
+    pub target_apy_bps: u16,
+    pub coupon_rate_bps: u16,
+    pub maturity_date: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultDeposited {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub nav_per_share: u64,
+    pub total_deposits: u64,
+    pub total_shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultWithdrawn {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub shares: u64,
+    pub amount_out: u64,
+    pub nav_per_share: u64,
+    pub total_deposits: u64,
+    pub total_shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserSharesSynced {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub old_shares: u64,
+    pub new_shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct YieldAccrued {
+    pub vault: Pubkey,
+    pub bond_type: u8,
+    pub old_nav: u64,
+    pub new_nav: u64,
+    /// Effective APY applied, after the 50% cap
+    pub apy_bps: u64,
+    /// True if the APY was derived from the bond price oracle, false for target_apy_bps
+    pub oracle_priced: bool,
+    /// Oracle bond price used (scaled 1e6); 0 when not oracle-priced
+    pub oracle_price: u64,
+    pub elapsed: u64,
+    /// Cranking keeper (Pubkey::default() for the permissionless crank)
+    pub keeper: Pubkey,
+    pub keeper_reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultApyUpdated {
+    pub vault: Pubkey,
+    pub old_apy_bps: u16,
+    pub new_apy_bps: u16,
+    /// Signer (or timelock proposer) that made the change
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleConfigured {
+    pub vault: Pubkey,
+    pub old_oracle_feed: Pubkey,
+    pub new_oracle_feed: Pubkey,
+    pub old_enabled: bool,
+    pub new_enabled: bool,
+    /// Signer (or timelock proposer) that made the change
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveAttestorConfigured {
+    pub vault: Pubkey,
+    pub old_attestor: Pubkey,
+    pub new_attestor: Pubkey,
+    pub old_max_staleness: i64,
+    pub new_max_staleness: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ImmediateWithdrawSet {
+    pub vault: Pubkey,
+    pub allow: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
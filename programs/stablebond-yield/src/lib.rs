@@ -1,17 +1,38 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
-use stablebond_types::BondType;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::Field;
+use anchor_spl::token_interface::{
+    self, Burn, FreezeAccount, Mint, MintTo, ThawAccount, TokenAccount, TokenInterface,
+};
+use stablebond_math::Rounding;
+use stablebond_token::TokenTransfer;
+use stablebond_types::{BondType, FreezeReason, PauseFlag, Role};
 
 pub mod errors;
+pub mod events;
+pub mod share_mint;
 pub mod state;
 
 use errors::BondVaultError;
-use state::{BondVault, UserShares};
+use events::{
+    AttestationDisagreement, AttestorSetConfigured, ComplianceOfficerSet, ImmediateWithdrawSet,
+    MinCoverageUpdated, OracleConfigured, QuorumAttestationRecorded, QuorumAttestationSubmitted,
+    ReserveAttestationRecorded, ReserveAttestorConfigured, ReserveHoldingVerified, ReserveShortfall,
+    ShareMetadataUpdated, SharesFrozen, SharesMigrated, SharesThawed, UserSharesSynced, VaultApyUpdated, VaultAuthorityAccepted, VaultAuthorityProposed,
+    VaultChangeCancelled, VaultChangeExecuted, VaultChangeQueued, VaultDeposited, VaultInitialized,
+    VaultMigrated,
+    VaultPauseFlagsUpdated, VaultRolesUpdated, VaultTimelockDelayUpdated, VaultWithdrawn,
+    YieldAccrued,
+};
+use state::{
+    AccrualHalt, AttestationHistory, AttestationRecord, AttestorSet, BondVault, LegacyBondVault, PendingVaultChange, ReserveCommitment, ReserveHolding, UserShares, VaultChange,
+    VaultMigration, VaultRole,
+};
+use share_mint::{ShareKyc, ShareMetadata, ShareMintInit};
 
 declare_id!("DLFUfzV4iqCzxmmXmCpR7qH6nhvPSLUekq7JCezV1LeE");
 
-const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
-const NAV_SCALE: u64 = 1_000_000;
+/// NAV fixed-point scale: 1_000_000 = 1.000000 settlement units per share.
+pub use stablebond_math::{NAV_SCALE, SECONDS_PER_YEAR};
 
 /// Oracle PriceFeed layout (Pyth/Switchboard simplified):
 ///   discriminator(8) + authority(32) + current_price(u64, 8) + last_update_time(i64, 8)
@@ -25,15 +46,49 @@ pub mod stablebond_yield {
     use super::*;
 
     /// Initialize a bond vault for a specific bond type with a target APY.
-    pub fn initialize_vault(
-        ctx: Context<InitializeVault>,
+    /// Co-signed by the program's upgrade authority.
+    /// The authority seeds it with a deposit of `BondVault::LOCKED_SHARES`
+    /// currency units whose shares are locked in the vault for good.
+    /// The share mint is created under `share_token_program`, which may be
+    /// SPL Token or Token-2022 independently of the currency mint. With
+    /// `share_transfer_hook` set, the share mint (Token-2022 only) routes
+    /// every transfer through that hook program, e.g. the KYC-gated
+    /// `stablebond-transfer-hook`. Token-2022 share mints also get on-mint
+    /// metadata (name, symbol, bond type, currency, maturity) that the
+    /// authority can later change with `set_share_metadata`.
+    pub fn initialize_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeVault<'info>>,
         bond_type: BondType,
         target_apy_bps: u16,
         coupon_rate_bps: u16,
         maturity_date: i64,
+        share_transfer_hook: Option<Pubkey>,
     ) -> Result<()> {
         require!(target_apy_bps <= 5000, BondVaultError::InvalidApy);
 
+        let bond_type_byte = bond_type.as_u8();
+        ShareMintInit {
+            payer: ctx.accounts.authority.to_account_info(),
+            vault: ctx.accounts.vault_config.to_account_info(),
+            share_mint: ctx.accounts.share_mint.to_account_info(),
+            locked_shares: ctx.accounts.locked_shares.to_account_info(),
+            token_program: &ctx.accounts.share_token_program,
+            system_program: ctx.accounts.system_program.to_account_info(),
+            transfer_hook: share_transfer_hook,
+        }
+        .create(
+            &[
+                BondVault::SHARE_MINT_SEED,
+                std::slice::from_ref(&bond_type_byte),
+                &[ctx.bumps.share_mint],
+            ],
+            &[
+                BondVault::LOCKED_SHARES_SEED,
+                std::slice::from_ref(&bond_type_byte),
+                &[ctx.bumps.locked_shares],
+            ],
+        )?;
+
         let vault = &mut ctx.accounts.vault_config;
         vault.authority = ctx.accounts.authority.key();
         vault.currency_mint = ctx.accounts.currency_mint.key();
@@ -43,8 +98,6 @@ pub mod stablebond_yield {
         vault.coupon_rate_bps = coupon_rate_bps;
         vault.maturity_date = maturity_date;
         vault.target_apy_bps = target_apy_bps;
-        vault.total_deposits = 0;
-        vault.total_shares = 0;
         vault.nav_per_share = NAV_SCALE; // 1.000000
         vault.last_accrual = Clock::get()?.unix_timestamp;
         vault.is_active = true;
@@ -61,6 +114,108 @@ pub mod stablebond_yield {
         vault.attested_reserve = 0;
         vault.attestation_max_staleness = BondVault::DEFAULT_ATTESTATION_STALENESS;
         vault.allow_immediate_withdraw = false;
+        vault.compliance_officer = Pubkey::default();
+        vault.pending_authority = Pubkey::default();
+        vault.timelock_delay = BondVault::MIN_TIMELOCK_DELAY;
+        vault.next_change_id = 0;
+        vault.paused_ops = 0;
+        vault.min_coverage_bps = BondVault::DEFAULT_MIN_COVERAGE_BPS;
+        vault.attested_coverage_bps = 0;
+        vault.reserve_merkle_root = [0; 32];
+        vault.reserve_holdings_count = 0;
+        vault.attested_liabilities = 0;
+        vault.net_flow_since_attestation = 0;
+
+        // Seed the vault with the authority's locked deposit, credited net of
+        // any transfer fee and minted at NAV 1.0
+        let seeded = TokenTransfer {
+            token_program: &ctx.accounts.token_program,
+            mint: &ctx.accounts.currency_mint,
+            from: &ctx.accounts.authority_currency,
+            authority: ctx.accounts.authority.to_account_info(),
+            hook_accounts: ctx.remaining_accounts,
+        }
+        .send_net::<BondVaultError>(&mut ctx.accounts.currency_vault, BondVault::LOCKED_SHARES, &[])?;
+        let locked_shares = ctx.accounts.vault_config.shares_for_amount(seeded)?;
+        require!(locked_shares > 0, BondVaultError::ZeroShares);
+
+        let vault_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            std::slice::from_ref(&bond_type_byte),
+            &[ctx.bumps.vault_config],
+        ];
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.share_token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.locked_shares.to_account_info(),
+                    authority: ctx.accounts.vault_config.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            locked_shares,
+        )?;
+
+        if ctx.accounts.share_token_program.key() == anchor_spl::token_2022::ID {
+            let maturity = if maturity_date > 0 {
+                maturity_date.to_string()
+            } else {
+                "rolling".to_string()
+            };
+            ShareMetadata {
+                payer: ctx.accounts.authority.to_account_info(),
+                vault: ctx.accounts.vault_config.to_account_info(),
+                share_mint: ctx.accounts.share_mint.to_account_info(),
+                token_program: &ctx.accounts.share_token_program,
+                system_program: ctx.accounts.system_program.to_account_info(),
+            }
+            .write(
+                vec![
+                    (Field::Name, bond_type.share_name()),
+                    (Field::Symbol, bond_type.share_symbol().to_string()),
+                    (Field::Uri, String::new()),
+                    (Field::Key("bond_type".to_string()), bond_type.as_str().to_string()),
+                    (
+                        Field::Key("currency".to_string()),
+                        String::from_utf8_lossy(&bond_type.denomination_currency()).into_owned(),
+                    ),
+                    (
+                        Field::Key("currency_mint".to_string()),
+                        ctx.accounts.currency_mint.key().to_string(),
+                    ),
+                    (Field::Key("maturity".to_string()), maturity),
+                ],
+                vault_seeds,
+            )?;
+        }
+
+        let vault = &mut ctx.accounts.vault_config;
+        vault.total_deposits = seeded;
+        vault.total_shares = locked_shares;
+
+        let vault = &ctx.accounts.vault_config;
+        emit!(VaultInitialized {
+            vault: vault.key(),
+            bond_type: bond_type.as_u8(),
+            authority: vault.authority,
+            currency_mint: vault.currency_mint,
+            share_mint: vault.share_mint,
+            target_apy_bps,
+            coupon_rate_bps,
+            maturity_date,
+            timestamp: vault.last_accrual,
+        });
+        emit!(VaultDeposited {
+            vault: vault.key(),
+            user: vault.authority,
+            amount: seeded,
+            shares: locked_shares,
+            nav_per_share: vault.nav_per_share,
+            total_deposits: vault.total_deposits,
+            total_shares: vault.total_shares,
+            timestamp: vault.last_accrual,
+        });
 
         msg!(
             "Bond vault initialized: {} with APY {} bps",
@@ -71,42 +226,49 @@ pub mod stablebond_yield {
     }
 
     /// Deposit settlement currency into the vault and receive shares.
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    /// When the share mint routes transfers through the KYC transfer hook,
+    /// the depositor must pass the same Accredit and Sovereign checks the
+    /// hook applies, so shares cannot be minted to a wallet that could not
+    /// receive them by transfer.
+    pub fn deposit<'info>(ctx: Context<'_, '_, '_, 'info, Deposit<'info>>, amount: u64) -> Result<()> {
         let vault = &ctx.accounts.vault_config;
-        require!(vault.is_active, BondVaultError::VaultNotActive);
+        vault.require_unpaused(PauseFlag::Deposits)?;
+        require!(!vault.has_reserve_shortfall(), BondVaultError::ReserveShortfall);
         require!(amount > 0, BondVaultError::ZeroDeposit);
 
-        // Calculate shares: shares = amount * NAV_SCALE / nav_per_share
-        let shares = amount
-            .checked_mul(NAV_SCALE)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_div(vault.nav_per_share)
-            .ok_or(BondVaultError::MathOverflow)?;
+        ShareKyc {
+            share_mint: &ctx.accounts.share_mint.to_account_info(),
+            extra_account_meta_list: ctx.accounts.extra_account_meta_list.as_deref(),
+            whitelist_entry: ctx.accounts.whitelist_entry.as_deref(),
+            sovereign_identity: ctx.accounts.sovereign_identity.as_deref(),
+        }
+        .require_eligible(&ctx.accounts.user.key(), vault.bond_type)?;
+
+        // Transfer currency from depositor to vault; shares are minted on
+        // what arrives, net of any transfer fee
+        let received = TokenTransfer {
+            token_program: &ctx.accounts.token_program,
+            mint: &ctx.accounts.currency_mint,
+            from: &ctx.accounts.user_currency,
+            authority: ctx.accounts.user.to_account_info(),
+            hook_accounts: ctx.remaining_accounts,
+        }
+        .send_net::<BondVaultError>(&mut ctx.accounts.currency_vault, amount, &[])?;
 
-        // Transfer currency from depositor to vault
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.user_currency.to_account_info(),
-                    to: ctx.accounts.currency_vault.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            amount,
-        )?;
+        // Calculate shares: shares = received * NAV_SCALE / nav_per_share
+        let shares = ctx.accounts.vault_config.shares_for_amount(received)?;
+        require!(shares > 0, BondVaultError::ZeroShares);
 
         // Mint shares to depositor
         let bond_type_byte = ctx.accounts.vault_config.bond_type.as_u8();
         let vault_seeds: &[&[u8]] = &[
             BondVault::SEED,
-            ctx.accounts.vault_config.authority.as_ref(),
             std::slice::from_ref(&bond_type_byte),
             &[ctx.accounts.vault_config.bump],
         ];
-        token::mint_to(
+        token_interface::mint_to(
             CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.share_token_program.to_account_info(),
                 MintTo {
                     mint: ctx.accounts.share_mint.to_account_info(),
                     to: ctx.accounts.user_shares_ata.to_account_info(),
@@ -121,29 +283,45 @@ pub mod stablebond_yield {
         let vault = &mut ctx.accounts.vault_config;
         vault.total_deposits = vault
             .total_deposits
-            .checked_add(amount)
+            .checked_add(received)
             .ok_or(BondVaultError::MathOverflow)?;
         vault.total_shares = vault
             .total_shares
             .checked_add(shares)
             .ok_or(BondVaultError::MathOverflow)?;
+        vault.record_inflow(received);
 
         // Update user shares tracking
         let user_shares = &mut ctx.accounts.user_shares;
         user_shares.user = ctx.accounts.user.key();
         user_shares.vault = ctx.accounts.vault_config.key();
         user_shares.bump = ctx.bumps.user_shares;
-        user_shares.shares = user_shares
-            .shares
+        user_shares.shares = ctx
+            .accounts
+            .user_shares_ata
+            .amount
             .checked_add(shares)
             .ok_or(BondVaultError::MathOverflow)?;
         user_shares.deposited_amount = user_shares
             .deposited_amount
-            .checked_add(amount)
+            .checked_add(received)
             .ok_or(BondVaultError::MathOverflow)?;
-        user_shares.last_deposit_at = Clock::get()?.unix_timestamp;
+        let now = Clock::get()?.unix_timestamp;
+        user_shares.last_deposit_at = now;
+
+        let vault = &ctx.accounts.vault_config;
+        emit!(VaultDeposited {
+            vault: vault.key(),
+            user: ctx.accounts.user.key(),
+            amount: received,
+            shares,
+            nav_per_share: vault.nav_per_share,
+            total_deposits: vault.total_deposits,
+            total_shares: vault.total_shares,
+            timestamp: now,
+        });
 
-        msg!("Deposited {} currency, minted {} shares", amount, shares);
+        msg!("Deposited {} currency, minted {} shares", received, shares);
         Ok(())
     }
 
@@ -152,27 +330,25 @@ pub mod stablebond_yield {
     /// When disabled (default), users must use the cooldown-based withdrawal flow
     /// on stablebond-core. Authority can enable this for emergency use via
     /// `set_immediate_withdraw`.
-    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+    ///
+    /// Shares are burned from the signer's share token account, so tokens
+    /// received by transfer can be redeemed; `user_shares` is optional and
+    /// only updated when passed.
+    pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>, shares: u64) -> Result<()> {
         let vault = &ctx.accounts.vault_config;
-        require!(vault.is_active, BondVaultError::VaultNotActive);
+        vault.require_unpaused(PauseFlag::WithdrawalRequests)?;
+        vault.require_unpaused(PauseFlag::WithdrawalClaims)?;
         require!(
             vault.allow_immediate_withdraw,
             BondVaultError::ImmediateWithdrawDisabled
         );
         require!(shares > 0, BondVaultError::ZeroWithdrawal);
 
-        let user_shares_account = &ctx.accounts.user_shares;
-        require!(
-            user_shares_account.shares >= shares,
-            BondVaultError::InsufficientShares
-        );
+        let share_balance = ctx.accounts.user_shares_ata.amount;
+        require!(share_balance >= shares, BondVaultError::InsufficientShares);
 
         // Calculate currency out: currency_out = shares * nav_per_share / NAV_SCALE
-        let currency_out = shares
-            .checked_mul(vault.nav_per_share)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_div(NAV_SCALE)
-            .ok_or(BondVaultError::MathOverflow)?;
+        let currency_out = vault.amount_for_shares(shares)?;
 
         require!(
             ctx.accounts.currency_vault.amount >= currency_out,
@@ -180,9 +356,9 @@ pub mod stablebond_yield {
         );
 
         // Burn shares from user
-        token::burn(
+        token_interface::burn(
             CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.share_token_program.to_account_info(),
                 Burn {
                     mint: ctx.accounts.share_mint.to_account_info(),
                     from: ctx.accounts.user_shares_ata.to_account_info(),
@@ -196,22 +372,17 @@ pub mod stablebond_yield {
         let bond_type_byte = ctx.accounts.vault_config.bond_type.as_u8();
         let vault_seeds: &[&[u8]] = &[
             BondVault::SEED,
-            ctx.accounts.vault_config.authority.as_ref(),
             std::slice::from_ref(&bond_type_byte),
             &[ctx.accounts.vault_config.bump],
         ];
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.currency_vault.to_account_info(),
-                    to: ctx.accounts.user_currency.to_account_info(),
-                    authority: ctx.accounts.vault_config.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
-            currency_out,
-        )?;
+        TokenTransfer {
+            token_program: &ctx.accounts.token_program,
+            mint: &ctx.accounts.currency_mint,
+            from: &ctx.accounts.currency_vault,
+            authority: ctx.accounts.vault_config.to_account_info(),
+            hook_accounts: ctx.remaining_accounts,
+        }
+        .send(&ctx.accounts.user_currency, currency_out, &[vault_seeds])?;
 
         // Update vault state
         let vault = &mut ctx.accounts.vault_config;
@@ -220,18 +391,53 @@ pub mod stablebond_yield {
             .total_shares
             .checked_sub(shares)
             .ok_or(BondVaultError::MathOverflow)?;
+        vault.record_outflow(currency_out);
 
-        // Update user shares
-        let user_shares_mut = &mut ctx.accounts.user_shares;
-        user_shares_mut.shares = user_shares_mut
-            .shares
-            .checked_sub(shares)
-            .ok_or(BondVaultError::MathOverflow)?;
+        // Update user shares metadata to the post-burn balance
+        if let Some(user_shares) = ctx.accounts.user_shares.as_mut() {
+            user_shares.shares = share_balance - shares;
+        }
+
+        let vault = &ctx.accounts.vault_config;
+        emit!(VaultWithdrawn {
+            vault: vault.key(),
+            user: ctx.accounts.user.key(),
+            shares,
+            amount_out: currency_out,
+            nav_per_share: vault.nav_per_share,
+            total_deposits: vault.total_deposits,
+            total_shares: vault.total_shares,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         msg!("Withdrew {} shares for {} currency", shares, currency_out);
         Ok(())
     }
 
+    /// Reconcile the signer's `UserShares` with their share token balance,
+    /// creating it if needed (e.g. for a holder who received shares by
+    /// transfer). `deposited_amount` is left untouched.
+    pub fn sync_user_shares(ctx: Context<SyncUserShares>) -> Result<()> {
+        let new_shares = ctx.accounts.user_shares_ata.amount;
+        let user_shares = &mut ctx.accounts.user_shares;
+        let old_shares = user_shares.shares;
+        user_shares.user = ctx.accounts.user.key();
+        user_shares.vault = ctx.accounts.vault_config.key();
+        user_shares.bump = ctx.bumps.user_shares;
+        user_shares.shares = new_shares;
+
+        emit!(UserSharesSynced {
+            vault: user_shares.vault,
+            user: user_shares.user,
+            old_shares,
+            new_shares,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("User shares synced: {} -> {}", old_shares, new_shares);
+        Ok(())
+    }
+
     /// Keeper crank: accrue yield using oracle-derived bond price or fallback APY.
     ///
     /// When oracle_enabled=true, reads the bond price oracle to compute a
@@ -240,24 +446,21 @@ pub mod stablebond_yield {
     /// e.g. 1_005_000 = 100.5% of par = 50bps yield above par).
     ///
     /// If a reserve attestor is configured, accrual pauses when the
-    /// attestation is stale (older than attestation_max_staleness).
+    /// attestation is stale (older than attestation_max_staleness) or showed
+    /// coverage below min_coverage_bps. Paused time is skipped, not accrued
+    /// on resume.
     ///
     /// Maturity-aware: stops accruing after bond maturity date.
     pub fn accrue_yield(ctx: Context<AccrueYield>) -> Result<()> {
         let vault = &mut ctx.accounts.vault_config;
-        require!(vault.is_active, BondVaultError::VaultNotActive);
+        vault.require_unpaused(PauseFlag::NavUpdates)?;
 
         let now = Clock::get()?.unix_timestamp;
 
-        // If bond has matured, stop accruing
-        if vault.maturity_date > 0 && now >= vault.maturity_date {
-            return Ok(());
-        }
-
-        // If reserve attestor is configured, check attestation freshness
-        if vault.reserve_attestor != Pubkey::default() {
-            let staleness = now - vault.last_attestation_at;
-            if staleness > vault.attestation_max_staleness {
+        // Stop after maturity; pause on a stale attestation or reserve shortfall
+        match vault.halt_accrual(now) {
+            Some(AccrualHalt::Matured) => return Ok(()),
+            Some(AccrualHalt::StaleAttestation { staleness }) => {
                 msg!(
                     "Reserve attestation stale ({} seconds), pausing yield accrual for {}",
                     staleness,
@@ -265,6 +468,17 @@ pub mod stablebond_yield {
                 );
                 return Ok(());
             }
+            Some(AccrualHalt::ReserveShortfall) => {
+                emit_reserve_shortfall(vault, now);
+                msg!(
+                    "Reserve coverage {} bps below minimum {} bps, pausing yield accrual for {}",
+                    vault.current_coverage_bps(),
+                    vault.min_coverage_bps,
+                    vault.bond_type.as_str()
+                );
+                return Ok(());
+            }
+            None => {}
         }
 
         let elapsed = (now - vault.last_accrual) as u64;
@@ -314,50 +528,33 @@ pub mod stablebond_yield {
             // If price > par: the bond trades at a premium, yield is the coupon
             //   minus the premium amortization (simplified: use coupon rate)
             // If price == par: yield = coupon rate
-            if bond_price < NAV_SCALE {
-                // Discount: yield = (par - price) / price * 10000
-                // This gives the current yield for a zero-coupon or discount bond
-                let discount_yield = ((NAV_SCALE - bond_price) as u128)
-                    .checked_mul(10_000)
-                    .ok_or(BondVaultError::MathOverflow)?
-                    .checked_div(bond_price as u128)
-                    .ok_or(BondVaultError::MathOverflow)? as u64;
-                // Add coupon rate for coupon-bearing bonds
-                discount_yield.saturating_add(vault.coupon_rate_bps as u64)
-            } else if bond_price > NAV_SCALE {
-                // Premium: yield = coupon - premium amortization
-                // premium_cost_bps = (price - par) / price * 10000
-                let premium_cost = ((bond_price - NAV_SCALE) as u128)
-                    .checked_mul(10_000)
-                    .ok_or(BondVaultError::MathOverflow)?
-                    .checked_div(bond_price as u128)
-                    .ok_or(BondVaultError::MathOverflow)? as u64;
-                (vault.coupon_rate_bps as u64).saturating_sub(premium_cost)
-            } else {
-                vault.coupon_rate_bps as u64
-            }
+            vault.oracle_apy_bps(bond_price)?
         } else {
             vault.target_apy_bps as u64
         };
 
         // Cap at 50% to prevent runaway yield
-        let capped_apy = effective_apy_bps.min(5000);
+        let capped_apy = effective_apy_bps.min(BondVault::MAX_APY_BPS);
 
         // accrual = nav_per_share * effective_apy * elapsed / (10000 * SECONDS_PER_YEAR)
-        let accrual = (vault.nav_per_share as u128)
-            .checked_mul(capped_apy as u128)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_mul(elapsed as u128)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_div(10_000u128 * SECONDS_PER_YEAR as u128)
-            .ok_or(BondVaultError::MathOverflow)?;
-
-        vault.nav_per_share = vault
-            .nav_per_share
-            .checked_add(accrual as u64)
-            .ok_or(BondVaultError::MathOverflow)?;
+        let old_nav = vault.nav_per_share;
+        vault.nav_per_share = vault.accrued_nav(capped_apy, elapsed)?;
         vault.last_accrual = now;
 
+        emit!(YieldAccrued {
+            vault: vault.key(),
+            bond_type: vault.bond_type.as_u8(),
+            old_nav,
+            new_nav: vault.nav_per_share,
+            apy_bps: capped_apy,
+            oracle_priced: vault.oracle_enabled,
+            oracle_price: if vault.oracle_enabled { vault.last_oracle_price } else { 0 },
+            elapsed,
+            keeper: Pubkey::default(),
+            keeper_reward: 0,
+            timestamp: now,
+        });
+
         msg!(
             "Yield accrued for {}: NAV per share now {}, effective APY {} bps (oracle={})",
             vault.bond_type.as_str(),
@@ -368,75 +565,212 @@ pub mod stablebond_yield {
         Ok(())
     }
 
-    /// Admin: update the fallback target APY (used when oracle is disabled).
-    pub fn update_apy(ctx: Context<UpdateApy>, new_apy_bps: u16) -> Result<()> {
-        require!(new_apy_bps <= 5000, BondVaultError::InvalidApy);
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
-            BondVaultError::Unauthorized
-        );
+    /// Queue a timelocked vault change (APY, oracle, delay or reserve attestors),
+    /// executable after `timelock_delay`.
+    pub fn queue_vault_change(ctx: Context<QueueVaultChange>, change: VaultChange) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposer = ctx.accounts.proposer.key();
+        let vault = &mut ctx.accounts.vault_config;
+
+        match change.required_role() {
+            Some(role) => {
+                vault.require_role(&proposer, ctx.accounts.role_assignment.as_deref(), role)?
+            }
+            None => require!(proposer == vault.authority, BondVaultError::Unauthorized),
+        }
+        change.validate()?;
+        if let VaultChange::ReserveAttestor { attestor, .. } = change {
+            require_single_attestor_mode(vault)?;
+            require!(
+                attestor != AttestorSet::address(&vault.key()),
+                BondVaultError::InvalidAttestationConfig
+            );
+        }
 
-        ctx.accounts.vault_config.target_apy_bps = new_apy_bps;
-        msg!("Fallback APY updated to {} bps", new_apy_bps);
+        let id = vault.next_change_id;
+        vault.next_change_id = id.checked_add(1).ok_or(BondVaultError::MathOverflow)?;
+        let eta = now
+            .checked_add(vault.timelock_delay)
+            .ok_or(BondVaultError::MathOverflow)?;
+
+        emit!(VaultChangeQueued {
+            vault: vault.key(),
+            change_id: id,
+            kind: change.kind(),
+            proposer,
+            eta,
+            timestamp: now,
+        });
+
+        let pending = &mut ctx.accounts.pending_change;
+        pending.id = id;
+        pending.vault = vault.key();
+        pending.proposer = proposer;
+        pending.change = change;
+        pending.queued_at = now;
+        pending.eta = eta;
+        pending.bump = ctx.bumps.pending_change;
+
+        msg!("Vault change {} queued, executable at {}", id, eta);
         Ok(())
     }
 
-    /// Admin: configure oracle feed for dynamic pricing.
-    /// Setting oracle_feed to Pubkey::default() disables oracle pricing.
-    pub fn configure_oracle(
-        ctx: Context<ConfigureOracle>,
-        oracle_feed: Pubkey,
-        enabled: bool,
-    ) -> Result<()> {
+    /// Apply a queued vault change once its eta has passed (permissionless).
+    /// An `AttestorSet` change needs the `attestor_set` account, created at the
+    /// executor's expense on first use; the set is overwritten and any open
+    /// round discarded.
+    pub fn execute_vault_change(ctx: Context<ExecuteVaultChange>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pending = &ctx.accounts.pending_change;
+        require!(now >= pending.eta, BondVaultError::TimelockNotElapsed);
+
+        let vault = &mut ctx.accounts.vault_config;
+        match &pending.change {
+            VaultChange::Apy(new_apy_bps) => emit!(VaultApyUpdated {
+                vault: vault.key(),
+                old_apy_bps: vault.target_apy_bps,
+                new_apy_bps: *new_apy_bps,
+                authority: pending.proposer,
+                timestamp: now,
+            }),
+            VaultChange::Oracle {
+                oracle_feed,
+                enabled,
+            } => emit!(OracleConfigured {
+                vault: vault.key(),
+                old_oracle_feed: vault.oracle_feed,
+                new_oracle_feed: *oracle_feed,
+                old_enabled: vault.oracle_enabled,
+                new_enabled: *enabled,
+                authority: pending.proposer,
+                timestamp: now,
+            }),
+            VaultChange::TimelockDelay(delay) => emit!(VaultTimelockDelayUpdated {
+                vault: vault.key(),
+                old_delay: vault.timelock_delay,
+                new_delay: *delay,
+                timestamp: now,
+            }),
+            VaultChange::ReserveAttestor {
+                attestor,
+                max_staleness,
+            } => {
+                require_single_attestor_mode(vault)?;
+                emit!(ReserveAttestorConfigured {
+                    vault: vault.key(),
+                    old_attestor: vault.reserve_attestor,
+                    new_attestor: *attestor,
+                    old_max_staleness: vault.attestation_max_staleness,
+                    new_max_staleness: *max_staleness,
+                    authority: pending.proposer,
+                    timestamp: now,
+                });
+            }
+            VaultChange::AttestorSet {
+                attestors,
+                threshold,
+                window,
+                tolerance_bps,
+            } => {
+                let set = ctx
+                    .accounts
+                    .attestor_set
+                    .as_mut()
+                    .ok_or(BondVaultError::MissingAttestorSet)?;
+                set.configure(
+                    vault.key(),
+                    attestors,
+                    *threshold,
+                    *window,
+                    *tolerance_bps,
+                    ctx.bumps.attestor_set.ok_or(BondVaultError::MissingAttestorSet)?,
+                );
+                vault.reserve_attestor = set.key();
+
+                emit!(AttestorSetConfigured {
+                    vault: vault.key(),
+                    attestor_set: set.key(),
+                    attestors: attestors.clone(),
+                    threshold: *threshold,
+                    window: *window,
+                    tolerance_bps: *tolerance_bps,
+                    authority: pending.proposer,
+                    timestamp: now,
+                });
+            }
+        }
+        pending.change.apply(vault);
+
+        emit!(VaultChangeExecuted {
+            vault: vault.key(),
+            change_id: pending.id,
+            kind: pending.change.kind(),
+            executor: ctx.accounts.executor.key(),
+            timestamp: now,
+        });
+
+        msg!("Vault change {} executed", pending.id);
+        Ok(())
+    }
+
+    /// Admin: drop a queued vault change before it executes.
+    pub fn cancel_vault_change(ctx: Context<CancelVaultChange>) -> Result<()> {
         require!(
             ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
             BondVaultError::Unauthorized
         );
 
-        let vault = &mut ctx.accounts.vault_config;
-        vault.oracle_feed = oracle_feed;
-        vault.oracle_enabled = enabled;
+        let pending = &ctx.accounts.pending_change;
+        emit!(VaultChangeCancelled {
+            vault: ctx.accounts.vault_config.key(),
+            change_id: pending.id,
+            kind: pending.change.kind(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        msg!(
-            "Oracle configured for {}: feed={}, enabled={}",
-            vault.bond_type.as_str(),
-            oracle_feed,
-            enabled
-        );
+        msg!("Vault change {} cancelled", pending.id);
         Ok(())
     }
 
-    /// Admin: configure the reserve attestor authority and staleness parameters.
-    pub fn configure_reserve_attestor(
-        ctx: Context<ConfigureReserveAttestor>,
-        attestor: Pubkey,
-        max_staleness: i64,
+    /// Admin: lengthen the vault timelock delay immediately.
+    /// Shortening it goes through `queue_vault_change`.
+    pub fn increase_vault_timelock_delay(
+        ctx: Context<IncreaseVaultTimelockDelay>,
+        new_delay: i64,
     ) -> Result<()> {
         require!(
             ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
             BondVaultError::Unauthorized
         );
-        require!(max_staleness > 0, BondVaultError::InvalidAttestationConfig);
-
         let vault = &mut ctx.accounts.vault_config;
-        vault.reserve_attestor = attestor;
-        vault.attestation_max_staleness = max_staleness;
-
-        msg!(
-            "Reserve attestor configured for {}: attestor={}, max_staleness={}s",
-            vault.bond_type.as_str(),
-            attestor,
-            max_staleness
+        require!(
+            new_delay > vault.timelock_delay && new_delay <= BondVault::MAX_TIMELOCK_DELAY,
+            BondVaultError::InvalidTimelockDelay
         );
+
+        emit!(VaultTimelockDelayUpdated {
+            vault: vault.key(),
+            old_delay: vault.timelock_delay,
+            new_delay,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        vault.timelock_delay = new_delay;
+
+        msg!("Vault timelock delay increased to {}s", new_delay);
         Ok(())
     }
 
     /// Attestor: submit a Proof-of-Reserve attestation.
     /// Only the configured reserve_attestor can call this.
     /// The attested reserve amount must be verifiable against off-chain custodian records.
+    /// An optional `commitment` records a Merkle root over the custodian holdings so
+    /// individual lots can be proven with `verify_reserve_holding`; omitting it clears
+    /// the previous root.
     pub fn submit_reserve_attestation(
         ctx: Context<SubmitReserveAttestation>,
         attested_reserve: u64,
+        commitment: Option<ReserveCommitment>,
     ) -> Result<()> {
         let vault = &mut ctx.accounts.vault_config;
 
@@ -449,34 +783,245 @@ pub mod stablebond_yield {
             BondVaultError::Unauthorized
         );
         require!(attested_reserve > 0, BondVaultError::InvalidAttestation);
+        if let Some(c) = &commitment {
+            c.validate()?;
+        }
 
         let now = Clock::get()?.unix_timestamp;
-        vault.last_attestation_at = now;
-        vault.attested_reserve = attested_reserve;
+        vault.record_attestation(attested_reserve, commitment, now);
+        push_attestation_history(
+            &mut ctx.accounts.attestation_history,
+            vault,
+            ctx.accounts.attestor.key(),
+            ctx.bumps.attestation_history,
+            now,
+        );
+
+        if vault.has_reserve_shortfall() {
+            emit_reserve_shortfall(vault, now);
+        }
 
         msg!(
-            "Reserve attestation submitted for {}: {} units at {}",
+            "Reserve attestation submitted for {}: {} units at {} (coverage {} bps)",
             vault.bond_type.as_str(),
             attested_reserve,
-            now
+            now,
+            vault.attested_coverage_bps
+        );
+        Ok(())
+    }
+
+    /// Attestor set member: submit a reserve figure for the open round. A round
+    /// opens on the first submission and expires `window` seconds later. Once
+    /// `threshold` members have submitted, the lowest figure (with its holdings
+    /// commitment) is recorded on the vault; a spread above `tolerance_bps`
+    /// emits `AttestationDisagreement`.
+    pub fn submit_quorum_attestation(
+        ctx: Context<SubmitQuorumAttestation>,
+        attested_reserve: u64,
+        commitment: Option<ReserveCommitment>,
+    ) -> Result<()> {
+        let set = &mut ctx.accounts.attestor_set;
+        require!(
+            ctx.accounts.vault_config.reserve_attestor == set.key(),
+            BondVaultError::QuorumNotActive
+        );
+        let slot = set
+            .slot_of(&ctx.accounts.attestor.key())
+            .ok_or(BondVaultError::Unauthorized)?;
+        require!(attested_reserve > 0, BondVaultError::InvalidAttestation);
+        if let Some(c) = &commitment {
+            c.validate()?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        if set.round_start != 0 && now > set.round_start.saturating_add(set.window) {
+            set.clear_round();
+        }
+        if set.round_start == 0 {
+            set.round_start = now;
+        }
+        set.submissions[slot] = attested_reserve;
+        set.commitments[slot] = commitment.unwrap_or_default();
+
+        let submitted: Vec<usize> = (0..set.attestor_count as usize)
+            .filter(|&i| set.submissions[i] > 0)
+            .collect();
+        let vault_key = ctx.accounts.vault_config.key();
+
+        emit!(QuorumAttestationSubmitted {
+            vault: vault_key,
+            attestor: ctx.accounts.attestor.key(),
+            attested_reserve,
+            submissions: submitted.len() as u8,
+            threshold: set.threshold,
+            round_start: set.round_start,
+            timestamp: now,
+        });
+
+        if submitted.len() < set.threshold as usize {
+            msg!(
+                "Quorum attestation {}/{} for {}",
+                submitted.len(),
+                set.threshold,
+                ctx.accounts.vault_config.bond_type.as_str()
+            );
+            return Ok(());
+        }
+
+        // Quorum reached: record the most conservative figure
+        let lowest_slot = submitted
+            .iter()
+            .copied()
+            .min_by_key(|&i| set.submissions[i])
+            .unwrap_or(slot);
+        let lowest = set.submissions[lowest_slot];
+        let highest = submitted
+            .iter()
+            .map(|&i| set.submissions[i])
+            .max()
+            .unwrap_or(lowest);
+        let spread_bps = stablebond_math::ratio_bps(highest - lowest, lowest, Rounding::Up)
+            .unwrap_or(u64::MAX);
+        let lowest_commitment =
+            Some(set.commitments[lowest_slot]).filter(|c| c.merkle_root != [0; 32]);
+
+        if spread_bps > set.tolerance_bps as u64 {
+            emit!(AttestationDisagreement {
+                vault: vault_key,
+                lowest_reserve: lowest,
+                highest_reserve: highest,
+                spread_bps,
+                tolerance_bps: set.tolerance_bps,
+                timestamp: now,
+            });
+        }
+        set.clear_round();
+
+        let vault = &mut ctx.accounts.vault_config;
+        vault.record_attestation(lowest, lowest_commitment, now);
+        push_attestation_history(
+            &mut ctx.accounts.attestation_history,
+            vault,
+            set.key(),
+            ctx.bumps.attestation_history,
+            now,
+        );
+        if vault.has_reserve_shortfall() {
+            emit_reserve_shortfall(vault, now);
+        }
+
+        emit!(QuorumAttestationRecorded {
+            vault: vault_key,
+            attested_reserve: lowest,
+            highest_reserve: highest,
+            spread_bps,
+            submissions: submitted.len() as u8,
+            timestamp: now,
+        });
+
+        msg!(
+            "Quorum attestation recorded for {}: {} units (spread {} bps, coverage {} bps)",
+            vault.bond_type.as_str(),
+            lowest,
+            spread_bps,
+            vault.attested_coverage_bps
+        );
+        Ok(())
+    }
+
+    /// Permissionless: prove that a custodian holding is included in the
+    /// latest attested reserve. Fails unless `proof` leads from the holding's
+    /// leaf to `reserve_merkle_root`.
+    pub fn verify_reserve_holding(
+        ctx: Context<VerifyReserveHolding>,
+        holding: ReserveHolding,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let vault = &ctx.accounts.vault_config;
+        require!(
+            vault.reserve_merkle_root != [0; 32],
+            BondVaultError::NoReserveRoot
+        );
+        require!(
+            holding.verify(&proof, &vault.reserve_merkle_root),
+            BondVaultError::InvalidReserveProof
+        );
+
+        emit!(ReserveHoldingVerified {
+            vault: vault.key(),
+            security_id: holding.security_id,
+            quantity: holding.quantity,
+            price: holding.price,
+            merkle_root: vault.reserve_merkle_root,
+            attested_at: vault.last_attestation_at,
+        });
+
+        msg!(
+            "Reserve holding verified for {}: {} units at {}",
+            vault.bond_type.as_str(),
+            holding.quantity,
+            holding.price
+        );
+        Ok(())
+    }
+
+    /// Admin or AttestorAdmin: set the minimum reserve coverage ratio in bps
+    /// (attested reserve vs. total_shares * nav_per_share). Below it, accrual
+    /// pauses and deposits are rejected. 0 disables the check.
+    pub fn set_min_coverage(
+        ctx: Context<SetMinCoverage>,
+        min_coverage_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.vault_config.require_role(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.role_assignment.as_deref(),
+            Role::AttestorAdmin,
+        )?;
+
+        let vault = &mut ctx.accounts.vault_config;
+        let old_min_coverage_bps = vault.min_coverage_bps;
+        vault.min_coverage_bps = min_coverage_bps;
+
+        emit!(MinCoverageUpdated {
+            vault: vault.key(),
+            old_min_coverage_bps,
+            new_min_coverage_bps: min_coverage_bps,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Minimum reserve coverage for {}: {} → {} bps",
+            vault.bond_type.as_str(),
+            old_min_coverage_bps,
+            min_coverage_bps
         );
         Ok(())
     }
 
-    /// Admin: toggle the legacy immediate withdrawal flag.
+    /// Admin or Pauser: toggle the legacy immediate withdrawal flag.
     /// When enabled, users can bypass the cooldown-based withdrawal flow.
     /// Use sparingly — intended for emergency liquidity situations only.
     pub fn set_immediate_withdraw(
         ctx: Context<SetImmediateWithdraw>,
         allow: bool,
     ) -> Result<()> {
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
-            BondVaultError::Unauthorized
-        );
+        ctx.accounts.vault_config.require_role(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.role_assignment.as_deref(),
+            Role::Pauser,
+        )?;
 
         ctx.accounts.vault_config.allow_immediate_withdraw = allow;
 
+        emit!(ImmediateWithdrawSet {
+            vault: ctx.accounts.vault_config.key(),
+            allow,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         msg!(
             "Immediate withdraw {} for {}",
             if allow { "enabled" } else { "disabled" },
@@ -485,349 +1030,1361 @@ pub mod stablebond_yield {
         Ok(())
     }
 
-    /// Keeper crank with reward: accrue yield and pay the caller a small incentive.
-    /// This enables decentralized keeper networks by embedding rewards in the program.
-    /// Reward = 0.01% of total_deposits, capped at 10_000 minor units (~$0.01).
-    pub fn accrue_yield_incentivized(ctx: Context<AccrueYieldIncentivized>) -> Result<()> {
-        let vault = &mut ctx.accounts.vault_config;
-        require!(vault.is_active, BondVaultError::VaultNotActive);
+    /// Admin: grant or revoke operational roles (bitmask of `Role`) for a key on this vault.
+    pub fn set_member_roles(
+        ctx: Context<SetVaultMemberRoles>,
+        member: Pubkey,
+        roles: u8,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
+            BondVaultError::Unauthorized
+        );
+        require!(
+            roles & !Role::ALL_MASK == 0,
+            BondVaultError::InvalidRoleMask
+        );
 
         let now = Clock::get()?.unix_timestamp;
+        let vault_key = ctx.accounts.vault_config.key();
+        let assignment = &mut ctx.accounts.role_assignment;
+        let old_roles = assignment.roles;
+
+        assignment.member = member;
+        assignment.vault = vault_key;
+        assignment.roles = roles;
+        assignment.granted_by = ctx.accounts.authority.key();
+        assignment.updated_at = now;
+        assignment.bump = ctx.bumps.role_assignment;
+
+        emit!(VaultRolesUpdated {
+            vault: vault_key,
+            bond_type: ctx.accounts.vault_config.bond_type.as_u8(),
+            member,
+            old_roles,
+            new_roles: roles,
+            timestamp: now,
+        });
+
+        msg!("Vault roles for {} set to {:#07b}", member, roles);
+        Ok(())
+    }
 
-        // If bond has matured, stop accruing
-        if vault.maturity_date > 0 && now >= vault.maturity_date {
-            return Ok(());
-        }
-
-        // If reserve attestor is configured, check attestation freshness
-        if vault.reserve_attestor != Pubkey::default() {
-            let staleness = now - vault.last_attestation_at;
-            if staleness > vault.attestation_max_staleness {
-                msg!("Reserve attestation stale, pausing accrual");
-                return Ok(());
-            }
-        }
-
-        let elapsed = (now - vault.last_accrual) as u64;
-        if elapsed == 0 || vault.total_shares == 0 {
-            return Ok(());
-        }
-
-        // Minimum 30 seconds between incentivized cranks to prevent spam
-        require!(elapsed >= 30, BondVaultError::CrankTooFrequent);
+    /// Admin or Pauser: set the vault pause bitmask (bits of `PauseFlag`).
+    pub fn set_vault_pause_flags(ctx: Context<SetVaultPauseFlags>, paused_ops: u8) -> Result<()> {
+        ctx.accounts.vault_config.require_role(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.role_assignment.as_deref(),
+            Role::Pauser,
+        )?;
+        require!(
+            paused_ops & !PauseFlag::ALL_MASK == 0,
+            BondVaultError::InvalidPauseMask
+        );
 
-        // Use target_apy_bps for incentivized path (oracle path uses accrue_yield)
-        let apy = vault.target_apy_bps as u128;
-
-        let accrual = (vault.nav_per_share as u128)
-            .checked_mul(apy)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_mul(elapsed as u128)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_div(10_000u128 * SECONDS_PER_YEAR as u128)
-            .ok_or(BondVaultError::MathOverflow)?;
+        let vault = &mut ctx.accounts.vault_config;
+        let old_flags = vault.paused_ops;
+        vault.paused_ops = paused_ops;
+
+        emit!(VaultPauseFlagsUpdated {
+            vault: vault.key(),
+            bond_type: vault.bond_type.as_u8(),
+            old_flags,
+            new_flags: paused_ops,
+            paused: paused_ops & !old_flags,
+            resumed: old_flags & !paused_ops,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        vault.nav_per_share = vault
-            .nav_per_share
-            .checked_add(accrual as u64)
-            .ok_or(BondVaultError::MathOverflow)?;
-        vault.last_accrual = now;
+        msg!(
+            "{} vault pause flags: {:#08b} → {:#08b}",
+            vault.bond_type.as_str(),
+            old_flags,
+            paused_ops
+        );
+        Ok(())
+    }
 
-        // Calculate keeper reward: 0.01% of total_deposits, capped at 10_000 (0.01 settlement units)
-        let reward = (vault.total_deposits as u128)
-            .checked_mul(1)
-            .ok_or(BondVaultError::MathOverflow)?
-            .checked_div(10_000)
-            .ok_or(BondVaultError::MathOverflow)? as u64;
-        let capped_reward = reward.min(10_000);
+    /// Admin: propose a new vault authority (step 1 of 2).
+    /// Proposing Pubkey::default() cancels a pending transfer.
+    pub fn propose_authority(
+        ctx: Context<ProposeVaultAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
+            BondVaultError::Unauthorized
+        );
 
-        // Extract values before dropping the mutable borrow for the CPI
-        let bond_type_byte = vault.bond_type.as_u8();
-        let authority_key = vault.authority;
-        let bump = vault.bump;
-        let nav = vault.nav_per_share;
-        let bond_name = vault.bond_type.as_str();
+        let vault = &mut ctx.accounts.vault_config;
+        vault.pending_authority = new_authority;
 
-        if capped_reward > 0 && ctx.accounts.currency_vault.amount > capped_reward {
-            let vault_seeds: &[&[u8]] = &[
-                BondVault::SEED,
-                authority_key.as_ref(),
-                std::slice::from_ref(&bond_type_byte),
-                &[bump],
-            ];
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.currency_vault.to_account_info(),
-                        to: ctx.accounts.keeper_token.to_account_info(),
-                        authority: ctx.accounts.vault_config.to_account_info(),
-                    },
-                    &[vault_seeds],
-                ),
-                capped_reward,
-            )?;
-        }
+        emit!(VaultAuthorityProposed {
+            vault: vault.key(),
+            bond_type: vault.bond_type.as_u8(),
+            authority: vault.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         msg!(
-            "Incentivized yield accrued for {}: NAV {}, reward {} to keeper",
-            bond_name,
-            nav,
-            capped_reward
+            "Authority transfer for {} proposed to {}",
+            vault.bond_type.as_str(),
+            new_authority
         );
         Ok(())
     }
-}
-
-// ─── Account Contexts ──────────────────────────────────────────────────────────
 
-#[derive(Accounts)]
-#[instruction(bond_type: BondType)]
-pub struct InitializeVault<'info> {
-    #[account(mut)]
+    /// Pending authority: accept the vault authority transfer (step 2 of 2).
+    pub fn accept_authority(ctx: Context<AcceptVaultAuthority>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault_config;
+        require!(
+            vault.pending_authority != Pubkey::default(),
+            BondVaultError::NoPendingAuthority
+        );
+        require!(
+            ctx.accounts.new_authority.key() == vault.pending_authority,
+            BondVaultError::Unauthorized
+        );
+
+        let old_authority = vault.authority;
+        vault.authority = vault.pending_authority;
+        vault.pending_authority = Pubkey::default();
+
+        emit!(VaultAuthorityAccepted {
+            vault: vault.key(),
+            bond_type: vault.bond_type.as_u8(),
+            old_authority,
+            new_authority: vault.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Authority for {} transferred to {}",
+            vault.bond_type.as_str(),
+            vault.authority
+        );
+        Ok(())
+    }
+
+    /// Admin: move a legacy vault (seeded `["bond_vault", authority,
+    /// bond_type]`, from before vault PDAs dropped the authority key) into
+    /// this bond type's vault. Its whole currency balance moves across and is
+    /// credited at the current NAV as shares reserved for legacy holders, who
+    /// swap with `migrate_shares`. The legacy vault is deactivated. Signed by
+    /// the authority of both vaults.
+    pub fn migrate_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateVault<'info>>,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            authority == ctx.accounts.vault_config.authority,
+            BondVaultError::Unauthorized
+        );
+
+        let legacy_info = ctx.accounts.legacy_vault.to_account_info();
+        let mut legacy = LegacyBondVault::load(&legacy_info)?;
+        require!(
+            legacy.is_active && legacy.currency_mint == ctx.accounts.vault_config.currency_mint,
+            BondVaultError::InvalidLegacyVault
+        );
+
+        let bond_type_byte = legacy.bond_type.as_u8();
+        let legacy_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            authority.as_ref(),
+            std::slice::from_ref(&bond_type_byte),
+            &[legacy.bump],
+        ];
+        let moved = TokenTransfer {
+            token_program: &ctx.accounts.token_program,
+            mint: &ctx.accounts.currency_mint,
+            from: &ctx.accounts.legacy_currency_vault,
+            authority: legacy_info.clone(),
+            hook_accounts: ctx.remaining_accounts,
+        }
+        .send_net::<BondVaultError>(
+            &mut ctx.accounts.currency_vault,
+            ctx.accounts.legacy_currency_vault.amount,
+            &[legacy_seeds],
+        )?;
+
+        let legacy_shares = ctx.accounts.legacy_share_mint.supply;
+        let reserved = ctx.accounts.vault_config.shares_for_amount(moved)?;
+        require!(legacy_shares > 0 && reserved > 0, BondVaultError::ZeroShares);
+
+        let now = Clock::get()?.unix_timestamp;
+        let vault = &mut ctx.accounts.vault_config;
+        vault.total_deposits = vault
+            .total_deposits
+            .checked_add(moved)
+            .ok_or(BondVaultError::MathOverflow)?;
+        vault.total_shares = vault
+            .total_shares
+            .checked_add(reserved)
+            .ok_or(BondVaultError::MathOverflow)?;
+        vault.record_inflow(moved);
+
+        let migration = &mut ctx.accounts.migration;
+        migration.vault = vault.key();
+        migration.legacy_vault = legacy_info.key();
+        migration.legacy_share_mint = ctx.accounts.legacy_share_mint.key();
+        migration.legacy_shares_outstanding = legacy_shares;
+        migration.reserved_shares = reserved;
+        migration.migrated_at = now;
+        migration.bump = ctx.bumps.migration;
+
+        legacy.is_active = false;
+        legacy.total_deposits = 0;
+        legacy.store(&legacy_info)?;
+
+        emit!(VaultMigrated {
+            vault: vault.key(),
+            bond_type: bond_type_byte,
+            legacy_vault: legacy_info.key(),
+            amount: moved,
+            legacy_shares,
+            reserved_shares: reserved,
+            nav_per_share: vault.nav_per_share,
+            authority,
+            timestamp: now,
+        });
+
+        msg!(
+            "Legacy {} vault migrated: {} moved, {} shares reserved",
+            vault.bond_type.as_str(),
+            moved,
+            reserved
+        );
+        Ok(())
+    }
+
+    /// Swap all of the signer's legacy vault shares for shares of the vault
+    /// they were migrated into, pro rata to the reserve `migrate_vault` set
+    /// aside. Legacy shares are burned.
+    pub fn migrate_shares(ctx: Context<MigrateShares>) -> Result<()> {
+        let legacy_shares = ctx.accounts.user_legacy_shares.amount;
+        require!(legacy_shares > 0, BondVaultError::ZeroShares);
+        let shares = ctx.accounts.migration.shares_for_legacy(legacy_shares)?;
+        require!(shares > 0, BondVaultError::ZeroShares);
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.legacy_token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.legacy_share_mint.to_account_info(),
+                    from: ctx.accounts.user_legacy_shares.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            legacy_shares,
+        )?;
+
+        let vault = &ctx.accounts.vault_config;
+        let bond_type_byte = vault.bond_type.as_u8();
+        let vault_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            std::slice::from_ref(&bond_type_byte),
+            &[vault.bump],
+        ];
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.share_token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.user_shares_ata.to_account_info(),
+                    authority: ctx.accounts.vault_config.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            shares,
+        )?;
+
+        let migration = &mut ctx.accounts.migration;
+        migration.legacy_shares_outstanding = migration
+            .legacy_shares_outstanding
+            .checked_sub(legacy_shares)
+            .ok_or(BondVaultError::MathOverflow)?;
+        migration.reserved_shares = migration
+            .reserved_shares
+            .checked_sub(shares)
+            .ok_or(BondVaultError::MathOverflow)?;
+
+        emit!(SharesMigrated {
+            vault: migration.vault,
+            user: ctx.accounts.user.key(),
+            legacy_shares,
+            shares,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Migrated {} legacy shares to {} shares", legacy_shares, shares);
+        Ok(())
+    }
+
+    /// Admin: set the compliance officer allowed to freeze share token accounts.
+    pub fn set_compliance_officer(
+        ctx: Context<SetComplianceOfficer>,
+        officer: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.vault_config.authority,
+            BondVaultError::Unauthorized
+        );
+
+        let vault = &mut ctx.accounts.vault_config;
+        let old_officer = vault.compliance_officer;
+        vault.compliance_officer = officer;
+
+        emit!(ComplianceOfficerSet {
+            vault: vault.key(),
+            bond_type: vault.bond_type.as_u8(),
+            old_officer,
+            new_officer: officer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Compliance officer for {} set to {}",
+            vault.bond_type.as_str(),
+            officer
+        );
+        Ok(())
+    }
+
+    /// Compliance officer: freeze a holder's share token account so the shares
+    /// cannot be transferred or burned (sanctions, legal holds).
+    pub fn freeze_shares(ctx: Context<SetSharesFreeze>, reason: FreezeReason) -> Result<()> {
+        let vault = &ctx.accounts.vault_config;
+        require!(
+            vault.compliance_officer != Pubkey::default(),
+            BondVaultError::NoComplianceOfficerConfigured
+        );
+        require!(
+            ctx.accounts.compliance_officer.key() == vault.compliance_officer,
+            BondVaultError::Unauthorized
+        );
+
+        let bond_type_byte = vault.bond_type.as_u8();
+        let vault_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            std::slice::from_ref(&bond_type_byte),
+            &[vault.bump],
+        ];
+        token_interface::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.share_token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.shares_account.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[vault_seeds],
+        ))?;
+
+        emit!(SharesFrozen {
+            vault: ctx.accounts.vault_config.key(),
+            bond_type: bond_type_byte,
+            owner: ctx.accounts.shares_account.owner,
+            token_account: ctx.accounts.shares_account.key(),
+            reason,
+            compliance_officer: ctx.accounts.compliance_officer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Shares frozen for {} ({})",
+            ctx.accounts.shares_account.owner,
+            reason.as_str()
+        );
+        Ok(())
+    }
+
+    /// Compliance officer: thaw a previously frozen share token account.
+    pub fn thaw_shares(ctx: Context<SetSharesFreeze>) -> Result<()> {
+        let vault = &ctx.accounts.vault_config;
+        require!(
+            vault.compliance_officer != Pubkey::default(),
+            BondVaultError::NoComplianceOfficerConfigured
+        );
+        require!(
+            ctx.accounts.compliance_officer.key() == vault.compliance_officer,
+            BondVaultError::Unauthorized
+        );
+
+        let bond_type_byte = vault.bond_type.as_u8();
+        let vault_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            std::slice::from_ref(&bond_type_byte),
+            &[vault.bump],
+        ];
+        token_interface::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.share_token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.shares_account.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            &[vault_seeds],
+        ))?;
+
+        emit!(SharesThawed {
+            vault: ctx.accounts.vault_config.key(),
+            bond_type: bond_type_byte,
+            owner: ctx.accounts.shares_account.owner,
+            token_account: ctx.accounts.shares_account.key(),
+            compliance_officer: ctx.accounts.compliance_officer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Shares thawed for {}", ctx.accounts.shares_account.owner);
+        Ok(())
+    }
+
+    /// Admin: set the Token-2022 share mint's name, symbol and URI, e.g. to
+    /// point wallets at an off-chain JSON with a logo. The authority pays any
+    /// extra rent for longer fields.
+    pub fn set_share_metadata(
+        ctx: Context<SetShareMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        let vault = &ctx.accounts.vault_config;
+        require!(
+            ctx.accounts.authority.key() == vault.authority,
+            BondVaultError::Unauthorized
+        );
+        require!(
+            name.len() <= share_mint::MAX_NAME_LEN
+                && symbol.len() <= share_mint::MAX_SYMBOL_LEN
+                && uri.len() <= share_mint::MAX_URI_LEN,
+            BondVaultError::InvalidShareMetadata
+        );
+
+        let bond_type_byte = vault.bond_type.as_u8();
+        let vault_seeds: &[&[u8]] = &[
+            BondVault::SEED,
+            std::slice::from_ref(&bond_type_byte),
+            &[vault.bump],
+        ];
+        ShareMetadata {
+            payer: ctx.accounts.authority.to_account_info(),
+            vault: ctx.accounts.vault_config.to_account_info(),
+            share_mint: ctx.accounts.share_mint.to_account_info(),
+            token_program: &ctx.accounts.share_token_program,
+            system_program: ctx.accounts.system_program.to_account_info(),
+        }
+        .write(
+            vec![
+                (Field::Name, name.clone()),
+                (Field::Symbol, symbol.clone()),
+                (Field::Uri, uri.clone()),
+            ],
+            vault_seeds,
+        )?;
+
+        emit!(ShareMetadataUpdated {
+            vault: ctx.accounts.vault_config.key(),
+            bond_type: bond_type_byte,
+            name: name.clone(),
+            symbol,
+            uri,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Share metadata updated: {}", name);
+        Ok(())
+    }
+
+    /// Keeper crank with reward: accrue yield and pay the caller a small incentive.
+    /// This enables decentralized keeper networks by embedding rewards in the program.
+    /// Reward = 0.01% of total_deposits, capped at 10_000 minor units (~$0.01).
+    pub fn accrue_yield_incentivized<'info>(
+        ctx: Context<'_, '_, '_, 'info, AccrueYieldIncentivized<'info>>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault_config;
+        vault.require_unpaused(PauseFlag::NavUpdates)?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // Stop after maturity; pause on a stale attestation or reserve shortfall
+        match vault.halt_accrual(now) {
+            Some(AccrualHalt::Matured) => return Ok(()),
+            Some(AccrualHalt::StaleAttestation { .. }) => {
+                msg!("Reserve attestation stale, pausing accrual");
+                return Ok(());
+            }
+            Some(AccrualHalt::ReserveShortfall) => {
+                emit_reserve_shortfall(vault, now);
+                msg!("Reserve coverage below minimum, pausing accrual");
+                return Ok(());
+            }
+            None => {}
+        }
+
+        let elapsed = (now - vault.last_accrual) as u64;
+        if elapsed == 0 || vault.total_shares == 0 {
+            return Ok(());
+        }
+
+        // Minimum 30 seconds between incentivized cranks to prevent spam
+        require!(elapsed >= 30, BondVaultError::CrankTooFrequent);
+
+        // Use target_apy_bps for incentivized path (oracle path uses accrue_yield)
+        let apy = vault.target_apy_bps as u64;
+        let old_nav = vault.nav_per_share;
+
+        vault.nav_per_share = vault.accrued_nav(apy, elapsed)?;
+        vault.last_accrual = now;
+
+        // Calculate keeper reward: 0.01% of total_deposits, capped at 10_000 (0.01 settlement units)
+        let capped_reward = vault.keeper_reward()?;
+
+        // Extract values before dropping the mutable borrow for the CPI
+        let bond_type_byte = vault.bond_type.as_u8();
+        let bump = vault.bump;
+        let nav = vault.nav_per_share;
+        let bond_name = vault.bond_type.as_str();
+
+        let reward_paid = capped_reward > 0 && ctx.accounts.currency_vault.amount > capped_reward;
+        if reward_paid {
+            let vault_seeds: &[&[u8]] = &[
+                BondVault::SEED,
+                std::slice::from_ref(&bond_type_byte),
+                &[bump],
+            ];
+            TokenTransfer {
+                token_program: &ctx.accounts.token_program,
+                mint: &ctx.accounts.currency_mint,
+                from: &ctx.accounts.currency_vault,
+                authority: ctx.accounts.vault_config.to_account_info(),
+                hook_accounts: ctx.remaining_accounts,
+            }
+            .send(&ctx.accounts.keeper_token, capped_reward, &[vault_seeds])?;
+            ctx.accounts.vault_config.record_outflow(capped_reward);
+        }
+
+        emit!(YieldAccrued {
+            vault: ctx.accounts.vault_config.key(),
+            bond_type: bond_type_byte,
+            old_nav,
+            new_nav: nav,
+            apy_bps: apy,
+            oracle_priced: false,
+            oracle_price: 0,
+            elapsed,
+            keeper: ctx.accounts.keeper.key(),
+            keeper_reward: if reward_paid { capped_reward } else { 0 },
+            timestamp: now,
+        });
+
+        msg!(
+            "Incentivized yield accrued for {}: NAV {}, reward {} to keeper",
+            bond_name,
+            nav,
+            capped_reward
+        );
+        Ok(())
+    }
+}
+
+// ─── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Append the vault's just-recorded attestation to its history and emit it.
+fn push_attestation_history(
+    history: &mut Account<AttestationHistory>,
+    vault: &Account<BondVault>,
+    attestor: Pubkey,
+    bump: u8,
+    now: i64,
+) {
+    if history.vault == Pubkey::default() {
+        history.vault = vault.key();
+        history.bump = bump;
+    }
+    let seq = history.push(AttestationRecord {
+        timestamp: now,
+        attested_reserve: vault.attested_reserve,
+        attestor,
+        merkle_root: vault.reserve_merkle_root,
+        nav_per_share: vault.nav_per_share,
+        total_shares: vault.total_shares,
+    });
+    emit!(ReserveAttestationRecorded {
+        vault: vault.key(),
+        seq,
+        attestor,
+        attested_reserve: vault.attested_reserve,
+        merkle_root: vault.reserve_merkle_root,
+        nav_per_share: vault.nav_per_share,
+        total_shares: vault.total_shares,
+        coverage_bps: vault.attested_coverage_bps,
+        timestamp: now,
+    });
+}
+
+/// Single-attestor changes may not undo a configured quorum.
+fn require_single_attestor_mode(vault: &Account<BondVault>) -> Result<()> {
+    require!(
+        vault.reserve_attestor != AttestorSet::address(&vault.key()),
+        BondVaultError::QuorumActive
+    );
+    Ok(())
+}
+
+fn emit_reserve_shortfall(vault: &Account<BondVault>, now: i64) {
+    emit!(ReserveShortfall {
+        vault: vault.key(),
+        bond_type: vault.bond_type.as_u8(),
+        attested_reserve: vault.attested_reserve,
+        coverage_bps: vault.current_coverage_bps(),
+        min_coverage_bps: vault.min_coverage_bps,
+        timestamp: now,
+    });
+}
+
+// ─── Account Contexts ──────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(bond_type: BondType)]
+pub struct InitializeVault<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Upgrade authority of this program. Each bond type has one vault, so
+    /// only the deployer may create it.
+    pub deployer: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ BondVaultError::NotUpgradeAuthority,
+    )]
+    pub program: Program<'info, crate::program::StablebondYield>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(deployer.key())
+            @ BondVaultError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
     #[account(
         init,
         payer = authority,
         space = BondVault::LEN,
-        seeds = [BondVault::SEED, authority.key().as_ref(), &[bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[bond_type.as_u8()]],
+        bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    #[account(mint::token_program = token_program)]
+    pub currency_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Share mint, created by the handler under `share_token_program` with
+    /// the vault as mint and freeze authority
+    /// CHECK: Address fixed by seeds; created and initialized in the handler
+    #[account(
+        mut,
+        seeds = [BondVault::SHARE_MINT_SEED, &[bond_type.as_u8()]],
+        bump,
+    )]
+    pub share_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [BondVault::CURRENCY_VAULT_SEED, &[bond_type.as_u8()]],
         bump,
+        token::mint = currency_mint,
+        token::authority = vault_config,
+        token::token_program = token_program,
+    )]
+    pub currency_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Authority's currency account funding the locked seed deposit
+    #[account(
+        mut,
+        constraint = authority_currency.owner == authority.key(),
+        constraint = authority_currency.mint == currency_mint.key(),
+    )]
+    pub authority_currency: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Holds the seed deposit's shares; owned by the vault and never moved
+    /// CHECK: Address fixed by seeds; created and initialized in the handler
+    #[account(
+        mut,
+        seeds = [BondVault::LOCKED_SHARES_SEED, &[bond_type.as_u8()]],
+        bump,
+    )]
+    pub locked_shares: UncheckedAccount<'info>,
+
+    /// Token program of the currency mint
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program the share mint is created under (SPL Token or Token-2022)
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::CURRENCY_VAULT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.vault_bump,
+    )]
+    pub currency_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = vault_config.currency_mint)]
+    pub currency_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SHARE_MINT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.share_mint_bump,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_currency.owner == user.key(),
+        constraint = user_currency.mint == vault_config.currency_mint,
+    )]
+    pub user_currency: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// User's share token ATA
+    #[account(
+        mut,
+        constraint = user_shares_ata.owner == user.key(),
+        constraint = user_shares_ata.mint == share_mint.key(),
+    )]
+    pub user_shares_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserShares::LEN,
+        seeds = [UserShares::SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_shares: Account<'info, UserShares>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    /// The share mint's transfer-hook `ExtraAccountMetaList`; required with
+    /// the two KYC accounts below when the share mint has a transfer hook
+    /// CHECK: Owner and address checked by `ShareKyc`
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+
+    /// Accredit WhitelistEntry PDA
+    /// CHECK: Owner and layout checked by `ShareKyc`
+    pub whitelist_entry: Option<UncheckedAccount<'info>>,
+
+    /// Sovereign Identity PDA
+    /// CHECK: Owner and layout checked by `ShareKyc`
+    pub sovereign_identity: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::CURRENCY_VAULT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.vault_bump,
+    )]
+    pub currency_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = vault_config.currency_mint)]
+    pub currency_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SHARE_MINT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.share_mint_bump,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_currency.owner == user.key(),
+        constraint = user_currency.mint == vault_config.currency_mint,
+    )]
+    pub user_currency: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_shares_ata.owner == user.key(),
+        constraint = user_shares_ata.mint == share_mint.key(),
+    )]
+    pub user_shares_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Deposit metadata; optional, since share tokens may have arrived by
+    /// transfer
+    #[account(
+        mut,
+        seeds = [UserShares::SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = user_shares.bump,
+    )]
+    pub user_shares: Option<Account<'info, UserShares>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SyncUserShares<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
 
-    pub currency_mint: Account<'info, Mint>,
-
     #[account(
-        init,
-        payer = authority,
-        seeds = [BondVault::SHARE_MINT_SEED, authority.key().as_ref(), &[bond_type.as_u8()]],
-        bump,
-        mint::decimals = 6,
-        mint::authority = vault_config,
+        constraint = user_shares_ata.owner == user.key(),
+        constraint = user_shares_ata.mint == vault_config.share_mint,
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub user_shares_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
-        init,
-        payer = authority,
-        seeds = [BondVault::CURRENCY_VAULT_SEED, authority.key().as_ref(), &[bond_type.as_u8()]],
+        init_if_needed,
+        payer = user,
+        space = UserShares::LEN,
+        seeds = [UserShares::SEED, vault_config.key().as_ref(), user.key().as_ref()],
         bump,
-        token::mint = currency_mint,
-        token::authority = vault_config,
     )]
-    pub currency_vault: Account<'info, TokenAccount>,
+    pub user_shares: Account<'info, UserShares>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct Deposit<'info> {
+pub struct AccrueYield<'info> {
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    /// Bond price oracle feed. Required when oracle_enabled=true.
+    /// CHECK: Validated against vault_config.oracle_feed in handler.
+    pub bond_price_oracle: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueYieldIncentivized<'info> {
+    /// Keeper that triggers the crank and receives reward
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub keeper: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
 
     #[account(
         mut,
-        seeds = [BondVault::CURRENCY_VAULT_SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::CURRENCY_VAULT_SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.vault_bump,
     )]
-    pub currency_vault: Account<'info, TokenAccount>,
+    pub currency_vault: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(address = vault_config.currency_mint)]
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+
+    /// Keeper's token account to receive reward
     #[account(
         mut,
-        seeds = [BondVault::SHARE_MINT_SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
-        bump = vault_config.share_mint_bump,
+        constraint = keeper_token.owner == keeper.key(),
+        constraint = keeper_token.mint == vault_config.currency_mint,
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub keeper_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SetMinCoverage<'info> {
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        constraint = user_currency.owner == user.key(),
-        constraint = user_currency.mint == vault_config.currency_mint,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    /// Signer's role assignment; not needed when the signer is the authority
+    #[account(
+        seeds = [VaultRole::SEED, vault_config.key().as_ref(), authority.key().as_ref()],
+        bump = role_assignment.bump,
     )]
-    pub user_currency: Account<'info, TokenAccount>,
+    pub role_assignment: Option<Account<'info, VaultRole>>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitReserveAttestation<'info> {
+    /// Pays for the history account on the vault's first attestation
+    #[account(mut)]
+    pub attestor: Signer<'info>,
 
-    /// User's share token ATA
     #[account(
         mut,
-        constraint = user_shares_ata.owner == user.key(),
-        constraint = user_shares_ata.mint == share_mint.key(),
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
     )]
-    pub user_shares_ata: Account<'info, TokenAccount>,
+    pub vault_config: Account<'info, BondVault>,
 
     #[account(
         init_if_needed,
-        payer = user,
-        space = UserShares::LEN,
-        seeds = [UserShares::SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        payer = attestor,
+        space = AttestationHistory::LEN,
+        seeds = [AttestationHistory::SEED, vault_config.key().as_ref()],
         bump,
     )]
-    pub user_shares: Account<'info, UserShares>,
+    pub attestation_history: Box<Account<'info, AttestationHistory>>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Withdraw<'info> {
+pub struct SubmitQuorumAttestation<'info> {
+    /// Pays for the history account on the vault's first attestation
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub attestor: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
 
     #[account(
         mut,
-        seeds = [BondVault::CURRENCY_VAULT_SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
-        bump = vault_config.vault_bump,
+        seeds = [AttestorSet::SEED, vault_config.key().as_ref()],
+        bump = attestor_set.bump,
+    )]
+    pub attestor_set: Box<Account<'info, AttestorSet>>,
+
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = AttestationHistory::LEN,
+        seeds = [AttestationHistory::SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub attestation_history: Box<Account<'info, AttestationHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyReserveHolding<'info> {
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
     )]
-    pub currency_vault: Account<'info, TokenAccount>,
+    pub vault_config: Account<'info, BondVault>,
+}
+
+#[derive(Accounts)]
+pub struct SetImmediateWithdraw<'info> {
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SHARE_MINT_SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
-        bump = vault_config.share_mint_bump,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    /// Signer's role assignment; not needed when the signer is the authority
+    #[account(
+        seeds = [VaultRole::SEED, vault_config.key().as_ref(), authority.key().as_ref()],
+        bump = role_assignment.bump,
     )]
-    pub share_mint: Account<'info, Mint>,
+    pub role_assignment: Option<Account<'info, VaultRole>>,
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceOfficer<'info> {
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        constraint = user_currency.owner == user.key(),
-        constraint = user_currency.mint == vault_config.currency_mint,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+}
+
+#[derive(Accounts)]
+pub struct SetSharesFreeze<'info> {
+    pub compliance_officer: Signer<'info>,
+
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        seeds = [BondVault::SHARE_MINT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.share_mint_bump,
     )]
-    pub user_currency: Account<'info, TokenAccount>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
+    /// Holder's share token account to freeze or thaw
     #[account(
         mut,
-        constraint = user_shares_ata.owner == user.key(),
-        constraint = user_shares_ata.mint == share_mint.key(),
+        constraint = shares_account.mint == share_mint.key(),
+    )]
+    pub shares_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SetShareMetadata<'info> {
+    /// Vault authority; pays any extra rent for the metadata
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
     )]
-    pub user_shares_ata: Account<'info, TokenAccount>,
+    pub vault_config: Account<'info, BondVault>,
 
     #[account(
         mut,
-        seeds = [UserShares::SEED, vault_config.key().as_ref(), user.key().as_ref()],
-        bump = user_shares.bump,
+        seeds = [BondVault::SHARE_MINT_SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.share_mint_bump,
     )]
-    pub user_shares: Account<'info, UserShares>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
-    pub token_program: Program<'info, Token>,
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AccrueYield<'info> {
+pub struct ProposeVaultAuthority<'info> {
+    pub authority: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
+}
 
-    /// Bond price oracle feed. Required when oracle_enabled=true.
-    /// CHECK: Validated against vault_config.oracle_feed in handler.
-    pub bond_price_oracle: AccountInfo<'info>,
+#[derive(Accounts)]
+pub struct AcceptVaultAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
 }
 
 #[derive(Accounts)]
-pub struct AccrueYieldIncentivized<'info> {
-    /// Keeper that triggers the crank and receives reward
+pub struct MigrateVault<'info> {
     #[account(mut)]
-    pub keeper: Signer<'info>,
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Box<Account<'info, BondVault>>,
+
+    /// Legacy vault of the same authority and bond type
+    /// CHECK: Address fixed by seeds; owner, discriminator and layout checked
+    /// by `LegacyBondVault::load`
+    #[account(
+        mut,
+        seeds = [BondVault::SEED, authority.key().as_ref(), &[vault_config.bond_type.as_u8()]],
+        bump,
+    )]
+    pub legacy_vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::CURRENCY_VAULT_SEED, authority.key().as_ref(), &[vault_config.bond_type.as_u8()]],
+        bump,
+        token::mint = currency_mint,
+        token::authority = legacy_vault,
+    )]
+    pub legacy_currency_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [BondVault::SHARE_MINT_SEED, authority.key().as_ref(), &[vault_config.bond_type.as_u8()]],
+        bump,
+    )]
+    pub legacy_share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VaultMigration::LEN,
+        seeds = [VaultMigration::SEED, legacy_vault.key().as_ref()],
+        bump,
+    )]
+    pub migration: Box<Account<'info, VaultMigration>>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        address = vault_config.currency_vault,
+    )]
+    pub currency_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = vault_config.currency_mint,
+        mint::token_program = token_program,
+    )]
+    pub currency_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token program of the currency mint
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateShares<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
 
     #[account(
         mut,
-        seeds = [BondVault::CURRENCY_VAULT_SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
-        bump = vault_config.vault_bump,
+        seeds = [VaultMigration::SEED, migration.legacy_vault.as_ref()],
+        bump = migration.bump,
+        has_one = legacy_share_mint,
+        constraint = migration.vault == vault_config.key() @ BondVaultError::InvalidLegacyVault,
     )]
-    pub currency_vault: Account<'info, TokenAccount>,
+    pub migration: Account<'info, VaultMigration>,
+
+    #[account(mut, mint::token_program = legacy_token_program)]
+    pub legacy_share_mint: InterfaceAccount<'info, Mint>,
 
-    /// Keeper's token account to receive reward
     #[account(
         mut,
-        constraint = keeper_token.owner == keeper.key(),
-        constraint = keeper_token.mint == vault_config.currency_mint,
+        token::mint = legacy_share_mint,
+        token::authority = user,
+    )]
+    pub user_legacy_shares: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = vault_config.share_mint,
+        mint::token_program = share_token_program,
     )]
-    pub keeper_token: Account<'info, TokenAccount>,
+    pub share_mint: InterfaceAccount<'info, Mint>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(mut, token::mint = share_mint)]
+    pub user_shares_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program of the legacy share mint
+    pub legacy_token_program: Interface<'info, TokenInterface>,
+    pub share_token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateApy<'info> {
+#[instruction(member: Pubkey)]
+pub struct SetVaultMemberRoles<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = VaultRole::LEN,
+        seeds = [VaultRole::SEED, vault_config.key().as_ref(), member.as_ref()],
+        bump,
+    )]
+    pub role_assignment: Account<'info, VaultRole>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QueueVaultChange<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingVaultChange::LEN,
+        seeds = [
+            PendingVaultChange::SEED,
+            vault_config.key().as_ref(),
+            &vault_config.next_change_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_change: Account<'info, PendingVaultChange>,
+
+    /// Proposer's role assignment; not needed when the proposer is the authority
+    #[account(
+        seeds = [VaultRole::SEED, vault_config.key().as_ref(), proposer.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, VaultRole>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ConfigureOracle<'info> {
-    pub authority: Signer<'info>,
+pub struct ExecuteVaultChange<'info> {
+    /// Anyone may execute a change once its eta has passed; pays for the
+    /// attestor set on its first configuration
+    #[account(mut)]
+    pub executor: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingVaultChange::SEED,
+            vault_config.key().as_ref(),
+            &pending_change.id.to_le_bytes(),
+        ],
+        bump = pending_change.bump,
+        has_one = proposer,
+    )]
+    pub pending_change: Account<'info, PendingVaultChange>,
+
+    /// CHECK: Rent refund destination, verified against pending_change.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// Only for `VaultChange::AttestorSet`
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = AttestorSet::LEN,
+        seeds = [AttestorSet::SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub attestor_set: Option<Box<Account<'info, AttestorSet>>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ConfigureReserveAttestor<'info> {
+pub struct CancelVaultChange<'info> {
     pub authority: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            PendingVaultChange::SEED,
+            vault_config.key().as_ref(),
+            &pending_change.id.to_le_bytes(),
+        ],
+        bump = pending_change.bump,
+        has_one = proposer,
+    )]
+    pub pending_change: Account<'info, PendingVaultChange>,
+
+    /// CHECK: Rent refund destination, verified against pending_change.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitReserveAttestation<'info> {
-    pub attestor: Signer<'info>,
+pub struct IncreaseVaultTimelockDelay<'info> {
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
 }
 
 #[derive(Accounts)]
-pub struct SetImmediateWithdraw<'info> {
+pub struct SetVaultPauseFlags<'info> {
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [BondVault::SEED, vault_config.authority.as_ref(), &[vault_config.bond_type.as_u8()]],
+        seeds = [BondVault::SEED, &[vault_config.bond_type.as_u8()]],
         bump = vault_config.bump,
     )]
     pub vault_config: Account<'info, BondVault>,
+
+    /// Signer's role assignment; not needed when the signer is the authority
+    #[account(
+        seeds = [VaultRole::SEED, vault_config.key().as_ref(), authority.key().as_ref()],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Option<Account<'info, VaultRole>>,
 }
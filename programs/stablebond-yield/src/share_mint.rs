@@ -0,0 +1,569 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::{
+    Account as TokenAccountState, Mint as MintState,
+};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{
+    self, find_mint_account_size, InitializeAccount3, InitializeMint2, MetadataPointerInitialize,
+    TokenInterface, TokenMetadataInitialize, TokenMetadataUpdateField, TransferHookInitialize,
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::get_extra_account_metas_address;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use spl_type_length_value::state::TlvStateBorrowed;
+use stablebond_types::{require_eligible_wallet, BondType};
+
+use crate::errors::BondVaultError;
+
+/// Share mint decimals, matching the settlement currencies.
+pub const SHARE_DECIMALS: u8 = 6;
+
+/// Share metadata limits, matching what wallets and explorers display.
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+
+/// Positions of the KYC registry and Sovereign program among the
+/// `ExtraAccountMetaList` entries that `stablebond-transfer-hook` writes for
+/// a share mint.
+pub const HOOK_KYC_REGISTRY_META: usize = 1;
+pub const HOOK_SOVEREIGN_PROGRAM_META: usize = 2;
+
+/// Creates a vault's share mint and locked-shares account under
+/// `token_program`. Done by hand rather than with `init` constraints because
+/// the mint's Token-2022 extensions depend on instruction arguments.
+/// Token-2022 share mints always get a metadata pointer to themselves, with
+/// the vault as pointer authority, so `ShareMetadata` can write to them.
+pub struct ShareMintInit<'a, 'info> {
+    pub payer: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub share_mint: AccountInfo<'info>,
+    pub locked_shares: AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: AccountInfo<'info>,
+    /// Token-2022 transfer-hook program share transfers must pass through
+    pub transfer_hook: Option<Pubkey>,
+}
+
+impl<'info> ShareMintInit<'_, 'info> {
+    pub fn create(&self, mint_seeds: &[&[u8]], locked_seeds: &[&[u8]]) -> Result<()> {
+        let is_token_2022 = self.token_program.key() == anchor_spl::token_2022::ID;
+        let mut extensions = Vec::new();
+        if is_token_2022 {
+            extensions.push(ExtensionType::MetadataPointer);
+        }
+        if self.transfer_hook.is_some() {
+            require!(is_token_2022, BondVaultError::TransferHookRequiresToken2022);
+            extensions.push(ExtensionType::TransferHook);
+        }
+
+        // ─── Mint ───
+        let mint_space = find_mint_account_size(Some(&extensions))?;
+        self.create_account(&self.share_mint, mint_space, mint_seeds)?;
+
+        if is_token_2022 {
+            token_interface::metadata_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    MetadataPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.share_mint.clone(),
+                    },
+                ),
+                Some(self.vault.key()),
+                Some(self.share_mint.key()),
+            )?;
+        }
+
+        if let Some(hook_program) = self.transfer_hook {
+            token_interface::transfer_hook_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferHookInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.share_mint.clone(),
+                    },
+                ),
+                Some(self.vault.key()),
+                Some(hook_program),
+            )?;
+        }
+
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.share_mint.clone(),
+                },
+            ),
+            SHARE_DECIMALS,
+            &self.vault.key(),
+            Some(&self.vault.key()),
+        )?;
+
+        // ─── Locked shares account ───
+        let account_space = if is_token_2022 {
+            let required = ExtensionType::get_required_init_account_extensions(&extensions);
+            ExtensionType::try_calculate_account_len::<TokenAccountState>(&required)?
+        } else {
+            anchor_spl::token::TokenAccount::LEN
+        };
+        self.create_account(&self.locked_shares, account_space, locked_seeds)?;
+
+        token_interface::initialize_account3(CpiContext::new(
+            self.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: self.locked_shares.clone(),
+                mint: self.share_mint.clone(),
+                authority: self.vault.clone(),
+            },
+        ))
+    }
+
+    /// Create a PDA owned by the token program, topping up instead of failing
+    /// if someone has already sent lamports to the address.
+    fn create_account(
+        &self,
+        account: &AccountInfo<'info>,
+        space: usize,
+        seeds: &[&[u8]],
+    ) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(space);
+        let owner = self.token_program.key();
+        let current = account.lamports();
+
+        if current == 0 {
+            return system_program::create_account(
+                CpiContext::new_with_signer(
+                    self.system_program.clone(),
+                    CreateAccount {
+                        from: self.payer.clone(),
+                        to: account.clone(),
+                    },
+                    &[seeds],
+                ),
+                rent,
+                space as u64,
+                &owner,
+            );
+        }
+
+        if current < rent {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    Transfer {
+                        from: self.payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                rent - current,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                self.system_program.clone(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                &[seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                self.system_program.clone(),
+                Assign {
+                    account_to_assign: account.clone(),
+                },
+                &[seeds],
+            ),
+            &owner,
+        )
+    }
+}
+
+/// Token-2022 metadata stored on a share mint itself, with the vault as
+/// mint and update authority.
+pub struct ShareMetadata<'a, 'info> {
+    pub payer: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub share_mint: AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> ShareMetadata<'_, 'info> {
+    /// Write `fields`, creating the metadata on first use. `payer` tops up
+    /// the mint's rent for the larger account first.
+    pub fn write(&self, fields: Vec<(Field, String)>, vault_seeds: &[&[u8]]) -> Result<()> {
+        require!(
+            self.token_program.key() == anchor_spl::token_2022::ID,
+            BondVaultError::ShareMetadataRequiresToken2022
+        );
+
+        let current = {
+            let data = self.share_mint.try_borrow_data()?;
+            let state = StateWithExtensions::<MintState>::unpack(&data)?;
+            state.get_variable_len_extension::<TokenMetadata>().ok()
+        };
+        // Authority and mint are fixed-size, so only the strings matter here
+        let mut target = current.clone().unwrap_or_default();
+        for (field, value) in &fields {
+            target.update(field.clone(), value.clone());
+        }
+        self.fund(&target)?;
+
+        if current.is_none() {
+            token_interface::token_metadata_initialize(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataInitialize {
+                        program_id: self.token_program.to_account_info(),
+                        metadata: self.share_mint.clone(),
+                        update_authority: self.vault.clone(),
+                        mint_authority: self.vault.clone(),
+                        mint: self.share_mint.clone(),
+                    },
+                    &[vault_seeds],
+                ),
+                target.name,
+                target.symbol,
+                target.uri,
+            )?;
+        }
+
+        for (field, value) in fields {
+            if current.is_none() && matches!(field, Field::Name | Field::Symbol | Field::Uri) {
+                continue;
+            }
+            token_interface::token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: self.token_program.to_account_info(),
+                        metadata: self.share_mint.clone(),
+                        update_authority: self.vault.clone(),
+                    },
+                    &[vault_seeds],
+                ),
+                field,
+                value,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Keep the mint rent-exempt once its metadata becomes `metadata`.
+    fn fund(&self, metadata: &TokenMetadata) -> Result<()> {
+        let len = {
+            let data = self.share_mint.try_borrow_data()?;
+            let state = StateWithExtensions::<MintState>::unpack(&data)?;
+            let mut extensions = state.get_extension_types()?;
+            extensions.retain(|e| *e != ExtensionType::TokenMetadata);
+            ExtensionType::try_calculate_account_len::<MintState>(&extensions)?
+                .checked_add(metadata.tlv_size_of()?)
+                .ok_or(BondVaultError::MathOverflow)?
+        };
+        let rent = Rent::get()?.minimum_balance(len);
+        let current = self.share_mint.lamports();
+        if current < rent {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.clone(),
+                    Transfer {
+                        from: self.payer.clone(),
+                        to: self.share_mint.clone(),
+                    },
+                ),
+                rent - current,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Mint-time gate for share mints routed through the KYC transfer hook: the
+/// depositor must pass the wallet check the hook applies to transfers,
+/// against the KYC registry and Sovereign program in the mint's
+/// `ExtraAccountMetaList`. Mints without a transfer hook are not gated.
+pub struct ShareKyc<'a, 'info> {
+    pub share_mint: &'a AccountInfo<'info>,
+    pub extra_account_meta_list: Option<&'a AccountInfo<'info>>,
+    pub whitelist_entry: Option<&'a AccountInfo<'info>>,
+    pub sovereign_identity: Option<&'a AccountInfo<'info>>,
+}
+
+impl ShareKyc<'_, '_> {
+    pub fn require_eligible(&self, wallet: &Pubkey, bond_type: BondType) -> Result<()> {
+        let Some(hook_program) = self.hook_program()? else {
+            return Ok(());
+        };
+        let (kyc_registry, sovereign_program) = self.hook_kyc_programs(&hook_program)?;
+
+        require_eligible_wallet::<BondVaultError>(
+            wallet,
+            self.whitelist_entry.ok_or(BondVaultError::KycRequired)?,
+            self.sovereign_identity
+                .ok_or(BondVaultError::SovereignIdentityNotFound)?,
+            &kyc_registry,
+            &sovereign_program,
+            bond_type,
+        )
+    }
+
+    /// Transfer-hook program of a Token-2022 share mint, if any.
+    fn hook_program(&self) -> Result<Option<Pubkey>> {
+        if *self.share_mint.owner != anchor_spl::token_2022::ID {
+            return Ok(None);
+        }
+        let data = self.share_mint.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        Ok(state
+            .get_extension::<TransferHook>()
+            .ok()
+            .and_then(|hook| Option::<Pubkey>::from(hook.program_id)))
+    }
+
+    /// KYC registry and Sovereign program recorded for the mint by its hook.
+    fn hook_kyc_programs(&self, hook_program: &Pubkey) -> Result<(Pubkey, Pubkey)> {
+        let meta_list = self
+            .extra_account_meta_list
+            .ok_or(BondVaultError::InvalidShareHookAccounts)?;
+        require!(
+            meta_list.owner == hook_program
+                && meta_list.key()
+                    == get_extra_account_metas_address(&self.share_mint.key(), hook_program),
+            BondVaultError::InvalidShareHookAccounts
+        );
+
+        let data = meta_list.try_borrow_data()?;
+        let tlv = TlvStateBorrowed::unpack(&data)
+            .map_err(|_| BondVaultError::InvalidShareHookAccounts)?;
+        let metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv)
+            .map_err(|_| BondVaultError::InvalidShareHookAccounts)?;
+        let fixed_key = |index: usize| {
+            metas
+                .data()
+                .get(index)
+                .filter(|meta| meta.discriminator == 0)
+                .map(|meta| Pubkey::new_from_array(meta.address_config))
+                .ok_or(BondVaultError::InvalidShareHookAccounts)
+        };
+        Ok((
+            fixed_key(HOOK_KYC_REGISTRY_META)?,
+            fixed_key(HOOK_SOVEREIGN_PROGRAM_META)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::extension::{
+        BaseStateWithExtensionsMut, StateWithExtensionsMut,
+    };
+    use spl_tlv_account_resolution::account::ExtraAccountMeta;
+
+    const JURISDICTION_MX: u8 = 1;
+    const JURISDICTION_USA: u8 = 4;
+
+    fn hooked_mint(hook_program: &Pubkey) -> Vec<u8> {
+        let len =
+            ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::TransferHook])
+                .unwrap();
+        let mut data = vec![0; len];
+        let mut state =
+            StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        state
+            .init_extension::<TransferHook>(true)
+            .unwrap()
+            .program_id = Some(*hook_program).try_into().unwrap();
+        state.base.decimals = SHARE_DECIMALS;
+        state.base.is_initialized = true;
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn meta_list(kyc_registry: &Pubkey, sovereign_program: &Pubkey) -> Vec<u8> {
+        let metas = [
+            ExtraAccountMeta::new_with_pubkey(&Pubkey::new_unique(), false, false).unwrap(),
+            ExtraAccountMeta::new_with_pubkey(kyc_registry, false, false).unwrap(),
+            ExtraAccountMeta::new_with_pubkey(sovereign_program, false, false).unwrap(),
+        ];
+        let mut data = vec![0; ExtraAccountMetaList::size_of(metas.len()).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas).unwrap();
+        data
+    }
+
+    fn whitelist_entry(wallet: &Pubkey, jurisdiction: u8) -> Vec<u8> {
+        let mut data = vec![0; 83];
+        data[8..40].copy_from_slice(wallet.as_ref());
+        data[72] = 1;
+        data[74] = jurisdiction;
+        data
+    }
+
+    fn error(err: BondVaultError) -> Error {
+        err.into()
+    }
+
+    #[test]
+    fn hooked_share_deposits_require_the_hooks_kyc_check() {
+        let wallet = Pubkey::new_unique();
+        let hook_program = Pubkey::new_unique();
+        let kyc_registry = Pubkey::new_unique();
+        let sovereign_program = Pubkey::new_unique();
+        let token_2022 = anchor_spl::token_2022::ID;
+
+        let mint_key = Pubkey::new_unique();
+        let (mut mint_lamports, mut mint_data) = (0, hooked_mint(&hook_program));
+        let mint = AccountInfo::new(
+            &mint_key,
+            false,
+            false,
+            &mut mint_lamports,
+            &mut mint_data,
+            &token_2022,
+            false,
+            0,
+        );
+
+        // Legacy SPL mints carry no hook, so deposits stay ungated
+        let spl_token = anchor_spl::token::ID;
+        let (mut spl_lamports, mut spl_data) = (0, vec![0; anchor_spl::token::Mint::LEN]);
+        let spl_mint = AccountInfo::new(
+            &mint_key,
+            false,
+            false,
+            &mut spl_lamports,
+            &mut spl_data,
+            &spl_token,
+            false,
+            0,
+        );
+        let ungated = ShareKyc {
+            share_mint: &spl_mint,
+            extra_account_meta_list: None,
+            whitelist_entry: None,
+            sovereign_identity: None,
+        };
+        assert!(ungated.require_eligible(&wallet, BondType::UsTBill).is_ok());
+
+        // A hooked mint needs its meta list at the hook's derived address
+        let without_list = ShareKyc {
+            share_mint: &mint,
+            ..ungated
+        };
+        assert_eq!(
+            without_list.require_eligible(&wallet, BondType::UsTBill),
+            Err(error(BondVaultError::InvalidShareHookAccounts))
+        );
+
+        let list_key = get_extra_account_metas_address(&mint_key, &hook_program);
+        let (mut list_lamports, mut list_data) = (0, meta_list(&kyc_registry, &sovereign_program));
+        let list = AccountInfo::new(
+            &list_key,
+            false,
+            false,
+            &mut list_lamports,
+            &mut list_data,
+            &hook_program,
+            false,
+            0,
+        );
+        let wrong_key = Pubkey::new_unique();
+        let (mut wrong_lamports, mut wrong_data) =
+            (0, meta_list(&kyc_registry, &sovereign_program));
+        let wrong_list = AccountInfo::new(
+            &wrong_key,
+            false,
+            false,
+            &mut wrong_lamports,
+            &mut wrong_data,
+            &hook_program,
+            false,
+            0,
+        );
+        let spoofed = ShareKyc {
+            extra_account_meta_list: Some(&wrong_list),
+            ..without_list
+        };
+        assert_eq!(
+            spoofed.require_eligible(&wallet, BondType::UsTBill),
+            Err(error(BondVaultError::InvalidShareHookAccounts))
+        );
+
+        // Wallets without a whitelist entry from the recorded registry fail
+        let identity_key = Pubkey::new_unique();
+        let (mut identity_lamports, mut identity_data) = (0, vec![0; 41]);
+        let identity = AccountInfo::new(
+            &identity_key,
+            false,
+            false,
+            &mut identity_lamports,
+            &mut identity_data,
+            &sovereign_program,
+            false,
+            0,
+        );
+        let no_entry = ShareKyc {
+            extra_account_meta_list: Some(&list),
+            sovereign_identity: Some(&identity),
+            ..without_list
+        };
+        assert_eq!(
+            no_entry.require_eligible(&wallet, BondType::UsTBill),
+            Err(error(BondVaultError::KycRequired))
+        );
+
+        let entry_key = Pubkey::new_unique();
+        let (mut forged_lamports, mut forged_data) = (0, whitelist_entry(&wallet, JURISDICTION_MX));
+        let other_registry = Pubkey::new_unique();
+        let forged = AccountInfo::new(
+            &entry_key,
+            false,
+            false,
+            &mut forged_lamports,
+            &mut forged_data,
+            &other_registry,
+            false,
+            0,
+        );
+        let forged_entry = ShareKyc {
+            whitelist_entry: Some(&forged),
+            ..no_entry
+        };
+        assert_eq!(
+            forged_entry.require_eligible(&wallet, BondType::UsTBill),
+            Err(error(BondVaultError::KycRequired))
+        );
+
+        // An entry from the recorded registry reaches the Accredit checks
+        let (mut entry_lamports, mut entry_data) = (0, whitelist_entry(&wallet, JURISDICTION_USA));
+        let entry = AccountInfo::new(
+            &entry_key,
+            false,
+            false,
+            &mut entry_lamports,
+            &mut entry_data,
+            &kyc_registry,
+            false,
+            0,
+        );
+        let restricted = ShareKyc {
+            whitelist_entry: Some(&entry),
+            ..no_entry
+        };
+        assert_eq!(
+            restricted.require_eligible(&wallet, BondType::UsTBill),
+            Err(error(BondVaultError::JurisdictionRestricted))
+        );
+    }
+}
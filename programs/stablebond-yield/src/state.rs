@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
-use stablebond_types::BondType;
+use solana_sha256_hasher::hashv;
+use stablebond_math::Rounding;
+use stablebond_types::{BondType, PauseFlag, Role};
+
+use crate::errors::BondVaultError;
 
 /// Per-bond-type vault configuration.
-/// PDA seeds: ["bond_vault", authority, &[bond_type as u8]]
+/// PDA seeds: ["bond_vault", &[bond_type as u8]] (independent of the authority key,
+/// so admin control can be transferred without moving the vault)
 #[account]
 #[derive(Debug)]
 pub struct BondVault {
@@ -58,6 +63,36 @@ pub struct BondVault {
     /// When false, users must use the cooldown-based withdrawal flow.
     /// Authority can toggle this for emergency use.
     pub allow_immediate_withdraw: bool,
+    // === Compliance ===
+    /// Compliance officer allowed to freeze share token accounts.
+    /// Pubkey::default() = not configured.
+    pub compliance_officer: Pubkey,
+    // === Authority transfer ===
+    /// Proposed new authority awaiting acceptance. Pubkey::default() = none pending.
+    pub pending_authority: Pubkey,
+    // === Timelock ===
+    /// Delay (seconds) before queued parameter changes can execute. 0 = timelock disabled.
+    pub timelock_delay: i64,
+    /// Next PendingVaultChange ID
+    pub next_change_id: u64,
+    // === Pause controls ===
+    /// Bitmask of `PauseFlag`s paused for this vault
+    pub paused_ops: u8,
+    // === Reserve coverage ===
+    /// Minimum attested reserve / liabilities ratio in bps (0 = not enforced)
+    pub min_coverage_bps: u16,
+    /// Coverage ratio in bps measured at the last attestation
+    pub attested_coverage_bps: u64,
+    /// Merkle root over custodian holdings from the last attestation
+    /// ([0; 32] = no holdings committed)
+    pub reserve_merkle_root: [u8; 32],
+    /// Number of holdings under `reserve_merkle_root`
+    pub reserve_holdings_count: u32,
+    /// Liabilities (total_shares * NAV) at the last attestation
+    pub attested_liabilities: u64,
+    /// Net settlement currency moved into the vault since the last
+    /// attestation: deposits less withdrawals and keeper rewards
+    pub net_flow_since_attestation: i64,
 }
 
 impl BondVault {
@@ -85,17 +120,358 @@ impl BondVault {
         + 8   // last_attestation_at
         + 8   // attested_reserve
         + 8   // attestation_max_staleness
-        + 1;  // allow_immediate_withdraw
+        + 1   // allow_immediate_withdraw
+        + 32  // compliance_officer
+        + 32  // pending_authority
+        + 8   // timelock_delay
+        + 8   // next_change_id
+        + 1   // paused_ops
+        + 2   // min_coverage_bps
+        + 8   // attested_coverage_bps
+        + 32  // reserve_merkle_root
+        + 4   // reserve_holdings_count
+        + 8   // attested_liabilities
+        + 8;  // net_flow_since_attestation
 
     pub const SEED: &'static [u8] = b"bond_vault";
     pub const CURRENCY_VAULT_SEED: &'static [u8] = b"bond_currency_vault";
     pub const SHARE_MINT_SEED: &'static [u8] = b"bond_share_mint";
+    pub const LOCKED_SHARES_SEED: &'static [u8] = b"bond_locked_shares";
+
+    /// Seed deposit the authority makes at `initialize_vault`, minted at
+    /// NAV 1.0 to a vault-owned token account that no instruction can move.
+    /// Keeps `total_shares` from ever returning to zero, so a first depositor
+    /// cannot inflate the value of a dust share supply. With a transfer-fee
+    /// currency the locked shares match the net amount the vault received.
+    pub const LOCKED_SHARES: u64 = 1_000;
 
     /// Default attestation staleness: 24 hours
     pub const DEFAULT_ATTESTATION_STALENESS: i64 = 86_400;
+
+    /// Default minimum reserve coverage: 99%, a tolerance band for rounding
+    /// and custodian timing below full backing
+    pub const DEFAULT_MIN_COVERAGE_BPS: u16 = 9_900;
+
+    /// Lower bound on the parameter-change timelock: 24 hours. Every
+    /// parameter change is queued; `localnet` builds shorten it so tests can
+    /// wait out the delay.
+    #[cfg(not(feature = "localnet"))]
+    pub const MIN_TIMELOCK_DELAY: i64 = 86_400;
+    #[cfg(feature = "localnet")]
+    pub const MIN_TIMELOCK_DELAY: i64 = 2;
+
+    /// Upper bound on the parameter-change timelock: 30 days
+    pub const MAX_TIMELOCK_DELAY: i64 = 30 * 86_400;
+
+    /// Cap on the effective accrual APY, oracle-derived or not: 50%
+    pub const MAX_APY_BPS: u64 = 5000;
+
+    /// Incentivized crank reward as a share of total deposits: 0.01%
+    pub const KEEPER_REWARD_BPS: u64 = 1;
+
+    /// Cap on the incentivized crank reward: 0.01 settlement units
+    pub const MAX_KEEPER_REWARD: u64 = 10_000;
+
+    /// Fails if the vault is inactive or `op` is paused for this vault.
+    pub fn require_unpaused(&self, op: PauseFlag) -> Result<()> {
+        require!(self.is_active, BondVaultError::VaultNotActive);
+        require!(!op.is_in(self.paused_ops), BondVaultError::OperationPaused);
+        Ok(())
+    }
+
+    /// Outstanding share value in settlement currency: total_shares * NAV,
+    /// rounded up and saturating at u64::MAX.
+    pub fn liabilities(&self) -> u64 {
+        stablebond_math::liability_for_shares(self.total_shares, self.nav_per_share)
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Attested reserve as a share of liabilities, in bps (rounded down).
+    /// Fully covered (u64::MAX) when nothing is outstanding.
+    pub fn coverage_bps(&self, attested_reserve: u64) -> u64 {
+        stablebond_math::ratio_bps(attested_reserve, self.liabilities(), Rounding::Down)
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Coverage carried forward from the last attestation: the attested
+    /// reserve and the liabilities snapshotted with it, both moved by the
+    /// currency deposited or paid out on-chain since. Accrual does not
+    /// change it, since the reserve is only re-measured by the next
+    /// attestation.
+    pub fn current_coverage_bps(&self) -> u64 {
+        let flow = self.net_flow_since_attestation;
+        stablebond_math::ratio_bps(
+            self.attested_reserve.saturating_add_signed(flow),
+            self.attested_liabilities.saturating_add_signed(flow),
+            Rounding::Down,
+        )
+        .unwrap_or(u64::MAX)
+    }
+
+    /// Track currency deposited into the vault since the last attestation.
+    pub fn record_inflow(&mut self, amount: u64) {
+        let amount = i64::try_from(amount).unwrap_or(i64::MAX);
+        self.net_flow_since_attestation = self.net_flow_since_attestation.saturating_add(amount);
+    }
+
+    /// Track currency paid out of the vault since the last attestation.
+    pub fn record_outflow(&mut self, amount: u64) {
+        let amount = i64::try_from(amount).unwrap_or(i64::MAX);
+        self.net_flow_since_attestation = self.net_flow_since_attestation.saturating_sub(amount);
+    }
+
+    /// True if an attestor is configured, at least one attestation has been
+    /// submitted, and `current_coverage_bps` is below `min_coverage_bps`.
+    pub fn has_reserve_shortfall(&self) -> bool {
+        self.reserve_attestor != Pubkey::default()
+            && self.last_attestation_at > 0
+            && self.min_coverage_bps > 0
+            && self.current_coverage_bps() < self.min_coverage_bps as u64
+    }
+
+    /// Store a reserve attestation: amount, coverage snapshot and the optional
+    /// holdings commitment (None clears the previous root).
+    pub fn record_attestation(
+        &mut self,
+        attested_reserve: u64,
+        commitment: Option<ReserveCommitment>,
+        now: i64,
+    ) {
+        self.last_attestation_at = now;
+        self.attested_reserve = attested_reserve;
+        self.attested_liabilities = self.liabilities();
+        self.net_flow_since_attestation = 0;
+        self.attested_coverage_bps = self.coverage_bps(attested_reserve);
+        let c = commitment.unwrap_or_default();
+        self.reserve_merkle_root = c.merkle_root;
+        self.reserve_holdings_count = c.holdings_count;
+    }
+
+    /// Shares minted for a deposit of `amount` at the current NAV (rounded
+    /// down).
+    pub fn shares_for_amount(&self, amount: u64) -> Result<u64> {
+        Ok(stablebond_math::shares_for_amount(amount, self.nav_per_share)
+            .map_err(BondVaultError::from)?)
+    }
+
+    /// Settlement currency paid out for `shares` at the current NAV (rounded
+    /// down).
+    pub fn amount_for_shares(&self, shares: u64) -> Result<u64> {
+        Ok(stablebond_math::amount_for_shares(shares, self.nav_per_share)
+            .map_err(BondVaultError::from)?)
+    }
+
+    /// Why a keeper crank at `now` must leave NAV unchanged, if it must:
+    /// the bond has matured, the reserve attestation is stale, or the last
+    /// attestation showed a shortfall.
+    pub fn accrual_halt(&self, now: i64) -> Option<AccrualHalt> {
+        if self.maturity_date > 0 && now >= self.maturity_date {
+            return Some(AccrualHalt::Matured);
+        }
+        if self.reserve_attestor != Pubkey::default() {
+            let staleness = now - self.last_attestation_at;
+            if staleness > self.attestation_max_staleness {
+                return Some(AccrualHalt::StaleAttestation { staleness });
+            }
+        }
+        if self.has_reserve_shortfall() {
+            return Some(AccrualHalt::ReserveShortfall);
+        }
+        None
+    }
+
+    /// `accrual_halt` for a crank at `now`. A stale attestation or a
+    /// shortfall also moves `last_accrual` to `now`, so the paused time is
+    /// skipped rather than credited in one jump once accrual resumes.
+    pub fn halt_accrual(&mut self, now: i64) -> Option<AccrualHalt> {
+        let halt = self.accrual_halt(now);
+        if matches!(
+            halt,
+            Some(AccrualHalt::StaleAttestation { .. } | AccrualHalt::ReserveShortfall)
+        ) {
+            self.last_accrual = now;
+        }
+        halt
+    }
+
+    /// Annual yield implied by a bond price (1e6-scaled fraction of par):
+    /// the coupon plus the discount to par, or less the premium over par,
+    /// both as a share of the price. Not capped.
+    pub fn oracle_apy_bps(&self, bond_price: u64) -> Result<u64> {
+        Ok(
+            stablebond_math::implied_apy_bps(bond_price, self.coupon_rate_bps as u64)
+                .map_err(BondVaultError::from)?,
+        )
+    }
+
+    /// NAV after accruing `apy_bps` on the current NAV for `elapsed` seconds:
+    /// nav + nav * apy * elapsed / (10000 * SECONDS_PER_YEAR), rounded down.
+    pub fn accrued_nav(&self, apy_bps: u64, elapsed: u64) -> Result<u64> {
+        Ok(
+            stablebond_math::accrued_nav(self.nav_per_share, apy_bps, elapsed)
+                .map_err(BondVaultError::from)?,
+        )
+    }
+
+    /// Incentivized crank reward: `KEEPER_REWARD_BPS` of total deposits,
+    /// rounded down and capped at `MAX_KEEPER_REWARD`.
+    pub fn keeper_reward(&self) -> Result<u64> {
+        let reward = stablebond_math::payout_bps(self.total_deposits, Self::KEEPER_REWARD_BPS)
+            .map_err(BondVaultError::from)?;
+        Ok(reward.min(Self::MAX_KEEPER_REWARD))
+    }
+
+    /// Passes if `signer` is the vault authority (which implicitly holds every
+    /// role) or presents a role assignment for this vault that includes `role`.
+    pub fn require_role(
+        &self,
+        signer: &Pubkey,
+        assignment: Option<&VaultRole>,
+        role: Role,
+    ) -> Result<()> {
+        if *signer == self.authority {
+            return Ok(());
+        }
+        match assignment {
+            Some(a) if a.member == *signer && role.is_in(a.roles) => Ok(()),
+            _ => err!(BondVaultError::MissingRole),
+        }
+    }
+}
+
+/// Reason a keeper crank leaves NAV unchanged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccrualHalt {
+    /// At or past the maturity date; accrual never resumes
+    Matured,
+    /// Attestor configured and the last attestation is older than allowed
+    StaleAttestation { staleness: i64 },
+    /// Last attestation showed coverage below `min_coverage_bps`
+    ReserveShortfall,
+}
+
+/// Vault layout from before vault PDAs dropped the authority key, at
+/// `["bond_vault", authority, &[bond_type]]`. Only read (and deactivated) by
+/// `migrate_vault`; it shares `BondVault`'s discriminator.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyBondVault {
+    pub authority: Pubkey,
+    pub currency_mint: Pubkey,
+    pub share_mint: Pubkey,
+    pub currency_vault: Pubkey,
+    pub bond_type: BondType,
+    pub coupon_rate_bps: u16,
+    pub maturity_date: i64,
+    pub target_apy_bps: u16,
+    pub total_deposits: u64,
+    pub total_shares: u64,
+    pub nav_per_share: u64,
+    pub last_accrual: i64,
+    pub is_active: bool,
+    pub bump: u8,
+    pub share_mint_bump: u8,
+    pub vault_bump: u8,
+    pub oracle_feed: Pubkey,
+    pub last_oracle_price: u64,
+    pub oracle_enabled: bool,
+    pub reserve_attestor: Pubkey,
+    pub last_attestation_at: i64,
+    pub attested_reserve: u64,
+    pub attestation_max_staleness: i64,
+    pub allow_immediate_withdraw: bool,
 }
 
-/// Per-user share tracking within a bond vault.
+impl LegacyBondVault {
+    pub const LEN: usize = 8  // discriminator
+        + 32 * 4  // authority, currency_mint, share_mint, currency_vault
+        + 1   // bond_type
+        + 2   // coupon_rate_bps
+        + 8   // maturity_date
+        + 2   // target_apy_bps
+        + 8 * 4  // total_deposits, total_shares, nav_per_share, last_accrual
+        + 1   // is_active
+        + 3   // bump, share_mint_bump, vault_bump
+        + 32  // oracle_feed
+        + 8   // last_oracle_price
+        + 1   // oracle_enabled
+        + 32  // reserve_attestor
+        + 8 * 3  // last_attestation_at, attested_reserve, attestation_max_staleness
+        + 1;  // allow_immediate_withdraw
+
+    /// Decode a legacy vault account, checking owner, discriminator and size.
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(info.owner == &crate::ID, BondVaultError::InvalidLegacyVault);
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == Self::LEN && data[..8] == *BondVault::DISCRIMINATOR,
+            BondVaultError::InvalidLegacyVault
+        );
+        Self::deserialize(&mut &data[8..]).map_err(|_| BondVaultError::InvalidLegacyVault.into())
+    }
+
+    /// Write back over the legacy account, keeping its discriminator.
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        self.serialize(&mut &mut data[8..])?;
+        Ok(())
+    }
+}
+
+/// Currency moved in from a legacy vault and the shares reserved for its
+/// holders, who swap legacy shares for them with `migrate_shares`. Reserved
+/// shares count towards the vault's `total_shares` until they are minted.
+/// PDA seeds: ["vault_migration", legacy_vault]
+#[account]
+#[derive(Debug)]
+pub struct VaultMigration {
+    /// Vault the legacy vault was migrated into
+    pub vault: Pubkey,
+    /// Legacy `["bond_vault", authority, bond_type]` vault
+    pub legacy_vault: Pubkey,
+    /// Legacy share mint; its tokens are burned on swap
+    pub legacy_share_mint: Pubkey,
+    /// Legacy shares not yet swapped
+    pub legacy_shares_outstanding: u64,
+    /// Vault shares still reserved for those legacy shares
+    pub reserved_shares: u64,
+    /// Migration timestamp
+    pub migrated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VaultMigration {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // vault
+        + 32  // legacy_vault
+        + 32  // legacy_share_mint
+        + 8   // legacy_shares_outstanding
+        + 8   // reserved_shares
+        + 8   // migrated_at
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"vault_migration";
+
+    /// Vault shares for `legacy_shares`, pro rata to what is still reserved
+    /// (rounded down; the last holder to swap receives the remainder).
+    pub fn shares_for_legacy(&self, legacy_shares: u64) -> Result<u64> {
+        require!(
+            legacy_shares <= self.legacy_shares_outstanding,
+            BondVaultError::InsufficientShares
+        );
+        Ok(stablebond_math::mul_div(
+            legacy_shares,
+            self.reserved_shares,
+            self.legacy_shares_outstanding,
+            Rounding::Down,
+        )
+        .map_err(BondVaultError::from)?)
+    }
+}
+
+/// Per-user deposit metadata within a bond vault, for P&L tracking.
+/// The share token balance is authoritative: withdrawals burn from the
+/// user's share token account and never read `shares`.
 /// PDA seeds: ["bond_shares", vault, user]
 #[account]
 #[derive(Debug)]
@@ -104,7 +480,8 @@ pub struct UserShares {
     pub user: Pubkey,
     /// Reference to the BondVault
     pub vault: Pubkey,
-    /// Number of share tokens held
+    /// Share token balance as of the user's last deposit, withdrawal or
+    /// `sync_user_shares`; stale after share token transfers
     pub shares: u64,
     /// Total currency deposited by this user (for P&L tracking)
     pub deposited_amount: u64,
@@ -125,3 +502,734 @@ impl UserShares {
 
     pub const SEED: &'static [u8] = b"bond_shares";
 }
+
+/// Operational roles granted to a key for a single vault.
+/// PDA seeds: ["vault_role", vault, member]
+#[account]
+#[derive(Debug)]
+pub struct VaultRole {
+    /// Key holding the roles
+    pub member: Pubkey,
+    /// Reference to the BondVault
+    pub vault: Pubkey,
+    /// Bitmask of granted `Role`s (0 = revoked)
+    pub roles: u8,
+    /// Authority that last changed the roles
+    pub granted_by: Pubkey,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VaultRole {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // member
+        + 32  // vault
+        + 1   // roles
+        + 32  // granted_by
+        + 8   // updated_at
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"vault_role";
+}
+
+/// A vault parameter change waiting out the vault timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum VaultChange {
+    /// New fallback target APY (bps)
+    Apy(u16),
+    /// New oracle feed and enabled flag
+    Oracle { oracle_feed: Pubkey, enabled: bool },
+    /// Shortening the delay must itself wait out the current delay
+    TimelockDelay(i64),
+    /// Single reserve attestor and staleness threshold; rejected once a
+    /// quorum attestor set is configured
+    ReserveAttestor { attestor: Pubkey, max_staleness: i64 },
+    /// M-of-N quorum attestor set; the vault's `reserve_attestor` then points
+    /// at the `AttestorSet` PDA
+    AttestorSet {
+        attestors: Vec<Pubkey>,
+        threshold: u8,
+        window: i64,
+        tolerance_bps: u16,
+    },
+}
+
+impl VaultChange {
+    pub const MAX_LEN: usize = 1 // variant tag
+        + 4 + 32 * AttestorSet::MAX_ATTESTORS  // attestors
+        + 1   // threshold
+        + 8   // window
+        + 2;  // tolerance_bps
+
+    pub fn kind(&self) -> u8 {
+        match self {
+            VaultChange::Apy(_) => 0,
+            VaultChange::Oracle { .. } => 1,
+            VaultChange::TimelockDelay(_) => 2,
+            VaultChange::ReserveAttestor { .. } => 3,
+            VaultChange::AttestorSet { .. } => 4,
+        }
+    }
+
+    /// Role a non-authority proposer needs to queue this change.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            VaultChange::Apy(_) | VaultChange::Oracle { .. } => Some(Role::OracleAdmin),
+            VaultChange::TimelockDelay(_)
+            | VaultChange::ReserveAttestor { .. }
+            | VaultChange::AttestorSet { .. } => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            VaultChange::Apy(apy_bps) => {
+                require!(*apy_bps <= 5000, BondVaultError::InvalidApy);
+            }
+            VaultChange::Oracle { .. } => {}
+            VaultChange::TimelockDelay(delay) => {
+                require!(
+                    (BondVault::MIN_TIMELOCK_DELAY..=BondVault::MAX_TIMELOCK_DELAY).contains(delay),
+                    BondVaultError::InvalidTimelockDelay
+                );
+            }
+            VaultChange::ReserveAttestor { max_staleness, .. } => {
+                require!(*max_staleness > 0, BondVaultError::InvalidAttestationConfig);
+            }
+            VaultChange::AttestorSet {
+                attestors,
+                threshold,
+                window,
+                ..
+            } => {
+                let count = attestors.len();
+                require!(
+                    count <= AttestorSet::MAX_ATTESTORS
+                        && *threshold >= AttestorSet::MIN_THRESHOLD
+                        && *threshold as usize <= count
+                        && *window > 0,
+                    BondVaultError::InvalidAttestorSet
+                );
+                for (i, key) in attestors.iter().enumerate() {
+                    require!(
+                        *key != Pubkey::default() && !attestors[..i].contains(key),
+                        BondVaultError::InvalidAttestorSet
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the change to the vault. `AttestorSet` is written to the set
+    /// account by `execute_vault_change`.
+    pub fn apply(&self, vault: &mut BondVault) {
+        match *self {
+            VaultChange::Apy(apy_bps) => vault.target_apy_bps = apy_bps,
+            VaultChange::Oracle {
+                oracle_feed,
+                enabled,
+            } => {
+                vault.oracle_feed = oracle_feed;
+                vault.oracle_enabled = enabled;
+            }
+            VaultChange::TimelockDelay(delay) => vault.timelock_delay = delay,
+            VaultChange::ReserveAttestor {
+                attestor,
+                max_staleness,
+            } => {
+                vault.reserve_attestor = attestor;
+                vault.attestation_max_staleness = max_staleness;
+            }
+            VaultChange::AttestorSet { .. } => {}
+        }
+    }
+}
+
+/// Queued vault change. Executable by anyone once `eta` has passed;
+/// cancellable by the vault authority until then.
+/// PDA seeds: ["vault_change", vault, &id.to_le_bytes()]
+#[account]
+#[derive(Debug)]
+pub struct PendingVaultChange {
+    /// Sequential change ID (from BondVault.next_change_id)
+    pub id: u64,
+    /// Reference to the BondVault
+    pub vault: Pubkey,
+    /// Key that queued the change (receives rent back on execute/cancel)
+    pub proposer: Pubkey,
+    /// The queued change
+    pub change: VaultChange,
+    /// Timestamp the change was queued
+    pub queued_at: i64,
+    /// Earliest execution timestamp
+    pub eta: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingVaultChange {
+    pub const LEN: usize = 8  // discriminator
+        + 8   // id
+        + 32  // vault
+        + 32  // proposer
+        + VaultChange::MAX_LEN // change
+        + 8   // queued_at
+        + 8   // eta
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"vault_change";
+}
+
+/// Merkle commitment over the custodian holdings behind an attestation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReserveCommitment {
+    /// Root over `ReserveHolding::leaf` hashes (sorted-pair SHA-256)
+    pub merkle_root: [u8; 32],
+    /// Number of holdings (leaves) in the tree
+    pub holdings_count: u32,
+}
+
+impl ReserveCommitment {
+    pub const LEN: usize = 32 + 4;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.merkle_root != [0; 32] && self.holdings_count > 0,
+            BondVaultError::InvalidReserveCommitment
+        );
+        Ok(())
+    }
+}
+
+/// A single custodian bond lot committed to in a reserve Merkle tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ReserveHolding {
+    /// ISIN, or a CUSIP right-padded with zero bytes
+    pub security_id: [u8; 12],
+    /// Face-value quantity held, in minor units
+    pub quantity: u64,
+    /// Price as a fraction of par, scaled 1e6
+    pub price: u64,
+}
+
+impl ReserveHolding {
+    /// Maximum proof depth accepted on-chain (covers u32::MAX leaves).
+    pub const MAX_PROOF_LEN: usize = 32;
+
+    /// Leaf hash: sha256(0x00 || security_id || quantity_le || price_le).
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            &[0x00][..],
+            &self.security_id,
+            &self.quantity.to_le_bytes(),
+            &self.price.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Walks `proof` from this holding's leaf and checks it reaches `root`.
+    /// Inner nodes are sha256(0x01 || min(a, b) || max(a, b)), so no leaf
+    /// index is needed. Proofs longer than `MAX_PROOF_LEN` are rejected.
+    pub fn verify(&self, proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+        if proof.len() > Self::MAX_PROOF_LEN {
+            return false;
+        }
+        let computed = proof.iter().fold(self.leaf(), |node, sibling| {
+            let (a, b) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            hashv(&[&[0x01][..], &a, &b]).to_bytes()
+        });
+        computed == *root
+    }
+}
+
+/// M-of-N reserve attestor set for a vault. While active, the vault's
+/// `reserve_attestor` is this account's address, so the single-key
+/// `submit_reserve_attestation` path is closed.
+/// PDA seeds: ["attestor_set", vault]
+#[account]
+#[derive(Debug)]
+pub struct AttestorSet {
+    /// Reference to the BondVault
+    pub vault: Pubkey,
+    /// Attestor keys; only the first `attestor_count` are in use
+    pub attestors: [Pubkey; AttestorSet::MAX_ATTESTORS],
+    /// Number of configured attestors (N)
+    pub attestor_count: u8,
+    /// Submissions required to record an attestation (M)
+    pub threshold: u8,
+    /// Seconds after the first submission during which the round stays open
+    pub window: i64,
+    /// Max spread between the lowest and highest submission, in bps of the lowest,
+    /// before an `AttestationDisagreement` alert is emitted
+    pub tolerance_bps: u16,
+    /// First submission time of the open round (0 = no round open)
+    pub round_start: i64,
+    /// Attested reserve per attestor slot in the open round (0 = not submitted)
+    pub submissions: [u64; AttestorSet::MAX_ATTESTORS],
+    /// Holdings commitment per attestor slot (zero root = none)
+    pub commitments: [ReserveCommitment; AttestorSet::MAX_ATTESTORS],
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AttestorSet {
+    pub const MAX_ATTESTORS: usize = 5;
+    /// A quorum of one would let a single key attest
+    pub const MIN_THRESHOLD: u8 = 2;
+
+    pub const LEN: usize = 8  // discriminator
+        + 32  // vault
+        + 32 * Self::MAX_ATTESTORS  // attestors
+        + 1   // attestor_count
+        + 1   // threshold
+        + 8   // window
+        + 2   // tolerance_bps
+        + 8   // round_start
+        + 8 * Self::MAX_ATTESTORS  // submissions
+        + ReserveCommitment::LEN * Self::MAX_ATTESTORS  // commitments
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"attestor_set";
+
+    /// The set's PDA for `vault`.
+    pub fn address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[Self::SEED, vault.as_ref()], &crate::ID).0
+    }
+
+    /// Slot of `key` among the configured attestors.
+    pub fn slot_of(&self, key: &Pubkey) -> Option<usize> {
+        self.attestors[..self.attestor_count as usize]
+            .iter()
+            .position(|a| a == key)
+    }
+
+    /// Replace the members and round parameters; any open round is discarded.
+    pub fn configure(
+        &mut self,
+        vault: Pubkey,
+        attestors: &[Pubkey],
+        threshold: u8,
+        window: i64,
+        tolerance_bps: u16,
+        bump: u8,
+    ) {
+        self.vault = vault;
+        self.attestors = [Pubkey::default(); Self::MAX_ATTESTORS];
+        self.attestors[..attestors.len()].copy_from_slice(attestors);
+        self.attestor_count = attestors.len() as u8;
+        self.threshold = threshold;
+        self.window = window;
+        self.tolerance_bps = tolerance_bps;
+        self.bump = bump;
+        self.clear_round();
+    }
+
+    /// Discard the open round.
+    pub fn clear_round(&mut self) {
+        self.round_start = 0;
+        self.submissions = [0; Self::MAX_ATTESTORS];
+        self.commitments = [ReserveCommitment::default(); Self::MAX_ATTESTORS];
+    }
+}
+
+/// One entry in a vault's attestation history.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct AttestationRecord {
+    /// Attestation timestamp
+    pub timestamp: i64,
+    /// Attested reserve in settlement currency minor units
+    pub attested_reserve: u64,
+    /// Submitting attestor (the AttestorSet PDA for quorum attestations)
+    pub attestor: Pubkey,
+    /// Holdings Merkle root ([0; 32] = none)
+    pub merkle_root: [u8; 32],
+    /// Vault NAV per share at attestation time
+    pub nav_per_share: u64,
+    /// Vault total shares at attestation time
+    pub total_shares: u64,
+}
+
+impl AttestationRecord {
+    pub const LEN: usize = 8  // timestamp
+        + 8   // attested_reserve
+        + 32  // attestor
+        + 32  // merkle_root
+        + 8   // nav_per_share
+        + 8;  // total_shares
+}
+
+/// Ring buffer of the last `CAPACITY` attestations for a vault.
+/// Entry `count % CAPACITY` is overwritten next.
+/// PDA seeds: ["attestation_history", vault]
+#[account]
+#[derive(Debug)]
+pub struct AttestationHistory {
+    /// Reference to the BondVault
+    pub vault: Pubkey,
+    /// Total attestations ever recorded
+    pub count: u64,
+    /// Ring buffer entries
+    pub entries: [AttestationRecord; AttestationHistory::CAPACITY],
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AttestationHistory {
+    pub const CAPACITY: usize = 32;
+
+    pub const LEN: usize = 8  // discriminator
+        + 32  // vault
+        + 8   // count
+        + AttestationRecord::LEN * Self::CAPACITY  // entries
+        + 1;  // bump
+
+    pub const SEED: &'static [u8] = b"attestation_history";
+
+    /// Append `record`, overwriting the oldest entry once full.
+    /// Returns the sequence number of the new entry.
+    pub fn push(&mut self, record: AttestationRecord) -> u64 {
+        let seq = self.count;
+        self.entries[(seq % Self::CAPACITY as u64) as usize] = record;
+        self.count = seq.saturating_add(1);
+        seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_vault() -> LegacyBondVault {
+        LegacyBondVault {
+            authority: Pubkey::new_unique(),
+            currency_mint: Pubkey::new_unique(),
+            share_mint: Pubkey::new_unique(),
+            currency_vault: Pubkey::new_unique(),
+            bond_type: BondType::MxCetes,
+            coupon_rate_bps: 900,
+            maturity_date: 0,
+            target_apy_bps: 900,
+            total_deposits: 5_000_000,
+            total_shares: 4_800_000,
+            nav_per_share: 1_041_666,
+            last_accrual: 1_700_000_000,
+            is_active: true,
+            bump: 255,
+            share_mint_bump: 254,
+            vault_bump: 253,
+            oracle_feed: Pubkey::default(),
+            last_oracle_price: crate::NAV_SCALE,
+            oracle_enabled: false,
+            reserve_attestor: Pubkey::default(),
+            last_attestation_at: 0,
+            attested_reserve: 0,
+            attestation_max_staleness: BondVault::DEFAULT_ATTESTATION_STALENESS,
+            allow_immediate_withdraw: false,
+        }
+    }
+
+    fn account_data(vault: &LegacyBondVault) -> Vec<u8> {
+        let mut data = BondVault::DISCRIMINATOR.to_vec();
+        vault.serialize(&mut data).unwrap();
+        data
+    }
+
+    fn with_account<R>(owner: Pubkey, data: &mut [u8], f: impl FnOnce(&AccountInfo) -> R) -> R {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, true, &mut lamports, data, &owner, false, 0);
+        f(&info)
+    }
+
+    #[test]
+    fn legacy_layout_matches_len() {
+        assert_eq!(account_data(&legacy_vault()).len(), LegacyBondVault::LEN);
+        const { assert!(LegacyBondVault::LEN < BondVault::LEN) };
+    }
+
+    #[test]
+    fn legacy_vault_round_trips() {
+        let vault = legacy_vault();
+        let mut data = account_data(&vault);
+        with_account(crate::ID, &mut data, |info| {
+            let mut loaded = LegacyBondVault::load(info).unwrap();
+            assert_eq!(loaded.authority, vault.authority);
+            assert_eq!(loaded.total_shares, vault.total_shares);
+            assert!(loaded.is_active);
+
+            loaded.is_active = false;
+            loaded.store(info).unwrap();
+            assert!(!LegacyBondVault::load(info).unwrap().is_active);
+            assert_eq!(info.try_borrow_data().unwrap()[..8], *BondVault::DISCRIMINATOR);
+        });
+    }
+
+    #[test]
+    fn legacy_vault_rejects_foreign_or_current_accounts() {
+        let mut data = account_data(&legacy_vault());
+        with_account(Pubkey::new_unique(), &mut data, |info| {
+            assert!(LegacyBondVault::load(info).is_err());
+        });
+
+        // A current-layout vault has the same discriminator but is longer
+        let mut data = account_data(&legacy_vault());
+        data.resize(BondVault::LEN, 0);
+        with_account(crate::ID, &mut data, |info| {
+            assert!(LegacyBondVault::load(info).is_err());
+        });
+
+        let mut data = account_data(&legacy_vault());
+        data[..8].copy_from_slice(UserShares::DISCRIMINATOR);
+        with_account(crate::ID, &mut data, |info| {
+            assert!(LegacyBondVault::load(info).is_err());
+        });
+    }
+
+    fn attested_vault() -> BondVault {
+        let mut data = BondVault::DISCRIMINATOR.to_vec();
+        data.resize(BondVault::LEN, 0);
+        let mut vault = BondVault::try_deserialize(&mut &data[..]).unwrap();
+        vault.nav_per_share = crate::NAV_SCALE;
+        vault.total_shares = 1_000_000;
+        vault.reserve_attestor = Pubkey::new_unique();
+        vault.min_coverage_bps = BondVault::DEFAULT_MIN_COVERAGE_BPS;
+        vault.record_attestation(1_000_000, None, 1_700_000_000);
+        vault
+    }
+
+    #[test]
+    fn shortfall_carries_the_attestation_forward_with_flows() {
+        let mut vault = attested_vault();
+        assert_eq!(vault.attested_coverage_bps, 10_000);
+        assert_eq!(vault.attested_liabilities, 1_000_000);
+
+        // Accrual after the attestation does not count against it
+        vault.nav_per_share += 1_000;
+        assert_eq!(vault.current_coverage_bps(), 10_000);
+        assert!(!vault.has_reserve_shortfall());
+
+        // Nor does a deposit, which brings its own currency
+        vault.total_shares += 1;
+        vault.record_inflow(1);
+        assert_eq!(vault.current_coverage_bps(), 10_000);
+        assert!(!vault.has_reserve_shortfall());
+
+        // An attested gap widens as currency is paid out against it
+        let mut vault = attested_vault();
+        vault.record_attestation(990_000, None, 1_700_000_100);
+        assert_eq!(vault.current_coverage_bps(), 9_900);
+        assert!(!vault.has_reserve_shortfall());
+        vault.record_outflow(10_000);
+        assert_eq!(vault.current_coverage_bps(), 9_898);
+        assert!(vault.has_reserve_shortfall());
+
+        // Deposits narrow it again, and the next attestation starts afresh
+        vault.record_inflow(500_000);
+        assert!(!vault.has_reserve_shortfall());
+        vault.record_attestation(1_000_000, None, 1_700_000_200);
+        assert_eq!(vault.net_flow_since_attestation, 0);
+    }
+
+    #[test]
+    fn halted_time_is_skipped_not_deferred() {
+        let mut vault = attested_vault();
+        vault.attestation_max_staleness = 86_400;
+        vault.target_apy_bps = 500;
+        let attested_at = vault.last_attestation_at;
+        vault.last_accrual = attested_at;
+
+        // Stale for ten days: cranks halt and keep last_accrual current
+        let stale = attested_at + 10 * 86_400;
+        assert!(matches!(
+            vault.halt_accrual(stale),
+            Some(AccrualHalt::StaleAttestation { .. })
+        ));
+        assert_eq!(vault.last_accrual, stale);
+        assert_eq!(vault.nav_per_share, crate::NAV_SCALE);
+
+        // A fresh attestation resumes accrual from the last halted crank
+        vault.record_attestation(1_000_000, None, stale + 60);
+        let resumed = stale + 3_600;
+        assert_eq!(vault.halt_accrual(resumed), None);
+        let elapsed = (resumed - vault.last_accrual) as u64;
+        assert_eq!(elapsed, 3_600);
+        let nav = vault.accrued_nav(500, elapsed).unwrap();
+        assert!(nav - crate::NAV_SCALE < 10);
+
+        // Maturity leaves last_accrual alone
+        vault.maturity_date = resumed;
+        let last = vault.last_accrual;
+        assert_eq!(vault.halt_accrual(resumed + 60), Some(AccrualHalt::Matured));
+        assert_eq!(vault.last_accrual, last);
+    }
+
+    #[test]
+    fn timelock_delay_changes_respect_the_floor() {
+        let below = VaultChange::TimelockDelay(BondVault::MIN_TIMELOCK_DELAY - 1);
+        assert!(below.validate().is_err());
+        assert!(VaultChange::TimelockDelay(0).validate().is_err());
+        assert!(VaultChange::TimelockDelay(BondVault::MIN_TIMELOCK_DELAY)
+            .validate()
+            .is_ok());
+        assert!(VaultChange::TimelockDelay(BondVault::MAX_TIMELOCK_DELAY + 1)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn attestor_set_changes_require_a_quorum() {
+        let keys: Vec<Pubkey> = (1..=6).map(|i| Pubkey::new_from_array([i; 32])).collect();
+        let set = |attestors: &[Pubkey], threshold| VaultChange::AttestorSet {
+            attestors: attestors.to_vec(),
+            threshold,
+            window: 600,
+            tolerance_bps: 100,
+        };
+        assert!(set(&keys[..3], 2).validate().is_ok());
+        assert!(set(&keys[..5], 5).validate().is_ok());
+        // A quorum of one, or a threshold above the member count
+        assert!(set(&keys[..3], 1).validate().is_err());
+        assert!(set(&keys[..1], 1).validate().is_err());
+        assert!(set(&keys[..2], 3).validate().is_err());
+        // Too many members, duplicates and the default key
+        assert!(set(&keys, 2).validate().is_err());
+        assert!(set(&[keys[0], keys[0]], 2).validate().is_err());
+        assert!(set(&[keys[0], Pubkey::default()], 2).validate().is_err());
+
+        // The largest change still fits the pending change account
+        let mut buf = Vec::new();
+        set(&keys[..5], 5).serialize(&mut buf).unwrap();
+        assert_eq!(buf.len(), VaultChange::MAX_LEN);
+    }
+
+    #[test]
+    fn keeper_reward_is_one_bps_of_deposits_capped() {
+        let mut vault = attested_vault();
+        vault.total_deposits = 9_999;
+        assert_eq!(vault.keeper_reward().unwrap(), 0);
+        vault.total_deposits = 50_000_000;
+        assert_eq!(vault.keeper_reward().unwrap(), 5_000);
+        vault.total_deposits = u64::MAX;
+        assert_eq!(vault.keeper_reward().unwrap(), BondVault::MAX_KEEPER_REWARD);
+    }
+
+    fn holding(n: u8) -> ReserveHolding {
+        let mut security_id = *b"US912797KJ50";
+        security_id[11] = b'0' + n;
+        ReserveHolding {
+            security_id,
+            quantity: 1_000_000_000 * (n as u64 + 1),
+            price: 990_000,
+        }
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[0x01][..], &lo, &hi]).to_bytes()
+    }
+
+    /// Same construction as the SDK's `buildProofOfReserveTree`: an unpaired
+    /// node is promoted unchanged.
+    fn build_tree(holdings: &[ReserveHolding]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut level: Vec<_> = holdings.iter().map(ReserveHolding::leaf).collect();
+        let mut positions: Vec<usize> = (0..holdings.len()).collect();
+        let mut proofs = vec![Vec::new(); holdings.len()];
+        while level.len() > 1 {
+            for (leaf, pos) in positions.iter_mut().enumerate() {
+                if let Some(sibling) = level.get(*pos ^ 1) {
+                    proofs[leaf].push(*sibling);
+                }
+                *pos >>= 1;
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(*a, *b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        (level[0], proofs)
+    }
+
+    #[test]
+    fn reserve_proofs_verify_with_an_odd_leaf_count() {
+        let holdings: Vec<_> = (0..5).map(holding).collect();
+        let (root, proofs) = build_tree(&holdings);
+        for (holding, proof) in holdings.iter().zip(&proofs) {
+            assert!(holding.verify(proof, &root));
+        }
+        // The fifth leaf is promoted twice and only pairs at the top
+        assert_eq!(proofs[4].len(), 1);
+        assert_eq!(proofs[0].len(), 3);
+
+        let single = [holding(7)];
+        let (root, proofs) = build_tree(&single);
+        assert_eq!(root, single[0].leaf());
+        assert!(single[0].verify(&proofs[0], &root));
+    }
+
+    #[test]
+    fn reserve_proof_rejects_a_wrong_sibling_or_holding() {
+        let holdings: Vec<_> = (0..4).map(holding).collect();
+        let (root, proofs) = build_tree(&holdings);
+
+        let mut proof = proofs[1].clone();
+        proof[0][0] ^= 1;
+        assert!(!holdings[1].verify(&proof, &root));
+
+        // Another leaf's proof, or a tampered quantity
+        assert!(!holdings[1].verify(&proofs[2], &root));
+        let mut inflated = holdings[1];
+        inflated.quantity += 1;
+        assert!(!inflated.verify(&proofs[1], &root));
+    }
+
+    #[test]
+    fn reserve_proof_rejects_more_than_max_len_steps() {
+        let lot = holding(0);
+        let siblings: Vec<[u8; 32]> = (0..=ReserveHolding::MAX_PROOF_LEN as u8)
+            .map(|i| hashv(&[&[i][..]]).to_bytes())
+            .collect();
+        let root_of = |proof: &[[u8; 32]]| {
+            proof
+                .iter()
+                .fold(lot.leaf(), |node, sibling| hash_pair(node, *sibling))
+        };
+
+        let max = &siblings[..ReserveHolding::MAX_PROOF_LEN];
+        assert!(lot.verify(max, &root_of(max)));
+        assert!(!lot.verify(&siblings, &root_of(&siblings)));
+    }
+
+    #[test]
+    fn migration_swaps_pro_rata_and_pays_the_remainder_last() {
+        let mut migration = VaultMigration {
+            vault: Pubkey::new_unique(),
+            legacy_vault: Pubkey::new_unique(),
+            legacy_share_mint: Pubkey::new_unique(),
+            legacy_shares_outstanding: 3,
+            reserved_shares: 10,
+            migrated_at: 0,
+            bump: 255,
+        };
+
+        // 1 of 3 legacy shares -> floor(10 / 3)
+        let first = migration.shares_for_legacy(1).unwrap();
+        assert_eq!(first, 3);
+        migration.legacy_shares_outstanding -= 1;
+        migration.reserved_shares -= first;
+
+        // The last 2 legacy shares take everything left
+        assert_eq!(migration.shares_for_legacy(2).unwrap(), 7);
+        assert!(migration.shares_for_legacy(3).is_err());
+    }
+}
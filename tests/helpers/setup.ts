@@ -13,6 +13,8 @@ import {
   mintTo,
   getAccount,
 } from "@solana/spl-token";
+import * as fs from "fs";
+import * as path from "path";
 
 // ─── Program IDs (must match Anchor.toml) ───────────────────────────────────
 
@@ -41,6 +43,9 @@ export const BOND_TYPE_U8 = {
   Custom: 4,
 };
 
+/** Seed deposit locked in every bond vault at `initialize_vault` (BondVault::LOCKED_SHARES) */
+export const LOCKED_SHARES = 1_000;
+
 // ─── PDA helpers ────────────────────────────────────────────────────────────
 
 export function findProtocolConfigPda(programId: PublicKey): [PublicKey, number] {
@@ -88,6 +93,41 @@ export function findUserPositionPda(
   );
 }
 
+export function findUserLimitPda(
+  config: PublicKey,
+  owner: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("user_limit"), config.toBuffer(), owner.toBuffer()],
+    programId
+  );
+}
+
+export function findRoleAssignmentPda(
+  config: PublicKey,
+  member: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("role"), config.toBuffer(), member.toBuffer()],
+    programId
+  );
+}
+
+export function findPendingChangePda(
+  config: PublicKey,
+  changeId: bigint,
+  programId: PublicKey
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(changeId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_change"), config.toBuffer(), idBuffer],
+    programId
+  );
+}
+
 export function findUsdcVaultPda(programId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("stablebond_usdc_vault")],
@@ -96,42 +136,85 @@ export function findUsdcVaultPda(programId: PublicKey): [PublicKey, number] {
 }
 
 export function findBondVaultPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("bond_vault"), authority.toBuffer(), Buffer.from([bondType])],
+    [Buffer.from("bond_vault"), Buffer.from([bondType])],
     programId
   );
 }
 
 export function findBondShareMintPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("bond_share_mint"),
-      authority.toBuffer(),
-      Buffer.from([bondType]),
-    ],
+    [Buffer.from("bond_share_mint"), Buffer.from([bondType])],
     programId
   );
 }
 
 export function findBondCurrencyVaultPda(
-  authority: PublicKey,
   bondType: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("bond_currency_vault"),
-      authority.toBuffer(),
-      Buffer.from([bondType]),
-    ],
+    [Buffer.from("bond_currency_vault"), Buffer.from([bondType])],
+    programId
+  );
+}
+
+export function findBondLockedSharesPda(
+  bondType: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bond_locked_shares"), Buffer.from([bondType])],
+    programId
+  );
+}
+
+export function findVaultRolePda(
+  vault: PublicKey,
+  member: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_role"), vault.toBuffer(), member.toBuffer()],
+    programId
+  );
+}
+
+export function findPendingVaultChangePda(
+  vault: PublicKey,
+  changeId: bigint,
+  programId: PublicKey
+): [PublicKey, number] {
+  const idBuffer = Buffer.alloc(8);
+  idBuffer.writeBigUInt64LE(changeId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_change"), vault.toBuffer(), idBuffer],
+    programId
+  );
+}
+
+export function findAttestorSetPda(
+  vault: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("attestor_set"), vault.toBuffer()],
+    programId
+  );
+}
+
+export function findAttestationHistoryPda(
+  vault: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("attestation_history"), vault.toBuffer()],
     programId
   );
 }
@@ -161,6 +244,18 @@ export function findWithdrawalRequestPda(
   );
 }
 
+/** BPF upgradeable loader: owns every deployed program's ProgramData account */
+export const BPF_UPGRADEABLE_LOADER_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+export function findProgramDataPda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [programId.toBuffer()],
+    BPF_UPGRADEABLE_LOADER_ID
+  );
+}
+
 // ─── Test fixtures ──────────────────────────────────────────────────────────
 
 export interface TestContext {
@@ -204,6 +299,167 @@ export async function setupTestContext(
   return { provider, connection, authority, user, keeper, usdcMint, treasury };
 }
 
+// ─── KYC fixtures (preloaded by Anchor.toml [[test.validator.account]]) ─────
+
+/** Accredit WhitelistEntry for the KYC user: active, non-US, expires 2100 */
+export const KYC_WHITELIST_ENTRY = new PublicKey(
+  "7ve78kAf6zkwekTe4wSkf4zf7EeM76cgvC9n8D7DiXwu"
+);
+/** SovereignIdentity for the KYC user at tier 4 (Diamond) */
+export const KYC_SOVEREIGN_IDENTITY = new PublicKey(
+  "GXpHVKrLUUHMwXQa9SBYyDUEsimPacWzyxtnhCWCK4Uv"
+);
+
+/** The fixed keypair the KYC fixtures were written for, funded with SOL */
+export async function loadKycUser(
+  connection: anchor.web3.Connection
+): Promise<Keypair> {
+  const secret = JSON.parse(
+    fs.readFileSync(
+      path.join(__dirname, "..", "fixtures", "kyc-user.json"),
+      "utf8"
+    )
+  );
+  const user = Keypair.fromSecretKey(Uint8Array.from(secret));
+  const sig = await connection.requestAirdrop(
+    user.publicKey,
+    10 * LAMPORTS_PER_SOL
+  );
+  await connection.confirmTransaction(sig);
+  return user;
+}
+
+// ─── Event helpers ──────────────────────────────────────────────────────────
+
+/** Decode the events `program` emitted in confirmed transaction `signature` */
+export async function getEvents(
+  program: Program,
+  signature: string
+): Promise<{ name: string; data: any }[]> {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(program.programId, program.coder);
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+/** Assert `program` emitted exactly one `name` event and return its data */
+export async function expectEvent(
+  program: Program,
+  signature: string,
+  name: string
+): Promise<any> {
+  const events = (await getEvents(program, signature)).filter(
+    (e) => e.name === name
+  );
+  if (events.length !== 1) {
+    throw new Error(`expected one ${name} event, found ${events.length}`);
+  }
+  return events[0].data;
+}
+
+// ─── Timelock helpers ───────────────────────────────────────────────────────
+
+/** Minimum timelock delay of a `localnet` build, in seconds */
+export const LOCALNET_TIMELOCK_DELAY = 2;
+
+/** Sleep until a change queued now is past its eta */
+export async function waitForTimelock(): Promise<void> {
+  await new Promise((resolve) =>
+    setTimeout(resolve, (LOCALNET_TIMELOCK_DELAY + 1) * 1000)
+  );
+}
+
+/**
+ * Queue a core `ConfigChange`, wait out the timelock and execute it.
+ * Returns the execute signature (confirmed), which carries the update events.
+ */
+export async function applyConfigChange(
+  program: Program,
+  proposer: Keypair,
+  change: any,
+  opts: { roleAssignment?: PublicKey; yieldSource?: PublicKey } = {}
+): Promise<string> {
+  const [configPda] = findProtocolConfigPda(program.programId);
+  const config: any = await program.account.protocolConfig.fetch(configPda);
+  const [pendingChange] = findPendingChangePda(
+    configPda,
+    BigInt(config.nextChangeId.toString()),
+    program.programId
+  );
+
+  await program.methods
+    .queueConfigChange(change)
+    .accounts({
+      proposer: proposer.publicKey,
+      protocolConfig: configPda,
+      pendingChange,
+      roleAssignment: opts.roleAssignment ?? null,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([proposer])
+    .rpc();
+  await waitForTimelock();
+
+  return program.methods
+    .executeConfigChange()
+    .accounts({
+      executor: proposer.publicKey,
+      protocolConfig: configPda,
+      pendingChange,
+      proposer: proposer.publicKey,
+      yieldSource: opts.yieldSource ?? null,
+    })
+    .signers([proposer])
+    .rpc({ commitment: "confirmed" });
+}
+
+/**
+ * Queue a yield `VaultChange`, wait out the timelock and execute it.
+ * Returns the execute signature (confirmed), which carries the update events.
+ */
+export async function applyVaultChange(
+  program: Program,
+  vaultPda: PublicKey,
+  proposer: Keypair,
+  change: any,
+  opts: { roleAssignment?: PublicKey; attestorSet?: PublicKey } = {}
+): Promise<string> {
+  const vault: any = await program.account.bondVault.fetch(vaultPda);
+  const [pendingChange] = findPendingVaultChangePda(
+    vaultPda,
+    BigInt(vault.nextChangeId.toString()),
+    program.programId
+  );
+
+  await program.methods
+    .queueVaultChange(change)
+    .accounts({
+      proposer: proposer.publicKey,
+      vaultConfig: vaultPda,
+      pendingChange,
+      roleAssignment: opts.roleAssignment ?? null,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([proposer])
+    .rpc();
+  await waitForTimelock();
+
+  return program.methods
+    .executeVaultChange()
+    .accounts({
+      executor: proposer.publicKey,
+      vaultConfig: vaultPda,
+      pendingChange,
+      proposer: proposer.publicKey,
+      attestorSet: opts.attestorSet ?? null,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([proposer])
+    .rpc({ commitment: "confirmed" });
+}
+
 export async function createAndFundTokenAccount(
   connection: anchor.web3.Connection,
   payer: Keypair,
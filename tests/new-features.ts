@@ -4,6 +4,7 @@ import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
   createAccount,
+  transfer,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import {
@@ -17,10 +18,21 @@ import {
   findBondVaultPda,
   findBondShareMintPda,
   findBondCurrencyVaultPda,
+  findBondLockedSharesPda,
+  findProgramDataPda,
   findUserSharesPda,
+  findAttestorSetPda,
+  findAttestationHistoryPda,
   findProtocolConfigPda,
+  applyVaultChange,
   TestContext,
 } from "./helpers/setup";
+import {
+  ReserveHolding,
+  buildProofOfReserveTree,
+  encodeSecurityId,
+  getReserveProof,
+} from "../packages/sdk/src/utils/reserve-proof";
 
 // ─── Withdrawal request PDA derivation (new feature) ─────────────────────────
 
@@ -54,6 +66,7 @@ describe("new-features", () => {
   let yieldProgram: Program;
   let ctx: TestContext;
   let usdcMint: PublicKey;
+  let authorityCurrency: PublicKey;
 
   // Shared vault state for yield program tests
   let vaultPda: PublicKey;
@@ -66,33 +79,44 @@ describe("new-features", () => {
     yieldProgram = anchor.workspace.StablebondYield as Program;
     ctx = await setupTestContext(provider);
     usdcMint = ctx.usdcMint;
-
-    // Initialize a US T-Bill vault for the new-feature tests
-    [vaultPda] = findBondVaultPda(
+    // Funds the locked seed deposit of each vault initialized below
+    authorityCurrency = await createAndFundTokenAccount(
+      ctx.connection,
+      ctx.authority,
+      usdcMint,
       ctx.authority.publicKey,
-      BOND_TYPE_U8.UsTBill,
-      yieldProgram.programId
+      1_000_000
     );
+
+    // Initialize a US T-Bill vault for the new-feature tests
+    [vaultPda] = findBondVaultPda(BOND_TYPE_U8.UsTBill, yieldProgram.programId);
     [shareMintPda] = findBondShareMintPda(
-      ctx.authority.publicKey,
       BOND_TYPE_U8.UsTBill,
       yieldProgram.programId
     );
     [currencyVaultPda] = findBondCurrencyVaultPda(
-      ctx.authority.publicKey,
       BOND_TYPE_U8.UsTBill,
       yieldProgram.programId
     );
 
     await yieldProgram.methods
-      .initializeVault(BondType.UsTBill, 450, 450, new BN(0))
+      .initializeVault(BondType.UsTBill, 450, 450, new BN(0), null)
       .accounts({
         authority: ctx.authority.publicKey,
+        deployer: ctx.provider.wallet.publicKey,
+        program: yieldProgram.programId,
+        programData: findProgramDataPda(yieldProgram.programId)[0],
         vaultConfig: vaultPda,
         currencyMint: usdcMint,
         shareMint: shareMintPda,
         currencyVault: currencyVaultPda,
+        authorityCurrency,
+        lockedShares: findBondLockedSharesPda(
+          BOND_TYPE_U8.UsTBill,
+          yieldProgram.programId
+        )[0],
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -127,12 +151,17 @@ describe("new-features", () => {
         user: ctx.user.publicKey,
         vaultConfig: vaultPda,
         currencyVault: currencyVaultPda,
+        currencyMint: usdcMint,
         shareMint: shareMintPda,
         userCurrency,
         userSharesAta,
         userShares: userSharesPda,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        extraAccountMetaList: null,
+        whitelistEntry: null,
+        sovereignIdentity: null,
       })
       .signers([ctx.user])
       .rpc();
@@ -146,14 +175,9 @@ describe("new-features", () => {
     it("authority can configure an oracle feed", async () => {
       const oracleFeed = Keypair.generate().publicKey;
 
-      await yieldProgram.methods
-        .configureOracle(oracleFeed, true)
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        oracle: { oracleFeed, enabled: true },
+      });
 
       const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
       expect(vault.oracleFeed.toBase58()).to.equal(oracleFeed.toBase58());
@@ -164,14 +188,9 @@ describe("new-features", () => {
       // Disable oracle by passing enabled=false
       const oracleFeed = Keypair.generate().publicKey;
 
-      await yieldProgram.methods
-        .configureOracle(oracleFeed, false)
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        oracle: { oracleFeed, enabled: false },
+      });
 
       const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
       expect(vault.oracleEnabled).to.be.false;
@@ -181,14 +200,9 @@ describe("new-features", () => {
       const oracleFeed = Keypair.generate().publicKey;
 
       try {
-        await yieldProgram.methods
-          .configureOracle(oracleFeed, true)
-          .accounts({
-            authority: ctx.user.publicKey,
-            vaultConfig: vaultPda,
-          })
-          .signers([ctx.user])
-          .rpc();
+        await applyVaultChange(yieldProgram, vaultPda, ctx.user, {
+          oracle: { oracleFeed, enabled: true },
+        });
         expect.fail("Should have thrown");
       } catch (err: any) {
         expect(err.toString()).to.include("Unauthorized");
@@ -197,14 +211,9 @@ describe("new-features", () => {
 
     it("accrueYield works with oracle disabled (fallback APY)", async () => {
       // Ensure oracle is disabled
-      await yieldProgram.methods
-        .configureOracle(SystemProgram.programId, false)
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        oracle: { oracleFeed: SystemProgram.programId, enabled: false },
+      });
 
       const vaultBefore = await yieldProgram.account.bondVault.fetch(vaultPda);
       const navBefore = vaultBefore.navPerShare.toNumber();
@@ -243,17 +252,10 @@ describe("new-features", () => {
       await ctx.connection.confirmTransaction(sig);
     });
 
-    it("authority can configure a reserve attestor", async () => {
-      const maxStaleness = new BN(3600); // 1 hour
-
-      await yieldProgram.methods
-        .configureReserveAttestor(attestor.publicKey, maxStaleness)
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+    it("authority can configure a reserve attestor through the timelock", async () => {
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        reserveAttestor: { attestor: attestor.publicKey, maxStaleness: new BN(3600) },
+      });
 
       const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
       expect(vault.reserveAttestor.toBase58()).to.equal(
@@ -266,14 +268,9 @@ describe("new-features", () => {
       const fakeAttestor = Keypair.generate().publicKey;
 
       try {
-        await yieldProgram.methods
-          .configureReserveAttestor(fakeAttestor, new BN(3600))
-          .accounts({
-            authority: ctx.user.publicKey,
-            vaultConfig: vaultPda,
-          })
-          .signers([ctx.user])
-          .rpc();
+        await applyVaultChange(yieldProgram, vaultPda, ctx.user, {
+          reserveAttestor: { attestor: fakeAttestor, maxStaleness: new BN(3600) },
+        });
         expect.fail("Should have thrown");
       } catch (err: any) {
         expect(err.toString()).to.include("Unauthorized");
@@ -281,7 +278,7 @@ describe("new-features", () => {
     });
 
     it("configured attestor can submit reserve attestation", async () => {
-      const attestedReserve = new BN(5_000_000_000); // matches deposits
+      const attestedReserve = new BN(5_500_000_000); // covers deposits + accrued yield
 
       await yieldProgram.methods
         .submitReserveAttestation(attestedReserve)
@@ -293,14 +290,17 @@ describe("new-features", () => {
         .rpc();
 
       const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
-      expect(vault.attestedReserve.toNumber()).to.equal(5_000_000_000);
+      expect(vault.attestedReserve.toNumber()).to.equal(5_500_000_000);
+      expect(vault.attestedCoverageBps.toNumber()).to.be.at.least(
+        vault.minCoverageBps
+      );
       expect(vault.lastAttestationAt.toNumber()).to.be.greaterThan(0);
     });
 
     it("non-attestor cannot submit attestation", async () => {
       try {
         await yieldProgram.methods
-          .submitReserveAttestation(new BN(5_000_000_000))
+          .submitReserveAttestation(new BN(5_500_000_000))
           .accounts({
             attestor: ctx.user.publicKey,
             vaultConfig: vaultPda,
@@ -319,18 +319,13 @@ describe("new-features", () => {
 
     it("accrual pauses when attestation is stale", async () => {
       // Reconfigure attestor with a very short max_staleness (1 second)
-      await yieldProgram.methods
-        .configureReserveAttestor(attestor.publicKey, new BN(1))
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        reserveAttestor: { attestor: attestor.publicKey, maxStaleness: new BN(1) },
+      });
 
       // Submit a fresh attestation
       await yieldProgram.methods
-        .submitReserveAttestation(new BN(5_000_000_000))
+        .submitReserveAttestation(new BN(5_500_000_000))
         .accounts({
           attestor: attestor.publicKey,
           vaultConfig: vaultPda,
@@ -359,20 +354,51 @@ describe("new-features", () => {
       expect(navAfter).to.equal(navBefore);
     });
 
-    it("accrual resumes after fresh attestation", async () => {
-      // Restore a reasonable max_staleness
+    it("accrual pauses when attested coverage is below minimum", async () => {
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        reserveAttestor: { attestor: attestor.publicKey, maxStaleness: new BN(3600) },
+      });
+
+      // 10% backing: fresh, but far below the 99% default minimum
       await yieldProgram.methods
-        .configureReserveAttestor(attestor.publicKey, new BN(3600))
+        .submitReserveAttestation(new BN(500_000_000))
         .accounts({
-          authority: ctx.authority.publicKey,
+          attestor: attestor.publicKey,
           vaultConfig: vaultPda,
         })
-        .signers([ctx.authority])
+        .signers([attestor])
+        .rpc();
+
+      const vaultBefore = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(vaultBefore.attestedCoverageBps.toNumber()).to.be.below(
+        vaultBefore.minCoverageBps
+      );
+
+      await sleep(2000);
+
+      await yieldProgram.methods
+        .accrueYield()
+        .accounts({
+          vaultConfig: vaultPda,
+          bondPriceOracle: SystemProgram.programId,
+        })
         .rpc();
 
+      const vaultAfter = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(vaultAfter.navPerShare.toNumber()).to.equal(
+        vaultBefore.navPerShare.toNumber()
+      );
+    });
+
+    it("accrual resumes after fresh attestation", async () => {
+      // Restore a reasonable max_staleness
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+        reserveAttestor: { attestor: attestor.publicKey, maxStaleness: new BN(3600) },
+      });
+
       // Submit a fresh attestation
       await yieldProgram.methods
-        .submitReserveAttestation(new BN(5_000_000_000))
+        .submitReserveAttestation(new BN(5_500_000_000))
         .accounts({
           attestor: attestor.publicKey,
           vaultConfig: vaultPda,
@@ -400,6 +426,164 @@ describe("new-features", () => {
       // NAV should increase since attestation is fresh
       expect(navAfter).to.be.at.least(navBefore);
     });
+
+    it("commits holdings to a Merkle root and verifies a lot on-chain", async () => {
+      const lots: ReserveHolding[] = [
+        { securityId: "US912797KJ59", quantity: 2_000_000_000n, price: 990_000n },
+        { securityId: "US912797KS58", quantity: 2_000_000_000n, price: 985_000n },
+        { securityId: "US912797LB17", quantity: 1_600_000_000n, price: 980_000n },
+      ];
+      const tree = buildProofOfReserveTree(lots);
+      const { root } = tree;
+
+      await yieldProgram.methods
+        .submitReserveAttestation(new BN(5_500_000_000), {
+          merkleRoot: Array.from(root),
+          holdingsCount: lots.length,
+        })
+        .accounts({
+          attestor: attestor.publicKey,
+          vaultConfig: vaultPda,
+        })
+        .signers([attestor])
+        .rpc();
+
+      const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(Buffer.from(vault.reserveMerkleRoot).equals(root)).to.be.true;
+      expect(vault.reserveHoldingsCount).to.equal(3);
+
+      const toArg = (lot: ReserveHolding) => ({
+        securityId: Array.from(encodeSecurityId(lot.securityId)),
+        quantity: new BN(lot.quantity.toString()),
+        price: new BN(lot.price.toString()),
+      });
+      const proofArg = (i: number) => getReserveProof(tree, lots[i]).proof;
+
+      await yieldProgram.methods
+        .verifyReserveHolding(toArg(lots[2]), proofArg(2))
+        .accounts({ vaultConfig: vaultPda })
+        .rpc();
+
+      try {
+        await yieldProgram.methods
+          .verifyReserveHolding(
+            toArg({ ...lots[2], quantity: 9_000_000_000n }),
+            proofArg(2)
+          )
+          .accounts({ vaultConfig: vaultPda })
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidReserveProof");
+      }
+    });
+
+    it("keeps a history of attestations with NAV and supply snapshots", async () => {
+      const [historyPda] = findAttestationHistoryPda(vaultPda, yieldProgram.programId);
+      const before = await yieldProgram.account.attestationHistory.fetch(historyPda);
+      const countBefore = before.count.toNumber();
+      // Every attestation in this block so far was recorded
+      expect(countBefore).to.be.greaterThan(0);
+
+      await yieldProgram.methods
+        .submitReserveAttestation(new BN(5_700_000_000))
+        .accounts({ attestor: attestor.publicKey, vaultConfig: vaultPda })
+        .signers([attestor])
+        .rpc();
+
+      const history = await yieldProgram.account.attestationHistory.fetch(historyPda);
+      const vault = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(history.count.toNumber()).to.equal(countBefore + 1);
+      expect(history.vault.toBase58()).to.equal(vaultPda.toBase58());
+
+      const latest = history.entries[countBefore % history.entries.length];
+      expect(latest.attestedReserve.toNumber()).to.equal(5_700_000_000);
+      expect(latest.attestor.toBase58()).to.equal(attestor.publicKey.toBase58());
+      expect(latest.navPerShare.toString()).to.equal(vault.navPerShare.toString());
+      expect(latest.totalShares.toString()).to.equal(vault.totalShares.toString());
+    });
+
+    it("records the lowest figure once a 2-of-3 attestor quorum is reached", async () => {
+      const members = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+      for (const m of members) {
+        const sig = await ctx.connection.requestAirdrop(
+          m.publicKey,
+          anchor.web3.LAMPORTS_PER_SOL
+        );
+        await ctx.connection.confirmTransaction(sig);
+      }
+      const [attestorSetPda] = findAttestorSetPda(vaultPda, yieldProgram.programId);
+
+      const attestorSet = (threshold: number) => ({
+        attestorSet: {
+          attestors: members.map((m) => m.publicKey),
+          threshold,
+          window: new BN(600),
+          toleranceBps: 100, // 1% tolerance
+        },
+      });
+
+      // A quorum of one is rejected when queued
+      try {
+        await applyVaultChange(yieldProgram, vaultPda, ctx.authority, attestorSet(1), {
+          attestorSet: attestorSetPda,
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidAttestorSet");
+      }
+
+      await applyVaultChange(yieldProgram, vaultPda, ctx.authority, attestorSet(2), {
+        attestorSet: attestorSetPda,
+      });
+
+      let vault = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(vault.reserveAttestor.toBase58()).to.equal(attestorSetPda.toBase58());
+
+      // The single-key path is closed while the quorum is active
+      try {
+        await yieldProgram.methods
+          .submitReserveAttestation(new BN(5_500_000_000))
+          .accounts({ attestor: attestor.publicKey, vaultConfig: vaultPda })
+          .signers([attestor])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+
+      const submit = (member: Keypair, amount: number) =>
+        yieldProgram.methods
+          .submitQuorumAttestation(new BN(amount))
+          .accounts({
+            attestor: member.publicKey,
+            vaultConfig: vaultPda,
+            attestorSet: attestorSetPda,
+          })
+          .signers([member])
+          .rpc();
+
+      await submit(members[0], 6_000_000_000);
+      vault = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(vault.attestedReserve.toNumber()).to.equal(5_700_000_000);
+
+      await submit(members[1], 5_600_000_000);
+      vault = await yieldProgram.account.bondVault.fetch(vaultPda);
+      expect(vault.attestedReserve.toNumber()).to.equal(5_600_000_000);
+
+      const set = await yieldProgram.account.attestorSet.fetch(attestorSetPda);
+      expect(set.roundStart.toNumber()).to.equal(0);
+
+      // The vault cannot fall back to a single attestor
+      try {
+        await applyVaultChange(yieldProgram, vaultPda, ctx.authority, {
+          reserveAttestor: { attestor: attestor.publicKey, maxStaleness: new BN(3600) },
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("QuorumActive");
+      }
+    });
   });
 
   // ═══════════════════════════════════════════════════════════════════════════
@@ -439,6 +623,7 @@ describe("new-features", () => {
             keeper: ctx.keeper.publicKey,
             vaultConfig: vaultPda,
             currencyVault: currencyVaultPda,
+            currencyMint: usdcMint,
             keeperToken: keeperTokenAccount,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
@@ -465,6 +650,7 @@ describe("new-features", () => {
           keeper: ctx.keeper.publicKey,
           vaultConfig: vaultPda,
           currencyVault: currencyVaultPda,
+          currencyMint: usdcMint,
           keeperToken: keeperTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
@@ -563,30 +749,36 @@ describe("new-features", () => {
     before(async () => {
       // Initialize a separate MxCetes vault for gating tests
       [gateVaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         yieldProgram.programId
       );
       [gateShareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         yieldProgram.programId
       );
       [gateCurrencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         yieldProgram.programId
       );
 
       await yieldProgram.methods
-        .initializeVault(BondType.MxCetes, 900, 900, new BN(0))
+        .initializeVault(BondType.MxCetes, 900, 900, new BN(0), null)
         .accounts({
           authority: ctx.authority.publicKey,
+          deployer: ctx.provider.wallet.publicKey,
+          program: yieldProgram.programId,
+          programData: findProgramDataPda(yieldProgram.programId)[0],
           vaultConfig: gateVaultPda,
           currencyMint: usdcMint,
           shareMint: gateShareMintPda,
           currencyVault: gateCurrencyVaultPda,
+          authorityCurrency,
+          lockedShares: findBondLockedSharesPda(
+            BOND_TYPE_U8.MxCetes,
+            yieldProgram.programId
+          )[0],
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -621,12 +813,17 @@ describe("new-features", () => {
           user: ctx.user.publicKey,
           vaultConfig: gateVaultPda,
           currencyVault: gateCurrencyVaultPda,
+          currencyMint: usdcMint,
           shareMint: gateShareMintPda,
           userCurrency,
           userSharesAta,
           userShares: userSharesPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          extraAccountMetaList: null,
+          whitelistEntry: null,
+          sovereignIdentity: null,
         })
         .signers([ctx.user])
         .rpc();
@@ -640,11 +837,13 @@ describe("new-features", () => {
             user: ctx.user.publicKey,
             vaultConfig: gateVaultPda,
             currencyVault: gateCurrencyVaultPda,
+            currencyMint: usdcMint,
             shareMint: gateShareMintPda,
             userCurrency,
             userSharesAta,
             userShares: userSharesPda,
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([ctx.user])
           .rpc();
@@ -677,11 +876,13 @@ describe("new-features", () => {
           user: ctx.user.publicKey,
           vaultConfig: gateVaultPda,
           currencyVault: gateCurrencyVaultPda,
+          currencyMint: usdcMint,
           shareMint: gateShareMintPda,
           userCurrency,
           userSharesAta,
           userShares: userSharesPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ctx.user])
         .rpc();
@@ -690,6 +891,75 @@ describe("new-features", () => {
       expect(Number(balanceAfter)).to.be.lessThan(Number(balanceBefore));
     });
 
+    it("recipient of transferred shares can withdraw, and sync reconciles UserShares", async () => {
+      const recipient = ctx.keeper;
+      const recipientSharesAta = await createAccount(
+        ctx.connection,
+        recipient,
+        gateShareMintPda,
+        recipient.publicKey
+      );
+      const recipientCurrency = await createAndFundTokenAccount(
+        ctx.connection,
+        ctx.authority,
+        usdcMint,
+        recipient.publicKey,
+        0
+      );
+      await transfer(
+        ctx.connection,
+        ctx.user,
+        userSharesAta,
+        recipientSharesAta,
+        ctx.user,
+        50_000_000
+      );
+
+      // No UserShares account for the recipient: the token balance is enough
+      await yieldProgram.methods
+        .withdraw(new BN(50_000_000))
+        .accounts({
+          user: recipient.publicKey,
+          vaultConfig: gateVaultPda,
+          currencyVault: gateCurrencyVaultPda,
+          currencyMint: usdcMint,
+          shareMint: gateShareMintPda,
+          userCurrency: recipientCurrency,
+          userSharesAta: recipientSharesAta,
+          userShares: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([recipient])
+        .rpc();
+      expect(
+        Number(await getTokenBalance(ctx.connection, recipientSharesAta))
+      ).to.equal(0);
+      expect(
+        Number(await getTokenBalance(ctx.connection, recipientCurrency))
+      ).to.be.greaterThan(0);
+
+      // The sender's metadata still counts the transferred shares until synced
+      const stale = await yieldProgram.account.userShares.fetch(userSharesPda);
+      const balance = await getTokenBalance(ctx.connection, userSharesAta);
+      expect(stale.shares.toString()).to.not.equal(balance.toString());
+
+      await yieldProgram.methods
+        .syncUserShares()
+        .accounts({
+          user: ctx.user.publicKey,
+          vaultConfig: gateVaultPda,
+          userSharesAta,
+          userShares: userSharesPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.user])
+        .rpc();
+
+      const synced = await yieldProgram.account.userShares.fetch(userSharesPda);
+      expect(synced.shares.toString()).to.equal(balance.toString());
+    });
+
     it("non-authority cannot toggle immediate withdraw", async () => {
       try {
         await yieldProgram.methods
@@ -21,6 +21,9 @@ import {
   findBondRegistryPda,
   findYieldSourcePda,
   findUserPositionPda,
+  findUserLimitPda,
+  findRoleAssignmentPda,
+  findPendingChangePda,
   findUsdcVaultPda,
   findBondVaultPda,
   findBondShareMintPda,
@@ -28,6 +31,17 @@ import {
   makeUsTBillConfig,
   makeMxCetesConfig,
   makeJpJgbConfig,
+  findBondLockedSharesPda,
+  findProgramDataPda,
+  findWithdrawalRequestPda,
+  loadKycUser,
+  expectEvent,
+  applyConfigChange,
+  waitForTimelock,
+  LOCALNET_TIMELOCK_DELAY,
+  KYC_WHITELIST_ENTRY,
+  KYC_SOVEREIGN_IDENTITY,
+  LOCKED_SHARES,
   TestContext,
 } from "./helpers/setup";
 
@@ -58,6 +72,9 @@ describe("stablebond-core", () => {
     it("initializes the protocol config and bond registry", async () => {
       await coreProgram.methods
         .initializeProtocol({
+          treasury: ctx.treasury.publicKey,
+          kycRegistry: Keypair.generate().publicKey,
+          sovereignProgram: Keypair.generate().publicKey,
           conversionFeeBps: 30,
           managementFeeBps: 100,
           performanceFeeBps: 1000,
@@ -68,8 +85,6 @@ describe("stablebond-core", () => {
           bondRegistry: registryPda,
           usdcMint: ctx.usdcMint,
           usdcVault: usdcVaultPda,
-          treasury: ctx.treasury.publicKey,
-          sovereignProgram: Keypair.generate().publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -86,6 +101,7 @@ describe("stablebond-core", () => {
       expect(config.performanceFeeBps).to.equal(1000);
       expect(config.isActive).to.be.true;
       expect(config.numSupportedBonds).to.equal(0);
+      expect(config.timelockDelay.toNumber()).to.equal(LOCALNET_TIMELOCK_DELAY);
     });
   });
 
@@ -279,13 +295,72 @@ describe("stablebond-core", () => {
     });
 
     it("updates protocol config fees", async () => {
+      const sig = await applyConfigChange(coreProgram, ctx.authority, {
+        protocolConfig: {
+          0: {
+            treasury: null,
+            conversionFeeBps: 50,
+            managementFeeBps: 150,
+            performanceFeeBps: 1500,
+            tierLimitWindows: null,
+            complianceOfficer: null,
+          },
+        },
+      });
+
+      const config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.conversionFeeBps).to.equal(50);
+      expect(config.managementFeeBps).to.equal(150);
+      expect(config.performanceFeeBps).to.equal(1500);
+
+      const event = await expectEvent(coreProgram, sig, "ProtocolConfigUpdated");
+      expect(event.authority.toBase58()).to.equal(
+        ctx.authority.publicKey.toBase58()
+      );
+      expect(event.oldConversionFeeBps).to.equal(30);
+      expect(event.newConversionFeeBps).to.equal(50);
+      expect(event.oldManagementFeeBps).to.equal(100);
+      expect(event.newManagementFeeBps).to.equal(150);
+      expect(event.oldPerformanceFeeBps).to.equal(1000);
+      expect(event.newPerformanceFeeBps).to.equal(1500);
+      expect(event.newTreasury.toBase58()).to.equal(
+        ctx.treasury.publicKey.toBase58()
+      );
+    });
+
+    it("pauses individual operations with the pause bitmask", async () => {
+      const DEPOSITS = 1 << 0;
+      const YIELD_CLAIMS = 1 << 4;
+
       await coreProgram.methods
-        .updateProtocolConfig({
-          treasury: null,
-          conversionFeeBps: 50,
-          managementFeeBps: 150,
-          performanceFeeBps: 1500,
+        .setPauseFlags(DEPOSITS | YIELD_CLAIMS)
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
         })
+        .signers([ctx.authority])
+        .rpc();
+
+      let config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.pausedOps).to.equal(DEPOSITS | YIELD_CLAIMS);
+      expect(config.isActive).to.be.true;
+
+      try {
+        await coreProgram.methods
+          .setPauseFlags(1 << 6)
+          .accounts({
+            authority: ctx.authority.publicKey,
+            protocolConfig: configPda,
+          })
+          .signers([ctx.authority])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPauseMask");
+      }
+
+      await coreProgram.methods
+        .setPauseFlags(0)
         .accounts({
           authority: ctx.authority.publicKey,
           protocolConfig: configPda,
@@ -293,10 +368,696 @@ describe("stablebond-core", () => {
         .signers([ctx.authority])
         .rpc();
 
-      const config = await coreProgram.account.protocolConfig.fetch(configPda);
-      expect(config.conversionFeeBps).to.equal(50);
-      expect(config.managementFeeBps).to.equal(150);
+      config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.pausedOps).to.equal(0);
+    });
+
+    it("delegates pausing to a Pauser role", async () => {
+      const PAUSER = 1 << 0;
+      const [rolePda] = findRoleAssignmentPda(
+        configPda,
+        ctx.user.publicKey,
+        coreProgram.programId
+      );
+
+      await coreProgram.methods
+        .setMemberRoles(ctx.user.publicKey, PAUSER)
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          roleAssignment: rolePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      await coreProgram.methods
+        .pauseProtocol()
+        .accounts({
+          authority: ctx.user.publicKey,
+          protocolConfig: configPda,
+          roleAssignment: rolePda,
+        })
+        .signers([ctx.user])
+        .rpc();
+      let config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.isActive).to.be.false;
+
+      await coreProgram.methods
+        .resumeProtocol()
+        .accounts({
+          authority: ctx.user.publicKey,
+          protocolConfig: configPda,
+          roleAssignment: rolePda,
+        })
+        .signers([ctx.user])
+        .rpc();
+      config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.isActive).to.be.true;
+
+      // A Pauser cannot change fees
+      try {
+        await applyConfigChange(
+          coreProgram,
+          ctx.user,
+          {
+            protocolConfig: {
+              0: {
+                treasury: null,
+                conversionFeeBps: 10,
+                managementFeeBps: null,
+                performanceFeeBps: null,
+                tierLimitWindows: null,
+                complianceOfficer: null,
+              },
+            },
+          },
+          { roleAssignment: rolePda }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.include("MissingRole");
+      }
+
+      // Revoke
+      await coreProgram.methods
+        .setMemberRoles(ctx.user.publicKey, 0)
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          roleAssignment: rolePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      try {
+        await coreProgram.methods
+          .pauseProtocol()
+          .accounts({
+            authority: ctx.user.publicKey,
+            protocolConfig: configPda,
+            roleAssignment: rolePda,
+          })
+          .signers([ctx.user])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.include("MissingRole");
+      }
+    });
+
+    it("queues, executes and cancels timelocked config changes", async () => {
+      let config = await coreProgram.account.protocolConfig.fetch(configPda);
+      const firstId = BigInt(config.nextChangeId.toString());
+      const [firstChange] = findPendingChangePda(
+        configPda,
+        firstId,
+        coreProgram.programId
+      );
+
+      await coreProgram.methods
+        .queueConfigChange({
+          protocolConfig: {
+            0: {
+              treasury: null,
+              conversionFeeBps: 40,
+              managementFeeBps: null,
+              performanceFeeBps: null,
+              tierLimitWindows: null,
+              complianceOfficer: null,
+            },
+          },
+        })
+        .accounts({
+          proposer: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          pendingChange: firstChange,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      // Not executable before the eta
+      try {
+        await coreProgram.methods
+          .executeConfigChange()
+          .accounts({
+            executor: ctx.user.publicKey,
+            protocolConfig: configPda,
+            pendingChange: firstChange,
+            proposer: ctx.authority.publicKey,
+            yieldSource: null,
+          })
+          .signers([ctx.user])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("TimelockNotElapsed");
+      }
+
+      await waitForTimelock();
+      await coreProgram.methods
+        .executeConfigChange()
+        .accounts({
+          executor: ctx.user.publicKey,
+          protocolConfig: configPda,
+          pendingChange: firstChange,
+          proposer: ctx.authority.publicKey,
+          yieldSource: null,
+        })
+        .signers([ctx.user])
+        .rpc();
+
+      config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.conversionFeeBps).to.equal(40);
+
+      const [secondChange] = findPendingChangePda(
+        configPda,
+        firstId + 1n,
+        coreProgram.programId
+      );
+      await coreProgram.methods
+        .queueConfigChange({
+          protocolConfig: {
+            0: {
+              treasury: null,
+              conversionFeeBps: null,
+              managementFeeBps: null,
+              performanceFeeBps: 5000,
+              tierLimitWindows: null,
+              complianceOfficer: null,
+            },
+          },
+        })
+        .accounts({
+          proposer: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          pendingChange: secondChange,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      await coreProgram.methods
+        .cancelConfigChange()
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          pendingChange: secondChange,
+          proposer: ctx.authority.publicKey,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      config = await coreProgram.account.protocolConfig.fetch(configPda);
       expect(config.performanceFeeBps).to.equal(1500);
+      const cancelled = await ctx.connection.getAccountInfo(secondChange);
+      expect(cancelled).to.be.null;
+    });
+
+    it("transfers authority in two steps", async () => {
+      const nominee = Keypair.generate();
+
+      await coreProgram.methods
+        .proposeAuthority(nominee.publicKey)
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      let config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.authority.toBase58()).to.equal(
+        ctx.authority.publicKey.toBase58()
+      );
+      expect(config.pendingAuthority.toBase58()).to.equal(
+        nominee.publicKey.toBase58()
+      );
+
+      // Only the nominee can accept
+      try {
+        await coreProgram.methods
+          .acceptAuthority()
+          .accounts({
+            newAuthority: ctx.user.publicKey,
+            protocolConfig: configPda,
+          })
+          .signers([ctx.user])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+
+      await coreProgram.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: nominee.publicKey,
+          protocolConfig: configPda,
+        })
+        .signers([nominee])
+        .rpc();
+
+      config = await coreProgram.account.protocolConfig.fetch(configPda);
+      expect(config.authority.toBase58()).to.equal(nominee.publicKey.toBase58());
+      expect(config.pendingAuthority.toBase58()).to.equal(
+        PublicKey.default.toBase58()
+      );
+
+      // Hand control back so later tests keep using ctx.authority
+      await coreProgram.methods
+        .proposeAuthority(ctx.authority.publicKey)
+        .accounts({
+          authority: nominee.publicKey,
+          protocolConfig: configPda,
+        })
+        .signers([nominee])
+        .rpc();
+      await coreProgram.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+        })
+        .signers([ctx.authority])
+        .rpc();
+    });
+  });
+
+  describe("withdrawal requests", () => {
+    // Fixed user with preloaded KYC fixtures (tier 4), depositing into a
+    // USDC-settled T-Bill yield source
+    let kycUser: Keypair;
+    let yieldSourcePda: PublicKey;
+    let depositVault: PublicKey;
+    let userToken: PublicKey;
+    let positionPda: PublicKey;
+    let complianceOfficer: Keypair;
+
+    before(async () => {
+      kycUser = await loadKycUser(ctx.connection);
+      [yieldSourcePda] = findYieldSourcePda(
+        configPda,
+        ctx.usdcMint,
+        coreProgram.programId
+      );
+      [positionPda] = findUserPositionPda(
+        configPda,
+        kycUser.publicKey,
+        BOND_TYPE_U8.UsTBill,
+        coreProgram.programId
+      );
+
+      // Deposits check the linked bond vault's reserves; other suites in the
+      // same validator may already have created it
+      const [bondVaultPda] = findBondVaultPda(
+        BOND_TYPE_U8.UsTBill,
+        yieldProgram.programId
+      );
+      if ((await ctx.connection.getAccountInfo(bondVaultPda)) === null) {
+        const authorityCurrency = await createAndFundTokenAccount(
+          ctx.connection,
+          ctx.authority,
+          ctx.usdcMint,
+          ctx.authority.publicKey,
+          LOCKED_SHARES
+        );
+        await yieldProgram.methods
+          .initializeVault(BondType.UsTBill, 450, 450, new BN(0), null)
+          .accounts({
+            authority: ctx.authority.publicKey,
+            deployer: ctx.provider.wallet.publicKey,
+            program: yieldProgram.programId,
+            programData: findProgramDataPda(yieldProgram.programId)[0],
+            vaultConfig: bondVaultPda,
+            currencyMint: ctx.usdcMint,
+            shareMint: findBondShareMintPda(
+              BOND_TYPE_U8.UsTBill,
+              yieldProgram.programId
+            )[0],
+            currencyVault: findBondCurrencyVaultPda(
+              BOND_TYPE_U8.UsTBill,
+              yieldProgram.programId
+            )[0],
+            authorityCurrency,
+            lockedShares: findBondLockedSharesPda(
+              BOND_TYPE_U8.UsTBill,
+              yieldProgram.programId
+            )[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([ctx.authority])
+          .rpc();
+      }
+
+      depositVault = await createAccount(
+        ctx.connection,
+        ctx.authority,
+        ctx.usdcMint,
+        yieldSourcePda,
+        Keypair.generate()
+      );
+      await coreProgram.methods
+        .registerYieldSource({
+          name: Array.from(Buffer.from("US T-Bill USDC".padEnd(32, "\0"))),
+          sourceType: { sovereignBond: {} },
+          bondType: BondType.UsTBill,
+          depositVault,
+          yieldTokenVault: Keypair.generate().publicKey,
+          currencyMint: ctx.usdcMint,
+          oracleFeed: oracleFeed.publicKey,
+          couponRateBps: 450,
+          maturityDate: new BN(0),
+          haircutBps: 0,
+          allocationWeightBps: 10_000,
+          minDeposit: new BN(1_000_000),
+          maxAllocation: new BN(1_000_000_000_000),
+        })
+        .accounts({
+          authority: ctx.authority.publicKey,
+          protocolConfig: configPda,
+          tokenMint: ctx.usdcMint,
+          yieldSource: yieldSourcePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      userToken = await createAndFundTokenAccount(
+        ctx.connection,
+        ctx.authority,
+        ctx.usdcMint,
+        kycUser.publicKey,
+        1_000_000_000
+      );
+    });
+
+    it("deposits with a KYC'd identity and opens a position", async () => {
+      await coreProgram.methods
+        .depositDirect(new BN(100_000_000), BondType.UsTBill)
+        .accounts({
+          user: kycUser.publicKey,
+          protocolConfig: configPda,
+          yieldSource: yieldSourcePda,
+          bondVault: findBondVaultPda(
+            BOND_TYPE_U8.UsTBill,
+            yieldProgram.programId
+          )[0],
+          userToken,
+          depositVault,
+          settlementMint: ctx.usdcMint,
+          userPosition: positionPda,
+          bondRegistry: registryPda,
+          userLimit: findUserLimitPda(
+            configPda,
+            kycUser.publicKey,
+            coreProgram.programId
+          )[0],
+          fxOracle: oracleFeed.publicKey,
+          whitelistEntry: KYC_WHITELIST_ENTRY,
+          sovereignIdentity: KYC_SOVEREIGN_IDENTITY,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([kycUser])
+        .rpc();
+
+      const position = await coreProgram.account.userPosition.fetch(
+        positionPda
+      );
+      expect(position.currentShares.toNumber()).to.equal(100_000_000);
+      expect(position.sovereignTier).to.equal(4);
+      expect(await getTokenBalance(ctx.connection, depositVault)).to.equal(
+        100_000_000n
+      );
+    });
+
+    it("cancels a withdrawal request and emits WithdrawalCancelled", async () => {
+      const nonce = 1n;
+      const [requestPda] = findWithdrawalRequestPda(
+        configPda,
+        kycUser.publicKey,
+        nonce,
+        coreProgram.programId
+      );
+
+      await coreProgram.methods
+        .requestWithdrawal(new BN(40_000_000), BondType.UsTBill)
+        .accounts({
+          user: kycUser.publicKey,
+          protocolConfig: configPda,
+          yieldSource: yieldSourcePda,
+          userPosition: positionPda,
+          withdrawalRequest: requestPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([kycUser])
+        .rpc();
+
+      let position = await coreProgram.account.userPosition.fetch(positionPda);
+      expect(position.currentShares.toNumber()).to.equal(60_000_000);
+
+      const sig = await coreProgram.methods
+        .cancelWithdrawal(BondType.UsTBill, new BN(nonce.toString()))
+        .accounts({
+          user: kycUser.publicKey,
+          protocolConfig: configPda,
+          yieldSource: yieldSourcePda,
+          userPosition: positionPda,
+          withdrawalRequest: requestPda,
+        })
+        .signers([kycUser])
+        .rpc({ commitment: "confirmed" });
+
+      position = await coreProgram.account.userPosition.fetch(positionPda);
+      expect(position.currentShares.toNumber()).to.equal(100_000_000);
+      const request = await coreProgram.account.withdrawalRequest.fetch(
+        requestPda
+      );
+      expect(request.isCancelled).to.be.true;
+
+      const event = await expectEvent(coreProgram, sig, "WithdrawalCancelled");
+      expect(event.user.toBase58()).to.equal(kycUser.publicKey.toBase58());
+      expect(event.bondType).to.equal(BOND_TYPE_U8.UsTBill);
+      expect(event.sharesReturned.toNumber()).to.equal(40_000_000);
+      expect(event.nonce.toNumber()).to.equal(1);
+    });
+
+    it("only the compliance officer can freeze a position", async () => {
+      complianceOfficer = Keypair.generate();
+      await applyConfigChange(coreProgram, ctx.authority, {
+        protocolConfig: {
+          0: {
+            treasury: null,
+            conversionFeeBps: null,
+            managementFeeBps: null,
+            performanceFeeBps: null,
+            tierLimitWindows: null,
+            complianceOfficer: complianceOfficer.publicKey,
+          },
+        },
+      });
+
+      // Neither the position owner nor the protocol authority may freeze
+      for (const signer of [kycUser, ctx.authority]) {
+        try {
+          await coreProgram.methods
+            .freezePosition({ sanctions: {} })
+            .accounts({
+              complianceOfficer: signer.publicKey,
+              protocolConfig: configPda,
+              userPosition: positionPda,
+            })
+            .signers([signer])
+            .rpc();
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.toString()).to.include("Unauthorized");
+        }
+      }
+
+      const sig = await coreProgram.methods
+        .freezePosition({ sanctions: {} })
+        .accounts({
+          complianceOfficer: complianceOfficer.publicKey,
+          protocolConfig: configPda,
+          userPosition: positionPda,
+        })
+        .signers([complianceOfficer])
+        .rpc({ commitment: "confirmed" });
+
+      const position = await coreProgram.account.userPosition.fetch(
+        positionPda
+      );
+      expect(position.isFrozen).to.be.true;
+      expect(position.freezeReason).to.deep.equal({ sanctions: {} });
+
+      const event = await expectEvent(coreProgram, sig, "PositionFrozen");
+      expect(event.user.toBase58()).to.equal(kycUser.publicKey.toBase58());
+      expect(event.complianceOfficer.toBase58()).to.equal(
+        complianceOfficer.publicKey.toBase58()
+      );
+
+      try {
+        await coreProgram.methods
+          .unfreezePosition()
+          .accounts({
+            complianceOfficer: kycUser.publicKey,
+            protocolConfig: configPda,
+            userPosition: positionPda,
+          })
+          .signers([kycUser])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+
+      await coreProgram.methods
+        .unfreezePosition()
+        .accounts({
+          complianceOfficer: complianceOfficer.publicKey,
+          protocolConfig: configPda,
+          userPosition: positionPda,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+    });
+
+    it("rejects withdrawal requests, withdrawal claims and yield claims while frozen", async () => {
+      // Open a request before the freeze so there is something to claim
+      const [openRequest] = findWithdrawalRequestPda(
+        configPda,
+        kycUser.publicKey,
+        2n,
+        coreProgram.programId
+      );
+      await coreProgram.methods
+        .requestWithdrawal(new BN(10_000_000), BondType.UsTBill)
+        .accounts({
+          user: kycUser.publicKey,
+          protocolConfig: configPda,
+          yieldSource: yieldSourcePda,
+          userPosition: positionPda,
+          withdrawalRequest: openRequest,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([kycUser])
+        .rpc();
+
+      await coreProgram.methods
+        .freezePosition({ legalHold: {} })
+        .accounts({
+          complianceOfficer: complianceOfficer.publicKey,
+          protocolConfig: configPda,
+          userPosition: positionPda,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+
+      const attempts: [string, () => Promise<string>][] = [
+        [
+          "request_withdrawal",
+          () =>
+            coreProgram.methods
+              .requestWithdrawal(new BN(10_000_000), BondType.UsTBill)
+              .accounts({
+                user: kycUser.publicKey,
+                protocolConfig: configPda,
+                yieldSource: yieldSourcePda,
+                userPosition: positionPda,
+                withdrawalRequest: findWithdrawalRequestPda(
+                  configPda,
+                  kycUser.publicKey,
+                  3n,
+                  coreProgram.programId
+                )[0],
+                systemProgram: SystemProgram.programId,
+              })
+              .signers([kycUser])
+              .rpc(),
+        ],
+        [
+          "claim_withdrawal",
+          () =>
+            coreProgram.methods
+              .claimWithdrawal(BondType.UsTBill, new BN(2))
+              .accounts({
+                user: kycUser.publicKey,
+                protocolConfig: configPda,
+                yieldSource: yieldSourcePda,
+                userPosition: positionPda,
+                withdrawalRequest: openRequest,
+                depositVault,
+                settlementMint: ctx.usdcMint,
+                userToken,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([kycUser])
+              .rpc(),
+        ],
+        [
+          "claim_yield",
+          () =>
+            coreProgram.methods
+              .claimYield(BondType.UsTBill)
+              .accounts({
+                user: kycUser.publicKey,
+                protocolConfig: configPda,
+                yieldSource: yieldSourcePda,
+                userPosition: positionPda,
+                depositVault,
+                settlementMint: ctx.usdcMint,
+                userToken,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              })
+              .signers([kycUser])
+              .rpc(),
+        ],
+      ];
+      for (const [name, attempt] of attempts) {
+        try {
+          await attempt();
+          expect.fail(`${name} should have thrown`);
+        } catch (err: any) {
+          expect(err.toString(), name).to.include("PositionFrozen");
+        }
+      }
+
+      const sig = await coreProgram.methods
+        .unfreezePosition()
+        .accounts({
+          complianceOfficer: complianceOfficer.publicKey,
+          protocolConfig: configPda,
+          userPosition: positionPda,
+        })
+        .signers([complianceOfficer])
+        .rpc({ commitment: "confirmed" });
+      const event = await expectEvent(coreProgram, sig, "PositionUnfrozen");
+      expect(event.user.toBase58()).to.equal(kycUser.publicKey.toBase58());
+
+      // Unfrozen, the open request can be cancelled again
+      await coreProgram.methods
+        .cancelWithdrawal(BondType.UsTBill, new BN(2))
+        .accounts({
+          user: kycUser.publicKey,
+          protocolConfig: configPda,
+          yieldSource: yieldSourcePda,
+          userPosition: positionPda,
+          withdrawalRequest: openRequest,
+        })
+        .signers([kycUser])
+        .rpc();
+      const position = await coreProgram.account.userPosition.fetch(
+        positionPda
+      );
+      expect(position.isFrozen).to.be.false;
+      expect(position.currentShares.toNumber()).to.equal(100_000_000);
     });
   });
 
@@ -332,16 +1093,31 @@ describe("stablebond-core", () => {
       expect(addresses.size).to.equal(3);
     });
 
-    it("bond vault PDA is unique per (authority, bondType)", () => {
-      const authority = ctx.authority.publicKey;
+    it("user limit PDA is shared across bond types and unique per user", () => {
+      const userA = Keypair.generate().publicKey;
+      const userB = Keypair.generate().publicKey;
+
+      const [limitA] = findUserLimitPda(configPda, userA, coreProgram.programId);
+      const [limitAAgain] = findUserLimitPda(configPda, userA, coreProgram.programId);
+      const [limitB] = findUserLimitPda(configPda, userB, coreProgram.programId);
+      const [posA] = findUserPositionPda(
+        configPda,
+        userA,
+        BOND_TYPE_U8.UsTBill,
+        coreProgram.programId
+      );
+
+      expect(limitA.toBase58()).to.equal(limitAAgain.toBase58());
+      expect(limitA.toBase58()).to.not.equal(limitB.toBase58());
+      expect(limitA.toBase58()).to.not.equal(posA.toBase58());
+    });
 
+    it("bond vault PDA is unique per bondType and independent of authority", () => {
       const [vaultTBill] = findBondVaultPda(
-        authority,
         BOND_TYPE_U8.UsTBill,
         yieldProgram.programId
       );
       const [vaultJgb] = findBondVaultPda(
-        authority,
         BOND_TYPE_U8.JpJgb,
         yieldProgram.programId
       );
@@ -7,6 +7,7 @@ import {
   createAccount,
   mintTo,
   getAccount,
+  transfer,
 } from "@solana/spl-token";
 import { expect } from "chai";
 import {
@@ -19,7 +20,13 @@ import {
   findBondVaultPda,
   findBondShareMintPda,
   findBondCurrencyVaultPda,
+  findBondLockedSharesPda,
+  findProgramDataPda,
   findUserSharesPda,
+  expectEvent,
+  applyVaultChange,
+  LOCALNET_TIMELOCK_DELAY,
+  LOCKED_SHARES,
   TestContext,
 } from "./helpers/setup";
 
@@ -28,6 +35,7 @@ describe("stablebond-yield", () => {
   let program: Program;
   let ctx: TestContext;
   let usdcMint: PublicKey;
+  let authorityCurrency: PublicKey;
 
   before(async () => {
     provider = anchor.AnchorProvider.env();
@@ -35,35 +43,49 @@ describe("stablebond-yield", () => {
     program = anchor.workspace.StablebondYield as Program;
     ctx = await setupTestContext(provider);
     usdcMint = ctx.usdcMint;
+    // Funds the locked seed deposit of each vault initialized below
+    authorityCurrency = await createAndFundTokenAccount(
+      ctx.connection,
+      ctx.authority,
+      usdcMint,
+      ctx.authority.publicKey,
+      1_000_000
+    );
   });
 
   describe("initialize_vault", () => {
     it("initializes a US T-Bill vault", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
       const [shareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
       const [currencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
 
       await program.methods
-        .initializeVault(BondType.UsTBill, 450, 450, new BN(0))
+        .initializeVault(BondType.UsTBill, 450, 450, new BN(0), null)
         .accounts({
           authority: ctx.authority.publicKey,
+          deployer: ctx.provider.wallet.publicKey,
+          program: program.programId,
+          programData: findProgramDataPda(program.programId)[0],
           vaultConfig: vaultPda,
           currencyMint: usdcMint,
           shareMint: shareMintPda,
           currencyVault: currencyVaultPda,
+          authorityCurrency,
+          lockedShares: findBondLockedSharesPda(
+            BOND_TYPE_U8.UsTBill,
+            program.programId
+          )[0],
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -76,37 +98,55 @@ describe("stablebond-yield", () => {
       );
       expect(vault.targetApyBps).to.equal(450);
       expect(vault.navPerShare.toNumber()).to.equal(1_000_000);
-      expect(vault.totalDeposits.toNumber()).to.equal(0);
-      expect(vault.totalShares.toNumber()).to.equal(0);
+      expect(vault.totalDeposits.toNumber()).to.equal(LOCKED_SHARES);
+      expect(vault.totalShares.toNumber()).to.equal(LOCKED_SHARES);
       expect(vault.isActive).to.be.true;
+
+      const [lockedSharesPda] = findBondLockedSharesPda(
+        BOND_TYPE_U8.UsTBill,
+        program.programId
+      );
+      const locked = await getTokenBalance(ctx.connection, lockedSharesPda);
+      expect(Number(locked)).to.equal(LOCKED_SHARES);
+      const vaultBalance = await getTokenBalance(
+        ctx.connection,
+        currencyVaultPda
+      );
+      expect(Number(vaultBalance)).to.equal(LOCKED_SHARES);
     });
 
     it("initializes a JP JGB vault", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.JpJgb,
         program.programId
       );
       const [shareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.JpJgb,
         program.programId
       );
       const [currencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.JpJgb,
         program.programId
       );
 
       await program.methods
-        .initializeVault(BondType.JpJgb, 40, 40, new BN(0))
+        .initializeVault(BondType.JpJgb, 40, 40, new BN(0), null)
         .accounts({
           authority: ctx.authority.publicKey,
+          deployer: ctx.provider.wallet.publicKey,
+          program: program.programId,
+          programData: findProgramDataPda(program.programId)[0],
           vaultConfig: vaultPda,
           currencyMint: usdcMint,
           shareMint: shareMintPda,
           currencyVault: currencyVaultPda,
+          authorityCurrency,
+          lockedShares: findBondLockedSharesPda(
+            BOND_TYPE_U8.JpJgb,
+            program.programId
+          )[0],
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -119,31 +159,37 @@ describe("stablebond-yield", () => {
 
     it("rejects APY above 50%", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         program.programId
       );
       const [shareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         program.programId
       );
       const [currencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.MxCetes,
         program.programId
       );
 
       try {
         await program.methods
-          .initializeVault(BondType.MxCetes, 5001, 5001, new BN(0))
+          .initializeVault(BondType.MxCetes, 5001, 5001, new BN(0), null)
           .accounts({
             authority: ctx.authority.publicKey,
+            deployer: ctx.provider.wallet.publicKey,
+            program: program.programId,
+            programData: findProgramDataPda(program.programId)[0],
             vaultConfig: vaultPda,
             currencyMint: usdcMint,
             shareMint: shareMintPda,
             currencyVault: currencyVaultPda,
+            authorityCurrency,
+            lockedShares: findBondLockedSharesPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           })
@@ -154,6 +200,117 @@ describe("stablebond-yield", () => {
         expect(err.toString()).to.include("InvalidApy");
       }
     });
+
+    it("rejects a share transfer hook on an SPL Token share mint", async () => {
+      try {
+        await program.methods
+          .initializeVault(
+            BondType.MxCetes,
+            900,
+            900,
+            new BN(0),
+            Keypair.generate().publicKey
+          )
+          .accounts({
+            authority: ctx.authority.publicKey,
+            deployer: ctx.provider.wallet.publicKey,
+            program: program.programId,
+            programData: findProgramDataPda(program.programId)[0],
+            vaultConfig: findBondVaultPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            currencyMint: usdcMint,
+            shareMint: findBondShareMintPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            currencyVault: findBondCurrencyVaultPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            authorityCurrency,
+            lockedShares: findBondLockedSharesPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([ctx.authority])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("TransferHookRequiresToken2022");
+      }
+    });
+
+    it("rejects a deployer that is not the upgrade authority", async () => {
+      try {
+        await program.methods
+          .initializeVault(BondType.MxCetes, 900, 900, new BN(0), null)
+          .accounts({
+            authority: ctx.authority.publicKey,
+            deployer: ctx.authority.publicKey,
+            program: program.programId,
+            programData: findProgramDataPda(program.programId)[0],
+            vaultConfig: findBondVaultPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            currencyMint: usdcMint,
+            shareMint: findBondShareMintPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            currencyVault: findBondCurrencyVaultPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            authorityCurrency,
+            lockedShares: findBondLockedSharesPda(
+              BOND_TYPE_U8.MxCetes,
+              program.programId
+            )[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          })
+          .signers([ctx.authority])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("NotUpgradeAuthority");
+      }
+    });
+
+    it("rejects share metadata on an SPL Token share mint", async () => {
+      try {
+        await program.methods
+          .setShareMetadata("Stablebond US T-Bill Share", "sbUSTB", "")
+          .accounts({
+            authority: ctx.authority.publicKey,
+            vaultConfig: findBondVaultPda(
+              BOND_TYPE_U8.UsTBill,
+              program.programId
+            )[0],
+            shareMint: findBondShareMintPda(
+              BOND_TYPE_U8.UsTBill,
+              program.programId
+            )[0],
+            shareTokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([ctx.authority])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("ShareMetadataRequiresToken2022");
+      }
+    });
   });
 
   describe("deposit & withdraw", () => {
@@ -165,18 +322,12 @@ describe("stablebond-yield", () => {
     let userSharesPda: PublicKey;
 
     before(async () => {
-      [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
-        BOND_TYPE_U8.UsTBill,
-        program.programId
-      );
+      [vaultPda] = findBondVaultPda(BOND_TYPE_U8.UsTBill, program.programId);
       [shareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
       [currencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
@@ -221,19 +372,28 @@ describe("stablebond-yield", () => {
           user: ctx.user.publicKey,
           vaultConfig: vaultPda,
           currencyVault: currencyVaultPda,
+          currencyMint: usdcMint,
           shareMint: shareMintPda,
           userCurrency,
           userSharesAta,
           userShares: userSharesPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          extraAccountMetaList: null,
+          whitelistEntry: null,
+          sovereignIdentity: null,
         })
         .signers([ctx.user])
         .rpc();
 
       const vault = await program.account.bondVault.fetch(vaultPda);
-      expect(vault.totalDeposits.toNumber()).to.equal(depositAmount);
-      expect(vault.totalShares.toNumber()).to.equal(depositAmount);
+      expect(vault.totalDeposits.toNumber()).to.equal(
+        LOCKED_SHARES + depositAmount
+      );
+      expect(vault.totalShares.toNumber()).to.equal(
+        LOCKED_SHARES + depositAmount
+      );
 
       const shareBalance = await getTokenBalance(ctx.connection, userSharesAta);
       expect(Number(shareBalance)).to.equal(depositAmount);
@@ -247,12 +407,17 @@ describe("stablebond-yield", () => {
             user: ctx.user.publicKey,
             vaultConfig: vaultPda,
             currencyVault: currencyVaultPda,
+            currencyMint: usdcMint,
             shareMint: shareMintPda,
             userCurrency,
             userSharesAta,
             userShares: userSharesPda,
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            extraAccountMetaList: null,
+            whitelistEntry: null,
+            sovereignIdentity: null,
           })
           .signers([ctx.user])
           .rpc();
@@ -271,17 +436,21 @@ describe("stablebond-yield", () => {
           user: ctx.user.publicKey,
           vaultConfig: vaultPda,
           currencyVault: currencyVaultPda,
+          currencyMint: usdcMint,
           shareMint: shareMintPda,
           userCurrency,
           userSharesAta,
           userShares: userSharesPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([ctx.user])
         .rpc();
 
       const vault = await program.account.bondVault.fetch(vaultPda);
-      expect(vault.totalShares.toNumber()).to.equal(500_000_000);
+      expect(vault.totalShares.toNumber()).to.equal(
+        LOCKED_SHARES + 500_000_000
+      );
 
       const shareBalance = await getTokenBalance(ctx.connection, userSharesAta);
       expect(Number(shareBalance)).to.equal(500_000_000);
@@ -295,11 +464,13 @@ describe("stablebond-yield", () => {
             user: ctx.user.publicKey,
             vaultConfig: vaultPda,
             currencyVault: currencyVaultPda,
+            currencyMint: usdcMint,
             shareMint: shareMintPda,
             userCurrency,
             userSharesAta,
             userShares: userSharesPda,
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([ctx.user])
           .rpc();
@@ -308,12 +479,94 @@ describe("stablebond-yield", () => {
         expect(err.toString()).to.include("InsufficientShares");
       }
     });
+
+    it("blocks share transfers after freeze_shares until thawed", async () => {
+      const officer = Keypair.generate();
+      await program.methods
+        .setComplianceOfficer(officer.publicKey)
+        .accounts({
+          authority: ctx.authority.publicKey,
+          vaultConfig: vaultPda,
+        })
+        .signers([ctx.authority])
+        .rpc();
+
+      const freezeAccounts = (signer: PublicKey) => ({
+        complianceOfficer: signer,
+        vaultConfig: vaultPda,
+        shareMint: shareMintPda,
+        sharesAccount: userSharesAta,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      });
+
+      // Only the vault's compliance officer may freeze, not the authority
+      try {
+        await program.methods
+          .freezeShares({ sanctions: {} })
+          .accounts(freezeAccounts(ctx.authority.publicKey))
+          .signers([ctx.authority])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+
+      const sig = await program.methods
+        .freezeShares({ sanctions: {} })
+        .accounts(freezeAccounts(officer.publicKey))
+        .signers([officer])
+        .rpc({ commitment: "confirmed" });
+      const frozen = await getAccount(ctx.connection, userSharesAta);
+      expect(frozen.isFrozen).to.be.true;
+      const event = await expectEvent(program, sig, "SharesFrozen");
+      expect(event.tokenAccount.toBase58()).to.equal(userSharesAta.toBase58());
+      expect(event.owner.toBase58()).to.equal(ctx.user.publicKey.toBase58());
+
+      const recipient = await createAccount(
+        ctx.connection,
+        ctx.authority,
+        shareMintPda,
+        ctx.authority.publicKey,
+        Keypair.generate()
+      );
+      try {
+        await transfer(
+          ctx.connection,
+          ctx.user,
+          userSharesAta,
+          recipient,
+          ctx.user,
+          1_000_000
+        );
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        // spl-token TokenError::AccountFrozen
+        expect(err.toString()).to.include("0x11");
+      }
+      expect(await getTokenBalance(ctx.connection, recipient)).to.equal(0n);
+
+      await program.methods
+        .thawShares()
+        .accounts(freezeAccounts(officer.publicKey))
+        .signers([officer])
+        .rpc();
+      await transfer(
+        ctx.connection,
+        ctx.user,
+        userSharesAta,
+        recipient,
+        ctx.user,
+        1_000_000
+      );
+      expect(await getTokenBalance(ctx.connection, recipient)).to.equal(
+        1_000_000n
+      );
+    });
   });
 
   describe("accrue_yield", () => {
     it("accrues yield based on time elapsed", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
@@ -323,35 +576,42 @@ describe("stablebond-yield", () => {
 
       await new Promise((resolve) => setTimeout(resolve, 2000));
 
-      await program.methods
+      const sig = await program.methods
         .accrueYield()
         .accounts({
           vaultConfig: vaultPda,
           bondPriceOracle: SystemProgram.programId,
         })
-        .rpc();
+        .rpc({ commitment: "confirmed" });
 
       const vaultAfter = await program.account.bondVault.fetch(vaultPda);
       const navAfter = vaultAfter.navPerShare.toNumber();
 
       expect(navAfter).to.be.at.least(navBefore);
+
+      const event = await expectEvent(program, sig, "YieldAccrued");
+      expect(event.vault.toBase58()).to.equal(vaultPda.toBase58());
+      expect(event.bondType).to.equal(BOND_TYPE_U8.UsTBill);
+      expect(event.oldNav.toNumber()).to.equal(navBefore);
+      expect(event.newNav.toNumber()).to.equal(navAfter);
+      expect(event.apyBps.toNumber()).to.equal(450);
+      expect(event.oraclePriced).to.be.false;
+      expect(event.elapsed.toNumber()).to.be.greaterThan(0);
+      expect(event.keeper.toBase58()).to.equal(PublicKey.default.toBase58());
     });
 
     it("stops accruing after maturity date", async () => {
       const pastMaturity = Math.floor(Date.now() / 1000) - 86400;
 
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.BrTesouro,
         program.programId
       );
       const [shareMintPda] = findBondShareMintPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.BrTesouro,
         program.programId
       );
       const [currencyVaultPda] = findBondCurrencyVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.BrTesouro,
         program.programId
       );
@@ -361,15 +621,25 @@ describe("stablebond-yield", () => {
           BondType.BrTesouro,
           1300,
           1300,
-          new BN(pastMaturity)
+          new BN(pastMaturity),
+          null
         )
         .accounts({
           authority: ctx.authority.publicKey,
+          deployer: ctx.provider.wallet.publicKey,
+          program: program.programId,
+          programData: findProgramDataPda(program.programId)[0],
           vaultConfig: vaultPda,
           currencyMint: usdcMint,
           shareMint: shareMintPda,
           currencyVault: currencyVaultPda,
+          authorityCurrency,
+          lockedShares: findBondLockedSharesPda(
+            BOND_TYPE_U8.BrTesouro,
+            program.programId
+          )[0],
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -390,59 +660,64 @@ describe("stablebond-yield", () => {
     });
   });
 
-  describe("update_apy", () => {
-    it("authority can update APY", async () => {
+  describe("timelocked APY change", () => {
+    it("authority can update APY through the queue", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
 
-      await program.methods
-        .updateApy(500)
-        .accounts({
-          authority: ctx.authority.publicKey,
-          vaultConfig: vaultPda,
-        })
-        .signers([ctx.authority])
-        .rpc();
+      let vault = await program.account.bondVault.fetch(vaultPda);
+      expect(vault.timelockDelay.toNumber()).to.equal(LOCALNET_TIMELOCK_DELAY);
 
-      const vault = await program.account.bondVault.fetch(vaultPda);
+      const sig = await applyVaultChange(program, vaultPda, ctx.authority, {
+        apy: { 0: 500 },
+      });
+
+      vault = await program.account.bondVault.fetch(vaultPda);
       expect(vault.targetApyBps).to.equal(500);
+      const event = await expectEvent(program, sig, "VaultApyUpdated");
+      expect(event.newApyBps).to.equal(500);
     });
 
-    it("non-authority cannot update APY", async () => {
+    it("non-authority cannot queue an APY change", async () => {
       const [vaultPda] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
 
       try {
-        await program.methods
-          .updateApy(600)
-          .accounts({
-            authority: ctx.user.publicKey,
-            vaultConfig: vaultPda,
-          })
-          .signers([ctx.user])
-          .rpc();
+        await applyVaultChange(program, vaultPda, ctx.user, { apy: { 0: 600 } });
         expect.fail("Should have thrown");
       } catch (err: any) {
         expect(err.toString()).to.include("Unauthorized");
       }
     });
+
+    it("rejects a timelock delay below the floor", async () => {
+      const [vaultPda] = findBondVaultPda(
+        BOND_TYPE_U8.UsTBill,
+        program.programId
+      );
+
+      try {
+        await applyVaultChange(program, vaultPda, ctx.authority, {
+          timelockDelay: { 0: new BN(0) },
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidTimelockDelay");
+      }
+    });
   });
 
   describe("multi-bond vaults", () => {
     it("each bond type gets its own independent vault", async () => {
       const [tbillVault] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.UsTBill,
         program.programId
       );
       const [jgbVault] = findBondVaultPda(
-        ctx.authority.publicKey,
         BOND_TYPE_U8.JpJgb,
         program.programId
       );